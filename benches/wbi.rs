@@ -0,0 +1,53 @@
+//! WBI 签名吞吐
+//!
+//! 预置密钥缓存后，在多个线程中并发签名，观察缓存命中时的吞吐是否随线程数线性增长。
+
+use std::collections::BTreeMap;
+use std::thread;
+
+use bpi_rs::utils::wbi::{ WbiKeys, cached_wbi_keys, store_wbi_keys };
+use criterion::{ BenchmarkId, Criterion, Throughput, criterion_group, criterion_main };
+
+const SIGNS_PER_THREAD: u64 = 1000;
+
+fn params() -> BTreeMap<String, String> {
+    BTreeMap::from([
+        ("bvid".to_string(), "BV18x411c74j".to_string()),
+        ("cid".to_string(), "21448".to_string()),
+        ("up_mid".to_string(), "46473".to_string()),
+        ("web_location".to_string(), "0.0".to_string()),
+    ])
+}
+
+fn sign_cached() {
+    let keys = cached_wbi_keys().expect("keys stored before benchmark");
+    let mut params = params();
+    keys.sign(&mut params);
+    std::hint::black_box(params);
+}
+
+fn bench_wbi_sign(c: &mut Criterion) {
+    store_wbi_keys(WbiKeys::new("7cd084941338484aae1ad9425b84077c", "4932caff0ff746eab6f01bf08b70ac45"));
+
+    let mut group = c.benchmark_group("wbi_sign_concurrent");
+    for threads in [1u64, 2, 4, 8] {
+        group.throughput(Throughput::Elements(threads * SIGNS_PER_THREAD));
+        group.bench_with_input(BenchmarkId::from_parameter(threads), &threads, |b, &threads| {
+            b.iter(|| {
+                thread::scope(|s| {
+                    for _ in 0..threads {
+                        s.spawn(|| {
+                            for _ in 0..SIGNS_PER_THREAD {
+                                sign_cached();
+                            }
+                        });
+                    }
+                });
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_wbi_sign);
+criterion_main!(benches);
@@ -74,8 +74,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_activity_info() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let sid = 4017552;
         let bvid = Some("BV1mKY4e8ELy");
 
@@ -87,8 +88,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_activity_info_without_bvid() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let sid = 4017552;
 
         let result = bpi.activity_info(sid, None).await?;
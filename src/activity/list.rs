@@ -135,8 +135,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_activity_list() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         // 测试获取活动列表
         let result = bpi.activity_list(Some("1,3"), None, None, Some(1), Some(4)).await?;
@@ -152,8 +153,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_activity_list_simple() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         // 测试简化版本获取活动列表
         let result = bpi.activity_list_default().await?;
@@ -168,8 +170,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_activity_item_fields() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let result = bpi.activity_list(Some("1,3"), None, None, Some(1), Some(1)).await?;
         let data = result.into_data()?;
@@ -108,18 +108,18 @@ impl BpiClient {
         Ok(result)
     }
 
-    /// 收藏文章
+    /// 取消收藏文章
     ///
     /// # 参数
     /// | 名称   | 类型  | 说明             |
     /// | ------ | ----- | ---------------- |
-    /// | `id`   | i64   | 文章 cvid (必要) |
+    /// | `id`   | u64   | 文章 cvid (必要) |
     ///
     /// # 文档
     /// [收藏文章](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/article/action.md#收藏文章)
     pub async fn article_unfavorite(
         &self,
-        id: i64
+        id: u64
     ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
         let csrf = self.csrf()?;
 
@@ -131,7 +131,7 @@ impl BpiClient {
                     ("csrf", csrf),
                 ]
             )
-            .send_bpi("收藏文章").await?;
+            .send_bpi("取消收藏文章").await?;
 
         Ok(result)
     }
@@ -144,8 +144,9 @@ mod tests {
     const TEST_CVID: u64 = 1;
     const TEST_UID: u64 = 91221505;
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_like_article() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         bpi.article_like(TEST_CVID, true).await
             .map(|_| ())
             .or_else(|e| {
@@ -154,8 +155,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_coin_article() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let multiply = 1;
 
         bpi.article_coin(TEST_CVID, TEST_UID, multiply).await
@@ -166,8 +168,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_favorite_article() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         bpi.article_favorite(TEST_CVID).await?;
         Ok(())
@@ -126,8 +126,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_articles_info() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let rlid = 207146;
 
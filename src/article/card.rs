@@ -4,6 +4,7 @@
 
 use super::models::{ ArticleAuthor, ArticleCategory, ArticleMedia, ArticleStats };
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use crate::utils::web_location::WebLocation;
 use serde::{ Deserialize, Serialize };
 
 /// 卡片信息响应类型
@@ -342,7 +343,7 @@ impl BpiClient {
     /// # 文档
     /// [获取专栏显示卡片信息](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/article/card.md#获取专栏显示卡片信息)
     pub async fn article_cards(&self, ids: &str) -> Result<CardResponse, BpiError> {
-        let params = vec![("ids", ids.to_string()), ("web_location", "333.1305".to_string())];
+        let params = vec![("ids", ids.to_string()), ("web_location", self.web_location(WebLocation::ARTICLE))];
 
         let params = self.get_wbi_sign2(params).await?;
 
@@ -361,8 +362,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_article_cards() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let ids = "av2,cv1,cv2";
 
@@ -0,0 +1,115 @@
+//! 我收藏的专栏
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/article)
+
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use serde::{ Deserialize, Serialize };
+
+/// 收藏专栏的作者
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FavoriteArticleAuthor {
+    /// 用户ID
+    #[serde(default)]
+    pub mid: i64,
+    /// 用户昵称
+    #[serde(default)]
+    pub name: String,
+    /// 用户头像
+    #[serde(default)]
+    pub face: String,
+}
+
+/// 收藏的专栏
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FavoriteArticle {
+    /// 专栏 cvid
+    pub id: i64,
+    /// 标题
+    pub title: String,
+    /// 摘要
+    #[serde(default)]
+    pub summary: String,
+    /// 头图
+    #[serde(default)]
+    pub banner_url: String,
+    /// 封面图
+    #[serde(default)]
+    pub image_urls: Vec<String>,
+    /// 发布时间秒级时间戳
+    #[serde(default)]
+    pub publish_time: i64,
+    /// 收藏时间秒级时间戳
+    #[serde(default)]
+    pub favorite_time: i64,
+    /// 是否有效 已删除的专栏为 false
+    #[serde(default)]
+    pub valid: bool,
+    #[serde(default)]
+    pub author: FavoriteArticleAuthor,
+}
+
+/// 分页信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FavoriteArticlePage {
+    #[serde(default)]
+    pub pn: u32,
+    #[serde(default)]
+    pub ps: u32,
+    #[serde(default)]
+    pub total: u64,
+}
+
+/// 收藏专栏列表
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FavoriteArticleList {
+    #[serde(default)]
+    pub favorites: Vec<FavoriteArticle>,
+    #[serde(default)]
+    pub page: FavoriteArticlePage,
+}
+
+impl BpiClient {
+    /// 获取我收藏的专栏
+    ///
+    /// 注意: 此接口需要登录态 (Cookie: SESSDATA)
+    ///
+    /// # 参数
+    /// | 名称 | 类型          | 说明             |
+    /// | ---- | ------------- | ---------------- |
+    /// | `pn` | `Option<u32>` | 页码，默认 1     |
+    /// | `ps` | `Option<u32>` | 每页项数，默认 16 |
+    ///
+    /// # 文档
+    /// [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/article)
+    pub async fn article_favorites_list(
+        &self,
+        pn: Option<u32>,
+        ps: Option<u32>
+    ) -> Result<BpiResponse<FavoriteArticleList>, BpiError> {
+        self
+            .get("https://api.bilibili.com/x/article/favorites/list/all")
+            .query(
+                &[
+                    ("pn", pn.unwrap_or(1).to_string()),
+                    ("ps", ps.unwrap_or(16).to_string()),
+                ]
+            )
+            .send_bpi("获取收藏的专栏").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_article_favorites_list() -> Result<(), Box<BpiError>> {
+        let bpi = crate::test_client!(Authed);
+        let data = bpi.article_favorites_list(Some(1), None).await?.into_data()?;
+
+        tracing::info!("收藏专栏总数: {}", data.page.total);
+
+        Ok(())
+    }
+}
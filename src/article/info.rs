@@ -96,8 +96,9 @@ mod tests {
     const TEST_CVID: i64 = 2;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_article_info() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let cvid = TEST_CVID;
 
@@ -112,8 +113,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_article_stats() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let result = bpi.article_info(TEST_CVID).await?;
 
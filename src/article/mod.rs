@@ -8,6 +8,7 @@ pub mod action;
 pub mod articles;
 pub mod card;
 pub mod category;
+pub mod favorites;
 pub mod info;
 mod models;
 pub mod view;
@@ -362,8 +362,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_article_view() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let cvid = 2;
 
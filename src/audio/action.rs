@@ -186,8 +186,9 @@ mod tests {
     const TEST_SID: u64 = 13603;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_collection_status() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.audio_collection_status(TEST_SID).await?;
 
         let data = result.into_data()?;
@@ -197,8 +198,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_coin_count() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.audio_coin_count(TEST_SID).await?;
 
         let data = result.data.unwrap();
@@ -208,8 +210,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_coin_audio() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         bpi.audio_coin(TEST_SID, 1).await
             .map(|_| ())
             .or_else(|e| {
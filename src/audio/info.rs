@@ -197,8 +197,9 @@ mod tests {
     use super::*;
     const TEST_SID: u64 = 13603;
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_info() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.audio_info(TEST_SID).await?;
         let data = result.data.unwrap();
         assert!(!data.title.is_empty());
@@ -209,8 +210,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_tags() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.audio_tags(TEST_SID).await?;
         let data = result.into_data()?;
 
@@ -220,8 +222,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_members() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.audio_members(TEST_SID).await?;
         let data = result.into_data()?;
 
@@ -231,8 +234,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_lyric() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let result = bpi.audio_lyric(TEST_SID).await?;
 
@@ -244,8 +248,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_info_fields() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let result = bpi.audio_info(13598).await?;
 
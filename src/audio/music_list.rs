@@ -282,11 +282,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_collections_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.audio_collections_list(1, 2).await;
         if let Ok(response) = result {
             assert_eq!(response.code, 0);
@@ -297,8 +296,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_collection_info() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.audio_collection_info(15967839).await;
         if let Ok(response) = result {
             assert_eq!(response.code, 0);
@@ -306,8 +306,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_hot_menu() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.audio_hot_menu(1, 3).await;
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -320,8 +321,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_rank_menu() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.audio_rank_menu(1, 6).await;
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -130,8 +130,9 @@ mod tests {
     const TEST_SID: u64 = 13603;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_stream_url_web() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.audio_stream_url_web(TEST_SID).await;
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -144,8 +145,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_audio_stream_url() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let result = bpi.audio_stream_url(15664, AudioQuality::HighQuality).await;
         assert!(result.is_ok());
         let response = result.unwrap();
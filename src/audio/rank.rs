@@ -165,8 +165,9 @@ mod tests {
     const TEST_LIST_ID: u64 = 76;
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_audio_rank_period() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let result = bpi.audio_rank_period(2).await?;
         let data = result.into_data()?;
         tracing::info!("{:#?}", data);
@@ -186,8 +187,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_audio_rank_detail() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let result = bpi.audio_rank_detail(TEST_LIST_ID).await?;
         let data = result.into_data()?;
         tracing::info!("{:#?}", data);
@@ -201,8 +203,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_audio_rank_music_list() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let result = bpi.audio_rank_music_list(TEST_LIST_ID).await?;
         let data = result.into_data()?;
         tracing::info!("{:#?}", data);
@@ -218,8 +221,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_update_audio_rank_subscribe() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         bpi.audio_rank_subscribe(1, Some(76)).await?;
 
         Ok(())
@@ -41,8 +41,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_audio_status_number() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.audio_status_number(15664).await?;
         let data = result.into_data()?;
         tracing::info!("{:#?}", data);
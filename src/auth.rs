@@ -1,11 +1,104 @@
+use std::path::Path;
+
+use crate::BpiError;
+use crate::utils::app_sign::AppKey;
+use crate::session::{ read_by_extension, write_by_extension };
+
 /// B站账号登录信息
-#[derive(Debug, Clone, serde::Deserialize)]
+///
+/// 除 Cookie 外的字段都是可选的元数据，缺省时不写入文件，旧的账号文件可以直接读取。
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct Account {
     pub dede_user_id: String,
     pub dede_user_id_ckmd5: String,
     pub sessdata: String,
     pub bili_jct: String,
     pub buvid3: String,
+    /// APP 登录凭证，只在调用 APP 接口时需要
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_token: Option<AppToken>,
+    /// `SESSDATA` 过期秒时间戳，从 Cookie 值中解析
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sessdata_expires_at: Option<i64>,
+    /// Cookie 刷新令牌，见 [`BpiClient::set_refresh_token`](crate::BpiClient::set_refresh_token)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// 登录秒时间戳
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub login_at: Option<i64>,
+    /// 登录方式
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<AccountSource>,
+}
+
+/// 账号的登录方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountSource {
+    /// Web 扫码登录
+    Qr,
+    /// TV 端扫码登录
+    Tv,
+    /// 密码登录
+    Password,
+    /// 短信登录
+    Sms,
+    /// 由 APP access_key 换取
+    Sso,
+    /// 从 Cookie 字符串等外部来源导入
+    Imported,
+}
+
+/// 从 `SESSDATA` 的值中解析过期秒时间戳
+///
+/// `SESSDATA` 形如 `xxxx%2C1700000000%2Cxxxx`，第二段为过期时间。
+pub fn parse_sessdata_expiry(sessdata: &str) -> Option<i64> {
+    let decoded = sessdata.replace("%2C", ",").replace("%2c", ",");
+    decoded.split(',').nth(1)?.parse().ok().filter(|ts| *ts > 0)
+}
+
+/// APP 登录凭证
+///
+/// APP 接口以 `access_key` 而非 Cookie 鉴权，且需使用签发凭证的 appkey 签名，
+/// 见 [`BilibiliRequest::with_access_key`](crate::BilibiliRequest::with_access_key)。
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AppToken {
+    pub access_key: String,
+    /// 刷新令牌，见 [`BpiClient::login_refresh_app_token`](crate::BpiClient::login_refresh_app_token)
+    pub refresh_token: String,
+    /// 签发凭证的 appkey
+    pub appkey: String,
+    #[serde(default)]
+    pub mid: u64,
+    /// 过期秒时间戳，0 表示未知
+    #[serde(default)]
+    pub expires_at: i64,
+}
+
+impl AppToken {
+    pub fn new(
+        access_key: impl Into<String>,
+        refresh_token: impl Into<String>,
+        key: AppKey,
+    ) -> Self {
+        Self {
+            access_key: access_key.into(),
+            refresh_token: refresh_token.into(),
+            appkey: key.appkey.to_string(),
+            mid: 0,
+            expires_at: 0,
+        }
+    }
+
+    /// 签发凭证的 APP，appkey 不在 [`AppKey::KNOWN`] 中时为 `None`
+    pub fn app_key(&self) -> Option<AppKey> {
+        AppKey::from_appkey(&self.appkey)
+    }
+
+    /// 是否将在 `margin_secs` 秒内过期，过期时间未知时为 `false`
+    pub fn expires_within(&self, margin_secs: i64) -> bool {
+        self.expires_at > 0 && self.expires_at - chrono::Utc::now().timestamp() <= margin_secs
+    }
 }
 
 impl Account {
@@ -17,20 +110,48 @@ impl Account {
         buvid3: String,
     ) -> Self {
         Self {
+            sessdata_expires_at: parse_sessdata_expiry(&sessdata),
             dede_user_id,
             dede_user_id_ckmd5,
             sessdata,
             bili_jct,
             buvid3,
+            ..Default::default()
         }
     }
 
+    /// `SESSDATA` 是否将在 `margin_secs` 秒内过期，过期时间未知时为 `false`
+    ///
+    /// 可据此决定何时调用 [`BpiClient::refresh_cookies`](crate::BpiClient::refresh_cookies)。
+    pub fn sessdata_expires_within(&self, margin_secs: i64) -> bool {
+        self.sessdata_expires_at
+            .is_some_and(|ts| ts - chrono::Utc::now().timestamp() <= margin_secs)
+    }
+
     pub fn is_complete(&self) -> bool {
         !self.dede_user_id.is_empty()
             && !self.sessdata.is_empty()
             && !self.bili_jct.is_empty()
             && !self.buvid3.is_empty()
     }
+
+    /// 拼接为 `Cookie` 请求头
+    pub fn cookie_header(&self) -> String {
+        format!(
+            "DedeUserID={}; DedeUserID__ckMd5={}; SESSDATA={}; bili_jct={}; buvid3={}",
+            self.dede_user_id, self.dede_user_id_ckmd5, self.sessdata, self.bili_jct, self.buvid3
+        )
+    }
+
+    /// 保存到文件，扩展名为 `.toml` 时保存为 TOML，否则为 JSON
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), BpiError> {
+        write_by_extension(path.as_ref(), self)
+    }
+
+    /// 从 [`Account::save_to_file`] 保存的文件读取，格式同样按扩展名判断
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, BpiError> {
+        read_by_extension(path.as_ref())
+    }
 }
 
 impl Account {
@@ -74,3 +195,69 @@ buvid3 = "your_buvid3_here"
     fs::write(path, template)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sessdata_expiry() {
+        assert_eq!(parse_sessdata_expiry("a1b2c3%2C1700000000%2Cd4e5f*c1"), Some(1700000000));
+        assert_eq!(parse_sessdata_expiry("a1b2c3%2c1700000000%2cd4e5f*c1"), Some(1700000000));
+        assert_eq!(parse_sessdata_expiry("a1b2c3,1700000000,d4e5f*c1"), Some(1700000000));
+
+        for garbage in ["", "a1b2c3", "a1b2c3%2Cnever%2Cd4", "a1b2c3%2C0%2Cd4", "a1b2c3%2C-1%2Cd4"] {
+            assert_eq!(parse_sessdata_expiry(garbage), None, "{:?}", garbage);
+        }
+    }
+
+    #[test]
+    fn test_expires_within() {
+        let now = chrono::Utc::now().timestamp();
+        let sessdata = |expires_at: i64| format!("a1b2c3%2C{}%2Cd4", expires_at);
+        let account = |sessdata: String| {
+            Account::new("1".into(), "md5".into(), sessdata, "jct".into(), "buvid".into())
+        };
+
+        assert!(account(sessdata(now + 60)).sessdata_expires_within(3600));
+        assert!(!account(sessdata(now + 7200)).sessdata_expires_within(3600));
+        assert!(!account("a1b2c3".into()).sessdata_expires_within(i64::MAX));
+
+        let mut token = AppToken::new("key", "refresh", AppKey::ANDROID);
+        assert!(!token.expires_within(i64::MAX));
+        token.expires_at = now + 60;
+        assert!(token.expires_within(3600));
+        token.expires_at = now + 7200;
+        assert!(!token.expires_within(3600));
+    }
+
+    #[test]
+    fn test_load_old_account_file() {
+        let dir = std::env::temp_dir().join(format!("bpi-account-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let json = dir.join("account.json");
+        std::fs::write(
+            &json,
+            r#"{"dede_user_id":"1","dede_user_id_ckmd5":"md5","sessdata":"s%2C1700000000%2Cx","bili_jct":"jct","buvid3":"buvid"}"#
+        ).unwrap();
+        let toml = dir.join("account.toml");
+        std::fs::write(
+            &toml,
+            "dede_user_id = \"1\"\ndede_user_id_ckmd5 = \"md5\"\nsessdata = \"s\"\nbili_jct = \"jct\"\nbuvid3 = \"buvid\"\n"
+        ).unwrap();
+
+        let from_json = Account::load_from_file(&json);
+        let from_toml = Account::load_from_file(&toml);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        for account in [from_json.unwrap(), from_toml.unwrap()] {
+            assert!(account.is_complete());
+            assert!(account.app_token.is_none());
+            assert!(account.refresh_token.is_none());
+            assert!(account.login_at.is_none() && account.source.is_none());
+            // 旧文件没有记录过期时间，不从 SESSDATA 补算
+            assert!(account.sessdata_expires_at.is_none());
+        }
+    }
+}
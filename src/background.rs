@@ -0,0 +1,188 @@
+//! 后台任务登记与关闭
+//!
+//! crate 内部启动的后台任务（如直播信息流的心跳、Cookie 与 bili_ticket 的定期刷新）统一通过
+//! [`TaskRegistry`] 启动并登记。每个 client 有自己的登记表，[`BpiClient::with_credentials`]
+//! 派生的 client 与原 client 共用。退出前调用 [`BpiClient::shutdown`] 可通知该 client 的所有任务停止，
+//! 并等待它们结束，避免连接在运行时销毁时被强行中断。
+//!
+//! ```no_run
+//! # async fn run() {
+//! use std::time::Duration;
+//! use bpi_rs::BpiClient;
+//!
+//! let bpi = BpiClient::new();
+//! BpiClient::spawn_cookie_refresh_loop(bpi, Duration::from_secs(6 * 3600), |_| {});
+//! // ... 使用 client 订阅直播信息流等
+//!
+//! bpi.shutdown().await;
+//! # }
+//! ```
+
+use std::future::Future;
+use std::sync::{ Arc, Mutex };
+
+use tokio::sync::watch;
+use tokio::task::{ AbortHandle, JoinHandle };
+
+use crate::BpiClient;
+
+/// 后台任务登记表
+pub struct TaskRegistry {
+    shutdown: watch::Sender<bool>,
+    tasks: Mutex<Vec<(&'static str, JoinHandle<()>)>>,
+}
+
+impl std::fmt::Debug for TaskRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskRegistry")
+            .field("shutdown", &self.is_shutdown())
+            .field("active", &self.active())
+            .finish()
+    }
+}
+
+impl Default for TaskRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TaskRegistry {
+    pub fn new() -> Self {
+        Self { shutdown: watch::Sender::new(false), tasks: Mutex::new(Vec::new()) }
+    }
+
+    /// 启动并登记后台任务，关闭时任务在下一个等待点被取消
+    ///
+    /// 已关闭后启动的任务会立即结束。
+    pub fn spawn<F>(&self, name: &'static str, task: F) -> AbortHandle
+        where F: Future<Output = ()> + Send + 'static
+    {
+        let mut shutdown = self.shutdown.subscribe();
+        let handle = tokio::spawn(async move {
+            tokio::select! {
+                _ = task => {}
+                _ = shutdown.wait_for(|closed| *closed) => {
+                    tracing::debug!("后台任务 {} 已取消", name);
+                }
+            }
+        });
+        let abort = handle.abort_handle();
+
+        let mut tasks = self.tasks.lock().unwrap_or_else(|e| e.into_inner());
+        tasks.retain(|(_, handle)| !handle.is_finished());
+        tasks.push((name, handle));
+        abort
+    }
+
+    /// 是否已关闭
+    pub fn is_shutdown(&self) -> bool {
+        *self.shutdown.borrow()
+    }
+
+    /// 等待关闭信号，可用于长期运行的循环中及时退出
+    pub async fn cancelled(&self) {
+        let mut shutdown = self.shutdown.subscribe();
+        let _ = shutdown.wait_for(|closed| *closed).await;
+    }
+
+    /// 仍在运行的任务名
+    pub fn active(&self) -> Vec<&'static str> {
+        self.tasks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|(_, handle)| !handle.is_finished())
+            .map(|(name, _)| *name)
+            .collect()
+    }
+
+    /// 通知所有任务停止，不等待
+    pub fn close(&self) {
+        self.shutdown.send_replace(true);
+    }
+
+    /// 等待所有已登记的任务结束
+    pub async fn join(&self) {
+        let tasks = std::mem::take(&mut *self.tasks.lock().unwrap_or_else(|e| e.into_inner()));
+        for (name, handle) in tasks {
+            if let Err(e) = handle.await && !e.is_cancelled() {
+                tracing::warn!("后台任务 {} 异常退出: {}", name, e);
+            }
+        }
+    }
+
+    /// 通知所有任务停止并等待结束
+    pub async fn shutdown(&self) {
+        self.close();
+        self.join().await;
+    }
+
+    /// 重新开放登记表，之后启动的任务正常运行
+    ///
+    /// 应在 [`shutdown`](Self::shutdown) 完成后调用，否则尚未响应关闭信号的任务可能继续运行。
+    pub fn reset(&self) {
+        self.shutdown.send_replace(false);
+    }
+}
+
+impl BpiClient {
+    /// 关闭该 client 启动的所有后台任务并等待结束
+    ///
+    /// 关闭后该 client 不再启动新的后台任务，直播信息流、刷新循环等会随之结束，
+    /// 需要继续使用时调用 [`TaskRegistry::reset`]。通常在退出前调用。
+    pub async fn shutdown(&self) {
+        self.tasks.shutdown().await;
+    }
+
+    /// 该 client 的后台任务登记表
+    pub fn background_tasks(&self) -> &Arc<TaskRegistry> {
+        &self.tasks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_shutdown_cancels_tasks() {
+        let registry = TaskRegistry::new();
+        registry.spawn("forever", std::future::pending());
+        registry.spawn("quick", async {});
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(registry.active(), ["forever"]);
+
+        tokio::time::timeout(Duration::from_secs(1), registry.shutdown()).await.unwrap();
+        assert!(registry.is_shutdown());
+        assert!(registry.active().is_empty());
+
+        // 关闭后启动的任务立即结束
+        registry.spawn("late", std::future::pending());
+        tokio::time::timeout(Duration::from_secs(1), registry.join()).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(1), registry.cancelled()).await.unwrap();
+
+        registry.reset();
+        registry.spawn("after_reset", std::future::pending());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(registry.active(), ["after_reset"]);
+        registry.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_registry_per_client() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        let other = BpiClient::try_new_local().unwrap();
+        let derived = bpi.with_credentials(&crate::Account::default());
+
+        bpi.background_tasks().spawn("forever", std::future::pending());
+        tokio::time::timeout(Duration::from_secs(1), other.shutdown()).await.unwrap();
+        assert_eq!(bpi.background_tasks().active(), ["forever"]);
+        assert!(!bpi.background_tasks().is_shutdown());
+
+        tokio::time::timeout(Duration::from_secs(1), derived.shutdown()).await.unwrap();
+        assert!(bpi.background_tasks().is_shutdown());
+        assert!(bpi.background_tasks().active().is_empty());
+    }
+}
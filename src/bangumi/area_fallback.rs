@@ -0,0 +1,331 @@
+//! 番剧视频流地区限制回退
+//!
+//! 官方接口返回 -10403（地区限制）时，按顺序尝试调用方配置的代理解析服务器，
+//! 启用 `global` feature 时最后尝试国际版（bilibili.tv）接口。
+//! 代理服务器需与官方 `pgc/player/web/playurl` 接口保持相同的参数与返回格式。
+//!
+//! 发往代理服务器与国际版接口的请求不携带 Cookie 等登录凭据。
+
+use serde::{ Deserialize, Serialize };
+
+use super::videostream_url::{ BangumiVideoStreamData, bangumi_video_stream_params };
+use crate::models::{ Fnval, VideoQuality };
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 代理解析服务器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayurlProxy {
+    /// 地区标识，如 `hk`、`tw`、`th`
+    pub area: String,
+    /// 与官方接口兼容的 playurl 地址
+    pub url: String,
+}
+
+impl PlayurlProxy {
+    pub fn new(area: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            area: area.into(),
+            url: url.into(),
+        }
+    }
+}
+
+/// 视频流来源
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlayurlSource {
+    /// 官方接口
+    Official,
+    /// 代理解析服务器
+    Proxy {
+        area: String,
+        url: String,
+    },
+    /// 国际版接口
+    #[cfg(feature = "global")]
+    Intl,
+}
+
+/// 回退得到的视频流数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FallbackStreamData {
+    /// 官方接口或代理解析服务器返回的视频流
+    Bangumi(Box<BangumiVideoStreamData>),
+    /// 国际版接口返回的视频流
+    #[cfg(feature = "global")]
+    Intl(IntlPlayurl),
+}
+
+/// 国际版视频流中的一路音频或视频
+#[cfg(feature = "global")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IntlStreamResource {
+    /// 流地址，为空时该清晰度不可用（如需要会员）
+    pub url: String,
+    pub backup_url: Vec<String>,
+    pub bandwidth: u64,
+    pub codecs: String,
+    pub width: u32,
+    pub height: u32,
+    /// 文件大小，字节
+    pub size: u64,
+    /// 清晰度代码
+    pub quality: u32,
+}
+
+/// 国际版视频清晰度说明
+#[cfg(feature = "global")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IntlStreamInfo {
+    pub quality: u32,
+    /// 清晰度名称，如 `1080P`
+    pub desc_words: String,
+}
+
+/// 国际版视频轨道
+#[cfg(feature = "global")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IntlVideo {
+    pub video_resource: IntlStreamResource,
+    pub stream_info: IntlStreamInfo,
+}
+
+/// 国际版视频流
+#[cfg(feature = "global")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IntlPlayurl {
+    /// 时长，毫秒
+    pub duration: u64,
+    pub video: Vec<IntlVideo>,
+    pub audio_resource: Vec<IntlStreamResource>,
+}
+
+#[cfg(feature = "global")]
+impl IntlPlayurl {
+    /// 可用的最高清晰度视频轨道
+    pub fn best_video(&self) -> Option<&IntlVideo> {
+        self.video
+            .iter()
+            .filter(|v| !v.video_resource.url.is_empty())
+            .max_by_key(|v| (v.stream_info.quality, v.video_resource.bandwidth))
+    }
+
+    /// 码率最高的音频轨道
+    pub fn best_audio(&self) -> Option<&IntlStreamResource> {
+        self.audio_resource
+            .iter()
+            .filter(|a| !a.url.is_empty())
+            .max_by_key(|a| a.bandwidth)
+    }
+}
+
+#[cfg(feature = "global")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntlPlayurlData {
+    /// 无权限或受地区限制时为空
+    #[serde(default)]
+    pub playurl: Option<IntlPlayurl>,
+}
+
+/// 带来源信息的番剧视频流
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FallbackVideoStream {
+    /// 成功返回数据的来源
+    pub source: PlayurlSource,
+    /// 视频流数据
+    pub data: FallbackStreamData,
+    /// 依次尝试失败的来源及错误信息
+    pub attempts: Vec<(PlayurlSource, String)>,
+}
+
+impl BpiClient {
+    /// 获取国际版（bilibili.tv）番剧视频流
+    ///
+    /// 国际版的剧集编号与国内版独立，请求不携带登录凭据。
+    ///
+    /// # 参数
+    /// | 名称       | 类型                   | 说明                         |
+    /// | ---------- | ---------------------- | ---------------------------- |
+    /// | `ep_id`    | u64                    | 国际版剧集 epid              |
+    /// | `qn`       | `Option<VideoQuality>` | 视频清晰度选择               |
+    /// | `s_locale` | `Option<&str>`         | 语言地区，默认 `zh_SG`       |
+    #[cfg(feature = "global")]
+    pub async fn bangumi_video_stream_intl(
+        &self,
+        ep_id: u64,
+        qn: Option<VideoQuality>,
+        s_locale: Option<&str>
+    ) -> Result<BpiResponse<IntlPlayurlData>, BpiError> {
+        let mut params = vec![
+            ("ep_id", ep_id.to_string()),
+            ("platform", "web".to_string()),
+            ("s_locale", s_locale.unwrap_or("zh_SG").to_string())
+        ];
+        if let Some(q) = qn {
+            params.push(("qn", q.as_u32().to_string()));
+        }
+
+        self
+            .get_anonymous("https://api.bilibili.tv/intl/gateway/web/playurl")
+            .query(&params)
+            .send_bpi("获取国际版番剧视频流URL").await
+    }
+
+    /// 获取番剧视频流 URL，遇到地区限制时依次回退到代理解析服务器
+    ///
+    /// 只有地区限制错误（见 [`BpiError::is_area_restricted`]）会触发回退，其他错误直接返回。
+    /// 启用 `global` feature 且提供了 `ep_id` 时，代理均失败后以同一 `ep_id` 尝试国际版接口；
+    /// 两版剧集编号不一定对应，对应不上时该来源的错误记录在 `attempts` 中。
+    ///
+    /// # 参数
+    /// | 名称      | 类型                  | 说明                              |
+    /// | --------- | --------------------- | --------------------------------- |
+    /// | `ep_id`   | `Option<u64>`         | 稿件 epid                         |
+    /// | `cid`     | `Option<u64>`         | 视频 cid（可选，与 ep_id 二选一） |
+    /// | `qn`      | `Option<VideoQuality>`| 视频清晰度选择                    |
+    /// | `fnval`   | `Option<Fnval>`       | 视频获取方式选择                  |
+    /// | `proxies` | `&[PlayurlProxy]`     | 回退使用的代理解析服务器，按顺序尝试 |
+    pub async fn bangumi_video_stream_with_fallback(
+        &self,
+        ep_id: Option<u64>,
+        cid: Option<u64>,
+        qn: Option<VideoQuality>,
+        fnval: Option<Fnval>,
+        proxies: &[PlayurlProxy]
+    ) -> Result<FallbackVideoStream, BpiError> {
+        let params = bangumi_video_stream_params(ep_id, cid, qn, fnval)?;
+        let mut attempts = Vec::new();
+
+        match self.bangumi_video_stream(ep_id, cid, qn, fnval).await {
+            Ok(resp) => {
+                return Ok(FallbackVideoStream {
+                    source: PlayurlSource::Official,
+                    data: FallbackStreamData::Bangumi(Box::new(resp.into_data()?)),
+                    attempts,
+                });
+            }
+            Err(e) if e.is_area_restricted() => {
+                tracing::warn!("番剧视频流受地区限制，尝试代理解析服务器");
+                attempts.push((PlayurlSource::Official, e.to_string()));
+            }
+            Err(e) => {
+                return Err(e);
+            }
+        }
+
+        let mut last_err = BpiError::from_code(-10403);
+        for proxy in proxies {
+            let source = PlayurlSource::Proxy {
+                area: proxy.area.clone(),
+                url: proxy.url.clone(),
+            };
+
+            let result: Result<BpiResponse<BangumiVideoStreamData>, BpiError> = self
+                .get_anonymous(&proxy.url)
+                .with_bilibili_headers()
+                .query(&params)
+                .query(&[("area", proxy.area.as_str())])
+                .send_bpi(&format!("获取番剧视频流URL[{}]", proxy.area)).await;
+
+            match result.and_then(|resp| resp.into_data()) {
+                Ok(data) => {
+                    let data = FallbackStreamData::Bangumi(Box::new(data));
+                    return Ok(FallbackVideoStream { source, data, attempts });
+                }
+                Err(e) => {
+                    attempts.push((source, e.to_string()));
+                    last_err = e;
+                }
+            }
+        }
+
+        #[cfg(feature = "global")]
+        if let Some(ep_id) = ep_id {
+            let result = self
+                .bangumi_video_stream_intl(ep_id, qn, None).await
+                .and_then(|resp| resp.into_data())
+                .and_then(|data| data.playurl.ok_or_else(BpiError::missing_data));
+            match result {
+                Ok(playurl) => {
+                    return Ok(FallbackVideoStream {
+                        source: PlayurlSource::Intl,
+                        data: FallbackStreamData::Intl(playurl),
+                        attempts,
+                    });
+                }
+                Err(e) => {
+                    attempts.push((PlayurlSource::Intl, e.to_string()));
+                    last_err = e;
+                }
+            }
+        }
+
+        Err(last_err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "global")]
+    #[test]
+    fn test_intl_playurl_deserialize() {
+        let data: IntlPlayurlData = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "playurl": {
+                        "duration": 1420000,
+                        "video": [
+                            {
+                                "video_resource": { "url": "", "quality": 112 },
+                                "stream_info": { "quality": 112, "desc_words": "1080P(高码率)" }
+                            },
+                            {
+                                "video_resource": {
+                                    "url": "https://upos-bstar1-mirrorakam.akamaized.net/v.m4s",
+                                    "backup_url": [],
+                                    "bandwidth": 1200000,
+                                    "codecs": "avc1.640032",
+                                    "width": 1920,
+                                    "height": 1080,
+                                    "size": 213000000,
+                                    "quality": 80
+                                },
+                                "stream_info": { "quality": 80, "desc_words": "1080P" }
+                            }
+                        ],
+                        "audio_resource": [
+                            { "url": "https://upos-bstar1-mirrorakam.akamaized.net/a.m4s", "bandwidth": 128000, "quality": 30280 }
+                        ]
+                    }
+                })
+            )
+            .unwrap();
+
+        let playurl = data.playurl.unwrap();
+        assert_eq!(playurl.best_video().unwrap().stream_info.quality, 80);
+        assert_eq!(playurl.best_audio().unwrap().quality, 30280);
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_bangumi_video_stream_with_fallback() -> Result<(), Box<BpiError>> {
+        let bpi = crate::test_client!(Guest);
+        let result = bpi.bangumi_video_stream_with_fallback(
+            Some(10001),
+            None,
+            Some(VideoQuality::P480),
+            Some(Fnval::DASH),
+            &[]
+        ).await?;
+
+        tracing::info!("来源: {:?}", result.source);
+        assert_eq!(result.source, PlayurlSource::Official);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+//! 单集状态：互动数据、在线人数与首播状态
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/bangumi/info.md)
+
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use serde::{ Deserialize, Serialize };
+
+use super::info::BangumiEpisode;
+
+/// 单集互动数据
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BangumiEpStat {
+    /// 投币数
+    #[serde(default)]
+    pub coin: u64,
+    /// 弹幕数
+    #[serde(default)]
+    pub dm: u64,
+    /// 点赞数
+    #[serde(default)]
+    pub like: u64,
+    /// 评论数
+    #[serde(default)]
+    pub reply: u64,
+    /// 播放数
+    #[serde(default)]
+    pub view: u64,
+}
+
+/// 单集信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BangumiEpInfo {
+    pub stat: BangumiEpStat,
+}
+
+/// 单集在线人数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BangumiEpOnline {
+    pub ep_id: u64,
+    /// 所有终端总计人数，如 `"1.2万+"`
+    pub total: String,
+    /// web 端实时在线人数
+    pub count: String,
+}
+
+impl BangumiEpOnline {
+    /// 将总计人数解析为近似数值
+    pub fn approx_total(&self) -> Option<u64> {
+        parse_online(&self.total)
+    }
+}
+
+fn parse_online(text: &str) -> Option<u64> {
+    let text = text.trim().trim_end_matches('+');
+    let (number, unit) = match text.strip_suffix('万') {
+        Some(number) => (number, 10_000.0),
+        None => (text, 1.0),
+    };
+    number
+        .parse::<f64>()
+        .ok()
+        .map(|n| (n * unit) as u64)
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OnlineTotal {
+    total: String,
+    count: String,
+}
+
+/// 首播阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PremiereState {
+    /// 未开始
+    Upcoming,
+    /// 首播中
+    Playing,
+    /// 已结束
+    Finished,
+}
+
+/// 单集首播状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BangumiPremiere {
+    pub ep_id: u64,
+    /// 是否为首播剧集
+    pub is_premiere: bool,
+    /// 开播秒级时间戳
+    pub start_time: i64,
+    /// 预计结束秒级时间戳（开播时间 + 时长）
+    pub end_time: i64,
+}
+
+impl BangumiPremiere {
+    fn from_episode(episode: &BangumiEpisode, is_premiere: bool) -> Self {
+        let start_time = episode.pub_time as i64;
+        Self {
+            ep_id: episode.ep_id,
+            is_premiere,
+            start_time,
+            end_time: start_time + ((episode.duration / 1000) as i64),
+        }
+    }
+
+    /// 指定时间所处的阶段
+    pub fn state_at(&self, timestamp: i64) -> PremiereState {
+        if timestamp < self.start_time {
+            PremiereState::Upcoming
+        } else if timestamp < self.end_time {
+            PremiereState::Playing
+        } else {
+            PremiereState::Finished
+        }
+    }
+
+    /// 当前所处的阶段
+    pub fn state(&self) -> PremiereState {
+        self.state_at(chrono::Utc::now().timestamp())
+    }
+
+    /// 距开播的秒数，已开播时为 0
+    pub fn seconds_until_start(&self) -> i64 {
+        (self.start_time - chrono::Utc::now().timestamp()).max(0)
+    }
+}
+
+impl BpiClient {
+    /// 获取单集互动数据
+    ///
+    /// # 参数
+    /// * `ep_id` - 剧集epid
+    pub async fn bangumi_ep_stat(&self, ep_id: u64) -> Result<BpiResponse<BangumiEpInfo>, BpiError> {
+        self
+            .get("https://api.bilibili.com/pgc/season/episode/web/info")
+            .query(&[("ep_id", ep_id.to_string())])
+            .send_bpi("获取单集互动数据").await
+    }
+
+    /// 查找剧集明细中的单集
+    async fn bangumi_find_episode(&self, ep_id: u64) -> Result<BangumiEpisode, BpiError> {
+        self.bangumi_detail_by_epid(ep_id).await?
+            .into_data()?
+            .episodes.into_iter()
+            .find(|ep| ep.ep_id == ep_id)
+            .ok_or_else(|| BpiError::parse(format!("剧集中不存在 ep{}", ep_id)))
+    }
+
+    /// 获取单集在线人数
+    ///
+    /// 先通过剧集明细取得 aid/cid，再查询播放器在线人数。
+    ///
+    /// # 参数
+    /// * `ep_id` - 剧集epid
+    pub async fn bangumi_ep_online(&self, ep_id: u64) -> Result<BangumiEpOnline, BpiError> {
+        let episode = self.bangumi_find_episode(ep_id).await?;
+
+        let online: BpiResponse<OnlineTotal> = self
+            .get("https://api.bilibili.com/x/player/online/total")
+            .query(
+                &[
+                    ("aid", episode.aid.to_string()),
+                    ("cid", episode.cid.to_string()),
+                ]
+            )
+            .send_bpi("获取单集在线人数").await?;
+        let online = online.into_data()?;
+
+        Ok(BangumiEpOnline {
+            ep_id,
+            total: online.total,
+            count: online.count,
+        })
+    }
+
+    /// 获取单集首播状态
+    ///
+    /// 开播时间与时长取自剧集明细，首播标记取自分集信息。
+    ///
+    /// # 参数
+    /// * `ep_id` - 剧集epid
+    pub async fn bangumi_premiere_status(&self, ep_id: u64) -> Result<BangumiPremiere, BpiError> {
+        let detail = self.bangumi_detail_by_epid(ep_id).await?.into_data()?;
+        let episode = detail.episodes
+            .iter()
+            .find(|ep| ep.ep_id == ep_id)
+            .ok_or_else(|| BpiError::parse(format!("剧集中不存在 ep{}", ep_id)))?;
+
+        let sections = self.bangumi_sections_by_season_id(detail.season_id).await?.into_data()?;
+        let is_premiere = std::iter::once(&sections.main_section)
+            .chain(sections.section.iter())
+            .flat_map(|s| s.episodes.iter())
+            .any(|ep| ep.id == ep_id && ep.is_premiere == 1);
+
+        Ok(BangumiPremiere::from_episode(episode, is_premiere))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_EP_ID: u64 = 21265;
+
+    #[test]
+    fn test_premiere_state() {
+        let premiere = BangumiPremiere {
+            ep_id: 1,
+            is_premiere: true,
+            start_time: 1000,
+            end_time: 2440,
+        };
+        assert_eq!(premiere.state_at(999), PremiereState::Upcoming);
+        assert_eq!(premiere.state_at(1000), PremiereState::Playing);
+        assert_eq!(premiere.state_at(2440), PremiereState::Finished);
+    }
+
+    #[test]
+    fn test_parse_online() {
+        assert_eq!(parse_online("1000+"), Some(1000));
+        assert_eq!(parse_online("1.2万+"), Some(12000));
+        assert_eq!(parse_online("12"), Some(12));
+        assert_eq!(parse_online("-"), None);
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_bangumi_ep_stat() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let info = bpi.bangumi_ep_stat(TEST_EP_ID).await?.into_data()?;
+        tracing::info!("{:?}", info.stat);
+
+        let online = bpi.bangumi_ep_online(TEST_EP_ID).await?;
+        tracing::info!("在线人数: {} ({:?})", online.total, online.approx_total());
+
+        let premiere = bpi.bangumi_premiere_status(TEST_EP_ID).await?;
+        assert_eq!(premiere.state(), PremiereState::Finished);
+        Ok(())
+    }
+}
@@ -12,6 +12,28 @@ pub struct BangumiFollowResult {
     pub toast: String,
 }
 
+/// 追番状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BangumiFollowStatus {
+    /// 想看
+    Want = 1,
+    /// 在看
+    Watching = 2,
+    /// 看过
+    Watched = 3,
+}
+
+impl BangumiFollowStatus {
+    pub fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BangumiFollowStatusResult {
+    pub toast: String,
+}
+
 impl BpiClient {
     /// 追番
     ///
@@ -59,6 +81,33 @@ impl BpiClient {
             )
             .send_bpi("取消追番").await
     }
+
+    /// 修改追番状态
+    ///
+    /// # 参数
+    /// * `season_id` - 剧集ssid
+    /// * `status` - 追番状态（想看、在看、看过）
+    ///
+    /// # 文档
+    /// [修改追番状态](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/bangumi/follow.md#修改追番状态)
+    pub async fn bangumi_follow_status_update(
+        &self,
+        season_id: u64,
+        status: BangumiFollowStatus
+    ) -> Result<BpiResponse<BangumiFollowStatusResult>, BpiError> {
+        let csrf = self.csrf()?;
+        self
+            .post("https://api.bilibili.com/pgc/web/follow/status/update")
+            .with_bilibili_headers()
+            .form(
+                &[
+                    ("season_id", season_id.to_string()),
+                    ("status", status.as_u32().to_string()),
+                    ("csrf", csrf.to_string()),
+                ]
+            )
+            .send_bpi("修改追番状态").await
+    }
 }
 
 #[cfg(test)]
@@ -67,8 +116,9 @@ mod tests {
 
     const TEST_BANGUMI_ID: u64 = 99644; // 小城日常
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_follow_bangumi() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let result = bpi.bangumi_follow(TEST_BANGUMI_ID).await?;
 
         let data = result.into_data()?;
@@ -80,8 +130,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_unfollow_bangumi() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let result = bpi.bangumi_unfollow(TEST_BANGUMI_ID).await?;
 
         let data = result.into_data()?;
@@ -91,4 +142,19 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[ignore = "write"]
+    async fn test_follow_status_update() -> Result<(), Box<BpiError>> {
+        let bpi = crate::test_client!(Write);
+        let result = bpi.bangumi_follow_status_update(
+            TEST_BANGUMI_ID,
+            BangumiFollowStatus::Watching
+        ).await?;
+
+        let data = result.into_data()?;
+        tracing::info!("{:#?}", data);
+
+        Ok(())
+    }
 }
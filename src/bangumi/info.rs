@@ -189,7 +189,9 @@ pub struct BangumiMedia {
     pub horizontal_picture: String,
     pub media_id: u64,
     pub new_ep: BangumiMediaNewEp,
-    pub rating: BangumiRating,
+    /// 评分，评分人数不足时不存在
+    #[serde(default)]
+    pub rating: Option<BangumiRating>,
     pub season_id: u64,
     pub share_url: String,
     pub title: String,
@@ -218,12 +220,38 @@ pub struct BangumiDetailNewEp {
     pub title: String,
 }
 
+/// 评分
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BangumiRating {
+    /// 评分人数
     pub count: u64,
+    /// 评分，满分 10
     pub score: f64,
 }
 
+/// 剧集统计数据（`pgc/web/season/stat`）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BangumiSeasonStatData {
+    /// 投币数
+    pub coins: u64,
+    /// 弹幕数
+    pub danmakus: u64,
+    /// 追番数
+    pub follow: u64,
+    /// 点赞数
+    #[serde(default)]
+    pub likes: u64,
+    /// 评论数
+    pub reply: u64,
+    /// 系列追番数
+    #[serde(default)]
+    pub series_follow: u64,
+    /// 分享数
+    pub share: u64,
+    /// 播放数
+    pub views: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BangumiReview {
     pub is_coin: u32,
@@ -712,6 +740,23 @@ impl BpiClient {
         Ok(result)
     }
 
+    /// 获取剧集统计数据
+    ///
+    /// # 参数
+    /// * `season_id` - 番剧ssid
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/bangumi/info.md)
+    pub async fn bangumi_season_stat(
+        &self,
+        season_id: u64
+    ) -> Result<BpiResponse<BangumiSeasonStatData>, BpiError> {
+        self
+            .get("https://api.bilibili.com/pgc/web/season/stat")
+            .query(&[("season_id", season_id.to_string())])
+            .send_bpi("获取剧集统计数据").await
+    }
+
     /// 获取剧集明细（web端）（ssid方式）
     ///
     /// # 参数
@@ -774,8 +819,9 @@ mod tests {
     const TEST_MEDIA_ID: u64 = 28220978; //  mdid
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_bangumi_info() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.bangumi_info(TEST_MEDIA_ID).await?;
         let data = result.into_data()?;
         tracing::info!("{:#?}", data);
@@ -787,9 +833,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_media_without_rating() {
+        let data: BangumiInfoResult = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "media": {
+                        "areas": [{ "id": 2, "name": "日本" }], "cover": "", "horizontal_picture": "",
+                        "media_id": 1, "new_ep": { "id": 1, "index": "1", "index_show": "更新至第1话" },
+                        "season_id": 2, "share_url": "", "title": "新番", "type": 1, "type_name": "番剧"
+                    }
+                })
+            )
+            .unwrap();
+        assert!(data.media.rating.is_none());
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_bangumi_season_stat() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let stat = bpi.bangumi_season_stat(TEST_SEASON_ID).await?.into_data()?;
+        tracing::info!("{:#?}", stat);
+        assert!(stat.views > 0);
+        Ok(())
+    }
+
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_bangumi_detail_by_season_id() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.bangumi_detail_by_season_id(TEST_SEASON_ID).await?;
         let data = result.into_data()?;
         tracing::info!("{:#?}", data);
@@ -802,8 +875,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_bangumi_detail_by_epid() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.bangumi_detail_by_epid(TEST_EP_ID).await?;
         let data = result.into_data()?;
         tracing::info!("{:#?}", data);
@@ -815,8 +889,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_bangumi_section() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.bangumi_sections_by_season_id(TEST_SEASON_ID).await?;
         let data = result.into_data()?;
         tracing::info!("{:#?}", data);
@@ -8,7 +8,11 @@
 //! * 媒体: [查看详情](https://www.bilibili.com/bangumi/media/md28220978)
 //! * 流: [查看详情](https://api.bilibili.com/pgc/player/web/playurl?qn=127&fnver=0&fnval=12240&fourk=&ep_id=65709)
 
+pub mod area_fallback;
+pub mod episode;
 pub mod follow;
 pub mod info;
+#[cfg(feature = "video_ranking")]
+pub mod rating;
 pub mod timeline;
 pub mod videostream_url;
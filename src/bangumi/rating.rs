@@ -0,0 +1,209 @@
+//! 番剧评分走势
+//!
+//! 接口只提供当前评分，[`BpiClient::bangumi_rating_trend`] 每次调用时记录当天的评分快照，
+//! 与之前保存的快照一起组成评分随时间的变化。定期（如每天）调用即可积累走势数据，
+//! 快照存储见 [`SnapshotStore`]。
+//!
+//! ```no_run
+//! # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+//! use bpi_rs::video_ranking::snapshot::FsSnapshotStore;
+//!
+//! let mut store = FsSnapshotStore::new("snapshots");
+//! let trend = bpi.bangumi_rating_trend(&mut store, 28220978).await?;
+//! for point in &trend.points {
+//!     println!("{} {:.1} ({} 人)", point.date, point.score, point.count);
+//! }
+//! if let Some(change) = trend.score_change() {
+//!     println!("{} 评分变化 {:+.1}", trend.title, change);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use chrono::NaiveDate;
+use serde::{ Deserialize, Serialize };
+
+use super::info::BangumiRating;
+use crate::video_ranking::snapshot::{ Snapshot, SnapshotStore };
+use crate::{ BpiClient, BpiError };
+
+/// 某天的评分快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RatingSnapshot {
+    /// 快照种类，见 [`RatingSnapshot::kind_of`]
+    pub kind: String,
+    pub media_id: u64,
+    pub season_id: u64,
+    pub title: String,
+    /// 快照日期（本地时间）
+    pub date: NaiveDate,
+    /// 获取时的秒级时间戳
+    pub fetched_at: i64,
+    /// 评分，评分人数不足时为 `None`
+    pub rating: Option<BangumiRating>,
+}
+
+impl RatingSnapshot {
+    /// 快照种类，如 `rating-md28220978`
+    pub fn kind_of(media_id: u64) -> String {
+        format!("rating-md{}", media_id)
+    }
+}
+
+impl Snapshot for RatingSnapshot {
+    fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    fn date(&self) -> NaiveDate {
+        self.date
+    }
+}
+
+/// 走势中的一个点
+#[derive(Debug, Clone, PartialEq)]
+pub struct RatingPoint {
+    pub date: NaiveDate,
+    /// 评分，满分 10
+    pub score: f64,
+    /// 评分人数
+    pub count: u64,
+}
+
+/// 评分走势
+#[derive(Debug, Clone, PartialEq)]
+pub struct RatingTrend {
+    pub media_id: u64,
+    pub title: String,
+    /// 有评分的快照，从早到晚排列，最后一个为本次获取的评分
+    pub points: Vec<RatingPoint>,
+}
+
+impl RatingTrend {
+    /// 由快照组成走势，跳过没有评分的快照
+    pub fn from_snapshots(media_id: u64, title: impl Into<String>, snapshots: &[RatingSnapshot]) -> Self {
+        let points = snapshots
+            .iter()
+            .filter_map(|s| {
+                s.rating.as_ref().map(|r| RatingPoint { date: s.date, score: r.score, count: r.count })
+            })
+            .collect();
+        Self { media_id, title: title.into(), points }
+    }
+
+    /// 最新的评分
+    pub fn latest(&self) -> Option<&RatingPoint> {
+        self.points.last()
+    }
+
+    /// 最早与最新评分之差，少于两个点时为 `None`
+    pub fn score_change(&self) -> Option<f64> {
+        match self.points.as_slice() {
+            [first, .., last] => Some(last.score - first.score),
+            _ => None,
+        }
+    }
+
+    /// 最早与最新评分人数之差，少于两个点时为 `None`
+    pub fn count_change(&self) -> Option<i64> {
+        match self.points.as_slice() {
+            [first, .., last] => Some((last.count as i64) - (first.count as i64)),
+            _ => None,
+        }
+    }
+}
+
+impl BpiClient {
+    /// 获取番剧当前评分的快照
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `media_id` | u64 | 番剧 mdid |
+    pub async fn bangumi_rating_snapshot(&self, media_id: u64) -> Result<RatingSnapshot, BpiError> {
+        let media = self.bangumi_info(media_id).await?.into_data()?.media;
+        let now = chrono::Local::now();
+        Ok(RatingSnapshot {
+            media_id,
+            season_id: media.season_id,
+            title: media.title,
+            date: now.date_naive(),
+            fetched_at: now.timestamp(),
+            rating: media.rating,
+            kind: RatingSnapshot::kind_of(media_id),
+        })
+    }
+
+    /// 记录番剧当天的评分，并返回包含历史快照的评分走势
+    ///
+    /// 同一天多次调用只保留最后一次的评分。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `store` | `&mut impl SnapshotStore` | 快照存储 |
+    /// | `media_id` | u64 | 番剧 mdid |
+    pub async fn bangumi_rating_trend(
+        &self,
+        store: &mut impl SnapshotStore,
+        media_id: u64
+    ) -> Result<RatingTrend, BpiError> {
+        let current = self.bangumi_rating_snapshot(media_id).await?;
+        store.save(&current)?;
+        let snapshots: Vec<RatingSnapshot> = store.history(current.kind())?;
+        Ok(RatingTrend::from_snapshots(media_id, current.title, &snapshots))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::video_ranking::snapshot::FsSnapshotStore;
+
+    fn snapshot(date: &str, rating: Option<(f64, u64)>) -> RatingSnapshot {
+        RatingSnapshot {
+            media_id: 28220978,
+            season_id: 1172,
+            title: "轻音少女 第一季".to_string(),
+            date: date.parse().unwrap(),
+            fetched_at: 0,
+            rating: rating.map(|(score, count)| BangumiRating { score, count }),
+            kind: RatingSnapshot::kind_of(28220978),
+        }
+    }
+
+    #[test]
+    fn test_rating_trend() {
+        let root = std::env::temp_dir().join(format!("bpi-rating-{}", uuid::Uuid::new_v4()));
+        let mut store = FsSnapshotStore::new(&root);
+        for s in [
+            snapshot("2025-01-03", Some((9.8, 1200))),
+            snapshot("2025-01-01", Some((9.9, 1000))),
+            snapshot("2025-01-02", None),
+        ] {
+            store.save(&s).unwrap();
+        }
+
+        let snapshots: Vec<RatingSnapshot> = store.history(&RatingSnapshot::kind_of(28220978)).unwrap();
+        std::fs::remove_dir_all(root).unwrap();
+        let trend = RatingTrend::from_snapshots(28220978, "轻音少女 第一季", &snapshots);
+
+        assert_eq!(trend.points.len(), 2);
+        assert_eq!(trend.points[0].date, "2025-01-01".parse::<NaiveDate>().unwrap());
+        assert_eq!(trend.latest().map(|p| p.count), Some(1200));
+        assert!((trend.score_change().unwrap() + 0.1).abs() < 1e-9);
+        assert_eq!(trend.count_change(), Some(200));
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_bangumi_rating_snapshot() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let snapshot = bpi.bangumi_rating_snapshot(28220978).await?;
+        tracing::info!("{} {:?}", snapshot.title, snapshot.rating);
+        assert_eq!(snapshot.kind(), "rating-md28220978");
+        Ok(())
+    }
+}
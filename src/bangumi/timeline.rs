@@ -97,8 +97,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_bangumi_timeline() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.bangumi_timeline(BangumiTimelineType::Anime, 3, 7).await;
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -118,8 +119,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_bangumi_timeline_invalid_before() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.bangumi_timeline(BangumiTimelineType::Anime, 8, 7).await;
         assert!(result.is_err());
         let error = result.unwrap_err();
@@ -133,8 +135,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_bangumi_timeline_invalid_after() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.bangumi_timeline(BangumiTimelineType::Anime, 3, 8).await;
         assert!(result.is_err());
         let error = result.unwrap_err();
@@ -27,6 +27,15 @@ pub struct BangumiVideoStreamData {
     pub vip_status: Option<u32>,
     /// 是否为DRM
     pub is_drm: bool,
+    /// DRM 类型
+    #[serde(default)]
+    pub drm_type: Option<u32>,
+    /// DRM 技术类型 1:ClearKey 2:Widevine
+    #[serde(default)]
+    pub drm_tech_type: Option<u32>,
+    /// DRM 许可证地址
+    #[serde(default)]
+    pub drm_license_url: Option<String>,
     /// 是否重编码
     pub no_rexcode: u32,
     /// 记录信息
@@ -39,6 +48,122 @@ pub struct BangumiRecordInfo {
     pub record: String,
 }
 
+/// DRM 加密方案
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DrmScheme {
+    ClearKey,
+    Widevine,
+    Unknown(u32),
+}
+
+impl From<u32> for DrmScheme {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => DrmScheme::ClearKey,
+            2 => DrmScheme::Widevine,
+            other => DrmScheme::Unknown(other),
+        }
+    }
+}
+
+/// 单条 DRM 流的密钥信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrmTrackInfo {
+    /// 对应 DASH 流 id
+    pub id: u32,
+    /// 编码
+    pub codecs: String,
+    /// 密钥 ID（hex）
+    pub key_id: Option<String>,
+    /// Widevine PSSH（base64）
+    pub pssh: Option<String>,
+}
+
+/// DRM 元数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrmMetadata {
+    /// 加密方案
+    pub scheme: DrmScheme,
+    /// DRM 类型
+    pub drm_type: Option<u32>,
+    /// 许可证地址
+    pub license_url: Option<String>,
+    /// 各视频/音频流的密钥信息
+    pub tracks: Vec<DrmTrackInfo>,
+}
+
+impl BangumiVideoStreamData {
+    /// 提取 DRM 元数据，非 DRM 内容返回 `None`
+    ///
+    /// 本库不做解密，由下游播放器决定如何处理。
+    pub fn drm_metadata(&self) -> Option<DrmMetadata> {
+        if !self.is_drm {
+            return None;
+        }
+
+        let tracks = self.base.dash
+            .as_ref()
+            .map(|dash| {
+                dash.video
+                    .iter()
+                    .chain(dash.audio.iter())
+                    .filter(|t| t.kid.is_some() || t.widevine_pssh.is_some())
+                    .map(|t| DrmTrackInfo {
+                        id: t.id,
+                        codecs: t.codecs.clone(),
+                        key_id: t.kid.clone(),
+                        pssh: t.widevine_pssh.clone(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(DrmMetadata {
+            scheme: self.drm_tech_type.map(DrmScheme::from).unwrap_or(DrmScheme::Unknown(0)),
+            drm_type: self.drm_type,
+            license_url: self.drm_license_url.clone(),
+            tracks,
+        })
+    }
+}
+
+/// 构造番剧视频流请求参数
+pub(crate) fn bangumi_video_stream_params(
+    ep_id: Option<u64>,
+    cid: Option<u64>,
+    qn: Option<VideoQuality>,
+    fnval: Option<Fnval>
+) -> Result<Vec<(&'static str, String)>, BpiError> {
+    // 验证参数
+    if ep_id.is_none() && cid.is_none() {
+        return Err(BpiError::InvalidParameter {
+            field: "ep_id/cid",
+            message: "ep_id和cid必须提供其中一个",
+        });
+    }
+
+    let mut params = vec![("fnver", "0".to_string())];
+
+    if fnval.is_some_and(|f| f.is_fourk()) {
+        params.push(("fourk", "1".to_string()));
+    }
+
+    if let Some(ep) = ep_id {
+        params.push(("ep_id", ep.to_string()));
+    }
+    if let Some(c) = cid {
+        params.push(("cid", c.to_string()));
+    }
+    if let Some(q) = qn {
+        params.push(("qn", q.as_u32().to_string()));
+    }
+    if let Some(fv) = fnval {
+        params.push(("fnval", fv.bits().to_string()));
+    }
+
+    Ok(params)
+}
+
 impl BpiClient {
     /// 获取番剧视频流 URL
     ///
@@ -59,32 +184,7 @@ impl BpiClient {
         qn: Option<VideoQuality>,
         fnval: Option<Fnval>
     ) -> Result<BpiResponse<BangumiVideoStreamData>, BpiError> {
-        // 验证参数
-        if ep_id.is_none() && cid.is_none() {
-            return Err(BpiError::InvalidParameter {
-                field: "ep_id/cid",
-                message: "ep_id和cid必须提供其中一个",
-            });
-        }
-
-        let mut params = vec![("fnver", "0".to_string())];
-
-        if fnval.is_some_and(|f| f.is_fourk()) {
-            params.push(("fourk", "1".to_string()));
-        }
-
-        if let Some(ep) = ep_id {
-            params.push(("ep_id", ep.to_string()));
-        }
-        if let Some(c) = cid {
-            params.push(("cid", c.to_string()));
-        }
-        if let Some(q) = qn {
-            params.push(("qn", q.as_u32().to_string()));
-        }
-        if let Some(fv) = fnval {
-            params.push(("fnval", fv.bits().to_string()));
-        }
+        let params = bangumi_video_stream_params(ep_id, cid, qn, fnval)?;
 
         self
             .get("https://api.bilibili.com/pgc/player/web/playurl")
@@ -141,9 +241,86 @@ mod tests {
     const TEST_EP_ID: u64 = 10001; // epid
     const TEST_CID: u64 = 772096113;
 
+    fn dash_track(id: u32, codecs: &str, kid: Option<&str>, pssh: Option<&str>) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "base_url": format!("https://upos-sz-mirrorcos.bilivideo.com/{}.m4s", id),
+            "backup_url": [],
+            "bandwidth": 1000000,
+            "mime_type": "video/mp4",
+            "codecs": codecs,
+            "width": 1920,
+            "height": 1080,
+            "frame_rate": "23.976",
+            "sar": "1:1",
+            "start_with_sap": 1,
+            "segment_base": { "initialization": "0-1000", "index_range": "1001-2000" },
+            "codecid": 7,
+            "size": 0,
+            "md5": null,
+            "widevine_pssh": pssh,
+            "key_id": kid
+        })
+    }
+
+    #[test]
+    fn test_drm_metadata() {
+        let data: BangumiVideoStreamData = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "quality": 80,
+                    "accept_quality": [80, 64],
+                    "accept_format": "mp4,mp4",
+                    "accept_description": ["1080P 高清", "720P 准高清"],
+                    "format": "mp4",
+                    "video_codecid": 7,
+                    "has_paid": true,
+                    "support_formats": [],
+                    "dash": {
+                        "duration": 1420,
+                        "min_buffer_time": 1.5,
+                        "video": [
+                            dash_track(80, "avc1.640032", Some("0123456789abcdef0123456789abcdef"), Some("AAAAW3Bzc2g=")),
+                            dash_track(64, "avc1.640028", None, None)
+                        ],
+                        "audio": [dash_track(30280, "mp4a.40.2", Some("fedcba9876543210fedcba9876543210"), None)],
+                        "dolby": null,
+                        "flac": null
+                    },
+                    "code": 0,
+                    "fnver": 0,
+                    "video_project": true,
+                    "type": "DASH",
+                    "bp": 0,
+                    "vip_type": 2,
+                    "vip_status": 1,
+                    "is_drm": true,
+                    "drm_type": 3,
+                    "drm_tech_type": 2,
+                    "drm_license_url": "https://bvc-drm.bilivideo.com/bili_widevine",
+                    "no_rexcode": 0,
+                    "record_info": null
+                })
+            )
+            .unwrap();
+
+        let drm = data.drm_metadata().unwrap();
+        assert_eq!(drm.scheme, DrmScheme::Widevine);
+        assert_eq!(drm.drm_type, Some(3));
+        assert_eq!(drm.license_url.as_deref(), Some("https://bvc-drm.bilivideo.com/bili_widevine"));
+        assert_eq!(drm.tracks.len(), 2);
+        assert_eq!(drm.tracks[0].id, 80);
+        assert_eq!(drm.tracks[0].pssh.as_deref(), Some("AAAAW3Bzc2g="));
+        assert_eq!(drm.tracks[1].key_id.as_deref(), Some("fedcba9876543210fedcba9876543210"));
+
+        let clear = BangumiVideoStreamData { is_drm: false, ..data };
+        assert!(clear.drm_metadata().is_none());
+    }
+
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_bangumi_video_stream_url_simple() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.bangumi_video_stream_by_epid(
             TEST_EP_ID,
             Some(VideoQuality::P8K),
@@ -170,8 +347,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_bangumi_video_stream_url_by_cid() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.bangumi_video_stream_by_cid(
             TEST_CID,
             Some(VideoQuality::P480),
@@ -183,8 +361,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_bangumi_video_stream_url_no_params() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.bangumi_video_stream(None, None, None, None).await;
         assert!(result.is_err());
         let error = result.unwrap_err();
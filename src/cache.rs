@@ -0,0 +1,357 @@
+//! 响应缓存
+//!
+//! 轮询类程序常以很短的间隔调用同一个便捷方法，而多数数据（直播间信息、粉丝数等）
+//! 并不需要每次都请求。[`ResponseCache`] 按键缓存任意类型的结果，
+//! [`ResponseCache::get_if_stale`] 只在缓存过期时才真正发起请求。
+//!
+//! 键的格式约定为 `接口名:参数`，如 `live_room_info:21452505`，
+//! 有效期可按接口名（第一个 `:` 之前的部分）单独配置。
+//!
+//! 缓存只通过 [`ResponseCache`] 的显式接口使用：各接口方法不会自动读写缓存，
+//! [`BpiResponse`](crate::BpiResponse) 也不携带有效期信息，新鲜度见 [`Cached`]。
+//! 过期的条目在访问该键或写入新键时清理，不会随键的增多无限堆积。
+//!
+//! ```no_run
+//! # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+//! use std::time::Duration;
+//!
+//! bpi.cache().set_ttl("user_card", Duration::from_secs(300));
+//!
+//! let card = bpi
+//!     .cache()
+//!     .get_if_stale("user_card:2", || async {
+//!         bpi.user_card_info(2, None).await?.into_data()
+//!     }).await?;
+//! println!("命中缓存: {}, 剩余有效期: {:?}", card.from_cache, card.expires_in());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::ops::Deref;
+use std::sync::{ Arc, Mutex, RwLock };
+use std::time::{ Duration, Instant };
+
+use crate::BpiError;
+
+/// 未配置接口有效期时使用的默认值
+pub const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+struct Entry {
+    value: Arc<dyn Any + Send + Sync>,
+    fetched_at: Instant,
+    ttl: Duration,
+}
+
+impl Entry {
+    fn is_fresh(&self) -> bool {
+        self.fetched_at.elapsed() < self.ttl
+    }
+}
+
+/// 同一个键的请求在槽位上排队，过期时只有一个任务发起请求
+type Slot = Arc<tokio::sync::Mutex<Option<Entry>>>;
+
+/// 缓存的结果及其新鲜度
+#[derive(Debug)]
+pub struct Cached<T> {
+    pub value: Arc<T>,
+    /// 是否来自缓存，`false` 表示本次调用发起了请求
+    pub from_cache: bool,
+    /// 获取时间
+    pub fetched_at: Instant,
+    /// 有效期
+    pub ttl: Duration,
+}
+
+impl<T> Cached<T> {
+    /// 距获取时经过的时间
+    pub fn age(&self) -> Duration {
+        self.fetched_at.elapsed()
+    }
+
+    /// 剩余有效期，已过期时为零
+    pub fn expires_in(&self) -> Duration {
+        self.ttl.saturating_sub(self.age())
+    }
+}
+
+impl<T> Clone for Cached<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            from_cache: self.from_cache,
+            fetched_at: self.fetched_at,
+            ttl: self.ttl,
+        }
+    }
+}
+
+impl<T> Deref for Cached<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// 按键缓存请求结果，每个 [`BpiClient`](crate::BpiClient) 持有一个，见 [`BpiClient::cache`](crate::BpiClient::cache)
+pub struct ResponseCache {
+    slots: Mutex<HashMap<String, Slot>>,
+    ttls: RwLock<HashMap<String, Duration>>,
+    default_ttl: RwLock<Duration>,
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self {
+            slots: Mutex::new(HashMap::new()),
+            ttls: RwLock::new(HashMap::new()),
+            default_ttl: RwLock::new(DEFAULT_TTL),
+        }
+    }
+}
+
+impl std::fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("len", &self.slots.lock().unwrap_or_else(|e| e.into_inner()).len())
+            .field("ttls", &*self.ttls.read().unwrap_or_else(|e| e.into_inner()))
+            .finish()
+    }
+}
+
+impl ResponseCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置接口的有效期，对之后写入的缓存生效
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `endpoint` | &str | 接口名，即键中第一个 `:` 之前的部分 |
+    /// | `ttl` | Duration | 有效期 |
+    pub fn set_ttl(&self, endpoint: &str, ttl: Duration) {
+        self.ttls
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(endpoint.to_string(), ttl);
+    }
+
+    /// 设置未单独配置的接口的有效期
+    pub fn set_default_ttl(&self, ttl: Duration) {
+        *self.default_ttl.write().unwrap_or_else(|e| e.into_inner()) = ttl;
+    }
+
+    /// 键对应的有效期
+    pub fn ttl_for(&self, key: &str) -> Duration {
+        let endpoint = key.split(':').next().unwrap_or(key);
+        self.ttls
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(endpoint)
+            .copied()
+            .unwrap_or_else(|| *self.default_ttl.read().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    fn slot(&self, key: &str) -> Slot {
+        let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(slot) = slots.get(key) {
+            return slot.clone();
+        }
+        evict_stale(&mut slots);
+        slots.entry(key.to_string()).or_default().clone()
+    }
+
+    /// 缓存未过期时直接返回，否则调用 `fetch` 获取并写入缓存
+    ///
+    /// 同一个键同时只有一个 `fetch` 在执行，其余调用等待其结果。`fetch` 失败时不写入缓存，
+    /// 错误原样返回。缓存中的值类型与 `T` 不一致时视为过期。
+    pub async fn get_if_stale<T, F, Fut>(&self, key: &str, fetch: F) -> Result<Cached<T>, BpiError>
+        where T: Send + Sync + 'static, F: FnOnce() -> Fut, Fut: Future<Output = Result<T, BpiError>>
+    {
+        let slot = self.slot(key);
+        let mut entry = slot.lock().await;
+
+        if
+            let Some(cached) = entry.as_ref() &&
+            cached.is_fresh() &&
+            let Ok(value) = cached.value.clone().downcast::<T>()
+        {
+            return Ok(Cached {
+                value,
+                from_cache: true,
+                fetched_at: cached.fetched_at,
+                ttl: cached.ttl,
+            });
+        }
+
+        let value = Arc::new(fetch().await?);
+        let fetched_at = Instant::now();
+        let ttl = self.ttl_for(key);
+        *entry = Some(Entry { value: value.clone(), fetched_at, ttl });
+
+        Ok(Cached { value, from_cache: false, fetched_at, ttl })
+    }
+
+    /// 未过期的缓存值，正在请求中的键返回 `None`
+    pub fn get<T: Send + Sync + 'static>(&self, key: &str) -> Option<Cached<T>> {
+        let slot = {
+            let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+            let slot = slots.get(key)?;
+            if is_stale(slot) {
+                slots.remove(key);
+                return None;
+            }
+            slot.clone()
+        };
+        let entry = slot.try_lock().ok()?;
+        let cached = entry.as_ref().filter(|entry| entry.is_fresh())?;
+        Some(Cached {
+            value: cached.value.clone().downcast::<T>().ok()?,
+            from_cache: true,
+            fetched_at: cached.fetched_at,
+            ttl: cached.ttl,
+        })
+    }
+
+    /// 直接写入缓存，如从写操作的响应中得到了最新数据
+    pub fn insert<T: Send + Sync + 'static>(&self, key: &str, value: T) {
+        let entry = Entry {
+            value: Arc::new(value),
+            fetched_at: Instant::now(),
+            ttl: self.ttl_for(key),
+        };
+        let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+        evict_stale(&mut slots);
+        slots.insert(key.to_string(), Arc::new(tokio::sync::Mutex::new(Some(entry))));
+    }
+
+    /// 缓存的键数，包括尚未清理的过期条目
+    pub fn len(&self) -> usize {
+        self.slots.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 使键失效，下次调用 [`get_if_stale`](Self::get_if_stale) 时重新请求
+    pub fn invalidate(&self, key: &str) {
+        self.slots.lock().unwrap_or_else(|e| e.into_inner()).remove(key);
+    }
+
+    /// 使接口的全部缓存失效
+    pub fn invalidate_endpoint(&self, endpoint: &str) {
+        self.slots
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|key, _| key.split(':').next() != Some(endpoint));
+    }
+
+    /// 清空缓存
+    pub fn clear(&self) {
+        self.slots.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+/// 槽位已过期或为空，且没有任务在使用
+///
+/// 只在持有 `slots` 锁时调用：此时无法从表中取得新的引用，引用计数为 1 即无人等待。
+fn is_stale(slot: &Slot) -> bool {
+    Arc::strong_count(slot) == 1 &&
+        slot.try_lock().is_ok_and(|entry| entry.as_ref().is_none_or(|entry| !entry.is_fresh()))
+}
+
+fn evict_stale(slots: &mut HashMap<String, Slot>) {
+    slots.retain(|_, slot| !is_stale(slot));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{ AtomicU32, Ordering };
+
+    #[tokio::test]
+    async fn test_get_if_stale() {
+        let cache = ResponseCache::new();
+        cache.set_ttl("count", Duration::from_secs(60));
+        cache.set_ttl("short", Duration::ZERO);
+        let calls = AtomicU32::new(0);
+        let fetch = || async { Ok::<_, BpiError>(calls.fetch_add(1, Ordering::SeqCst)) };
+
+        let first = cache.get_if_stale("count:1", fetch).await.unwrap();
+        let second = cache.get_if_stale("count:1", fetch).await.unwrap();
+        assert!(!first.from_cache);
+        assert!(second.from_cache);
+        assert_eq!(*second, 0);
+        assert_eq!(cache.get::<u32>("count:1").map(|c| *c), Some(0));
+
+        // 有效期为零时每次都请求
+        cache.get_if_stale("short:1", fetch).await.unwrap();
+        let short = cache.get_if_stale("short:1", fetch).await.unwrap();
+        assert!(!short.from_cache);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        cache.invalidate_endpoint("count");
+        assert!(cache.get::<u32>("count:1").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stale_entries_evicted() {
+        let cache = ResponseCache::new();
+        cache.set_ttl("short", Duration::ZERO);
+        for id in 0..10 {
+            cache.insert(&format!("short:{}", id), id);
+        }
+        // 写入新键时清理之前过期的条目
+        assert_eq!(cache.len(), 1);
+
+        cache.get_if_stale("count:1", || async { Ok::<_, BpiError>(1u32) }).await.unwrap();
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get::<u32>("short:9").is_none());
+        assert_eq!(cache.get::<u32>("count:1").map(|c| *c), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_get_if_stale_error_not_cached() {
+        let cache = ResponseCache::new();
+        let err = cache.get_if_stale::<u32, _, _>("k", || async {
+            Err(BpiError::missing_data())
+        }).await;
+        assert!(err.is_err());
+        assert!(cache.get::<u32>("k").is_none());
+
+        cache.insert("k", 7u32);
+        assert_eq!(cache.get::<u32>("k").map(|c| *c), Some(7));
+        assert!(cache.get::<String>("k").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_if_stale_single_flight() {
+        let cache = Arc::new(ResponseCache::new());
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let cache = cache.clone();
+                let calls = calls.clone();
+                tokio::spawn(async move {
+                    cache.get_if_stale("slow:1", || async move {
+                        tokio::time::sleep(Duration::from_millis(20)).await;
+                        Ok::<_, BpiError>(calls.fetch_add(1, Ordering::SeqCst))
+                    }).await.map(|c| *c)
+                })
+            })
+            .collect();
+
+        for task in tasks {
+            assert_eq!(task.await.unwrap().unwrap(), 0);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
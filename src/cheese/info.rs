@@ -306,8 +306,9 @@ mod tests {
     const TEST_EP_ID: u64 = 20767;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_cheese_info_by_season_id() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let data = bpi.cheese_info_by_season_id(TEST_SEASON_ID).await?.into_data()?;
 
         assert_eq!(data.season_id, TEST_SEASON_ID);
@@ -316,8 +317,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_cheese_info_by_ep_id() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let data = bpi.cheese_info_by_ep_id(TEST_EP_ID).await?.into_data()?;
         assert_eq!(data.season_id, TEST_SEASON_ID);
 
@@ -327,8 +329,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_cheese_ep_list() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let data = bpi.cheese_ep_list(TEST_SEASON_ID, Some(50), Some(1)).await?.into_data()?;
         assert_eq!(data.items.first().unwrap().id, TEST_SEASON_ID);
 
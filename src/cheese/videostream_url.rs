@@ -180,8 +180,9 @@ mod tests {
     const TEST_CID: u64 = 1183682680;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_cheese_playurl() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         let data = bpi
             .cheese_video_stream(
@@ -2,11 +2,108 @@ use crate::{ BpiError };
 use reqwest::RequestBuilder;
 use reqwest::cookie::CookieStore;
 use reqwest::{ Client, Url, cookie::Jar };
-use std::sync::{ Arc, Mutex };
+use std::collections::{ BTreeMap, HashMap };
+use std::sync::{ Arc, OnceLock, RwLock };
+use std::time::Duration;
 use tracing;
+use uuid::Uuid;
 
-use super::auth::Account;
-use super::request::BilibiliRequest;
+use super::auth::{ Account, AccountSource, AppToken };
+use super::background::TaskRegistry;
+use super::cache::ResponseCache;
+use super::device::DeviceProfile;
+use super::rate_limit::RateLimit;
+use super::request::{ self, DEFAULT_USER_AGENT, RequestPolicy };
+use super::utils::web_location::{ self, WebLocation };
+
+/// 全局单例
+static INSTANCE: OnceLock<BpiClient> = OnceLock::new();
+
+bitflags::bitflags! {
+    /// 触发重试的错误类型
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct RetryOn: u8 {
+        /// 连接失败、超时等网络错误
+        const NETWORK = 1 << 0;
+        /// HTTP 5xx，以及业务码 -500/-503/-504
+        const SERVER_ERROR = 1 << 1;
+        /// 风控与限流：HTTP 412/429，业务码 -412/-509/-799
+        const RATE_LIMIT = 1 << 2;
+    }
+}
+
+impl RetryOn {
+    /// 错误是否属于要重试的类型
+    pub fn matches(&self, err: &BpiError) -> bool {
+        match err {
+            BpiError::Network { .. } => self.contains(Self::NETWORK),
+            BpiError::Http { status: 412 | 429 } => self.contains(Self::RATE_LIMIT),
+            BpiError::Http { status } => *status >= 500 && self.contains(Self::SERVER_ERROR),
+            BpiError::Api { code: -500 | -503 | -504, .. } => self.contains(Self::SERVER_ERROR),
+            BpiError::Api { code: -412 | -509 | -799, .. } => self.contains(Self::RATE_LIMIT),
+            _ => false,
+        }
+    }
+}
+
+/// 请求失败时的重试策略
+///
+/// 第 n 次重试前等待 `backoff * 2^n`（不超过 `max_backoff`），并在其一半到全部之间随机取值，
+/// 避免多个任务同时重试。每个 client 单独设置，见 [`BpiClient::set_retry_config`]。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// 最大重试次数，0 表示不重试
+    pub max_retries: u32,
+    /// 首次重试前的等待时间
+    pub backoff: Duration,
+    /// 等待时间上限
+    pub max_backoff: Duration,
+    /// 触发重试的错误类型
+    pub retry_on: RetryOn,
+    /// 是否重试 GET 以外的请求，默认否
+    ///
+    /// 写操作超时后可能已在服务端生效，重试会导致重复提交（如重复发送评论）。
+    pub retry_non_get: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+            retry_on: RetryOn::all(),
+            retry_non_get: false,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// 不重试，失败立即返回
+    pub fn disabled() -> Self {
+        Self { max_retries: 0, ..Self::default() }
+    }
+
+    /// 该方法的请求是否会重试
+    pub fn applies_to(&self, method: &reqwest::Method) -> bool {
+        self.max_retries > 0 && (self.retry_non_get || method == reqwest::Method::GET)
+    }
+
+    /// 第 `attempt` 次重试（从 0 开始）失败后是否继续重试
+    pub fn should_retry(&self, attempt: u32, err: &BpiError) -> bool {
+        attempt < self.max_retries && self.retry_on.matches(err)
+    }
+
+    /// 第 `attempt` 次重试（从 0 开始）前的等待时间，含随机抖动
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let ceiling = self.backoff
+            .saturating_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX))
+            .min(self.max_backoff);
+        let half = ceiling / 2;
+        let jitter = (Uuid::new_v4().as_u128() % (half.as_millis() + 1)) as u64;
+        half + Duration::from_millis(jitter)
+    }
+}
 
 /// 使用示例：
 ///
@@ -23,6 +120,7 @@ use super::request::BilibiliRequest;
 ///         sessdata: "".to_string(),
 ///         bili_jct: "".to_string(),
 ///         buvid3: "".to_string(),
+///         ..Default::default()
 ///     });
 ///
 ///     // bpi.set_account_from_cookie_str("dede_user_id=123;bili_jct=456...");
@@ -41,57 +139,386 @@ use super::request::BilibiliRequest;
 /// ```
 pub struct BpiClient {
     client: Client,
+    /// 不读写 cookie jar 的 client，供 [`BpiClient::with_credentials`] 使用
+    stateless: Client,
+    /// 不跟随重定向、不读写 cookie jar 的 client，供需要读取重定向响应的接口使用
+    no_redirect: Client,
     jar: Arc<Jar>,
-    account: Mutex<Option<Account>>,
+    /// 账号快照，写入时整体替换，读取只克隆 `Arc`
+    account: RwLock<Option<Arc<Account>>>,
+    /// 设备信息，设置后请求统一使用其 User-Agent 与设备 cookie
+    device: RwLock<Option<Arc<DeviceProfile>>>,
+    /// Cookie 刷新令牌，登录时下发，刷新 Cookie 后更新
+    refresh_token: RwLock<Option<String>>,
+    /// APP 登录凭证
+    app_token: RwLock<Option<Arc<AppToken>>>,
+    /// 覆盖的 `web_location`
+    web_locations: RwLock<HashMap<WebLocation, String>>,
+    /// 响应缓存，单次凭据模式下不与原 client 共享
+    cache: ResponseCache,
+    /// 单次凭据模式下固定附带的 Cookie 请求头
+    cookie_header: Option<String>,
+    /// 后台任务登记表，单次凭据模式下与原 client 共用
+    pub(crate) tasks: Arc<TaskRegistry>,
+    /// 限速等请求设置，随请求传递，单次凭据模式下与原 client 共用
+    pub(crate) policy: Arc<RequestPolicy>,
+    /// 创建时设置的 User-Agent，未设置设备时使用
+    default_user_agent: String,
+    /// 创建时设置的代理，供不经过 reqwest 的连接（如直播信息流）使用
+    proxy: Option<ProxyConfig>,
+}
+
+/// 客户端配置
+///
+/// 单例 [`BpiClient::new`] 使用默认配置，[`BpiClient::builder`] 可自定义后创建独立实例。
+#[derive(Debug, Clone)]
+pub struct BpiClientBuilder {
+    timeout: Duration,
+    connect_timeout: Option<Duration>,
+    proxy: Option<ProxyConfig>,
+    user_agent: Option<String>,
+    account: Option<Account>,
+    refresh_token: Option<String>,
+    web_locations: HashMap<WebLocation, String>,
+    device: Option<DeviceProfile>,
+    pool_max_idle_per_host: usize,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    http2_prior_knowledge: bool,
+    http2_keep_alive_interval: Option<Duration>,
+    rate_limit: Option<RateLimit>,
+    retry: RetryConfig,
+}
+
+#[derive(Debug, Clone)]
+enum ProxyConfig {
+    Url(String),
+    Custom(Box<reqwest::Proxy>),
+}
+
+impl Default for BpiClientBuilder {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            connect_timeout: None,
+            proxy: None,
+            user_agent: None,
+            account: None,
+            refresh_token: None,
+            web_locations: HashMap::new(),
+            device: None,
+            pool_max_idle_per_host: 8,
+            pool_idle_timeout: Some(Duration::from_secs(30)),
+            tcp_keepalive: None,
+            http2_prior_knowledge: false,
+            http2_keep_alive_interval: None,
+            rate_limit: None,
+            retry: RetryConfig::default(),
+        }
+    }
+}
+
+impl BpiClientBuilder {
+    /// 请求超时，默认 10 秒
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 建立连接的超时，默认不单独限制，仅受 [`timeout`](Self::timeout) 约束
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// 使用代理，默认不使用任何代理（包括系统代理）
+    ///
+    /// 支持 `http://127.0.0.1:7890`、`socks5://127.0.0.1:1080` 等地址，
+    /// `socks5h://` 由代理端解析域名。所有请求都经过该代理。
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(ProxyConfig::Url(proxy.into()));
+        self
+    }
+
+    /// 使用自定义的 [`reqwest::Proxy`]，可设置代理认证、仅代理 HTTPS 等
+    ///
+    /// ```no_run
+    /// use bpi_rs::BpiClient;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let proxy = reqwest::Proxy::https("http://127.0.0.1:7890")?.basic_auth("user", "pass");
+    /// let bpi = BpiClient::builder().custom_proxy(proxy).build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn custom_proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(ProxyConfig::Custom(Box::new(proxy)));
+        self
+    }
+
+    /// 请求使用的 User-Agent，默认模拟桌面版 Chrome
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// 创建时设置账号
+    pub fn account(mut self, account: Account) -> Self {
+        self.account = Some(account);
+        self
+    }
+
+    /// 创建时覆盖页面的 `web_location`，见 [`BpiClient::set_web_location`]
+    pub fn web_location(mut self, location: WebLocation, value: impl Into<String>) -> Self {
+        self.web_locations.insert(location, value.into());
+        self
+    }
+
+    /// 创建时设置 Cookie 刷新令牌，见 [`BpiClient::set_refresh_token`]
+    pub fn refresh_token(mut self, refresh_token: impl Into<String>) -> Self {
+        self.refresh_token = Some(refresh_token.into());
+        self
+    }
+
+    /// 创建时设置设备信息，见 [`BpiClient::set_device`]
+    pub fn device(mut self, device: DeviceProfile) -> Self {
+        self.device = Some(device);
+        self
+    }
+
+    /// 每个域名保留的空闲连接数，默认 8
+    ///
+    /// 复用连接可省去每次请求的 TCP 与 TLS 握手，批量请求时延迟明显更低；
+    /// 设为 0 则每次请求都新建连接。
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = max;
+        self
+    }
+
+    /// 空闲连接的保留时间，默认 30 秒，`None` 表示不主动关闭
+    ///
+    /// 服务端会关闭长时间空闲的连接，保留时间过长时复用到已关闭的连接会导致请求失败。
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = timeout;
+        self
+    }
+
+    /// TCP keep-alive 间隔，默认不开启
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// 直接使用 HTTP/2 而不经协商，默认否
+    ///
+    /// 仅在确认服务端与代理都支持 HTTP/2 时开启。
+    pub fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = enabled;
+        self
+    }
+
+    /// HTTP/2 连接的 PING 间隔，空闲时同样发送，用于保持长连接，默认不发送
+    pub fn http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// 每个域名的请求限速，默认不限速，见 [`rate_limit`](crate::rate_limit)
+    pub fn rate_limit(mut self, limit: Option<RateLimit>) -> Self {
+        self.rate_limit = limit;
+        self
+    }
+
+    /// 请求失败时的重试策略，默认见 [`RetryConfig::default`]
+    pub fn retry_config(mut self, config: RetryConfig) -> Self {
+        self.retry = config;
+        self
+    }
+
+    /// 创建独立的 client
+    ///
+    /// TLS 后端初始化失败或代理地址无效时返回 [`BpiError::Network`]，不会 panic。
+    pub fn build(self) -> Result<BpiClient, BpiError> {
+        let jar = Arc::new(Jar::default());
+        let client = self.http_client(Some(jar.clone()), true)?;
+        let stateless = self.http_client(None, true)?;
+        let no_redirect = self.http_client(None, false)?;
+
+        let instance = BpiClient {
+            client,
+            stateless,
+            no_redirect,
+            jar,
+            account: RwLock::new(None),
+            device: RwLock::new(None),
+            refresh_token: RwLock::new(None),
+            app_token: RwLock::new(None),
+            web_locations: RwLock::new(self.web_locations),
+            cache: ResponseCache::new(),
+            cookie_header: None,
+            tasks: Arc::new(TaskRegistry::new()),
+            policy: Arc::new(RequestPolicy::default()),
+            default_user_agent: self.user_agent.clone().unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+            proxy: self.proxy.clone(),
+        };
+        instance.set_rate_limit(self.rate_limit);
+        instance.set_retry_config(self.retry);
+
+        if let Some(device) = self.device {
+            instance.set_device(device);
+        }
+        if let Some(account) = self.account {
+            instance.set_account(account);
+        }
+        if let Some(refresh_token) = self.refresh_token {
+            instance.set_refresh_token(refresh_token);
+        }
+
+        Ok(instance)
+    }
+
+    /// 以当前配置创建全局单例，之后 [`BpiClient::new`] 返回该实例
+    ///
+    /// 需在首次调用 [`BpiClient::new`] 之前调用，单例已存在时返回 [`BpiError::InvalidParameter`]。
+    ///
+    /// ```no_run
+    /// use bpi_rs::BpiClient;
+    ///
+    /// # fn main() -> Result<(), bpi_rs::BpiError> {
+    /// BpiClient::builder().proxy("socks5h://127.0.0.1:1080").build_global()?;
+    /// let bpi = BpiClient::new();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_global(self) -> Result<&'static BpiClient, BpiError> {
+        if INSTANCE.get().is_some() {
+            return Err(BpiError::invalid_parameter("client", "全局 client 已初始化"));
+        }
+
+        let instance = self.build()?;
+        let mut created = false;
+        let global = INSTANCE.get_or_init(|| {
+            created = true;
+            instance
+        });
+
+        if created {
+            Ok(global)
+        } else {
+            Err(BpiError::invalid_parameter("client", "全局 client 已初始化"))
+        }
+    }
+
+    fn http_client(&self, jar: Option<Arc<Jar>>, follow_redirect: bool) -> Result<Client, BpiError> {
+        let mut builder = Client::builder()
+            .timeout(self.timeout)
+            .user_agent(self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT))
+            .gzip(true) // 启用gzip自动解压缩
+            .deflate(true) // 启用deflate解压缩
+            .brotli(true) // 启用brotli解压缩
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .tcp_keepalive(self.tcp_keepalive);
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if !follow_redirect {
+            builder = builder.redirect(reqwest::redirect::Policy::none());
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(interval) = self.http2_keep_alive_interval {
+            builder = builder.http2_keep_alive_interval(interval).http2_keep_alive_while_idle(true);
+        }
+
+        if let Some(jar) = jar {
+            builder = builder.cookie_provider(jar);
+        }
+
+        builder = match &self.proxy {
+            Some(ProxyConfig::Url(proxy)) => {
+                let proxy = reqwest::Proxy::all(proxy).map_err(|e|
+                    BpiError::network(format!("代理地址无效: {}", e))
+                )?;
+                builder.proxy(proxy)
+            }
+            Some(ProxyConfig::Custom(proxy)) => builder.proxy(proxy.as_ref().clone()),
+            None => builder.no_proxy(),
+        };
+
+        builder.build().map_err(|e| BpiError::network(format!("创建 HTTP 客户端失败: {}", e)))
+    }
 }
 
 impl BpiClient {
     /// 创建client
+    ///
+    /// 返回全局单例，创建失败时 panic，需要处理错误请使用 [`BpiClient::try_new`]。
     pub fn new() -> &'static Self {
-        static INSTANCE: std::sync::OnceLock<BpiClient> = std::sync::OnceLock::new();
-        INSTANCE.get_or_init(|| {
-            let jar = Arc::new(Jar::default());
-            let client = Client::builder()
-                .timeout(std::time::Duration::from_secs(10))
-                .gzip(true) // 启用gzip自动解压缩
-                .deflate(true) // 启用deflate解压缩
-                .brotli(true) // 启用brotli解压缩
-                .no_proxy()
-                .cookie_provider(jar.clone())
-                .pool_max_idle_per_host(0)
-                .build()
-                .unwrap();
-
-            let instance = Self {
-                client,
-                jar,
-                account: Mutex::new(None),
-            };
+        Self::try_new().expect("创建 BpiClient 失败")
+    }
 
-            // 在 debug 模式下自动从account.toml加载测试账号
-            #[cfg(any(test, debug_assertions))]
-            {
-                use super::log::init_log;
-
-                init_log();
-                if let Ok(test_account) = Account::load_test_account() {
-                    instance.set_account(test_account);
-                    tracing::info!("已自动加载测试账号");
-                } else {
-                    tracing::warn!("无法加载测试账号，使用默认配置");
-                }
+    /// 获取全局单例，首次创建失败时返回错误，之后可重试
+    pub fn try_new() -> Result<&'static Self, BpiError> {
+        if let Some(instance) = INSTANCE.get() {
+            return Ok(instance);
+        }
+
+        let instance = BpiClientBuilder::default().build()?;
+
+        // 在 debug 模式下自动从account.toml加载测试账号
+        #[cfg(any(test, debug_assertions))]
+        {
+            use super::log::init_log;
+
+            init_log();
+            if let Ok(test_account) = Account::load_test_account() {
+                instance.set_account(test_account);
+                tracing::info!("已自动加载测试账号");
+            } else {
+                tracing::warn!("无法加载测试账号，使用默认配置");
             }
+        }
 
-            instance
-        })
+        // 并发初始化时以先写入的实例为准
+        Ok(INSTANCE.get_or_init(|| instance))
+    }
+
+    /// 创建独立于全局单例的 client，使用默认配置
+    pub fn try_new_local() -> Result<Self, BpiError> {
+        BpiClientBuilder::default().build()
+    }
+
+    /// 自定义配置创建独立 client
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use bpi_rs::BpiClient;
+    ///
+    /// # fn main() -> Result<(), bpi_rs::BpiError> {
+    /// let bpi = BpiClient::builder().timeout(Duration::from_secs(30)).build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> BpiClientBuilder {
+        BpiClientBuilder::default()
     }
 
     /// 设置账号信息
+    ///
+    /// 账号带有 [`AppToken`] 或 `refresh_token` 时同时设置 APP 登录凭证与 Cookie 刷新令牌。
     pub fn set_account(&self, account: Account) {
         if account.is_complete() {
             self.load_cookies_from_account(&account);
-            let mut acc = self.account.lock().unwrap();
-            *acc = Some(account);
+            if let Some(token) = &account.refresh_token {
+                *self.refresh_token.write().unwrap_or_else(|e| e.into_inner()) = Some(token.clone());
+            }
+            if let Some(token) = &account.app_token {
+                *self.app_token.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(token.clone()));
+            }
+            *self.account.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(account));
             tracing::info!("设置账号信息完成，使用[登录]模式");
         } else {
             tracing::warn!("账号信息不完整，使用[游客]模式");
@@ -113,10 +540,143 @@ impl BpiClient {
         tracing::info!("从账号信息加载登录 cookies 完成");
     }
 
+    /// 设置设备信息
+    ///
+    /// 设备 cookie（buvid3、buvid4、`_uuid`、`buvid_fp`、`b_nut`）写入 cookie jar，
+    /// 之后的请求使用设备的 User-Agent。设备中的 buvid3 会覆盖账号的 buvid3，
+    /// 同一账号应始终使用同一套设备，见 [`DeviceStore`](crate::device::DeviceStore)。
+    pub fn set_device(&self, device: DeviceProfile) {
+        self.add_cookies(device.cookies());
+        *self.device.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(device));
+        tracing::info!("设置设备信息完成");
+    }
+
+    /// 当前设备信息
+    pub fn device(&self) -> Option<Arc<DeviceProfile>> {
+        self.device
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// 设置 Cookie 刷新令牌
+    ///
+    /// 扫码登录成功后会自动设置；其他方式登录时为网页 localStorage 中的 `ac_time_value`。
+    /// 刷新 Cookie 后旧令牌失效，需要持久化时读取 [`BpiClient::refresh_token`] 保存新令牌。
+    /// 已设置账号时同时写入账号的 `refresh_token`。
+    pub fn set_refresh_token(&self, refresh_token: impl Into<String>) {
+        let refresh_token = refresh_token.into();
+        {
+            let mut account = self.account.write().unwrap_or_else(|e| e.into_inner());
+            if let Some(current) = account.as_mut() {
+                let mut updated = (**current).clone();
+                updated.refresh_token = Some(refresh_token.clone());
+                *current = Arc::new(updated);
+            }
+        }
+        *self.refresh_token.write().unwrap_or_else(|e| e.into_inner()) = Some(refresh_token);
+    }
+
+    /// 当前 Cookie 刷新令牌
+    pub fn refresh_token(&self) -> Option<String> {
+        self.refresh_token
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// 设置 APP 登录凭证
+    ///
+    /// 已设置账号时同时写入账号的 `app_token`，保存账号或会话时一并保存。
+    pub fn set_app_token(&self, token: AppToken) {
+        {
+            let mut account = self.account.write().unwrap_or_else(|e| e.into_inner());
+            if let Some(current) = account.as_mut() {
+                let mut updated = (**current).clone();
+                updated.app_token = Some(token.clone());
+                *current = Arc::new(updated);
+            }
+        }
+        *self.app_token.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(token));
+    }
+
+    /// 当前 APP 登录凭证
+    pub fn app_token(&self) -> Option<Arc<AppToken>> {
+        self.app_token
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// 当前 APP 登录凭证，未设置时返回需要登录的错误
+    pub fn require_app_token(&self) -> Result<Arc<AppToken>, BpiError> {
+        self.app_token().ok_or_else(|| BpiError::auth("未设置 APP 登录凭证 access_key"))
+    }
+
+    /// 覆盖页面的 `web_location`，之后使用该页面取值的接口都改用 `value`
+    pub fn set_web_location(&self, location: WebLocation, value: impl Into<String>) {
+        self.web_locations
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(location, value.into());
+    }
+
+    /// 页面的 `web_location`，未覆盖时为默认值
+    pub fn web_location(&self, location: WebLocation) -> String {
+        self.web_locations
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&location)
+            .cloned()
+            .unwrap_or_else(|| location.as_str().to_string())
+    }
+
+    /// 页面的 spm id，用于 `spmid`/`from_spmid`
+    pub fn spm_id(&self, location: WebLocation) -> String {
+        web_location::spm_id(&self.web_location(location))
+    }
+
+    /// 设置该 client 的重试策略，默认见 [`RetryConfig::default`]
+    ///
+    /// 对之后发出的请求生效，[`BpiClient::with_credentials`] 派生的 client 与原 client 共用。
+    /// 也可在创建时通过 [`BpiClientBuilder::retry_config`] 设置。
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use bpi_rs::{ BpiClient, RetryConfig, RetryOn };
+    ///
+    /// BpiClient::new().set_retry_config(RetryConfig {
+    ///     max_retries: 5,
+    ///     backoff: Duration::from_secs(1),
+    ///     retry_on: RetryOn::NETWORK | RetryOn::RATE_LIMIT,
+    ///     ..RetryConfig::default()
+    /// });
+    /// ```
+    pub fn set_retry_config(&self, config: RetryConfig) {
+        *self.policy.retry.write().unwrap_or_else(|e| e.into_inner()) = config;
+    }
+
+    /// 当前重试策略
+    pub fn retry_config(&self) -> RetryConfig {
+        self.policy.retry_config()
+    }
+
+    /// 响应缓存，见 [`ResponseCache::get_if_stale`]
+    pub fn cache(&self) -> &ResponseCache {
+        &self.cache
+    }
+
+    /// 生成并换用一套新设备
+    pub fn rotate_device(&self) -> Arc<DeviceProfile> {
+        self.set_device(DeviceProfile::generate());
+        self.device().expect("设备信息刚写入")
+    }
+
     /// 清除账号信息
     pub fn clear_account(&self) {
-        let mut acc = self.account.lock().unwrap();
-        *acc = None;
+        *self.account.write().unwrap_or_else(|e| e.into_inner()) = None;
+        *self.refresh_token.write().unwrap_or_else(|e| e.into_inner()) = None;
+        *self.app_token.write().unwrap_or_else(|e| e.into_inner()) = None;
         self.clear_cookies();
         tracing::info!("清除账号信息完成");
     }
@@ -129,7 +689,7 @@ impl BpiClient {
     }
 
     /// 批量添加 cookies
-    fn add_cookies<I, K, V>(&self, cookies: I)
+    pub(crate) fn add_cookies<I, K, V>(&self, cookies: I)
         where I: IntoIterator<Item = (K, V)>, K: ToString, V: ToString
     {
         for (key, value) in cookies {
@@ -156,17 +716,46 @@ impl BpiClient {
             }
         }
 
-        let account = Account {
-            dede_user_id: map.get("DedeUserID").cloned().unwrap_or_default(),
-            dede_user_id_ckmd5: map.get("DedeUserID__ckMd5").cloned().unwrap_or_default(),
-            sessdata: map.get("SESSDATA").cloned().unwrap_or_default(),
-            bili_jct: map.get("bili_jct").cloned().unwrap_or_default(),
-            buvid3: map.get("buvid3").cloned().unwrap_or_default(),
-        };
+        let mut account = Account::new(
+            map.get("DedeUserID").cloned().unwrap_or_default(),
+            map.get("DedeUserID__ckMd5").cloned().unwrap_or_default(),
+            map.get("SESSDATA").cloned().unwrap_or_default(),
+            map.get("bili_jct").cloned().unwrap_or_default(),
+            map.get("buvid3").cloned().unwrap_or_default()
+        );
+        account.source = Some(AccountSource::Imported);
 
         self.set_account(account);
     }
 
+    /// cookie jar 中 bilibili.com 各站点可见的全部 cookie
+    pub(crate) fn jar_cookies(&self) -> BTreeMap<String, String> {
+        const SITES: [&str; 5] = [
+            "https://www.bilibili.com",
+            "https://api.bilibili.com",
+            "https://passport.bilibili.com",
+            "https://api.live.bilibili.com",
+            "https://member.bilibili.com",
+        ];
+
+        let mut cookies = BTreeMap::new();
+        for site in SITES {
+            let url = Url::parse(site).unwrap();
+            let Some(header) = self.jar.cookies(&url) else {
+                continue;
+            };
+            let Ok(header) = header.to_str() else {
+                continue;
+            };
+            for pair in header.split(';') {
+                if let Some((name, value)) = pair.trim().split_once('=') {
+                    cookies.insert(name.to_string(), value.to_string());
+                }
+            }
+        }
+        cookies
+    }
+
     /// 检查是否有登录 cookies
     pub fn has_login_cookies(&self) -> bool {
         let url = Url::parse("https://api.bilibili.com").unwrap();
@@ -175,29 +764,159 @@ impl BpiClient {
 
     /// 获取当前账号信息
     pub fn get_account(&self) -> Option<Account> {
-        self.account.lock().unwrap().clone()
+        self.account_snapshot().map(|acc| (*acc).clone())
+    }
+
+    /// 获取当前账号快照
+    ///
+    /// 只持有读锁克隆 `Arc`，适合在异步热路径中频繁读取。
+    pub fn account_snapshot(&self) -> Option<Arc<Account>> {
+        self.account
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
     }
 
     /// 从账号信息获取 CSRF token
     pub fn csrf(&self) -> Result<String, BpiError> {
-        let account = self.account.lock().unwrap();
-        account
-            .as_ref()
+        self.account_snapshot()
             .filter(|acc| !acc.bili_jct.is_empty())
             .map(|acc| acc.bili_jct.clone())
             .ok_or_else(BpiError::missing_csrf)
     }
 
+    /// 使用指定账号发起请求，不修改当前 client 的账号与 cookie jar
+    ///
+    /// 返回的 client 向 bilibili.com 及其子域名的请求都携带该账号的 Cookie，其他站点（如 CDN、
+    /// 代理解析服务）不携带；响应中的 Set-Cookie 会被忽略，适合在同一进程中为多个用户服务。
+    ///
+    /// ```no_run
+    /// # async fn run(bpi: &bpi_rs::BpiClient, account: &bpi_rs::Account) -> Result<(), bpi_rs::BpiError> {
+    /// let resp = bpi.with_credentials(account).user_card_info(2, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_credentials(&self, account: &Account) -> BpiClient {
+        let device = self.device();
+        let mut cookie_header = account.cookie_header();
+        if let Some(device) = &device {
+            for (name, value) in device.cookies() {
+                if name != "buvid3" {
+                    cookie_header.push_str(&format!("; {}={}", name, value));
+                }
+            }
+        }
+
+        BpiClient {
+            client: self.stateless.clone(),
+            stateless: self.stateless.clone(),
+            no_redirect: self.no_redirect.clone(),
+            jar: Arc::new(Jar::default()),
+            account: RwLock::new(Some(Arc::new(account.clone()))),
+            device: RwLock::new(device),
+            refresh_token: RwLock::new(None),
+            app_token: RwLock::new(account.app_token.clone().map(Arc::new)),
+            web_locations: RwLock::new(
+                self.web_locations
+                    .read()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .clone()
+            ),
+            cache: ResponseCache::new(),
+            cookie_header: Some(cookie_header),
+            tasks: self.tasks.clone(),
+            policy: self.policy.clone(),
+            default_user_agent: self.default_user_agent.clone(),
+            proxy: self.proxy.clone(),
+        }
+    }
+
     /// reqwest的get请求包装, 自带user_agent
     pub fn get(&self, url: &str) -> RequestBuilder {
-        self.client.get(url).with_user_agent()
+        self.with_policy(self.with_cookie_header(url, self.with_device(self.client.get(url))))
     }
     /// reqwest的post请求包装, 自带user_agent
     pub fn post(&self, url: &str) -> RequestBuilder {
-        self.client.post(url).with_user_agent()
+        self.with_policy(self.with_cookie_header(url, self.with_device(self.client.post(url))))
+    }
+
+    fn with_device(&self, req: RequestBuilder) -> RequestBuilder {
+        match self.device() {
+            Some(device) => req.header(reqwest::header::USER_AGENT, device.user_agent.as_str()),
+            None => req,
+        }
+    }
+
+    /// 不跟随重定向的 get 请求，不读写 cookie jar
+    pub(crate) fn get_no_redirect(&self, url: &str) -> RequestBuilder {
+        self.with_policy(self.no_redirect.get(url))
+    }
+
+    /// 不携带任何凭据的 get 请求：不读写 cookie jar，也不附带单次凭据模式的 Cookie，
+    /// 供发往第三方服务器的请求使用
+    #[cfg(feature = "bangumi")]
+    pub(crate) fn get_anonymous(&self, url: &str) -> RequestBuilder {
+        self.with_policy(self.stateless.get(url))
+    }
+
+    fn with_policy(&self, req: RequestBuilder) -> RequestBuilder {
+        request::attach_policy(req, &self.policy)
+    }
+
+    /// 请求使用的 User-Agent：设置了设备时为设备的 User-Agent，否则为创建时的设置
+    #[cfg(feature = "live")]
+    pub(crate) fn user_agent(&self) -> String {
+        match self.device() {
+            Some(device) => device.user_agent.clone(),
+            None => self.default_user_agent.clone(),
+        }
+    }
+
+    /// 向 `url` 发送请求时携带的 Cookie 请求头，供不经过 reqwest 的连接使用
+    #[cfg(feature = "live")]
+    pub(crate) fn cookie_header_for(&self, url: &Url) -> Option<String> {
+        match &self.cookie_header {
+            Some(cookie) => is_bilibili_host(url.as_str()).then(|| cookie.clone()),
+            None =>
+                self.jar
+                    .cookies(url)
+                    .and_then(|header| header.to_str().ok().map(str::to_string)),
+        }
+    }
+
+    /// 创建时设置的代理地址，供不经过 reqwest 的连接使用
+    ///
+    /// 使用 [`BpiClientBuilder::custom_proxy`] 时无法取得代理地址，返回错误而不是绕过代理直连。
+    #[cfg(feature = "live")]
+    pub(crate) fn proxy_url(&self) -> Result<Option<Url>, BpiError> {
+        match &self.proxy {
+            None => Ok(None),
+            Some(ProxyConfig::Url(proxy)) =>
+                Url::parse(proxy)
+                    .map(Some)
+                    .map_err(|e| BpiError::network(format!("代理地址无效: {}", e))),
+            Some(ProxyConfig::Custom(_)) =>
+                Err(BpiError::invalid_parameter("proxy", "该连接不支持 custom_proxy，请使用 proxy 设置代理地址")),
+        }
+    }
+
+    /// 单次凭据模式下附带 Cookie，仅限 bilibili.com 及其子域名
+    fn with_cookie_header(&self, url: &str, req: RequestBuilder) -> RequestBuilder {
+        match &self.cookie_header {
+            Some(cookie) if is_bilibili_host(url) => req.header(reqwest::header::COOKIE, cookie),
+            _ => req,
+        }
     }
 }
 
+/// 地址的主机是否为 bilibili.com 或其子域名
+fn is_bilibili_host(url: &str) -> bool {
+    Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(|host| host.to_ascii_lowercase()))
+        .is_some_and(|host| host == "bilibili.com" || host.ends_with(".bilibili.com"))
+}
+
 impl BpiClient {
     /// 从配置创建Client
     pub fn from_config(config: &Account) -> &Self {
@@ -222,3 +941,184 @@ impl BpiClient {
         bpi
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_on_matches() {
+        let all = RetryOn::all();
+        assert!(all.matches(&BpiError::network("连接失败")));
+        assert!(all.matches(&BpiError::http(502)));
+        assert!(all.matches(&BpiError::http(412)));
+        assert!(all.matches(&BpiError::from_code(-412)));
+        assert!(all.matches(&BpiError::from_code(-509)));
+        assert!(all.matches(&BpiError::from_code(-503)));
+        assert!(!all.matches(&BpiError::http(404)));
+        assert!(!all.matches(&BpiError::from_code(-101)));
+        assert!(!all.matches(&BpiError::parse("bad json")));
+
+        assert!(!RetryOn::NETWORK.matches(&BpiError::from_code(-412)));
+        assert!(!RetryOn::RATE_LIMIT.matches(&BpiError::http(500)));
+    }
+
+    #[test]
+    fn test_retry_config() {
+        let config = RetryConfig::default();
+        assert!(config.applies_to(&reqwest::Method::GET));
+        assert!(!config.applies_to(&reqwest::Method::POST));
+        assert!(!RetryConfig::disabled().applies_to(&reqwest::Method::GET));
+
+        let err = BpiError::from_code(-412);
+        assert!(config.should_retry(0, &err));
+        assert!(config.should_retry(1, &err));
+        assert!(!config.should_retry(2, &err));
+    }
+
+    #[test]
+    fn test_retry_config_per_client() {
+        let bpi = BpiClient::builder().retry_config(RetryConfig::disabled()).build().unwrap();
+        let derived = bpi.with_credentials(&Account::default());
+        let other = BpiClient::try_new_local().unwrap();
+
+        assert_eq!(derived.retry_config(), RetryConfig::disabled());
+        assert_eq!(other.retry_config(), RetryConfig::default());
+
+        other.set_retry_config(RetryConfig { max_retries: 5, ..RetryConfig::default() });
+        assert_eq!(bpi.retry_config().max_retries, 0);
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let config = RetryConfig {
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(300),
+            ..RetryConfig::default()
+        };
+        for _ in 0..20 {
+            let first = config.delay(0);
+            assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+            let second = config.delay(1);
+            assert!(second >= Duration::from_millis(100) && second <= Duration::from_millis(200));
+            // 超过上限后不再增长
+            assert!(config.delay(40) <= Duration::from_millis(300));
+        }
+    }
+
+    #[test]
+    fn test_builder_invalid_proxy() {
+        let result = BpiClient::builder().proxy("not a proxy").build();
+        assert!(matches!(result, Err(BpiError::Network { .. })));
+    }
+
+    #[test]
+    fn test_builder_proxy_options() {
+        let socks = BpiClient::builder()
+            .proxy("socks5h://127.0.0.1:1080")
+            .connect_timeout(Duration::from_secs(3))
+            .user_agent("bpi-rs")
+            .build();
+        assert!(socks.is_ok());
+
+        let proxy = reqwest::Proxy::https("http://127.0.0.1:7890").unwrap().basic_auth("u", "p");
+        assert!(BpiClient::builder().custom_proxy(proxy).build().is_ok());
+    }
+
+    #[test]
+    fn test_build_global_after_init() {
+        BpiClient::try_new().unwrap();
+        let result = BpiClient::builder().build_global();
+        assert!(matches!(result, Err(BpiError::InvalidParameter { .. })));
+    }
+
+    #[test]
+    fn test_builder_connection_options() {
+        let bpi = BpiClient::builder()
+            .pool_max_idle_per_host(0)
+            .pool_idle_timeout(None)
+            .tcp_keepalive(Duration::from_secs(60))
+            .http2_prior_knowledge(true)
+            .http2_keep_alive_interval(Duration::from_secs(20))
+            .build();
+        assert!(bpi.is_ok());
+    }
+
+    #[test]
+    fn test_try_new_local_with_account() {
+        let account = Account::new(
+            "1".to_string(),
+            "md5".to_string(),
+            "sess".to_string(),
+            "jct".to_string(),
+            "buvid".to_string()
+        );
+        let bpi = BpiClient::builder().account(account).build().unwrap();
+        assert_eq!(bpi.csrf().unwrap(), "jct");
+        assert!(BpiClient::try_new_local().unwrap().get_account().is_none());
+    }
+
+    #[test]
+    fn test_account_snapshot() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        bpi.set_account_from_cookie_str("DedeUserID=1;DedeUserID__ckMd5=md5;SESSDATA=sess;bili_jct=jct;buvid3=buvid");
+
+        let snapshot = bpi.account_snapshot().unwrap();
+        bpi.clear_account();
+
+        assert_eq!(snapshot.bili_jct, "jct");
+        assert!(bpi.account_snapshot().is_none());
+    }
+
+    #[test]
+    fn test_with_credentials() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        let account = Account::new(
+            "1".to_string(),
+            "md5".to_string(),
+            "sess".to_string(),
+            "jct".to_string(),
+            "buvid".to_string()
+        );
+
+        let user = bpi.with_credentials(&account);
+        let req = user.get("https://api.bilibili.com/x/web-interface/nav").build().unwrap();
+
+        assert_eq!(user.csrf().unwrap(), "jct");
+        assert!(req.headers()[reqwest::header::COOKIE].to_str().unwrap().contains("SESSDATA=sess"));
+        assert!(bpi.account_snapshot().is_none());
+        assert!(!bpi.has_login_cookies());
+    }
+
+    #[test]
+    fn test_with_credentials_other_hosts() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        let account = Account::new(
+            "1".to_string(),
+            "md5".to_string(),
+            "sess".to_string(),
+            "jct".to_string(),
+            "buvid".to_string()
+        );
+        let user = bpi.with_credentials(&account);
+
+        for url in [
+            "https://upos-sz-mirrorcos.bilivideo.com/video.m4s",
+            "https://api.example.com/pgc/player/web/playurl",
+            "https://bilibili.com.example.com/",
+            "https://evilbilibili.com/",
+        ] {
+            let req = user.post(url).build().unwrap();
+            assert!(!req.headers().contains_key(reqwest::header::COOKIE), "{}", url);
+        }
+
+        let req = user.get("https://bilibili.com/").build().unwrap();
+        assert!(req.headers().contains_key(reqwest::header::COOKIE));
+
+        #[cfg(feature = "bangumi")]
+        {
+            let req = user.get_anonymous("https://api.bilibili.com/").build().unwrap();
+            assert!(!req.headers().contains_key(reqwest::header::COOKIE));
+        }
+    }
+}
@@ -60,8 +60,9 @@ mod tests {
     const TEST_IP: &str = "8.8.8.8";
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_clientinfo_ip() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let resp = bpi.clientinfo_ip(Some(TEST_IP)).await?;
         if resp.code == 0 {
@@ -5,15 +5,10 @@
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 use serde::{ Deserialize, Serialize };
 
-/// 评论区类型枚举（部分示例，需按需求扩展）
-#[derive(Debug, Clone, Copy, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum CommentType {
-    Video = 1, // 视频
-    Article = 12, // 专栏
-    Dynamic = 17, // 动态
-    Unknown = 0,
-}
+pub use super::resource::CommentResourceType;
+
+/// 评论区类型，等同于 [`CommentResourceType`]
+pub type CommentType = CommentResourceType;
 
 /// 举报原因枚举
 #[derive(Debug, Clone, Copy, Serialize)]
@@ -61,7 +56,7 @@ impl BpiClient {
     /// # 参数
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
-    /// | `type` | CommentType | 评论区类型 |
+    /// | `type` | CommentResourceType | 评论区类型 |
     /// | `oid` | u64 | 对象 ID |
     /// | `message` | &str | 评论内容 |
     /// | `root` | `Option<u64>` | 根评论 rpid，可选 |
@@ -71,7 +66,7 @@ impl BpiClient {
     /// [发表评论](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/action.md#发表评论)
     pub async fn comment_add(
         &self,
-        r#type: CommentType,
+        r#type: CommentResourceType,
         oid: u64,
         message: &str,
         root: Option<u64>,
@@ -79,7 +74,7 @@ impl BpiClient {
     ) -> Result<BpiResponse<CommentData>, BpiError> {
         let csrf = self.csrf()?;
         let mut params = vec![
-            ("type", (r#type as u32).to_string()),
+            ("type", r#type.code().to_string()),
             ("oid", oid.to_string()),
             ("message", message.to_string()),
             ("plat", "1".to_string()), // 默认 web
@@ -102,7 +97,7 @@ impl BpiClient {
     /// # 参数
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
-    /// | `type` | CommentType | 评论区类型 |
+    /// | `type` | CommentResourceType | 评论区类型 |
     /// | `oid` | u64 | 对象 ID |
     /// | `rpid` | u64 | 评论 rpid |
     /// | `action` | u8 | 操作：0 取消，1 点赞 |
@@ -111,7 +106,7 @@ impl BpiClient {
     /// [点赞评论](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/action.md#点赞评论)
     pub async fn comment_like(
         &self,
-        r#type: CommentType,
+        r#type: CommentResourceType,
         oid: u64,
         rpid: u64,
         action: u8
@@ -119,7 +114,7 @@ impl BpiClient {
         let csrf = self.csrf()?;
 
         let params = [
-            ("type", (r#type as u32).to_string()),
+            ("type", r#type.code().to_string()),
             ("oid", oid.to_string()),
             ("rpid", rpid.to_string()),
             ("action", action.to_string()), // 0 取消，1 点赞
@@ -137,7 +132,7 @@ impl BpiClient {
     /// # 参数
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
-    /// | `type` | CommentType | 评论区类型 |
+    /// | `type` | CommentResourceType | 评论区类型 |
     /// | `oid` | u64 | 对象 ID |
     /// | `rpid` | u64 | 评论 rpid |
     /// | `action` | u8 | 操作：0 取消，1 点踩 |
@@ -146,7 +141,7 @@ impl BpiClient {
     /// [点踩评论](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/action.md#点踩评论)
     pub async fn comment_dislike(
         &self,
-        r#type: CommentType,
+        r#type: CommentResourceType,
         oid: u64,
         rpid: u64,
         action: u8
@@ -154,7 +149,7 @@ impl BpiClient {
         let csrf = self.csrf()?;
 
         let params = [
-            ("type", (r#type as u32).to_string()),
+            ("type", r#type.code().to_string()),
             ("oid", oid.to_string()),
             ("rpid", rpid.to_string()),
             ("action", action.to_string()), // 0 取消，1 点踩
@@ -171,7 +166,7 @@ impl BpiClient {
     /// # 参数
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
-    /// | `type` | CommentType | 评论区类型 |
+    /// | `type` | CommentResourceType | 评论区类型 |
     /// | `oid` | u64 | 对象 ID |
     /// | `rpid` | u64 | 评论 rpid |
     ///
@@ -179,14 +174,14 @@ impl BpiClient {
     /// [删除评论](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/action.md#删除评论)
     pub async fn comment_delete(
         &self,
-        r#type: CommentType,
+        r#type: CommentResourceType,
         oid: u64,
         rpid: u64
     ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
         let csrf = self.csrf()?;
 
         let params = [
-            ("type", (r#type as u32).to_string()),
+            ("type", r#type.code().to_string()),
             ("oid", oid.to_string()),
             ("rpid", rpid.to_string()),
             ("csrf", csrf.to_string()),
@@ -202,7 +197,7 @@ impl BpiClient {
     /// # 参数
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
-    /// | `type` | CommentType | 评论区类型 |
+    /// | `type` | CommentResourceType | 评论区类型 |
     /// | `oid` | u64 | 对象 ID |
     /// | `rpid` | u64 | 评论 rpid |
     /// | `action` | u8 | 操作：0 取消置顶，1 置顶 |
@@ -211,7 +206,7 @@ impl BpiClient {
     /// [置顶评论](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/action.md#置顶评论)
     pub async fn comment_top(
         &self,
-        r#type: CommentType,
+        r#type: CommentResourceType,
         oid: u64,
         rpid: u64,
         action: u8
@@ -219,7 +214,7 @@ impl BpiClient {
         let csrf = self.csrf()?;
 
         let params = [
-            ("type", (r#type as u32).to_string()),
+            ("type", r#type.code().to_string()),
             ("oid", oid.to_string()),
             ("rpid", rpid.to_string()),
             ("action", action.to_string()), // 0 取消置顶，1 置顶
@@ -237,7 +232,7 @@ impl BpiClient {
     /// # 参数
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
-    /// | `type` | CommentType | 评论区类型 |
+    /// | `type` | CommentResourceType | 评论区类型 |
     /// | `oid` | u64 | 对象 ID |
     /// | `rpid` | u64 | 评论 rpid |
     /// | `reason` | ReportReason | 举报原因 |
@@ -247,7 +242,7 @@ impl BpiClient {
     /// [举报评论](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/action.md#举报评论)
     pub async fn comment_report(
         &self,
-        r#type: CommentType,
+        r#type: CommentResourceType,
         oid: u64,
         rpid: u64,
         reason: ReportReason,
@@ -255,7 +250,7 @@ impl BpiClient {
     ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
         let csrf = self.csrf()?;
         let mut params = vec![
-            ("type", (r#type as u32).to_string()),
+            ("type", r#type.code().to_string()),
             ("oid", oid.to_string()),
             ("rpid", rpid.to_string()),
             ("reason", (reason as u32).to_string()),
@@ -289,7 +284,7 @@ mod tests {
         // 简单伪随机：用当前秒对一个常数取模再加偏移
         let random_secs = (now % 1_000_000) + 1_600_000_000;
         let resp = bpi.comment_add(
-            CommentType::Video,
+            CommentResourceType::Video,
             TEST_AID,
             &random_secs.to_string(),
             None,
@@ -303,17 +298,18 @@ mod tests {
     /// 测试辅助函数：删除评论
     async fn delete_test_comment(rpid: u64) -> Result<(), BpiError> {
         let bpi = BpiClient::new();
-        bpi.comment_delete(CommentType::Video, TEST_AID, rpid).await?;
+        bpi.comment_delete(CommentResourceType::Video, TEST_AID, rpid).await?;
         Ok(())
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_comment_like() -> Result<(), BpiError> {
         let rpid = add_test_comment().await?;
         time::sleep(Duration::from_secs(3)).await;
 
-        let bpi = BpiClient::new();
-        let resp = bpi.comment_like(CommentType::Video, TEST_AID, rpid, 1).await?;
+        let bpi = crate::test_client!(Write);
+        let resp = bpi.comment_like(CommentResourceType::Video, TEST_AID, rpid, 1).await?;
         assert_eq!(resp.code, 0);
 
         time::sleep(Duration::from_secs(3)).await;
@@ -323,12 +319,13 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_comment_dislike() -> Result<(), BpiError> {
         let rpid = add_test_comment().await?;
         time::sleep(Duration::from_secs(3)).await;
 
-        let bpi = BpiClient::new();
-        let resp = bpi.comment_dislike(CommentType::Video, TEST_AID, rpid, 1).await?;
+        let bpi = crate::test_client!(Write);
+        let resp = bpi.comment_dislike(CommentResourceType::Video, TEST_AID, rpid, 1).await?;
 
         assert_eq!(resp.code, 0);
 
@@ -0,0 +1,341 @@
+//! 评论导出
+//!
+//! 将已获取的 [`Comment`] 展平为 [`CommentRecord`]，写出为 CSV 或 NDJSON，
+//! 便于导入表格或数据分析工具。发送者信息展平为 `uname`、`level` 等列，
+//! 楼中楼预览回复可一并展开为独立的行。
+//!
+//! ```no_run
+//! # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+//! use bpi_rs::comment::export::{ ExportOptions, write_csv };
+//! use bpi_rs::comment::resource::CommentResourceType;
+//!
+//! let page = bpi
+//!     .comment_list(CommentResourceType::Video, 2, None, None, None, None).await?
+//!     .into_data()?;
+//! let comments = page.replies.unwrap_or_default();
+//! let file = std::fs::File::create("comments.csv").map_err(|e| bpi_rs::BpiError::parse(e.to_string()))?;
+//! write_csv(file, &comments, &ExportOptions::default().strip_emotes(true).excel_bom(true))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Write;
+
+use serde::Serialize;
+
+use super::types::Comment;
+use crate::BpiError;
+use crate::utils::time::{ DEFAULT_TIMEZONE_OFFSET, timestamp_to_datetime };
+
+/// 导出选项
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    strip_emotes: bool,
+    include_replies: bool,
+    excel_bom: bool,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self { strip_emotes: false, include_replies: true, excel_bom: false }
+    }
+}
+
+impl ExportOptions {
+    /// 删除内容中的表情转义符（如 `[doge]`），默认保留
+    pub fn strip_emotes(mut self, strip: bool) -> Self {
+        self.strip_emotes = strip;
+        self
+    }
+
+    /// 是否展开评论附带的楼中楼预览回复，默认展开
+    pub fn include_replies(mut self, include: bool) -> Self {
+        self.include_replies = include;
+        self
+    }
+
+    /// CSV 开头写入 UTF-8 BOM，Excel 打开时中文不乱码，默认不写入
+    pub fn excel_bom(mut self, bom: bool) -> Self {
+        self.excel_bom = bom;
+        self
+    }
+}
+
+/// 展平后的一条评论
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommentRecord {
+    /// 评论 rpid
+    pub rpid: i64,
+    /// 评论区对象 id
+    pub oid: i64,
+    /// 评论区类型代码
+    pub oid_type: i64,
+    /// 根评论 rpid，一级评论为 0
+    pub root: i64,
+    /// 回复父评论 rpid
+    pub parent: i64,
+    /// 发送者 mid
+    pub mid: i64,
+    /// 发送者昵称
+    pub uname: String,
+    /// 发送者性别
+    pub sex: String,
+    /// 发送者等级
+    pub level: i32,
+    /// 发送者是否为大会员
+    pub vip: bool,
+    /// 粉丝勋章名
+    pub medal_name: Option<String>,
+    /// 粉丝勋章等级
+    pub medal_level: Option<u64>,
+    /// 发送时间戳
+    pub ctime: i64,
+    /// 发送时间，UTC+8
+    pub time: String,
+    /// 获赞数
+    pub like: i64,
+    /// 回复数
+    pub rcount: i64,
+    /// IP 属地
+    pub location: Option<String>,
+    /// 评论内容
+    pub message: String,
+}
+
+impl CommentRecord {
+    /// CSV 表头，与 [`CommentRecord::csv_row`] 的列一一对应
+    pub const CSV_HEADER: [&'static str; 18] = [
+        "rpid",
+        "oid",
+        "oid_type",
+        "root",
+        "parent",
+        "mid",
+        "uname",
+        "sex",
+        "level",
+        "vip",
+        "medal_name",
+        "medal_level",
+        "ctime",
+        "time",
+        "like",
+        "rcount",
+        "location",
+        "message",
+    ];
+
+    /// 展平一条评论，不包含其楼中楼回复
+    pub fn from_comment(comment: &Comment, options: &ExportOptions) -> Self {
+        let member = &comment.member;
+        let medal = member.fans_detail_a.as_ref();
+        let location = comment.reply_control.location
+            .as_deref()
+            .map(|location| location.trim_start_matches("IP属地：").to_string());
+
+        Self {
+            rpid: comment.rpid,
+            oid: comment.oid,
+            oid_type: comment.oid_type,
+            root: comment.root,
+            parent: comment.parent,
+            mid: comment.mid,
+            uname: member.uname.clone(),
+            sex: member.sex.clone(),
+            level: member.level_info.current_level,
+            vip: member.vip.vip_status == 1,
+            medal_name: medal.map(|medal| medal.medal_name.clone()),
+            medal_level: medal.map(|medal| medal.level),
+            ctime: comment.ctime,
+            time: timestamp_to_datetime(comment.ctime, DEFAULT_TIMEZONE_OFFSET)
+                .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_default(),
+            like: comment.like,
+            rcount: comment.rcount,
+            location,
+            message: if options.strip_emotes {
+                strip_emotes(comment)
+            } else {
+                comment.content.message.clone()
+            },
+        }
+    }
+
+    /// 按 [`CommentRecord::CSV_HEADER`] 的顺序输出各列
+    pub fn csv_row(&self) -> [String; 18] {
+        [
+            self.rpid.to_string(),
+            self.oid.to_string(),
+            self.oid_type.to_string(),
+            self.root.to_string(),
+            self.parent.to_string(),
+            self.mid.to_string(),
+            self.uname.clone(),
+            self.sex.clone(),
+            self.level.to_string(),
+            self.vip.to_string(),
+            self.medal_name.clone().unwrap_or_default(),
+            self.medal_level.map(|level| level.to_string()).unwrap_or_default(),
+            self.ctime.to_string(),
+            self.time.clone(),
+            self.like.to_string(),
+            self.rcount.to_string(),
+            self.location.clone().unwrap_or_default(),
+            self.message.clone(),
+        ]
+    }
+}
+
+/// 删除评论内容中的表情转义符
+///
+/// 优先按评论附带的表情表删除；表情表缺失时不做处理，避免误删正文中的方括号。
+fn strip_emotes(comment: &Comment) -> String {
+    let mut message = comment.content.message.clone();
+    if let Some(emotes) = &comment.content.emote {
+        for text in emotes.keys() {
+            message = message.replace(text.as_str(), "");
+        }
+    }
+    message.trim().to_string()
+}
+
+/// 展平评论列表，开启 [`ExportOptions::include_replies`] 时楼中楼回复紧跟在所属评论之后
+pub fn to_records(comments: &[Comment], options: &ExportOptions) -> Vec<CommentRecord> {
+    let mut records = Vec::with_capacity(comments.len());
+    for comment in comments {
+        records.push(CommentRecord::from_comment(comment, options));
+        if options.include_replies {
+            for reply in comment.replies.iter().flatten() {
+                records.push(CommentRecord::from_comment(reply, options));
+            }
+        }
+    }
+    records
+}
+
+/// 按 RFC 4180 转义 CSV 字段
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn write_error(e: std::io::Error) -> BpiError {
+    BpiError::parse(format!("写入导出文件失败: {}", e))
+}
+
+/// 将评论写出为 CSV，首行为表头
+pub fn write_csv<W: Write>(
+    mut writer: W,
+    comments: &[Comment],
+    options: &ExportOptions
+) -> Result<(), BpiError> {
+    if options.excel_bom {
+        writer.write_all("\u{feff}".as_bytes()).map_err(write_error)?;
+    }
+    writeln!(writer, "{}", CommentRecord::CSV_HEADER.join(",")).map_err(write_error)?;
+
+    for record in to_records(comments, options) {
+        let row: Vec<String> = record
+            .csv_row()
+            .iter()
+            .map(|field| csv_field(field))
+            .collect();
+        writeln!(writer, "{}", row.join(",")).map_err(write_error)?;
+    }
+    writer.flush().map_err(write_error)
+}
+
+/// 将评论写出为 NDJSON，每行一条 [`CommentRecord`]
+pub fn write_ndjson<W: Write>(
+    mut writer: W,
+    comments: &[Comment],
+    options: &ExportOptions
+) -> Result<(), BpiError> {
+    for record in to_records(comments, options) {
+        serde_json::to_writer(&mut writer, &record)?;
+        writer.write_all(b"\n").map_err(write_error)?;
+    }
+    writer.flush().map_err(write_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(rpid: i64, root: i64, message: &str) -> serde_json::Value {
+        serde_json::json!({
+            "rpid": rpid, "oid": 1, "type": 1, "mid": 2, "root": root, "parent": root, "dialog": 0,
+            "count": 0, "rcount": 0, "state": 0, "fansgrade": 0, "attr": 0, "ctime": 1700000000,
+            "like": 3, "action": 0, "invisible": false,
+            "member": {
+                "mid": "2", "uname": "碧诗", "sex": "男", "sign": "", "avatar": "",
+                "level_info": { "current_level": 6, "current_min": 0, "current_exp": 0, "next_exp": 0 },
+                "pendant": { "pid": 0, "name": "", "image": "", "expire": 0 },
+                "nameplate": { "nid": 0, "name": "", "image": "", "image_small": "", "level": "", "condition": "" },
+                "official_verify": { "type": -1, "desc": "" },
+                "vip": {
+                    "vipType": 2, "vipDueDate": 0, "dueRemark": "", "accessStatus": 0, "vipStatus": 1,
+                    "vipStatusWarn": "", "themeType": 0, "avatar_subscript": 0, "nickname_color": ""
+                }
+            },
+            "content": {
+                "message": message,
+                "emote": { "[doge]": { "id": 1, "package_id": 1, "state": 0, "type": 1, "text": "[doge]", "url": "" } }
+            },
+            "up_action": { "like": false, "reply": false },
+            "reply_control": { "location": "IP属地：上海" },
+            "folder": { "has_folded": false, "is_folded": false, "rule": "" }
+        })
+    }
+
+    fn comments() -> Vec<Comment> {
+        let mut root = comment(1, 0, "好看[doge]，\"真的\"");
+        root["replies"] = serde_json::json!([comment(2, 1, "同意\n+1")]);
+        vec![serde_json::from_value(root).unwrap()]
+    }
+
+    #[test]
+    fn test_to_records() {
+        let comments = comments();
+        let records = to_records(&comments, &ExportOptions::default().strip_emotes(true));
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].message, "好看，\"真的\"");
+        assert_eq!(records[0].time, "2023-11-15 06:13:20");
+        assert_eq!(records[0].location.as_deref(), Some("上海"));
+        assert!(records[0].vip);
+        assert_eq!(records[1].root, 1);
+
+        let top_level = to_records(&comments, &ExportOptions::default().include_replies(false));
+        assert_eq!(top_level.len(), 1);
+        assert_eq!(top_level[0].message, "好看[doge]，\"真的\"");
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut out = Vec::new();
+        write_csv(&mut out, &comments(), &ExportOptions::default()).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+
+        assert!(csv.starts_with("rpid,oid,oid_type,"));
+        assert!(csv.contains(",\"好看[doge]，\"\"真的\"\"\"\n"));
+        assert!(csv.contains(",\"同意\n+1\"\n"));
+    }
+
+    #[test]
+    fn test_write_ndjson() {
+        let mut out = Vec::new();
+        write_ndjson(&mut out, &comments(), &ExportOptions::default()).unwrap();
+        let lines: Vec<serde_json::Value> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["uname"], "碧诗");
+        assert_eq!(lines[1]["message"], "同意\n+1");
+    }
+}
@@ -0,0 +1,265 @@
+//! 评论过滤
+//!
+//! 在已解析的 [`Comment`] 上按顺序执行一组规则，命中的评论附带命中信息返回，
+//! 用于审核机器人等场景，无需再次解析原始 JSON。
+//!
+//! 内置关键词规则与谓词规则；正则或情感分析等规则可实现 [`CommentRule`] 接入。
+
+use super::list::CommentListData;
+use super::types::Comment;
+
+/// 规则命中信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterMatch {
+    /// 命中的规则名
+    pub rule: String,
+    /// 命中的关键词或说明
+    pub detail: Option<String>,
+}
+
+/// 命中规则的评论
+#[derive(Debug, Clone)]
+pub struct FilteredComment {
+    pub comment: Comment,
+    pub matches: Vec<FilterMatch>,
+}
+
+/// 评论规则
+pub trait CommentRule: Send + Sync {
+    /// 检查评论，命中时返回命中信息
+    fn check(&self, comment: &Comment) -> Option<FilterMatch>;
+}
+
+/// 关键词规则，评论内容包含任一关键词即命中
+#[derive(Debug, Clone)]
+pub struct KeywordRule {
+    name: String,
+    keywords: Vec<String>,
+    case_sensitive: bool,
+}
+
+impl KeywordRule {
+    pub fn new<I, S>(name: impl Into<String>, keywords: I) -> Self
+        where I: IntoIterator<Item = S>, S: Into<String>
+    {
+        Self {
+            name: name.into(),
+            keywords: keywords.into_iter().map(Into::into).collect(),
+            case_sensitive: false,
+        }
+    }
+
+    /// 区分大小写，默认不区分
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+}
+
+impl CommentRule for KeywordRule {
+    fn check(&self, comment: &Comment) -> Option<FilterMatch> {
+        let message = &comment.content.message;
+        let lowered;
+        let haystack = if self.case_sensitive {
+            message
+        } else {
+            lowered = message.to_lowercase();
+            &lowered
+        };
+
+        self.keywords
+            .iter()
+            .find(|kw| {
+                if self.case_sensitive {
+                    haystack.contains(kw.as_str())
+                } else {
+                    haystack.contains(&kw.to_lowercase())
+                }
+            })
+            .map(|kw| FilterMatch {
+                rule: self.name.clone(),
+                detail: Some(kw.clone()),
+            })
+    }
+}
+
+struct PredicateRule<F> {
+    name: String,
+    predicate: F,
+}
+
+impl<F> CommentRule for PredicateRule<F> where F: Fn(&Comment) -> bool + Send + Sync {
+    fn check(&self, comment: &Comment) -> Option<FilterMatch> {
+        (self.predicate)(comment).then(|| FilterMatch {
+            rule: self.name.clone(),
+            detail: None,
+        })
+    }
+}
+
+/// 规则组合方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatchMode {
+    /// 命中任一规则即保留
+    #[default]
+    Any,
+    /// 命中全部规则才保留
+    All,
+}
+
+/// 评论过滤器
+///
+/// ```
+/// use bpi_rs::comment::filter::{ CommentFilter, KeywordRule };
+///
+/// let filter = CommentFilter::new()
+///     .rule(KeywordRule::new("广告", ["加群", "私信领取"]))
+///     .predicate("低等级", |c| c.member.level_info.current_level < 2);
+/// ```
+#[derive(Default)]
+pub struct CommentFilter {
+    rules: Vec<Box<dyn CommentRule>>,
+    mode: MatchMode,
+    include_replies: bool,
+}
+
+impl CommentFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加规则
+    pub fn rule(mut self, rule: impl CommentRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// 添加谓词规则
+    pub fn predicate<F>(self, name: impl Into<String>, predicate: F) -> Self
+        where F: Fn(&Comment) -> bool + Send + Sync + 'static
+    {
+        self.rule(PredicateRule {
+            name: name.into(),
+            predicate,
+        })
+    }
+
+    /// 规则组合方式，默认 [`MatchMode::Any`]
+    pub fn mode(mut self, mode: MatchMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// 是否同时检查评论附带的楼中楼回复，默认否
+    pub fn include_replies(mut self, include_replies: bool) -> Self {
+        self.include_replies = include_replies;
+        self
+    }
+
+    /// 检查单条评论，返回全部命中信息；未满足组合条件时返回空
+    pub fn check(&self, comment: &Comment) -> Vec<FilterMatch> {
+        let matches: Vec<FilterMatch> = self.rules
+            .iter()
+            .filter_map(|rule| rule.check(comment))
+            .collect();
+
+        let passed = match self.mode {
+            MatchMode::Any => !matches.is_empty(),
+            MatchMode::All => !self.rules.is_empty() && matches.len() == self.rules.len(),
+        };
+
+        if passed { matches } else { Vec::new() }
+    }
+
+    /// 过滤评论，只返回命中的评论
+    pub fn apply<'a, I>(&self, comments: I) -> Vec<FilteredComment>
+        where I: IntoIterator<Item = &'a Comment>
+    {
+        let mut result = Vec::new();
+        for comment in comments {
+            self.collect(comment, &mut result);
+        }
+        result
+    }
+
+    fn collect(&self, comment: &Comment, result: &mut Vec<FilteredComment>) {
+        let matches = self.check(comment);
+        if !matches.is_empty() {
+            result.push(FilteredComment {
+                comment: comment.clone(),
+                matches,
+            });
+        }
+
+        if self.include_replies {
+            for reply in comment.replies.iter().flatten() {
+                self.collect(reply, result);
+            }
+        }
+    }
+}
+
+impl CommentListData {
+    /// 对置顶评论与评论列表执行过滤
+    pub fn filter(&self, filter: &CommentFilter) -> Vec<FilteredComment> {
+        filter.apply(self.top_replies.iter().flatten().chain(self.replies.iter().flatten()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(rpid: i64, message: &str, replies: Vec<Comment>) -> Comment {
+        let mut value = serde_json::json!({
+            "rpid": rpid, "oid": 1, "type": 1, "mid": 2, "root": 0, "parent": 0, "dialog": 0,
+            "count": 0, "rcount": 0, "state": 0, "fansgrade": 0, "attr": 0, "ctime": 0,
+            "like": 0, "action": 0, "invisible": false,
+            "member": {
+                "mid": "2", "uname": "test", "sex": "保密", "sign": "", "avatar": "",
+                "level_info": { "current_level": 1, "current_min": 0, "current_exp": 0, "next_exp": 0 },
+                "pendant": { "pid": 0, "name": "", "image": "", "expire": 0 },
+                "nameplate": { "nid": 0, "name": "", "image": "", "image_small": "", "level": "", "condition": "" },
+                "official_verify": { "type": -1, "desc": "" },
+                "vip": {
+                    "vipType": 0, "vipDueDate": 0, "dueRemark": "", "accessStatus": 0, "vipStatus": 0,
+                    "vipStatusWarn": "", "themeType": 0, "avatar_subscript": 0, "nickname_color": ""
+                }
+            },
+            "content": { "message": message },
+            "up_action": { "like": false, "reply": false },
+            "reply_control": {},
+            "folder": { "has_folded": false, "is_folded": false, "rule": "" }
+        });
+        value["replies"] = serde_json::to_value(replies).unwrap();
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_comment_filter() {
+        let comments = vec![
+            comment(1, "加群领取资料", vec![]),
+            comment(2, "好看", vec![comment(3, "私信领取", vec![])])
+        ];
+
+        let filter = CommentFilter::new().rule(KeywordRule::new("广告", ["加群", "私信"]));
+        let hits = filter.apply(&comments);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].matches[0].detail.as_deref(), Some("加群"));
+
+        let filter = filter.include_replies(true);
+        let hits: Vec<_> = filter
+            .apply(&comments)
+            .into_iter()
+            .map(|c| c.comment.rpid)
+            .collect();
+        assert_eq!(hits, vec![1, 3]);
+
+        let filter = CommentFilter::new()
+            .rule(KeywordRule::new("广告", ["领取"]))
+            .predicate("一级评论", |c| c.root == 0)
+            .mode(MatchMode::All)
+            .include_replies(true);
+        assert_eq!(filter.apply(&comments).len(), 2);
+    }
+}
@@ -0,0 +1,244 @@
+//! UP 主评论区关键词屏蔽
+//!
+//! 读取与修改创作者的评论关键词屏蔽列表，命中关键词的评论会被自动折叠。
+//! 配合 [`comment_filter_sync`](crate::BpiClient::comment_filter_sync) 可以将本地维护的
+//! 关键词列表同步到一个或多个账号。
+//!
+//! [参考文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/action)
+
+use std::collections::HashSet;
+
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use serde::{ Deserialize, Serialize };
+
+/// 屏蔽关键词
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilterKeyword {
+    /// 关键词 id，删除时使用
+    pub id: u64,
+    /// 关键词
+    #[serde(alias = "filter")]
+    pub keyword: String,
+    /// 添加时间
+    #[serde(default)]
+    pub ctime: i64,
+}
+
+/// 屏蔽关键词列表
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FilterKeywordList {
+    #[serde(default)]
+    pub list: Vec<FilterKeyword>,
+}
+
+impl FilterKeywordList {
+    /// 所有关键词
+    pub fn keywords(&self) -> Vec<&str> {
+        self.list
+            .iter()
+            .map(|k| k.keyword.as_str())
+            .collect()
+    }
+}
+
+/// 关键词同步结果
+#[derive(Debug, Default)]
+pub struct FilterSyncReport {
+    /// 新增的关键词
+    pub added: Vec<String>,
+    /// 删除的关键词
+    pub removed: Vec<String>,
+    /// 操作失败的关键词及错误
+    pub failed: Vec<(String, BpiError)>,
+}
+
+/// 计算需要新增的关键词与需要删除的已有条目
+fn diff<'a>(
+    current: &'a [FilterKeyword],
+    wanted: &[String]
+) -> (Vec<String>, Vec<&'a FilterKeyword>) {
+    let existing: HashSet<&str> = current
+        .iter()
+        .map(|k| k.keyword.as_str())
+        .collect();
+    let wanted_set: HashSet<&str> = wanted
+        .iter()
+        .map(String::as_str)
+        .collect();
+
+    let mut seen = HashSet::new();
+    let add = wanted
+        .iter()
+        .filter(|k| !existing.contains(k.as_str()) && seen.insert(k.as_str()))
+        .cloned()
+        .collect();
+    let remove = current
+        .iter()
+        .filter(|k| !wanted_set.contains(k.keyword.as_str()))
+        .collect();
+
+    (add, remove)
+}
+
+impl BpiClient {
+    /// 获取评论区屏蔽关键词列表
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/action)
+    pub async fn comment_filter_list(&self) -> Result<BpiResponse<FilterKeywordList>, BpiError> {
+        self
+            .get("https://api.bilibili.com/x/v2/reply/filter/list")
+            .send_bpi("获取评论屏蔽关键词").await
+    }
+
+    /// 添加评论区屏蔽关键词
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `keyword` | &str | 关键词 |
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/action)
+    pub async fn comment_filter_add(
+        &self,
+        keyword: &str
+    ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        let keyword = keyword.trim();
+        if keyword.is_empty() {
+            return Err(BpiError::invalid_parameter("keyword", "关键词不能为空"));
+        }
+        let csrf = self.csrf()?;
+
+        let params = [
+            ("filter", keyword.to_string()),
+            ("csrf", csrf),
+        ];
+
+        self
+            .post("https://api.bilibili.com/x/v2/reply/filter/add")
+            .form(&params)
+            .send_bpi("添加评论屏蔽关键词").await
+    }
+
+    /// 删除评论区屏蔽关键词
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `id` | u64 | 关键词 id，见 [`FilterKeyword::id`] |
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/action)
+    pub async fn comment_filter_del(
+        &self,
+        id: u64
+    ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        let csrf = self.csrf()?;
+
+        let params = [
+            ("id", id.to_string()),
+            ("csrf", csrf),
+        ];
+
+        self
+            .post("https://api.bilibili.com/x/v2/reply/filter/del")
+            .form(&params)
+            .send_bpi("删除评论屏蔽关键词").await
+    }
+
+    /// 将屏蔽关键词列表同步为给定内容
+    ///
+    /// 添加缺少的关键词并删除多余的关键词，单个关键词失败不会中断同步。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `keywords` | `&[impl AsRef<str>]` | 期望的关键词列表 |
+    pub async fn comment_filter_sync(
+        &self,
+        keywords: &[impl AsRef<str>]
+    ) -> Result<FilterSyncReport, BpiError> {
+        let wanted: Vec<String> = keywords
+            .iter()
+            .map(|k| k.as_ref().trim().to_string())
+            .filter(|k| !k.is_empty())
+            .collect();
+        let current = self.comment_filter_list().await?.into_data()?.list;
+        let (add, remove) = diff(&current, &wanted);
+
+        let mut report = FilterSyncReport::default();
+        for item in remove {
+            match self.comment_filter_del(item.id).await {
+                Ok(_) => report.removed.push(item.keyword.clone()),
+                Err(e) => report.failed.push((item.keyword.clone(), e)),
+            }
+        }
+        for keyword in add {
+            match self.comment_filter_add(&keyword).await {
+                Ok(_) => report.added.push(keyword),
+                Err(e) => report.failed.push((keyword, e)),
+            }
+        }
+
+        tracing::info!(
+            "屏蔽关键词同步完成: 新增 {} 个, 删除 {} 个, 失败 {} 个",
+            report.added.len(),
+            report.removed.len(),
+            report.failed.len()
+        );
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyword(id: u64, keyword: &str) -> FilterKeyword {
+        FilterKeyword { id, keyword: keyword.to_string(), ctime: 0 }
+    }
+
+    #[test]
+    fn test_filter_diff() {
+        let current = vec![keyword(1, "加群"), keyword(2, "引流"), keyword(3, "广告")];
+        let wanted = vec!["加群".to_string(), "私信".to_string(), "私信".to_string()];
+
+        let (add, remove) = diff(&current, &wanted);
+        assert_eq!(add, vec!["私信".to_string()]);
+        let removed: Vec<u64> = remove
+            .iter()
+            .map(|k| k.id)
+            .collect();
+        assert_eq!(removed, vec![2, 3]);
+    }
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_comment_filter_list() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Authed);
+        let data = bpi.comment_filter_list().await?.into_data()?;
+        tracing::info!("屏蔽关键词: {:?}", data.keywords());
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "write"]
+    async fn test_comment_filter_sync() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Write);
+        let before = bpi.comment_filter_list().await?.into_data()?;
+        let original: Vec<String> = before
+            .keywords()
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let mut wanted = original.clone();
+        wanted.push("bpi-rs测试关键词".to_string());
+        let report = bpi.comment_filter_sync(&wanted).await?;
+        assert!(report.failed.is_empty());
+
+        bpi.comment_filter_sync(&original).await?;
+        Ok(())
+    }
+}
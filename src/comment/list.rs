@@ -2,7 +2,11 @@
 //!
 //! [参考文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/list.md)
 
+use crate::pagination::{ Paginated, paginate };
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+use super::resource::CommentResourceType;
+use futures_util::Stream;
 use serde::{ Deserialize, Serialize };
 
 use super::types::{
@@ -25,6 +29,7 @@ pub struct CommentListData {
     pub replies: Option<Vec<Comment>>, // 评论列表，禁用时为 null
     pub top: Option<Top>, // 评论列表顶部信息
     pub top_replies: Option<Vec<Comment>>,
+    pub root: Option<Comment>, // 根评论，仅子评论列表
     pub effects: Option<serde_json::Value>,
     pub assist: Option<u64>, // 待确认
     pub blacklist: Option<u64>, // 待确认
@@ -73,7 +78,12 @@ pub struct HotCommentPage {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CountData {
-    count: u64,
+    pub count: u64,
+}
+
+/// 懒加载模式的分页参数，`offset` 为上一页的 `next_offset`
+fn pagination_str(offset: Option<&str>) -> String {
+    serde_json::json!({ "offset": offset.unwrap_or_default() }).to_string()
 }
 
 impl BpiClient {
@@ -84,7 +94,7 @@ impl BpiClient {
     /// # 参数
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
-    /// | `type` | i32 | 评论区类型 |
+    /// | `type` | CommentResourceType | 评论区类型 |
     /// | `oid` | i64 | 对象 ID |
     /// | `pn` | `Option<i32>` | 页码，可选，默认为 1 |
     /// | `ps` | `Option<i32>` | 每页条数，可选，范围 1-20 |
@@ -95,14 +105,14 @@ impl BpiClient {
     /// [获取评论主列表](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/list.md#获取评论主列表)
     pub async fn comment_list(
         &self,
-        r#type: i32,
+        r#type: CommentResourceType,
         oid: i64,
         pn: Option<i32>,
         ps: Option<i32>,
         sort: Option<i32>,
         nohot: Option<i32>
     ) -> Result<CommentListResponse, BpiError> {
-        let mut params = vec![("type", r#type.to_string()), ("oid", oid.to_string())];
+        let mut params = vec![("type", r#type.code().to_string()), ("oid", oid.to_string())];
         if let Some(pn) = pn {
             params.push(("pn", pn.to_string()));
         }
@@ -122,6 +132,69 @@ impl BpiClient {
             .send_bpi("获取评论主列表").await
     }
 
+    /// 获取评论主列表（懒加载）
+    ///
+    /// 网页端当前使用的接口，以游标代替页码翻页。下一页的偏移通过
+    /// [`Cursor::next_offset`](super::types::Cursor::next_offset) 获取。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `type` | CommentResourceType | 评论区类型 |
+    /// | `oid` | i64 | 对象 ID |
+    /// | `mode` | `Option<i32>` | 排序方式，可选：2 按时间，3 按热度（默认） |
+    /// | `offset` | `Option<&str>` | 上一页返回的偏移，首页为 `None` |
+    ///
+    /// # 文档
+    /// [获取评论区明细_懒加载](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/list.md#获取评论区明细_懒加载)
+    pub async fn comment_list_lazy(
+        &self,
+        r#type: CommentResourceType,
+        oid: i64,
+        mode: Option<i32>,
+        offset: Option<&str>
+    ) -> Result<CommentListResponse, BpiError> {
+        let params = [
+            ("type", r#type.code().to_string()),
+            ("oid", oid.to_string()),
+            ("mode", mode.unwrap_or(3).to_string()),
+            ("pagination_str", pagination_str(offset)),
+            ("plat", "1".to_string()),
+        ];
+
+        self
+            .get("https://api.bilibili.com/x/v2/reply/wbi/main")
+            .query(&params)
+            .send_bpi_wbi("获取评论主列表").await
+    }
+
+    /// 逐页输出评论区的全部一级评论（懒加载接口）
+    ///
+    /// 置顶评论不在输出之列，需要时从首页的 `top_replies` 获取。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `type` | CommentResourceType | 评论区类型 |
+    /// | `oid` | i64 | 对象 ID |
+    /// | `mode` | `Option<i32>` | 排序方式，可选：2 按时间，3 按热度（默认） |
+    pub fn comment_list_stream(
+        &self,
+        r#type: CommentResourceType,
+        oid: i64,
+        mode: Option<i32>
+    ) -> impl Stream<Item = Result<Comment, BpiError>> + '_ {
+        paginate(String::new(), move |offset| async move {
+            let offset = Some(offset.as_str()).filter(|o| !o.is_empty());
+            let data = self.comment_list_lazy(r#type, oid, mode, offset).await?.into_data()?;
+            let next = data.cursor
+                .as_ref()
+                .and_then(|c| c.next_offset())
+                .map(str::to_string);
+            Ok(Paginated::new(data.replies.unwrap_or_default(), next))
+        })
+    }
+
     /// 获取某条根评论下的子评论列表
     ///
     /// 获取指定根评论下的所有子评论，支持分页。
@@ -129,7 +202,7 @@ impl BpiClient {
     /// # 参数
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
-    /// | `type` | i32 | 评论区类型 |
+    /// | `type` | CommentResourceType | 评论区类型 |
     /// | `oid` | i64 | 对象 ID |
     /// | `root` | i64 | 根评论 rpid |
     /// | `pn` | `Option<i32>` | 页码，可选，默认为 1 |
@@ -139,14 +212,14 @@ impl BpiClient {
     /// [获取子评论列表](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/list.md#获取子评论列表)
     pub async fn comment_replies(
         &self,
-        r#type: i32,
+        r#type: CommentResourceType,
         oid: i64,
         root: i64,
         pn: Option<i32>,
         ps: Option<i32>
     ) -> Result<CommentListResponse, BpiError> {
         let mut params = vec![
-            ("type", r#type.to_string()),
+            ("type", r#type.code().to_string()),
             ("oid", oid.to_string()),
             ("root", root.to_string())
         ];
@@ -170,7 +243,7 @@ impl BpiClient {
     /// # 参数
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
-    /// | `type` | i32 | 评论区类型 |
+    /// | `type` | CommentResourceType | 评论区类型 |
     /// | `oid` | i64 | 对象 ID |
     /// | `root` | i64 | 根评论 rpid |
     /// | `pn` | `Option<i32>` | 页码，可选，默认为 1 |
@@ -180,14 +253,14 @@ impl BpiClient {
     /// [获取热评列表](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/list.md#获取热评列表)
     pub async fn comment_hot(
         &self,
-        r#type: i32,
+        r#type: CommentResourceType,
         oid: i64,
         root: i64,
         pn: Option<i32>,
         ps: Option<i32>
     ) -> Result<HotCommentResponse, BpiError> {
         let mut params = vec![
-            ("type", r#type.to_string()),
+            ("type", r#type.code().to_string()),
             ("oid", oid.to_string()),
             ("root", root.to_string())
         ];
@@ -208,18 +281,18 @@ impl BpiClient {
     /// # 参数
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
-    /// | `type` | i32 | 评论区类型 |
+    /// | `type` | CommentResourceType | 评论区类型 |
     /// | `oid` | i64 | 对象 ID |
     ///
     /// # 文档
     /// [获取评论总数](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/list.md#获取评论总数)
     pub async fn comment_count(
         &self,
-        r#type: i32,
+        r#type: CommentResourceType,
         oid: i64
     ) -> Result<BpiResponse<CountData>, BpiError> {
         let params = [
-            ("type", r#type.to_string()),
+            ("type", r#type.code().to_string()),
             ("oid", oid.to_string()),
         ];
         self
@@ -227,6 +300,36 @@ impl BpiClient {
             .query(&params)
             .send_bpi("获取评论区评论总数").await
     }
+
+    /// 获取评论区评论总数（仅返回数量）
+    ///
+    /// 基于 [`comment_count`](Self::comment_count)，适合列表页只展示数量的场景。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `type` | CommentResourceType | 评论区类型 |
+    /// | `oid` | i64 | 对象 ID |
+    pub async fn reply_count(&self, r#type: CommentResourceType, oid: i64) -> Result<u64, BpiError> {
+        Ok(self.comment_count(r#type, oid).await?.into_data()?.count)
+    }
+
+    /// 检查评论区是否存在
+    ///
+    /// 评论区已关闭（12002）或对象不存在（-404）时返回 `false`，其余错误照常返回。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `type` | CommentResourceType | 评论区类型 |
+    /// | `oid` | i64 | 对象 ID |
+    pub async fn has_comment_section(&self, r#type: CommentResourceType, oid: i64) -> Result<bool, BpiError> {
+        match self.comment_count(r#type, oid).await {
+            Ok(_) => Ok(true),
+            Err(e) if matches!(e.code(), Some(12002) | Some(-404)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -234,13 +337,55 @@ mod tests {
     use super::*;
     use tracing::info;
 
-    const TEST_TYPE: i32 = 1;
+    const TEST_TYPE: CommentResourceType = CommentResourceType::Video;
     const TEST_OID: i64 = 23199;
     const TEST_ROOT_RPID: i64 = 2554491176;
 
+    #[test]
+    fn test_pagination_str() {
+        assert_eq!(pagination_str(None), r#"{"offset":""}"#);
+        assert_eq!(
+            pagination_str(Some(r#"{"type":1,"data":{"pn":2}}"#)),
+            r#"{"offset":"{\"type\":1,\"data\":{\"pn\":2}}"}"#
+        );
+    }
+
     #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_comment_list_stream() -> Result<(), BpiError> {
+        use futures_util::{ StreamExt, TryStreamExt };
+
+        let bpi = crate::test_client!(Guest);
+        let comments: Vec<Comment> = bpi
+            .comment_list_stream(TEST_TYPE, TEST_OID, Some(2))
+            .take(45)
+            .try_collect().await?;
+        info!("获取评论数: {}", comments.len());
+        assert_eq!(comments.len(), 45);
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_comment_list_lazy() -> Result<(), Box<BpiError>> {
+        let bpi = crate::test_client!(Guest);
+
+        let data = bpi.comment_list_lazy(TEST_TYPE, TEST_OID, Some(2), None).await?.into_data()?;
+        let cursor = data.cursor.ok_or_else(BpiError::missing_data)?;
+        info!("第一页评论数: {}", data.replies.unwrap_or_default().len());
+
+        if let Some(offset) = cursor.next_offset() {
+            let next = bpi.comment_list_lazy(TEST_TYPE, TEST_OID, Some(2), Some(offset)).await?;
+            info!("第二页评论数: {}", next.into_data()?.replies.unwrap_or_default().len());
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
     async fn test_comment_list() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let result = bpi.comment_list(
             TEST_TYPE,
@@ -257,8 +402,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_comment_replies() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let result = bpi.comment_replies(
             TEST_TYPE,
@@ -274,8 +420,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_comment_hot() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let root_rpid = 654321;
 
         let result = bpi.comment_hot(TEST_TYPE, TEST_OID, root_rpid, Some(1), Some(5)).await?;
@@ -290,8 +437,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_comment_count() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let result = bpi.comment_count(TEST_TYPE, TEST_OID).await?;
 
@@ -300,4 +448,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_reply_count() -> Result<(), Box<BpiError>> {
+        let bpi = crate::test_client!(Guest);
+
+        let count = bpi.reply_count(TEST_TYPE, TEST_OID).await?;
+        info!("评论总数: {}", count);
+
+        let exists = bpi.has_comment_section(TEST_TYPE, TEST_OID).await?;
+        assert!(exists);
+
+        Ok(())
+    }
 }
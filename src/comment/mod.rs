@@ -1,4 +1,10 @@
 //! 评论
 pub mod action;
+pub mod export;
+pub mod filter;
+pub mod keyword;
 pub mod list;
+pub mod moderation;
+pub mod resource;
 pub mod types;
+pub mod watch;
@@ -0,0 +1,328 @@
+//! 评论自动管理
+//!
+//! UP 主为自己的评论区配置一组 [`ModerationRule`]：条件复用 [`CommentFilter`]
+//! （关键词、等级、粉丝勋章，或自行实现 [`CommentRule`] 的正则等规则），
+//! 命中后依次执行删除、举报、拉黑等操作。规则按添加顺序匹配，只执行第一条命中的规则。
+//!
+//! 开启 `dry_run` 时只返回处理结果而不调用任何接口，便于先观察规则效果。
+
+use futures_util::{ Stream, StreamExt };
+
+use super::action::ReportReason;
+use super::resource::CommentResourceType;
+use super::filter::{ CommentFilter, CommentRule, FilterMatch };
+use super::types::Comment;
+use crate::{ BpiClient, BpiError };
+
+/// 等级规则，评论者等级低于 `min_level` 即命中
+#[derive(Debug, Clone, Copy)]
+pub struct LevelRule {
+    pub min_level: i32,
+}
+
+impl CommentRule for LevelRule {
+    fn check(&self, comment: &Comment) -> Option<FilterMatch> {
+        let level = comment.member.level_info.current_level;
+        (level < self.min_level).then(|| FilterMatch {
+            rule: "等级".to_string(),
+            detail: Some(format!("LV{}", level)),
+        })
+    }
+}
+
+/// 粉丝勋章规则，评论者未佩戴指定勋章或勋章等级低于 `min_level` 即命中
+#[derive(Debug, Clone)]
+pub struct FanMedalRule {
+    /// 勋章名，为空时佩戴任意勋章均可
+    pub medal_name: Option<String>,
+    pub min_level: u64,
+}
+
+impl CommentRule for FanMedalRule {
+    fn check(&self, comment: &Comment) -> Option<FilterMatch> {
+        let medal = comment.member.fans_detail_a
+            .as_ref()
+            .filter(|m| self.medal_name.as_ref().is_none_or(|name| &m.medal_name == name));
+
+        let passed = medal.is_some_and(|m| m.level >= self.min_level);
+        (!passed).then(|| FilterMatch {
+            rule: "粉丝勋章".to_string(),
+            detail: Some(
+                medal.map_or_else(
+                    || "未佩戴".to_string(),
+                    |m| format!("{} {}", m.medal_name, m.level)
+                )
+            ),
+        })
+    }
+}
+
+/// 管理操作
+#[derive(Debug, Clone, Copy)]
+pub enum ModerationAction {
+    /// 删除评论
+    Delete,
+    /// 举报评论
+    Report(ReportReason),
+    /// 拉黑评论者，需启用 `user` 特性
+    Blacklist,
+}
+
+/// 管理规则
+pub struct ModerationRule {
+    pub name: String,
+    filter: CommentFilter,
+    actions: Vec<ModerationAction>,
+}
+
+impl ModerationRule {
+    pub fn new(name: impl Into<String>, filter: CommentFilter, action: ModerationAction) -> Self {
+        Self {
+            name: name.into(),
+            filter,
+            actions: vec![action],
+        }
+    }
+
+    /// 追加命中后执行的操作
+    pub fn then(mut self, action: ModerationAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+}
+
+/// 单条评论的处理结果
+#[derive(Debug, Clone)]
+pub struct ModerationOutcome {
+    pub rpid: i64,
+    pub mid: i64,
+    /// 命中的规则名
+    pub rule: String,
+    pub matches: Vec<FilterMatch>,
+    /// 已执行（dry-run 时为将要执行）的操作
+    pub actions: Vec<ModerationAction>,
+    pub dry_run: bool,
+}
+
+/// 评论管理器
+///
+/// ```no_run
+/// # async fn run(bpi: &bpi_rs::BpiClient) {
+/// use std::time::Duration;
+/// use futures_util::StreamExt;
+/// use bpi_rs::comment::action::ReportReason;
+/// use bpi_rs::comment::resource::CommentResourceType;
+/// use bpi_rs::comment::filter::{ CommentFilter, KeywordRule };
+/// use bpi_rs::comment::moderation::{ LevelRule, Moderator, ModerationAction, ModerationRule };
+///
+/// let moderator = Moderator::new(CommentResourceType::Video)
+///     .rule(
+///         ModerationRule::new(
+///             "引流",
+///             CommentFilter::new().rule(KeywordRule::new("引流", ["加群", "私信领取"])),
+///             ModerationAction::Delete
+///         ).then(ModerationAction::Report(ReportReason::Ad))
+///     )
+///     .rule(
+///         ModerationRule::new(
+///             "小号",
+///             CommentFilter::new().rule(LevelRule { min_level: 1 }),
+///             ModerationAction::Delete
+///         )
+///     )
+///     .dry_run(true);
+///
+/// let comments = bpi.reply_watch(170001, CommentResourceType::Video, Duration::from_secs(30));
+/// let mut outcomes = Box::pin(moderator.moderate(bpi, comments));
+/// while let Some(Ok(outcome)) = outcomes.next().await {
+///     println!("{} 命中 {}: {:?}", outcome.rpid, outcome.rule, outcome.actions);
+/// }
+/// # }
+/// ```
+pub struct Moderator {
+    r#type: CommentResourceType,
+    rules: Vec<ModerationRule>,
+    dry_run: bool,
+}
+
+impl Moderator {
+    /// 创建管理器，`type` 为评论区类型
+    pub fn new(r#type: CommentResourceType) -> Self {
+        Self {
+            r#type,
+            rules: Vec::new(),
+            dry_run: false,
+        }
+    }
+
+    /// 添加规则
+    pub fn rule(mut self, rule: ModerationRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// 只匹配不执行，默认否
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// 匹配评论，返回第一条命中的规则
+    pub fn evaluate(&self, comment: &Comment) -> Option<ModerationOutcome> {
+        self.rules.iter().find_map(|rule| {
+            let matches = rule.filter.check(comment);
+            (!matches.is_empty()).then(|| ModerationOutcome {
+                rpid: comment.rpid,
+                mid: comment.mid,
+                rule: rule.name.clone(),
+                matches,
+                actions: rule.actions.clone(),
+                dry_run: self.dry_run,
+            })
+        })
+    }
+
+    /// 匹配并执行操作，未命中时返回 `Ok(None)`
+    pub async fn execute(
+        &self,
+        bpi: &BpiClient,
+        comment: &Comment
+    ) -> Result<Option<ModerationOutcome>, BpiError> {
+        let Some(outcome) = self.evaluate(comment) else {
+            return Ok(None);
+        };
+
+        if self.dry_run {
+            tracing::info!("[dry-run] 评论 {} 命中规则 {}: {:?}", comment.rpid, outcome.rule, outcome.actions);
+            return Ok(Some(outcome));
+        }
+
+        let oid = comment.oid as u64;
+        let rpid = comment.rpid as u64;
+        for action in &outcome.actions {
+            match *action {
+                ModerationAction::Delete => {
+                    bpi.comment_delete(self.r#type, oid, rpid).await?;
+                }
+                ModerationAction::Report(reason) => {
+                    bpi.comment_report(self.r#type, oid, rpid, reason, None).await?;
+                }
+                ModerationAction::Blacklist => {
+                    blacklist(bpi, comment.mid as u64).await?;
+                }
+            }
+        }
+
+        tracing::info!("评论 {} 命中规则 {}，已执行 {:?}", comment.rpid, outcome.rule, outcome.actions);
+        Ok(Some(outcome))
+    }
+
+    /// 对评论流逐条执行管理，只输出命中规则的结果与错误
+    pub fn moderate<'a, S>(
+        &'a self,
+        bpi: &'a BpiClient,
+        comments: S
+    ) -> impl Stream<Item = Result<ModerationOutcome, BpiError>> + 'a
+        where S: Stream<Item = Result<Comment, BpiError>> + 'a
+    {
+        comments
+            .then(move |item| async move {
+                match item {
+                    Ok(comment) => self.execute(bpi, &comment).await,
+                    Err(e) => Err(e),
+                }
+            })
+            .filter_map(|result| async move { result.transpose() })
+    }
+}
+
+#[cfg(feature = "user")]
+async fn blacklist(bpi: &BpiClient, mid: u64) -> Result<(), BpiError> {
+    use crate::user::relation::action::RelationAction;
+
+    bpi.user_modify_relation(mid, RelationAction::Blacklist, None).await?;
+    Ok(())
+}
+
+#[cfg(not(feature = "user"))]
+async fn blacklist(_bpi: &BpiClient, _mid: u64) -> Result<(), BpiError> {
+    Err(BpiError::invalid_parameter("action", "拉黑需要启用 user 特性"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comment::filter::KeywordRule;
+
+    fn comment(rpid: i64, level: i32, message: &str, medal: Option<(&str, u64)>) -> Comment {
+        let mut value = serde_json::json!({
+            "rpid": rpid, "oid": 1, "type": 1, "mid": 2, "root": 0, "parent": 0, "dialog": 0,
+            "count": 0, "rcount": 0, "state": 0, "fansgrade": 0, "attr": 0, "ctime": 0,
+            "like": 0, "action": 0, "invisible": false,
+            "member": {
+                "mid": "2", "uname": "test", "sex": "保密", "sign": "", "avatar": "",
+                "level_info": { "current_level": level, "current_min": 0, "current_exp": 0, "next_exp": 0 },
+                "pendant": { "pid": 0, "name": "", "image": "", "expire": 0 },
+                "nameplate": { "nid": 0, "name": "", "image": "", "image_small": "", "level": "", "condition": "" },
+                "official_verify": { "type": -1, "desc": "" },
+                "vip": {
+                    "vipType": 0, "vipDueDate": 0, "dueRemark": "", "accessStatus": 0, "vipStatus": 0,
+                    "vipStatusWarn": "", "themeType": 0, "avatar_subscript": 0, "nickname_color": ""
+                }
+            },
+            "content": { "message": message },
+            "up_action": { "like": false, "reply": false },
+            "reply_control": {},
+            "folder": { "has_folded": false, "is_folded": false, "rule": "" }
+        });
+        if let Some((name, level)) = medal {
+            value["member"]["fans_detail_a"] = serde_json::json!({
+                "uid": 2, "medal_id": 1, "medal_name": name, "level": level, "nickname_color": ""
+            });
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_moderator_dry_run() {
+        let moderator = Moderator::new(CommentResourceType::Video)
+            .rule(
+                ModerationRule::new(
+                    "引流",
+                    CommentFilter::new().rule(KeywordRule::new("引流", ["加群"])),
+                    ModerationAction::Delete
+                ).then(ModerationAction::Blacklist)
+            )
+            .rule(
+                ModerationRule::new(
+                    "非粉丝小号",
+                    CommentFilter::new()
+                        .rule(LevelRule { min_level: 2 })
+                        .rule(FanMedalRule { medal_name: Some("测试".to_string()), min_level: 1 })
+                        .mode(crate::comment::filter::MatchMode::All),
+                    ModerationAction::Delete
+                )
+            )
+            .dry_run(true);
+
+        let comments = vec![
+            comment(1, 6, "加群领取", None),
+            comment(2, 1, "好看", None),
+            comment(3, 1, "好看", Some(("测试", 3))),
+            comment(4, 6, "好看", None)
+        ];
+
+        let outcome = moderator.evaluate(&comments[0]).unwrap();
+        assert_eq!(outcome.rule, "引流");
+        assert_eq!(outcome.actions.len(), 2);
+        assert!(moderator.evaluate(&comments[2]).is_none());
+
+        let bpi = BpiClient::try_new_local().unwrap();
+        let stream = futures_util::stream::iter(comments.into_iter().map(Ok));
+        let rpids: Vec<i64> = moderator
+            .moderate(&bpi, stream)
+            .map(|r| r.unwrap().rpid)
+            .collect().await;
+        assert_eq!(rpids, vec![1, 2]);
+    }
+}
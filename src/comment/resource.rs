@@ -0,0 +1,232 @@
+//! 评论区类型
+//!
+//! 评论接口中的 `type` 参数决定 `oid` 的含义，如视频为 avid、专栏为 cvid、
+//! 图片动态为相簿 id。[`ResourceId`] 将已解析的资源与其评论区类型绑定，避免手动对应数字。
+//!
+//! [参考文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/comment/readme.md)
+
+use serde::{ Deserialize, Serialize };
+
+use crate::BpiError;
+
+/// 评论区类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum CommentResourceType {
+    /// 未知类型
+    Unknown,
+    /// 视频稿件，oid 为 avid
+    Video,
+    /// 话题
+    Topic,
+    /// 活动
+    Activity,
+    /// 小视频
+    ShortVideo,
+    /// 小黑屋封禁信息
+    BlackRoom,
+    /// 公告信息
+    Notice,
+    /// 直播活动
+    LiveActivity,
+    /// 活动稿件
+    ActivityArchive,
+    /// 直播公告
+    LiveNotice,
+    /// 图片动态（相簿），oid 为相簿 id
+    DynamicDraw,
+    /// 专栏，oid 为 cvid
+    Article,
+    /// 票务
+    Ticket,
+    /// 音频，oid 为 auid
+    Audio,
+    /// 风纪委员会
+    Judgement,
+    /// 点评
+    Review,
+    /// 纯文字、转发等动态，oid 为动态 id
+    Dynamic,
+    /// 音频歌单
+    AudioPlaylist,
+    /// 漫画
+    Manga,
+    /// 课程，oid 为课程 epid
+    Cheese,
+    /// 未收录的类型，保留原始数字
+    Other(i32),
+}
+
+impl CommentResourceType {
+    /// 接口使用的数字类型
+    pub fn code(self) -> i32 {
+        use CommentResourceType::*;
+        match self {
+            Unknown => 0,
+            Video => 1,
+            Topic => 2,
+            Activity => 4,
+            ShortVideo => 5,
+            BlackRoom => 6,
+            Notice => 7,
+            LiveActivity => 8,
+            ActivityArchive => 9,
+            LiveNotice => 10,
+            DynamicDraw => 11,
+            Article => 12,
+            Ticket => 13,
+            Audio => 14,
+            Judgement => 15,
+            Review => 16,
+            Dynamic => 17,
+            AudioPlaylist => 19,
+            Manga => 22,
+            Cheese => 33,
+            Other(code) => code,
+        }
+    }
+
+    /// 由数字类型转换，未收录的类型为 [`CommentResourceType::Other`]
+    pub fn from_code(code: i32) -> Self {
+        use CommentResourceType::*;
+        match code {
+            0 => Unknown,
+            1 => Video,
+            2 => Topic,
+            4 => Activity,
+            5 => ShortVideo,
+            6 => BlackRoom,
+            7 => Notice,
+            8 => LiveActivity,
+            9 => ActivityArchive,
+            10 => LiveNotice,
+            11 => DynamicDraw,
+            12 => Article,
+            13 => Ticket,
+            14 => Audio,
+            15 => Judgement,
+            16 => Review,
+            17 => Dynamic,
+            19 => AudioPlaylist,
+            22 => Manga,
+            33 => Cheese,
+            code => Other(code),
+        }
+    }
+}
+
+impl From<i32> for CommentResourceType {
+    fn from(code: i32) -> Self {
+        Self::from_code(code)
+    }
+}
+
+/// 由动态、图文 `basic` 中的 `comment_type` 与 `comment_id_str` 得到评论区参数
+///
+/// 返回 `(type, oid)`，可直接传给评论列表等接口。
+pub fn comment_params(
+    comment_type: i64,
+    comment_id_str: &str
+) -> Result<(CommentResourceType, i64), BpiError> {
+    let r#type = i32::try_from(comment_type)
+        .map(CommentResourceType::from_code)
+        .map_err(|_| BpiError::parse(format!("未知的评论区类型: {}", comment_type)))?;
+    let oid = comment_id_str
+        .parse::<i64>()
+        .map_err(|_| BpiError::parse(format!("评论区 id 无效: {:?}", comment_id_str)))?;
+    Ok((r#type, oid))
+}
+
+impl From<CommentResourceType> for i32 {
+    fn from(value: CommentResourceType) -> Self {
+        value.code()
+    }
+}
+
+/// 已解析的评论区资源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceId {
+    /// 视频 avid
+    Video(u64),
+    /// 专栏 cvid
+    Article(u64),
+    /// 纯文字、转发等动态 id
+    Dynamic(u64),
+    /// 图片动态的相簿 id
+    DynamicDraw(u64),
+    /// 音频 auid
+    Audio(u64),
+    /// 课程 epid
+    Cheese(u64),
+    /// 其他类型
+    Other(CommentResourceType, u64),
+}
+
+impl ResourceId {
+    /// 评论区 oid
+    pub fn oid(&self) -> u64 {
+        match *self {
+            | ResourceId::Video(id)
+            | ResourceId::Article(id)
+            | ResourceId::Dynamic(id)
+            | ResourceId::DynamicDraw(id)
+            | ResourceId::Audio(id)
+            | ResourceId::Cheese(id)
+            | ResourceId::Other(_, id) => id,
+        }
+    }
+
+    /// 评论区类型
+    pub fn comment_type(&self) -> CommentResourceType {
+        match *self {
+            ResourceId::Video(_) => CommentResourceType::Video,
+            ResourceId::Article(_) => CommentResourceType::Article,
+            ResourceId::Dynamic(_) => CommentResourceType::Dynamic,
+            ResourceId::DynamicDraw(_) => CommentResourceType::DynamicDraw,
+            ResourceId::Audio(_) => CommentResourceType::Audio,
+            ResourceId::Cheese(_) => CommentResourceType::Cheese,
+            ResourceId::Other(r#type, _) => r#type,
+        }
+    }
+}
+
+impl From<ResourceId> for CommentResourceType {
+    fn from(value: ResourceId) -> Self {
+        value.comment_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_resource_type() {
+        for code in [0, 1, 2, 11, 12, 17, 33] {
+            assert_eq!(CommentResourceType::from_code(code).code(), code);
+        }
+        assert_eq!(CommentResourceType::from_code(3), CommentResourceType::Other(3));
+        assert_eq!(CommentResourceType::from(42).code(), 42);
+
+        let resource = ResourceId::DynamicDraw(123);
+        assert_eq!(CommentResourceType::from(resource), CommentResourceType::DynamicDraw);
+        assert_eq!(resource.oid(), 123);
+
+        let parsed: CommentResourceType = serde_json::from_str("12").unwrap();
+        assert_eq!(parsed, CommentResourceType::Article);
+        assert_eq!(serde_json::to_string(&CommentResourceType::Dynamic).unwrap(), "17");
+        let unknown: CommentResourceType = serde_json::from_str("1000").unwrap();
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), "1000");
+    }
+
+    #[test]
+    fn test_comment_params() {
+        let (r#type, oid) = comment_params(11, "312530520").unwrap();
+        assert_eq!(r#type, CommentResourceType::DynamicDraw);
+        assert_eq!(oid, 312530520);
+
+        assert_eq!(comment_params(3, "1").unwrap().0, CommentResourceType::Other(3));
+        assert!(comment_params(i64::MAX, "1").is_err());
+        assert!(comment_params(17, "").is_err());
+    }
+}
@@ -284,6 +284,19 @@ pub struct Cursor {
     pub support_mode: Vec<i64>,
 }
 
+impl Cursor {
+    /// 懒加载模式下一页的偏移，已到末页时为 `None`
+    pub fn next_offset(&self) -> Option<&str> {
+        if self.is_end {
+            return None;
+        }
+        self.pagination_reply
+            .get("next_offset")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+    }
+}
+
 /// 评论区顶部信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Upper {
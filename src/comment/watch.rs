@@ -0,0 +1,137 @@
+//! 新评论轮询
+//!
+//! 定时按时间排序拉取评论区第一页，只输出上次轮询之后新发布的根评论。
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures_util::Stream;
+use tokio::time::{ Interval, MissedTickBehavior };
+
+use super::resource::CommentResourceType;
+use super::types::Comment;
+use crate::{ BpiClient, BpiError };
+
+struct WatchState<'a> {
+    bpi: &'a BpiClient,
+    oid: i64,
+    r#type: CommentResourceType,
+    interval: Interval,
+    /// 已输出的最大 rpid，首次轮询前为 `None`
+    last_rpid: Option<i64>,
+    pending: VecDeque<Comment>,
+}
+
+impl WatchState<'_> {
+    /// 拉取一页评论，返回比 `last_rpid` 新的根评论（按发布顺序）
+    async fn poll(&mut self) -> Result<(), BpiError> {
+        let data = self.bpi
+            .comment_list(self.r#type, self.oid, Some(1), Some(20), Some(0), Some(1)).await?
+            .into_data()?;
+
+        let mut fresh: Vec<Comment> = data.replies
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|c| c.root == 0)
+            .collect();
+        fresh.sort_by_key(|c| c.rpid);
+
+        let newest = fresh.last().map(|c| c.rpid);
+        match self.last_rpid {
+            // 首次轮询只记录位置，不输出已有评论
+            None => {
+                self.last_rpid = Some(newest.unwrap_or(0));
+            }
+            Some(last) => {
+                self.pending.extend(fresh.into_iter().filter(|c| c.rpid > last));
+                if let Some(newest) = newest {
+                    self.last_rpid = Some(last.max(newest));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BpiClient {
+    /// 轮询评论区，输出新发布的根评论
+    ///
+    /// 首次轮询只记录当前最新评论，之后每隔 `interval` 拉取一次；请求失败时输出 `Err` 并在下次继续轮询。
+    /// 每次只检查按时间排序的第一页（20 条），两次轮询之间新增超过 20 条时多出的评论会被跳过。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `oid` | i64 | 对象 ID |
+    /// | `type` | CommentResourceType | 评论区类型 |
+    /// | `interval` | Duration | 轮询间隔 |
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// # async fn run(bpi: &bpi_rs::BpiClient) {
+    /// use std::time::Duration;
+    /// use futures_util::StreamExt;
+    /// use bpi_rs::comment::resource::CommentResourceType;
+    ///
+    /// let watch = bpi.reply_watch(170001, CommentResourceType::Video, Duration::from_secs(30));
+    /// let mut watch = Box::pin(watch);
+    /// while let Some(Ok(comment)) = watch.next().await {
+    ///     println!("{}: {}", comment.member.uname, comment.content.message);
+    /// }
+    /// # }
+    /// ```
+    pub fn reply_watch(
+        &self,
+        oid: i64,
+        r#type: CommentResourceType,
+        interval: Duration
+    ) -> impl Stream<Item = Result<Comment, BpiError>> + '_ {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let state = WatchState {
+            bpi: self,
+            oid,
+            r#type,
+            interval,
+            last_rpid: None,
+            pending: VecDeque::new(),
+        };
+
+        futures_util::stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(comment) = state.pending.pop_front() {
+                    return Some((Ok(comment), state));
+                }
+
+                state.interval.tick().await;
+                if let Err(e) = state.poll().await {
+                    tracing::warn!("评论轮询失败: {}", e);
+                    return Some((Err(e), state));
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_reply_watch() {
+        let bpi = crate::test_client!(Guest);
+        let watch = bpi.reply_watch(23199, CommentResourceType::Video, Duration::from_secs(5));
+        let mut watch = Box::pin(watch);
+
+        // 首次轮询不输出已有评论，超时即视为正常
+        let next = tokio::time::timeout(Duration::from_secs(12), watch.next()).await;
+        if let Ok(Some(item)) = next {
+            tracing::info!("新评论: {:?}", item.map(|c| c.rpid));
+        }
+    }
+}
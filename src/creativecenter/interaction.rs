@@ -0,0 +1,116 @@
+//! 稿件互动设置 API
+//!
+//! 开关单个稿件的评论区与弹幕，配合 [`up_archives_list`](crate::BpiClient::up_archives_list)
+//! 可批量修改全部稿件。
+//!
+//! [参考文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/creativecenter)
+
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+impl BpiClient {
+    /// 开启或关闭稿件评论区
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `aid` | u64 | 稿件 avid |
+    /// | `on` | bool | `true` 开启，`false` 关闭 |
+    pub async fn archive_reply_switch(
+        &self,
+        aid: u64,
+        on: bool
+    ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        let csrf = self.csrf()?;
+
+        self
+            .post("https://api.bilibili.com/x/v2/reply/subject/modify")
+            .form(
+                &[
+                    ("oid", aid.to_string()),
+                    ("type", "1".to_string()),
+                    // 0: 开启 1: 关闭
+                    ("action", (if on { "0" } else { "1" }).to_string()),
+                    ("csrf", csrf),
+                ]
+            )
+            .send_bpi("修改稿件评论区开关").await
+    }
+
+    /// 开启或关闭单个分P的弹幕
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `cid` | u64 | 分P cid |
+    /// | `on` | bool | `true` 开启，`false` 关闭 |
+    pub async fn archive_danmaku_switch_part(
+        &self,
+        cid: u64,
+        on: bool
+    ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        let csrf = self.csrf()?;
+
+        self
+            .post("https://api.bilibili.com/x/v2/dm/subject/state")
+            .form(
+                &[
+                    ("oid", cid.to_string()),
+                    ("type", "1".to_string()),
+                    // 0: 开启 1: 关闭
+                    ("state", (if on { "0" } else { "1" }).to_string()),
+                    ("csrf", csrf),
+                ]
+            )
+            .send_bpi("修改弹幕开关").await
+    }
+
+    /// 开启或关闭稿件所有分P的弹幕
+    ///
+    /// 先通过稿件基础信息获取全部分P，再逐个修改，遇到失败立即返回。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `aid` | u64 | 稿件 avid |
+    /// | `on` | bool | `true` 开启，`false` 关闭 |
+    pub async fn archive_danmaku_switch(&self, aid: u64, on: bool) -> Result<usize, BpiError> {
+        let videos = self.up_archive_videos(aid as i64).await?.into_data()?.videos;
+
+        for part in &videos {
+            self.archive_danmaku_switch_part(part.cid as u64, on).await?;
+        }
+
+        tracing::info!("稿件 av{} 共 {} 个分P弹幕已{}", aid, videos.len(), if on { "开启" } else { "关闭" });
+        Ok(videos.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_AID: u64 = 113602455409683;
+
+    #[tokio::test]
+    #[ignore = "write"]
+    async fn test_archive_reply_switch() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Write);
+
+        bpi.archive_reply_switch(TEST_AID, false).await?;
+        bpi.archive_reply_switch(TEST_AID, true).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "write"]
+    async fn test_archive_danmaku_switch() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Write);
+
+        let parts = bpi.archive_danmaku_switch(TEST_AID, false).await?;
+        assert!(parts > 0);
+        bpi.archive_danmaku_switch(TEST_AID, true).await?;
+
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 //! 用户中心
 
+pub mod interaction;
 pub mod opus;
 pub mod railgun;
 pub mod season;
 pub mod statistics_data;
+pub mod submit;
 pub mod upload;
 pub mod videos;
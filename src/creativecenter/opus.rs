@@ -64,19 +64,19 @@ mod tests {
     const TEST_DYN_ID: &str = "951560312615600129";
     const TEST_AID: u64 = 42997969;
 
-    #[ignore]
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_dynamic_delete() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         bpi.dynamic_delete(TEST_DYN_ID).await?;
 
         Ok(())
     }
-    #[ignore]
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_article_delete() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         bpi.article_delete(TEST_AID).await?;
 
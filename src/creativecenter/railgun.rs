@@ -41,8 +41,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_electromagnetic_info() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         let data = bpi.up_electromagnetic_info().await?.into_data()?;
 
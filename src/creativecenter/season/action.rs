@@ -138,8 +138,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_create_season() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let img_data = fs::read("./assets/test.jpg").map_err(|_| BpiError::parse("读取图片失败"))?;
         let img_base64 = general_purpose::STANDARD.encode(&img_data);
@@ -175,8 +176,9 @@ mod tests {
         Ok(())
     }
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_add_season() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let episodes = vec![EpisodeAdd {
             aid: TEST_AID,
             cid: TEST_CID,
@@ -115,8 +115,9 @@ mod tests {
     const TEST_AID: u64 = 113602455409683;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_season_by_aid() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         let data = bpi.season_by_aid(TEST_AID).await?.into_data()?;
         tracing::info!("视频 {} 所属合集 {} - {}", TEST_AID, data.id, data.title);
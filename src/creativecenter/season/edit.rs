@@ -247,8 +247,9 @@ mod tests {
     const TEST_CID: u64 = 829554597;
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_edit_season_info() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let season = SeasonEdit {
             id: TEST_SEASON_ID,
@@ -270,8 +271,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_edit_season_section() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let section = SeasonSectionEdit {
             id: TEST_SECTION_ID,
@@ -292,8 +294,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_edit_season_episode() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let section = EpisodeEdit {
             id: TEST_PART_ID,
@@ -318,8 +321,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_add_episodes() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         bpi
             .season_section_add_episodes(
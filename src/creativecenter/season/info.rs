@@ -43,8 +43,9 @@ mod tests {
     const TEST_SSID: u64 = 4294056;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_season_list() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let data = bpi.season_info(TEST_SSID).await?.into_data()?;
 
         tracing::info!("共 {:?} 个合集", data);
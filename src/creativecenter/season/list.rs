@@ -132,8 +132,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_season_list() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let data = bpi.season_list(1, 10, Some("ctime"), Some("desc")).await?.into_data()?;
 
         tracing::info!("共 {} 个合集", data.total);
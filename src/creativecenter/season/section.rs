@@ -98,8 +98,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_season_section_episodes() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         let season_id = 176088;
         let data = bpi.season_section_episodes(season_id).await?.into_data()?;
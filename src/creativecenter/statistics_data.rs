@@ -557,48 +557,54 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_up_stat() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let data = bpi.up_stat().await?.into_data()?;
         info!("UP主视频状态数据: {:?}", data);
         Ok(())
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_archive_compare() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let data = bpi.up_archive_compare(None, Some(3)).await?.into_data()?;
         info!("UP主视频数据比较: {:?}", data);
         Ok(())
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_up_article_stat() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let data = bpi.up_article_stat().await?.into_data()?;
         info!("UP主专栏状态数据: {:?}", data);
         Ok(())
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_video_trend() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let data = bpi.up_video_trend(1).await?.into_data()?; // 1 = 播放
         info!("UP主视频数据增量趋势: {:?}", data);
         Ok(())
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_article_trend() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let data = bpi.up_article_trend(1).await?.into_data()?; // 1 = 阅读
         info!("UP主专栏数据增量趋势: {:?}", data);
         Ok(())
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_viewer_data() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let data = bpi.up_viewer_data().await?.into_data()?;
         info!("播放分布情况: {:?}", data);
         Ok(())
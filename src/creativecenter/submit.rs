@@ -0,0 +1,208 @@
+//! 稿件投稿 API
+//!
+//! 分P视频需先上传取得服务端文件名（[`SubmitVideo::filename`]），封面可通过
+//! [`BpiClient::upload_cover`] 上传。联合投稿时在 [`SubmitArchive::staffs`] 中列出参与创作的 UP 主，
+//! 被邀请的 UP 主需在客户端中确认邀请后才会显示在稿件的创作团队中。
+//!
+//! [参考文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/creativecenter/upload.md)
+
+use serde::{ Deserialize, Serialize };
+
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 稿件分P
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SubmitVideo {
+    /// 上传完成后得到的服务端文件名（不含扩展名）
+    pub filename: String,
+    /// 分P标题
+    pub title: String,
+    /// 分P简介
+    #[serde(default)]
+    pub desc: String,
+}
+
+/// 联合投稿成员
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmitStaff {
+    /// 成员 mid
+    pub mid: u64,
+    /// 成员在稿件中担任的职能，如 `剪辑`、`配音`
+    pub title: String,
+}
+
+impl SubmitStaff {
+    pub fn new(mid: u64, title: impl Into<String>) -> Self {
+        Self { mid, title: title.into() }
+    }
+}
+
+/// 投稿或编辑稿件的信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitArchive {
+    /// 1: 自制 2: 转载
+    pub copyright: u8,
+    /// 转载来源，自制稿件留空
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub source: String,
+    /// 分区 ID
+    pub tid: u32,
+    /// 封面 URL
+    pub cover: String,
+    pub title: String,
+    #[serde(default)]
+    pub desc: String,
+    /// 标签，多个标签以 `,` 分隔
+    pub tag: String,
+    /// 粉丝动态文字
+    #[serde(default)]
+    pub dynamic: String,
+    /// 定时发布的秒级时间戳，需在当前时间 2 小时后、15 天内
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dtime: Option<i64>,
+    pub videos: Vec<SubmitVideo>,
+    /// 联合投稿成员，不含投稿者本人；为空时为普通投稿
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub staffs: Vec<SubmitStaff>,
+}
+
+impl Default for SubmitArchive {
+    fn default() -> Self {
+        Self {
+            copyright: 1,
+            source: String::new(),
+            tid: 0,
+            cover: String::new(),
+            title: String::new(),
+            desc: String::new(),
+            tag: String::new(),
+            dynamic: String::new(),
+            dtime: None,
+            videos: Vec::new(),
+            staffs: Vec::new(),
+        }
+    }
+}
+
+impl SubmitArchive {
+    fn validate(&self) -> Result<(), BpiError> {
+        if self.videos.is_empty() {
+            return Err(BpiError::invalid_parameter("videos", "至少需要一个分P"));
+        }
+        if self.copyright == 2 && self.source.is_empty() {
+            return Err(BpiError::invalid_parameter("source", "转载稿件需填写转载来源"));
+        }
+        if !self.staffs.is_empty() && self.copyright != 1 {
+            return Err(BpiError::invalid_parameter("staffs", "只有自制稿件可以联合投稿"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+struct EditArchivePayload<'a> {
+    aid: u64,
+    #[serde(flatten)]
+    archive: &'a SubmitArchive,
+}
+
+/// 投稿结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitResult {
+    pub aid: u64,
+    pub bvid: String,
+}
+
+impl BpiClient {
+    /// 投稿视频
+    ///
+    /// `archive.staffs` 非空时为联合投稿，成员需确认邀请后才会显示。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `archive` | &SubmitArchive | 稿件信息 |
+    pub async fn submit_archive(
+        &self,
+        archive: &SubmitArchive
+    ) -> Result<BpiResponse<SubmitResult>, BpiError> {
+        archive.validate()?;
+        let csrf = self.csrf()?;
+
+        self
+            .post("https://member.bilibili.com/x/vu/web/add/v3")
+            .query(&[("csrf", csrf)])
+            .json(archive)
+            .send_bpi("投稿视频").await
+    }
+
+    /// 编辑稿件
+    ///
+    /// 以 `archive` 整体替换稿件信息，可用于调整联合投稿成员。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `aid` | u64 | 稿件 avid |
+    /// | `archive` | &SubmitArchive | 稿件信息 |
+    pub async fn submit_archive_edit(
+        &self,
+        aid: u64,
+        archive: &SubmitArchive
+    ) -> Result<BpiResponse<SubmitResult>, BpiError> {
+        archive.validate()?;
+        let csrf = self.csrf()?;
+
+        self
+            .post("https://member.bilibili.com/x/vu/web/edit")
+            .query(&[("csrf", csrf)])
+            .json(&EditArchivePayload { aid, archive })
+            .send_bpi("编辑稿件").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archive() -> SubmitArchive {
+        SubmitArchive {
+            tid: 17,
+            title: "联合投稿测试".to_string(),
+            tag: "测试".to_string(),
+            videos: vec![SubmitVideo {
+                filename: "n250101abcdef".to_string(),
+                title: "P1".to_string(),
+                desc: String::new(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_submit_archive_staffs() {
+        let mut archive = archive();
+        let solo = serde_json::to_value(&archive).unwrap();
+        assert!(solo.get("staffs").is_none());
+        assert!(solo.get("source").is_none());
+
+        archive.staffs = vec![SubmitStaff::new(2, "剪辑"), SubmitStaff::new(3, "配音")];
+        let value = serde_json::to_value(EditArchivePayload { aid: 170001, archive: &archive }).unwrap();
+        assert_eq!(value["aid"], 170001);
+        assert_eq!(value["staffs"], serde_json::json!([
+            { "mid": 2, "title": "剪辑" },
+            { "mid": 3, "title": "配音" }
+        ]));
+        assert!(archive.validate().is_ok());
+
+        archive.copyright = 2;
+        archive.source = "https://example.com".to_string();
+        assert!(archive.validate().is_err());
+    }
+
+    #[test]
+    fn test_submit_archive_requires_videos() {
+        let archive = SubmitArchive { videos: Vec::new(), ..archive() };
+        assert!(archive.validate().is_err());
+    }
+}
@@ -85,8 +85,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_cover_upload() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         // 1. 文件路径
         // run_upload_test(&bpi, "image/jpeg", "./assets/test.jpg").await?;
@@ -157,16 +157,18 @@ mod tests {
     const TEST_AID: i64 = 113602455409683;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_archives_list() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let data = bpi.up_archives_list(1, Some(10)).await?.into_data()?;
         info!("稿件列表: {:?}", data);
         Ok(())
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_archive_videos() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let data = bpi.up_archive_videos(TEST_AID).await?.into_data()?;
         info!("视频基础信息: {:?}", data);
         Ok(())
@@ -1,6 +1,7 @@
 use serde::{ Deserialize, Serialize };
 
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use crate::utils::web_location::WebLocation;
 
 // -------------------
 // 发送视频弹幕
@@ -27,8 +28,8 @@ impl BpiClient {
     /// | `avid` | `Option<u64>` | 稿件 aid（`avid` 与 `bvid` 二选一） |
     /// | `bvid` | `Option<&str>` | 稿件 bvid（`avid` 与 `bvid` 二选一） |
     /// | `mode` | `Option<u8>` | 弹幕模式：1 滚动，4 底端，5 顶端，7 高级，9 BAS（`pool=2`） |
-    /// | `typ` | `Option<u8>` | 弹幕类型：1 视频弹幕，2 漫画弹幕 |
-    /// | `progress` | `Option<u32>` | 弹幕出现时间（毫秒） |
+    /// | `typ` | `Option<u8>` | 弹幕类型：1 视频弹幕，2 漫画弹幕，默认 1 |
+    /// | `progress` | `Option<u32>` | 弹幕出现时间（毫秒），默认 0 |
     /// | `color` | `Option<u32>` | 颜色（rgb888），如 16777215 为白色 |
     /// | `fontsize` | `Option<u8>` | 字号，默认 25（12/16/18/25/36/45/64） |
     /// | `pool` | `Option<u8>` | 弹幕池：0 普通池，1 字幕池，2 特殊池（代码/BAS） |
@@ -47,14 +48,16 @@ impl BpiClient {
     ) -> Result<BpiResponse<DanmakuPostData>, BpiError> {
         let csrf = self.csrf()?;
 
+        // 可选参数直接替换默认值，避免表单中出现重复的同名参数
         let mut form = vec![
+            ("type", typ.unwrap_or(1).to_string()),
             ("oid", oid.to_string()),
             ("msg", msg.to_string()),
-            ("mode", "1".to_string()),
-            ("fontsize", "25".to_string()),
-            ("color", "16777215".to_string()),
-            ("pool", "0".to_string()),
-            ("progress", "1878".to_string()),
+            ("mode", mode.unwrap_or(1).to_string()),
+            ("fontsize", fontsize.unwrap_or(25).to_string()),
+            ("color", color.unwrap_or(16777215).to_string()),
+            ("pool", pool.unwrap_or(0).to_string()),
+            ("progress", progress.unwrap_or(0).to_string()),
             ("rnd", "2".to_string()),
             ("plat", "1".to_string()),
             ("csrf", csrf),
@@ -63,28 +66,10 @@ impl BpiClient {
             ("gaiasource", "main_web".to_string()),
             ("polaris_app_id", "100".to_string()),
             ("polaris_platform", "5".to_string()),
-            ("spmid", "333.788.0.0".to_string()),
-            ("from_spmid", "333.788.0.0".to_string())
+            ("spmid", self.spm_id(WebLocation::VIDEO)),
+            ("from_spmid", self.spm_id(WebLocation::VIDEO))
         ];
 
-        if let Some(m) = mode {
-            form.push(("mode", m.to_string()));
-        }
-        if let Some(t) = typ {
-            form.push(("type", t.to_string()));
-        }
-        if let Some(p) = progress {
-            form.push(("progress", p.to_string()));
-        }
-        if let Some(c) = color {
-            form.push(("color", c.to_string()));
-        }
-        if let Some(f) = fontsize {
-            form.push(("fontsize", f.to_string()));
-        }
-        if let Some(p) = pool {
-            form.push(("pool", p.to_string()));
-        }
         if let Some(b) = bvid {
             form.push(("bvid", b.to_string()));
         }
@@ -424,13 +409,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
-    #[ignore]
+    #[ignore = "write"]
     async fn test_danmaku_post() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let resp = bpi.danmaku_send(
             413195701,
@@ -450,9 +434,9 @@ mod tests {
     }
 
     #[tokio::test]
-    #[ignore]
+    #[ignore = "write"]
     async fn test_danmaku_recall() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let resp = bpi.danmaku_recall(413195701, 1932013422544416768).await;
         info!("{:#?}", resp);
@@ -460,9 +444,9 @@ mod tests {
     }
 
     #[tokio::test]
-    #[ignore]
+    #[ignore = "write"]
     async fn test_danmaku_buy_adv() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let resp = bpi.danmaku_buy_adv(413195701).await;
         info!("{:#?}", resp);
@@ -470,9 +454,9 @@ mod tests {
     }
 
     #[tokio::test]
-    #[ignore]
+    #[ignore = "guest"]
     async fn test_danmaku_get_adv_state() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let resp = bpi.danmaku_adv_state(413195701).await;
         info!("{:#?}", resp);
@@ -480,9 +464,9 @@ mod tests {
     }
 
     #[tokio::test]
-    #[ignore]
+    #[ignore = "write"]
     async fn test_danmaku_thumbup() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let resp = bpi.danmaku_thumbup(413195701, 1932011031958944000, 1).await;
         info!("{:#?}", resp);
@@ -490,9 +474,9 @@ mod tests {
     }
 
     #[tokio::test]
-    #[ignore]
+    #[ignore = "write"]
     async fn test_danmaku_edit_state() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let dmids = vec![1932011031958944000];
         let resp = bpi.danmaku_edit_state(413195701, &dmids, 1).await;
@@ -501,9 +485,9 @@ mod tests {
     }
 
     #[tokio::test]
-    #[ignore]
+    #[ignore = "write"]
     async fn test_danmaku_edit_pool() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let dmids = vec![1932011031958944000];
         let resp = bpi.danmaku_edit_pool(413195701, &dmids, 1).await;
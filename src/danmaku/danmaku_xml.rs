@@ -164,8 +164,9 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_danmaku_xml_api() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let start = Instant::now();
 
         let data = bpi.danmaku_xml_list_so(16546).await?;
@@ -176,8 +177,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_danmaku_xml_cid() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let start = Instant::now();
 
         let data = bpi.danmaku_xml_list(16546).await?;
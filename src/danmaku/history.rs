@@ -47,8 +47,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_history_dates() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.danmaku_history_dates(144541892, "2022-01").await?;
         let data = result.into_data()?;
         tracing::info!("{:#?}", data);
@@ -0,0 +1,77 @@
+//! 弹幕发送者 midHash 反查
+//!
+//! XML 弹幕 `p` 属性中的发送者标识（[`DanmakuMeta::user_hash`](super::danmaku_xml::DanmakuMeta)）
+//! 为发送者 mid 十进制字符串的 CRC32 值（十六进制），可在本地穷举还原候选 mid。
+//!
+//! CRC32 存在碰撞，同一个 hash 可能对应多个 mid，结果只能作为候选。
+
+use std::fmt::Write;
+
+/// 计算 mid 对应的 midHash
+pub fn midhash(mid: u64) -> String {
+    format!("{:x}", crc32fast::hash(mid.to_string().as_bytes()))
+}
+
+fn parse_midhash(midhash: &str) -> Option<u32> {
+    u32::from_str_radix(midhash.trim(), 16).ok()
+}
+
+/// 在候选 mid 中查找与 midHash 匹配的第一个 mid
+///
+/// # 参数
+///
+/// | 名称 | 类型 | 说明 |
+/// | ---- | ---- | ---- |
+/// | `midhash` | &str | 弹幕中的发送者 hash |
+/// | `candidates` | `&[u64]` | 候选 mid，如 UP 主的粉丝或评论区用户 |
+pub fn crc32_lookup(midhash: &str, candidates: &[u64]) -> Option<u64> {
+    let target = parse_midhash(midhash)?;
+    candidates
+        .iter()
+        .copied()
+        .find(|mid| crc32fast::hash(mid.to_string().as_bytes()) == target)
+}
+
+/// 穷举 `1..=max_mid` 反查 midHash，返回所有匹配的 mid
+///
+/// 耗时与 `max_mid` 成正比，亿级范围需要数十秒，建议在阻塞线程中调用。
+///
+/// # 参数
+///
+/// | 名称 | 类型 | 说明 |
+/// | ---- | ---- | ---- |
+/// | `midhash` | &str | 弹幕中的发送者 hash |
+/// | `max_mid` | u64 | 穷举上限 |
+pub fn crack_midhash(midhash: &str, max_mid: u64) -> Vec<u64> {
+    let Some(target) = parse_midhash(midhash) else {
+        return Vec::new();
+    };
+
+    let mut buf = String::with_capacity(20);
+    (1..=max_mid)
+        .filter(|mid| {
+            buf.clear();
+            let _ = write!(buf, "{}", mid);
+            crc32fast::hash(buf.as_bytes()) == target
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_midhash_roundtrip() {
+        let hash = midhash(2);
+        assert_eq!(crc32_lookup(&hash, &[1, 2, 3]), Some(2));
+        assert_eq!(crc32_lookup(&hash, &[1, 3]), None);
+        assert!(crack_midhash(&hash, 100_000).contains(&2));
+    }
+
+    #[test]
+    fn test_invalid_midhash() {
+        assert_eq!(crc32_lookup("not hex", &[1, 2]), None);
+        assert!(crack_midhash("not hex", 10).is_empty());
+    }
+}
@@ -3,6 +3,10 @@
 pub mod action;
 pub mod danmaku_xml;
 pub mod history;
+pub mod midhash;
+pub mod segment;
 
 pub mod snapshot;
 pub mod thumbup;
+
+pub use midhash::{ crack_midhash, crc32_lookup };
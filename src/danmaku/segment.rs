@@ -0,0 +1,289 @@
+//! 分段弹幕（protobuf）
+//!
+//! 弹幕按每 6 分钟一段下发，完整获取一个视频的弹幕需要请求全部分段。
+//!
+//! [文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/danmaku/danmaku_proto.md)
+
+use std::time::Duration;
+
+use futures_util::{ Stream, StreamExt, TryStreamExt };
+use prost::Message;
+use serde::{ Deserialize, Serialize };
+
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 每个分段覆盖的时长
+pub const SEGMENT_DURATION: Duration = Duration::from_secs(6 * 60);
+
+/// 默认同时请求的分段数
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// 分段弹幕条目
+#[derive(Clone, PartialEq, Message, Serialize, Deserialize)]
+pub struct DanmakuElem {
+    /// 弹幕 dmid
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// 出现时间（毫秒）
+    #[prost(int32, tag = "2")]
+    pub progress: i32,
+    /// 弹幕类型 1-3: 普通 4: 底部 5: 顶部 6: 逆向 7: 高级 8: 代码 9: BAS
+    #[prost(int32, tag = "3")]
+    pub mode: i32,
+    /// 字号
+    #[prost(int32, tag = "4")]
+    pub fontsize: i32,
+    /// 颜色（十进制 RGB888）
+    #[prost(uint32, tag = "5")]
+    pub color: u32,
+    /// 发送者 mid 的 HASH
+    #[prost(string, tag = "6")]
+    pub mid_hash: String,
+    /// 弹幕内容
+    #[prost(string, tag = "7")]
+    pub content: String,
+    /// 发送时间戳
+    #[prost(int64, tag = "8")]
+    pub ctime: i64,
+    /// 权重，用于智能屏蔽
+    #[prost(int32, tag = "9")]
+    pub weight: i32,
+    #[prost(string, tag = "10")]
+    pub action: String,
+    /// 弹幕池 0: 普通池 1: 字幕池 2: 特殊池
+    #[prost(int32, tag = "11")]
+    pub pool: i32,
+    #[prost(string, tag = "12")]
+    pub id_str: String,
+    /// 弹幕属性位
+    #[prost(int32, tag = "13")]
+    pub attr: i32,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct DmSegMobileReply {
+    #[prost(message, repeated, tag = "1")]
+    elems: Vec<DanmakuElem>,
+}
+
+/// 弹幕元数据 `x/v2/dm/web/view`，仅解析分段信息
+#[derive(Clone, PartialEq, Message)]
+struct DmWebViewReply {
+    #[prost(message, optional, tag = "4")]
+    dm_sge: Option<DmSegConfig>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct DmSegConfig {
+    /// 分段时长（毫秒）
+    #[prost(int64, tag = "1")]
+    page_size: i64,
+    /// 分段总数
+    #[prost(int64, tag = "2")]
+    total: i64,
+}
+
+/// 覆盖 `duration` 时长所需的分段数，至少为 1
+pub fn segment_count(duration: Duration) -> u32 {
+    let secs = duration.as_secs().max(1);
+    secs.div_ceil(SEGMENT_DURATION.as_secs()) as u32
+}
+
+/// 请求出错时接口返回 JSON 而非 protobuf
+fn check_json_error(bytes: &[u8]) -> Result<(), BpiError> {
+    if
+        bytes.first() == Some(&b'{') &&
+        let Ok(resp) = serde_json::from_slice::<BpiResponse<serde_json::Value>>(bytes) &&
+        resp.code != 0
+    {
+        return Err(BpiError::from_code_message(resp.code, resp.message));
+    }
+    Ok(())
+}
+
+/// 解码分段响应
+fn decode_segment(bytes: &[u8]) -> Result<Vec<DanmakuElem>, BpiError> {
+    check_json_error(bytes)?;
+    DmSegMobileReply::decode(bytes)
+        .map(|reply| reply.elems)
+        .map_err(|e| BpiError::parse(format!("解析分段弹幕失败: {}", e)))
+}
+
+/// 解码弹幕元数据中的分段总数，至少为 1
+fn decode_segment_total(bytes: &[u8]) -> Result<u32, BpiError> {
+    if bytes.is_empty() {
+        return Err(BpiError::parse("弹幕元数据为空"));
+    }
+    check_json_error(bytes)?;
+    let reply = DmWebViewReply::decode(bytes).map_err(|e|
+        BpiError::parse(format!("解析弹幕元数据失败: {}", e))
+    )?;
+    let total = reply.dm_sge.ok_or_else(|| BpiError::parse("弹幕元数据缺少分段信息"))?.total;
+    Ok(total.clamp(1, u32::MAX as i64) as u32)
+}
+
+/// 合并分段，按出现时间排序并去重
+fn merge_segments(segments: Vec<Vec<DanmakuElem>>) -> Vec<DanmakuElem> {
+    let mut all: Vec<DanmakuElem> = segments.into_iter().flatten().collect();
+    all.sort_by_key(|dm| (dm.progress, dm.id));
+    all.dedup_by_key(|dm| dm.id);
+    all
+}
+
+impl BpiClient {
+    /// 获取单个分段的弹幕
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `cid` | i64 | 视频 cid |
+    /// | `segment_index` | u32 | 分段序号，从 1 开始，每段 6 分钟 |
+    pub async fn danmaku_segment(
+        &self,
+        cid: i64,
+        segment_index: u32
+    ) -> Result<Vec<DanmakuElem>, BpiError> {
+        let bytes = self
+            .get("https://api.bilibili.com/x/v2/dm/web/seg.so")
+            .query(
+                &[
+                    ("type", "1".to_string()),
+                    ("oid", cid.to_string()),
+                    ("segment_index", segment_index.to_string()),
+                ]
+            )
+            .send_request("获取分段弹幕").await?;
+
+        decode_segment(&bytes)
+    }
+
+    /// 获取视频的弹幕分段总数
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `cid` | i64 | 视频 cid |
+    pub async fn danmaku_segment_total(&self, cid: i64) -> Result<u32, BpiError> {
+        let bytes = self
+            .get("https://api.bilibili.com/x/v2/dm/web/view")
+            .query(
+                &[
+                    ("type", "1".to_string()),
+                    ("oid", cid.to_string()),
+                ]
+            )
+            .send_request("获取弹幕元数据").await?;
+
+        decode_segment_total(&bytes)
+    }
+
+    /// 按分段顺序输出视频全部弹幕，适合超长视频边取边处理
+    ///
+    /// 最多同时请求 4 个分段，输出顺序与分段顺序一致。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `cid` | i64 | 视频 cid |
+    /// | `duration` | Duration | 视频时长 |
+    pub fn danmaku_segments(
+        &self,
+        cid: i64,
+        duration: Duration
+    ) -> impl Stream<Item = Result<Vec<DanmakuElem>, BpiError>> + '_ {
+        self.danmaku_segment_range(cid, segment_count(duration))
+    }
+
+    fn danmaku_segment_range(
+        &self,
+        cid: i64,
+        total: u32
+    ) -> impl Stream<Item = Result<Vec<DanmakuElem>, BpiError>> + '_ {
+        futures_util::stream
+            ::iter(1..=total)
+            .map(move |index| self.danmaku_segment(cid, index))
+            .buffered(DEFAULT_CONCURRENCY)
+    }
+
+    /// 获取视频全部弹幕，合并所有分段后按出现时间排序
+    ///
+    /// 分段总数通过 [`BpiClient::danmaku_segment_total`] 获取。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `cid` | i64 | 视频 cid |
+    pub async fn danmaku_all(&self, cid: i64) -> Result<Vec<DanmakuElem>, BpiError> {
+        let total = self.danmaku_segment_total(cid).await?;
+        Ok(merge_segments(self.danmaku_segment_range(cid, total).try_collect().await?))
+    }
+
+    /// 获取视频全部弹幕，已知视频时长时可省去查询分段总数的请求
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `cid` | i64 | 视频 cid |
+    /// | `duration` | Duration | 视频时长 |
+    pub async fn danmaku_all_with_duration(
+        &self,
+        cid: i64,
+        duration: Duration
+    ) -> Result<Vec<DanmakuElem>, BpiError> {
+        Ok(merge_segments(self.danmaku_segments(cid, duration).try_collect().await?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_count() {
+        assert_eq!(segment_count(Duration::ZERO), 1);
+        assert_eq!(segment_count(Duration::from_secs(360)), 1);
+        assert_eq!(segment_count(Duration::from_secs(361)), 2);
+        assert_eq!(segment_count(Duration::from_secs(3600)), 10);
+    }
+
+    #[test]
+    fn test_decode_segment() {
+        let reply = DmSegMobileReply {
+            elems: vec![DanmakuElem {
+                id: 1,
+                progress: 1500,
+                content: "测试".to_string(),
+                ..Default::default()
+            }],
+        };
+        let elems = decode_segment(&reply.encode_to_vec()).unwrap();
+        assert_eq!(elems, reply.elems);
+
+        let err = decode_segment(br#"{"code":-404,"message":"not found","ttl":1}"#).unwrap_err();
+        assert_eq!(err.code(), Some(-404));
+    }
+
+    #[test]
+    fn test_decode_segment_total() {
+        let reply = DmWebViewReply {
+            dm_sge: Some(DmSegConfig { page_size: 360_000, total: 3 }),
+        };
+        assert_eq!(decode_segment_total(&reply.encode_to_vec()).unwrap(), 3);
+        assert!(decode_segment_total(&[]).is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_danmaku_all() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let all = bpi.danmaku_all(16546).await?;
+        tracing::info!("弹幕数: {}", all.len());
+        assert!(all.windows(2).all(|w| w[0].progress <= w[1].progress));
+        Ok(())
+    }
+}
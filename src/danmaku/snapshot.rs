@@ -32,8 +32,9 @@ pub mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_danmaku_snapshot() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.danmaku_snapshot("BV1fK4y1t741").await?;
 
         let data = result.into_data()?;
@@ -0,0 +1,243 @@
+//! 设备指纹
+//!
+//! B站风控会比对同一账号请求间的设备信号（buvid3/4、`_uuid`、`buvid_fp`、User-Agent 等），
+//! 同一账号混用多套设备信息容易触发验证。[`DeviceProfile`] 将这些信号打包，
+//! 生成后可持久化，设置到 [`BpiClient`] 后所有请求都使用同一套设备信息。
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::{ Deserialize, Serialize };
+use uuid::Uuid;
+
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 生成设备时随机选用的桌面浏览器 User-Agent
+const USER_AGENTS: [&str; 4] = [
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36 Edg/124.0.0.0",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/122.0.0.0 Safari/537.36",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.4 Safari/605.1.15",
+];
+
+/// 生成设备时随机选用的屏幕分辨率
+const SCREEN_SIZES: [(u32, u32); 5] = [
+    (1920, 1080),
+    (2560, 1440),
+    (1536, 864),
+    (1440, 900),
+    (1366, 768),
+];
+
+/// 设备信息
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceProfile {
+    /// Cookie `buvid3`
+    pub buvid3: String,
+    /// Cookie `buvid4`，由服务端下发，本地生成时为空，见 [`BpiClient::device_activate`]
+    #[serde(default)]
+    pub buvid4: String,
+    /// Cookie `_uuid`
+    pub uuid: String,
+    /// Cookie `buvid_fp`，浏览器指纹
+    pub fp: String,
+    /// Cookie `b_nut`，设备首次访问时间戳
+    pub b_nut: i64,
+    pub user_agent: String,
+    pub screen_width: u32,
+    pub screen_height: u32,
+}
+
+impl DeviceProfile {
+    /// 随机生成一套设备信息
+    pub fn generate() -> Self {
+        let seed = Uuid::new_v4().as_u128();
+        let (screen_width, screen_height) = SCREEN_SIZES[(seed % 5) as usize];
+
+        Self {
+            buvid3: infoc_id(),
+            buvid4: String::new(),
+            uuid: infoc_id(),
+            fp: format!("{:x}", md5::compute(Uuid::new_v4().as_bytes())),
+            b_nut: chrono::Utc::now().timestamp(),
+            user_agent: USER_AGENTS[((seed >> 8) % 4) as usize].to_string(),
+            screen_width,
+            screen_height,
+        }
+    }
+
+    /// 设备信息对应的 cookie，`buvid4` 为空时不包含
+    pub fn cookies(&self) -> Vec<(&'static str, String)> {
+        let mut cookies = vec![
+            ("buvid3", self.buvid3.clone()),
+            ("_uuid", self.uuid.clone()),
+            ("buvid_fp", self.fp.clone()),
+            ("b_nut", self.b_nut.to_string())
+        ];
+        if !self.buvid4.is_empty() {
+            cookies.push(("buvid4", self.buvid4.clone()));
+        }
+        cookies
+    }
+
+    /// 从 JSON 文件读取
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BpiError> {
+        let content = std::fs
+            ::read_to_string(path)
+            .map_err(|e| BpiError::parse(format!("读取设备信息失败: {}", e)))?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// 保存为 JSON 文件
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), BpiError> {
+        std::fs
+            ::write(path, serde_json::to_string_pretty(self)?)
+            .map_err(|e| BpiError::parse(format!("保存设备信息失败: {}", e)))
+    }
+}
+
+/// `{UUID}{5位数字}infoc` 格式的标识，用于 buvid3 与 `_uuid`
+fn infoc_id() -> String {
+    let id = Uuid::new_v4();
+    let suffix = (id.as_u128() >> 64) % 100_000;
+    format!("{}{:05}infoc", id.hyphenated().to_string().to_uppercase(), suffix)
+}
+
+/// 多账号的设备信息，按账号 mid（`DedeUserID`）保存
+///
+/// ```no_run
+/// use bpi_rs::device::DeviceStore;
+///
+/// # fn main() -> Result<(), bpi_rs::BpiError> {
+/// let mut store = DeviceStore::load("devices.json")?;
+/// let device = store.get_or_generate("123456").clone();
+/// store.save("devices.json")?;
+///
+/// let bpi = bpi_rs::BpiClient::builder().device(device).build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeviceStore {
+    devices: HashMap<String, DeviceProfile>,
+}
+
+impl DeviceStore {
+    /// 从 JSON 文件读取，文件不存在时返回空集合
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BpiError> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(BpiError::parse(format!("读取设备信息失败: {}", e))),
+        }
+    }
+
+    /// 保存为 JSON 文件
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), BpiError> {
+        std::fs
+            ::write(path, serde_json::to_string_pretty(self)?)
+            .map_err(|e| BpiError::parse(format!("保存设备信息失败: {}", e)))
+    }
+
+    pub fn get(&self, mid: &str) -> Option<&DeviceProfile> {
+        self.devices.get(mid)
+    }
+
+    /// 获取账号的设备信息，没有时生成一套
+    pub fn get_or_generate(&mut self, mid: &str) -> &DeviceProfile {
+        self.devices.entry(mid.to_string()).or_insert_with(DeviceProfile::generate)
+    }
+
+    /// 为账号换一套新设备，如账号被风控后
+    pub fn rotate(&mut self, mid: &str) -> &DeviceProfile {
+        self.devices.insert(mid.to_string(), DeviceProfile::generate());
+        &self.devices[mid]
+    }
+
+    /// 更新账号的设备信息，如激活后写回 buvid4
+    pub fn insert(&mut self, mid: &str, device: DeviceProfile) {
+        self.devices.insert(mid.to_string(), device);
+    }
+
+    pub fn remove(&mut self, mid: &str) -> Option<DeviceProfile> {
+        self.devices.remove(mid)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SpiData {
+    b_3: String,
+    b_4: String,
+}
+
+impl BpiClient {
+    /// 向服务端登记当前设备，获取 buvid3/buvid4 并写回设备信息
+    ///
+    /// 未设置设备时先生成一套。返回更新后的设备信息，需要持久化时自行保存。
+    pub async fn device_activate(&self) -> Result<Arc<DeviceProfile>, BpiError> {
+        let mut device = self
+            .device()
+            .map(|device| device.as_ref().clone())
+            .unwrap_or_else(DeviceProfile::generate);
+
+        let resp: BpiResponse<SpiData> = self
+            .get("https://api.bilibili.com/x/frontend/finger/spi")
+            .send_bpi("登记设备").await?;
+        let data = resp.into_data()?;
+
+        device.buvid3 = data.b_3;
+        device.buvid4 = data.b_4;
+        self.set_device(device);
+
+        self.device().ok_or_else(BpiError::missing_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_generate() {
+        let device = DeviceProfile::generate();
+        assert!(device.buvid3.ends_with("infoc"));
+        assert_eq!(device.buvid3.len(), 36 + 5 + 5);
+        assert_eq!(device.fp.len(), 32);
+        assert_ne!(device.buvid3, device.uuid);
+        assert!(device.cookies().iter().all(|(name, _)| *name != "buvid4"));
+        assert_ne!(DeviceProfile::generate(), device);
+    }
+
+    #[test]
+    fn test_device_store() {
+        let mut store = DeviceStore::default();
+        let first = store.get_or_generate("1").clone();
+        assert_eq!(store.get_or_generate("1"), &first);
+        assert_ne!(store.rotate("1"), &first);
+
+        let json = serde_json::to_string(&store).unwrap();
+        let restored: DeviceStore = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.get("1"), store.get("1"));
+    }
+
+    #[test]
+    fn test_client_device() {
+        let device = DeviceProfile::generate();
+        let bpi = BpiClient::builder().device(device.clone()).build().unwrap();
+        assert_eq!(bpi.device().as_deref(), Some(&device));
+
+        let rotated = bpi.rotate_device();
+        assert_ne!(rotated.buvid3, device.buvid3);
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_device_activate() -> Result<(), BpiError> {
+        let bpi = BpiClient::try_new_local()?;
+        let device = bpi.device_activate().await?;
+        tracing::info!("{:?}", device);
+        assert!(!device.buvid4.is_empty());
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use serde_json::json;
 
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use crate::utils::web_location::WebLocation;
 
 impl BpiClient {
     /// 点赞动态
@@ -25,8 +26,8 @@ impl BpiClient {
             json!({
             "dyn_id_str": dyn_id_str,
             "up": up ,
-            "spmid":"333.1369.0.0",
-            "from_spmid":"333.999.0.0"
+            "spmid": self.spm_id(WebLocation::DYNAMIC_DETAIL),
+            "from_spmid": self.spm_id(WebLocation::DYNAMIC_SOURCE)
 
         });
 
@@ -119,11 +120,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_dynamic_like() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let dynamic_id = "1099138163191840776";
 
         // 测试新版点赞 API
@@ -132,9 +132,9 @@ mod tests {
     }
 
     #[tokio::test]
-    #[ignore]
+    #[ignore = "write"]
     async fn test_dynamic_top() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         // 替换为你需要置顶或取消置顶的动态ID
         let dynamic_id = "1099138163191840776";
 
@@ -1,6 +1,14 @@
 use serde::{ Deserialize, Serialize };
 
+#[cfg(feature = "comment")]
+use crate::comment::resource::{ CommentResourceType, comment_params };
+use crate::models::OpusFeatures;
+use crate::utils::time::{ modules_pub_ts, timestamp_to_datetime };
+use crate::pagination::{ Paginated, paginate };
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use crate::utils::web_location::WebLocation;
+use chrono::{ DateTime, FixedOffset };
+use futures_util::Stream;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DynamicAllData {
@@ -21,6 +29,21 @@ pub struct DynamicItem {
     pub visible: bool,
 }
 
+impl DynamicItem {
+    /// 发布时间
+    ///
+    /// `timezone_offset` 为分钟偏移，含义同接口参数（UTC+8 为 `-480`）。
+    pub fn published_at(&self, timezone_offset: i32) -> Option<DateTime<FixedOffset>> {
+        timestamp_to_datetime(modules_pub_ts(&self.modules)?, timezone_offset)
+    }
+
+    /// 评论区参数 `(type, oid)`，取自 `basic.comment_type` 与 `basic.comment_id_str`
+    #[cfg(feature = "comment")]
+    pub fn comment_params(&self) -> Result<(CommentResourceType, i64), BpiError> {
+        comment_params(self.basic.comment_type, &self.basic.comment_id_str)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Basic {
     pub comment_id_str: String,
@@ -61,9 +84,15 @@ impl BpiClient {
             &[
                 (
                     "features",
-                    "itemOpusStyle,listOnlyfans,opusBigCover,onlyfansVote,decorationCard,onlyfansAssetsV2,forwardListHidden,ugcDelete",
+                    (
+                        OpusFeatures::DYNAMIC_FEED |
+                        OpusFeatures::DECORATION_CARD |
+                        OpusFeatures::ONLYFANS_ASSETS_V2 |
+                        OpusFeatures::FORWARD_LIST_HIDDEN |
+                        OpusFeatures::UGC_DELETE
+                    ).to_string(),
                 ),
-                ("web_location", "333.1365"),
+                ("web_location", self.web_location(WebLocation::DYNAMIC)),
             ]
         );
 
@@ -80,6 +109,29 @@ impl BpiClient {
         req.send_bpi("获取全部动态列表").await
     }
 
+    /// 从最新的动态开始逐页输出全部动态
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `host_mid` | `Option<&str>` | UP 主 UID，为 `None` 时为关注的全部动态 |
+    pub fn dynamic_all_stream(
+        &self,
+        host_mid: Option<&str>
+    ) -> impl Stream<Item = Result<DynamicItem, BpiError>> + '_ {
+        let host_mid = host_mid.map(str::to_string);
+        paginate(String::new(), move |offset| {
+            let host_mid = host_mid.clone();
+            async move {
+                let offset = Some(offset.as_str()).filter(|o| !o.is_empty());
+                let data = self.dynamic_all(host_mid.as_deref(), offset, None).await?.into_data()?;
+                let next = (data.has_more && !data.offset.is_empty()).then_some(data.offset);
+                Ok(Paginated::new(data.items, next))
+            }
+        })
+    }
+
     /// 检测是否有新动态
     ///
     /// # 文档
@@ -106,6 +158,39 @@ impl BpiClient {
 
         req.send_bpi("检测新动态").await
     }
+
+    /// 获取用户空间动态列表
+    ///
+    /// 置顶动态排在第一条。
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/dynamic)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `host_mid` | u64 | 用户 UID |
+    /// | `offset` | `Option<&str>` | 分页偏移量 |
+    pub async fn dynamic_space(
+        &self,
+        host_mid: u64,
+        offset: Option<&str>
+    ) -> Result<BpiResponse<DynamicAllData>, BpiError> {
+        let mut req = self.get("https://api.bilibili.com/x/polymer/web-dynamic/v1/feed/space").query(
+            &[
+                ("host_mid", host_mid.to_string()),
+                ("features", OpusFeatures::DYNAMIC_FEED.to_string()),
+                ("web_location", self.web_location(WebLocation::SPACE)),
+            ]
+        );
+
+        if let Some(off) = offset {
+            req = req.query(&[("offset", off)]);
+        }
+
+        req.send_bpi_wbi("获取用户空间动态列表").await
+    }
 }
 
 #[cfg(test)]
@@ -114,8 +199,20 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_dynamic_all_stream() -> Result<(), BpiError> {
+        use futures_util::{ StreamExt, TryStreamExt };
+
+        let bpi = crate::test_client!(Authed);
+        let items: Vec<DynamicItem> = bpi.dynamic_all_stream(None).take(30).try_collect().await?;
+        info!("获取动态数: {}", items.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
     async fn test_dynamic_get_all() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.dynamic_all(None, None, None).await?;
         assert_eq!(resp.code, 0);
 
@@ -127,8 +224,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_dynamic_check_new() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let update_baseline = "0";
         let resp = bpi.dynamic_check_new(update_baseline, None).await?;
         let data = resp.into_data().unwrap();
@@ -137,4 +235,13 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_dynamic_space() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi.dynamic_space(2, None).await?.into_data()?;
+        info!("获取到 {} 条空间动态，has_more: {}", data.items.len(), data.has_more);
+        Ok(())
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{BilibiliRequest, BpiClient, BpiError, BpiResponse};
+use crate::utils::web_location::WebLocation;
 use serde::{Deserialize, Serialize};
 
 /// 动态首页公告栏响应数据
@@ -40,9 +41,9 @@ impl BpiClient {
         let req = self
             .get("https://api.bilibili.com/x/dynamic/feed/dyn/banner")
             .query(&[
-                ("platform", "1"),
-                ("position", "web动态"),
-                ("web_location", "333.1365"),
+                ("platform", "1".to_string()),
+                ("position", "web动态".to_string()),
+                ("web_location", self.web_location(WebLocation::DYNAMIC)),
             ]);
 
         req.send_bpi("获取动态首页公告栏").await
@@ -55,8 +56,9 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_dynamic_feed_banner() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.dynamic_feed_banner().await?;
         let data = resp.into_data()?;
 
@@ -336,8 +336,9 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_repost_detail() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 替换为有效的动态ID进行测试
         let dynamic_id = "1099138163191840776";
         let resp = bpi.dynamic_repost_detail(dynamic_id, None).await?;
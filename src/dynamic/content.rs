@@ -117,8 +117,9 @@ mod tests {
     // 例如: tracing_subscriber::fmt::init();
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_live_users() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.dynamic_live_users(Some(1)).await?;
         let data = resp.into_data()?;
 
@@ -129,8 +130,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_dyn_up_users() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.dynamic_up_users(None).await?;
         let data = resp.into_data()?;
 
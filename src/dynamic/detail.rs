@@ -1,5 +1,9 @@
-use crate::models::{ Official, Pendant, Vip };
+#[cfg(feature = "comment")]
+use crate::comment::resource::{ CommentResourceType, comment_params };
+use crate::models::{ Official, OpusFeatures, OpusState, Pendant, Vip };
+use crate::utils::time::{ modules_pub_ts, timestamp_to_datetime };
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use chrono::{ DateTime, FixedOffset };
 use serde::{ Deserialize, Serialize };
 // --- 动态详情 API 结构体 ---
 
@@ -22,6 +26,21 @@ pub struct DynamicDetailItem {
     pub visible: bool,
 }
 
+impl DynamicDetailItem {
+    /// 发布时间
+    ///
+    /// `timezone_offset` 为分钟偏移，含义同接口参数（UTC+8 为 `-480`）。
+    pub fn published_at(&self, timezone_offset: i32) -> Option<DateTime<FixedOffset>> {
+        timestamp_to_datetime(modules_pub_ts(&self.modules)?, timezone_offset)
+    }
+
+    /// 评论区参数 `(type, oid)`，取自 `basic.comment_type` 与 `basic.comment_id_str`
+    #[cfg(feature = "comment")]
+    pub fn comment_params(&self) -> Result<(CommentResourceType, i64), BpiError> {
+        comment_params(self.basic.comment_type, &self.basic.comment_id_str)
+    }
+}
+
 /// 动态卡片内容，作为多个 API 的共享结构体
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DynamicForwardItem {
@@ -91,7 +110,7 @@ pub struct DynamicReactionData {
 
 // --- 动态抽奖详情 API 结构体 ---
 
-/// 抽奖结果
+/// 中奖用户
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct LotteryResultItem {
     pub uid: u64,
@@ -100,6 +119,30 @@ pub struct LotteryResultItem {
     pub hongbao_money: Option<f64>,
 }
 
+/// 开奖结果，按奖项分列
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct LotteryResult {
+    #[serde(default)]
+    pub first_prize_result: Option<Vec<LotteryResultItem>>,
+    #[serde(default)]
+    pub second_prize_result: Option<Vec<LotteryResultItem>>,
+    #[serde(default)]
+    pub third_prize_result: Option<Vec<LotteryResultItem>>,
+}
+
+/// 奖项
+#[derive(Debug, Clone, PartialEq)]
+pub struct LotteryPrize {
+    /// 奖项等级，1 为一等奖
+    pub tier: u8,
+    /// 名额
+    pub count: u32,
+    /// 奖品描述
+    pub description: String,
+    /// 奖品图片
+    pub pic: String,
+}
+
 /// 动态抽奖详情响应数据
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DynamicLotteryData {
@@ -107,14 +150,100 @@ pub struct DynamicLotteryData {
     pub sender_uid: u64,
     pub business_type: u8,
     pub business_id: u64,
+    /// 开奖状态，0 未开奖，2 已开奖
     pub status: u8,
+    /// 开奖时间戳
     pub lottery_time: u64,
+    /// 参与人数
     pub participants: u64,
+    /// 一等奖名额
+    #[serde(default)]
+    pub first_prize: u32,
+    /// 二等奖名额
+    #[serde(default)]
+    pub second_prize: u32,
+    /// 三等奖名额
+    #[serde(default)]
+    pub third_prize: u32,
     pub first_prize_cmt: String,
     pub second_prize_cmt: Option<String>,
     pub third_prize_cmt: Option<String>,
-    pub lottery_result: Option<serde_json::Value>, // 使用 Value 以应对可选的嵌套对象
-    // ... 其他字段
+    #[serde(default)]
+    pub first_prize_pic: String,
+    #[serde(default)]
+    pub second_prize_pic: String,
+    #[serde(default)]
+    pub third_prize_pic: String,
+    /// 是否需要转发，1 为需要
+    #[serde(default)]
+    pub need_post: u8,
+    /// 是否需要关注发起人，1 为需要
+    #[serde(default)]
+    pub lottery_feed_limit: u8,
+    /// 转发时需要 @ 的人数
+    #[serde(default)]
+    pub lottery_at_num: u32,
+    /// 当前用户是否已参与
+    #[serde(default)]
+    pub participated: bool,
+    /// 当前用户是否已关注发起人
+    #[serde(default)]
+    pub followed: bool,
+    /// 当前用户是否已转发
+    #[serde(default)]
+    pub reposted: bool,
+    /// 开奖结果，开奖前不存在
+    #[serde(default)]
+    pub lottery_result: Option<LotteryResult>,
+}
+
+impl DynamicLotteryData {
+    /// 是否已开奖
+    pub fn is_drawn(&self) -> bool {
+        self.status == 2
+    }
+
+    /// 开奖时间
+    ///
+    /// `timezone_offset` 为分钟偏移，含义同接口参数（UTC+8 为 `-480`）。
+    pub fn deadline(&self, timezone_offset: i32) -> Option<DateTime<FixedOffset>> {
+        timestamp_to_datetime(self.lottery_time as i64, timezone_offset)
+    }
+
+    /// 参与条件：是否需要转发、关注发起人以及需要 @ 的人数
+    pub fn conditions(&self) -> (bool, bool, u32) {
+        (self.need_post == 1, self.lottery_feed_limit == 1, self.lottery_at_num)
+    }
+
+    /// 各奖项，跳过没有设置的奖项
+    pub fn prizes(&self) -> Vec<LotteryPrize> {
+        [
+            (1, self.first_prize, Some(&self.first_prize_cmt), &self.first_prize_pic),
+            (2, self.second_prize, self.second_prize_cmt.as_ref(), &self.second_prize_pic),
+            (3, self.third_prize, self.third_prize_cmt.as_ref(), &self.third_prize_pic),
+        ]
+            .into_iter()
+            .filter_map(|(tier, count, description, pic)| {
+                let description = description.filter(|d| !d.is_empty())?;
+                Some(LotteryPrize { tier, count, description: description.clone(), pic: pic.clone() })
+            })
+            .collect()
+    }
+
+    /// 中奖名单，按 `(奖项等级, 中奖用户)` 排列，开奖前为空
+    pub fn winners(&self) -> Vec<(u8, &LotteryResultItem)> {
+        let Some(result) = &self.lottery_result else {
+            return Vec::new();
+        };
+        [
+            (1, &result.first_prize_result),
+            (2, &result.second_prize_result),
+            (3, &result.third_prize_result),
+        ]
+            .into_iter()
+            .flat_map(|(tier, items)| items.iter().flatten().map(move |item| (tier, item)))
+            .collect()
+    }
 }
 
 // --- 动态转发列表 API 结构体 ---
@@ -160,24 +289,41 @@ impl BpiClient {
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
     /// | `id` | &str | 动态 ID |
-    /// | `features` | `Option<&str>` | 功能特性，如 `itemOpusStyle,opusBigCover,onlyfansVote...` |
+    /// | `features` | `Option<OpusFeatures>` | 功能特性，默认 [`OpusFeatures::DYNAMIC_DETAIL`] |
     pub async fn dynamic_detail(
         &self,
         id: &str,
-        features: Option<&str>
+        features: Option<OpusFeatures>
     ) -> Result<BpiResponse<DynamicDetailData>, BpiError> {
-        let mut req = self
+        let features = features.unwrap_or(OpusFeatures::DYNAMIC_DETAIL).to_string();
+        self
             .get("https://api.bilibili.com/x/polymer/web-dynamic/v1/detail")
-            .query(&[("id", id)]);
+            .query(&[("id", id), ("features", &features)])
+            .send_bpi("获取动态详情").await
+    }
 
-        if let Some(f) = features {
-            req = req.query(&[("features", f)]);
-        } else {
-            // 默认值处理
-            req = req.query(&[("features", "htmlNewStyle,itemOpusStyle,decorationCard")]);
+    /// 获取动态详情，并将私密、删除、审核中等情况归类为 [`OpusState`]
+    ///
+    /// 动态可见但 `visible` 为 `false` 时视为仅作者可见。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `id` | &str | 动态 ID |
+    pub async fn dynamic_detail_state(
+        &self,
+        id: &str
+    ) -> Result<OpusState<DynamicDetailItem>, BpiError> {
+        let result = match self.dynamic_detail(id, None).await {
+            Ok(resp) => resp.into_data().map(|data| data.item),
+            Err(e) => Err(e),
+        };
+
+        match OpusState::from_result(result)? {
+            OpusState::Normal(item) if !item.visible => Ok(OpusState::Private),
+            state => Ok(state),
         }
-
-        req.send_bpi("获取动态详情").await
     }
 
     /// 获取动态点赞与转发列表
@@ -305,8 +451,9 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_dynamic_detail() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let dynamic_id = "1099138163191840776";
         let resp = bpi.dynamic_detail(dynamic_id, None).await?;
         let data = resp.into_data()?;
@@ -318,8 +465,20 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_get_dynamic_detail_state() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let state = bpi.dynamic_detail_state("1099138163191840776").await?;
+
+        assert!(state.is_normal());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_dynamic_reactions() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let dynamic_id = "1099138163191840776";
         let resp = bpi.dynamic_reactions(dynamic_id, None).await?;
         let data = resp.into_data()?;
@@ -330,22 +489,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_lottery_notice() {
+        let data: DynamicLotteryData = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "lottery_id": 1, "sender_uid": 2, "business_type": 1, "business_id": 969916293954142214_u64,
+                    "status": 2, "lottery_time": 1700000000, "participants": 100,
+                    "first_prize": 1, "second_prize": 2, "third_prize": 0,
+                    "first_prize_cmt": "键盘", "second_prize_cmt": "鼠标", "third_prize_cmt": "",
+                    "first_prize_pic": "https://i0.hdslb.com/1.jpg",
+                    "need_post": 1, "lottery_feed_limit": 1, "lottery_at_num": 0,
+                    "lottery_result": {
+                        "first_prize_result": [{ "uid": 3, "name": "a", "face": "" }],
+                        "second_prize_result": [{ "uid": 4, "name": "b", "face": "" }, { "uid": 5, "name": "c", "face": "" }]
+                    }
+                })
+            )
+            .unwrap();
+
+        assert!(data.is_drawn());
+        assert_eq!(data.conditions(), (true, true, 0));
+        let prizes = data.prizes();
+        assert_eq!(prizes.len(), 2);
+        assert_eq!((prizes[1].tier, prizes[1].count, prizes[1].description.as_str()), (2, 2, "鼠标"));
+        let winners: Vec<(u8, u64)> = data
+            .winners()
+            .into_iter()
+            .map(|(tier, item)| (tier, item.uid))
+            .collect();
+        assert_eq!(winners, [(1, 3), (2, 4), (2, 5)]);
+    }
+
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_get_lottery_notice() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let dynamic_id = "969916293954142214";
         let resp = bpi.dynamic_lottery_notice(dynamic_id).await?;
         let data = resp.into_data()?;
 
-        info!("抽奖状态: {}", data.status);
+        info!("抽奖状态: {}，奖项: {:?}，中奖 {} 人", data.status, data.prizes(), data.winners().len());
         assert_eq!(data.business_id.to_string(), dynamic_id);
 
         Ok(())
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_dynamic_forwards() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let dynamic_id = "1099138163191840776";
         let resp = bpi.dynamic_forwards(dynamic_id, None).await?;
         let data = resp.into_data()?;
@@ -357,8 +550,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_dynamic_pics() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let dynamic_id = "1099138163191840776";
         let resp = bpi.dynamic_pics(dynamic_id).await?;
         let data = resp.into_data()?;
@@ -370,8 +564,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_forward_item() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let dynamic_id = "1110902525317349376";
         let resp = bpi.dynamic_forward_item(dynamic_id).await?;
         let data = resp.into_data()?;
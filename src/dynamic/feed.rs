@@ -0,0 +1,376 @@
+//! 关注动态
+//!
+//! [`BpiClient::dynamic_all`] 的 `modules` 为原始 JSON，这里提供类型化的关注动态列表：
+//! 作者、动态内容与统计模块按结构解析，视频、图文、转发、纯文字与直播推荐
+//! 可通过 [`DynamicFeedItem::content`] 取得对应的内容。
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/dynamic/all.md)
+
+use serde::{ Deserialize, Serialize };
+
+use super::all::Basic;
+use super::module::{ ModuleAuthor, ModuleStat };
+use crate::models::OpusFeatures;
+use crate::utils::query::Query;
+use crate::utils::web_location::WebLocation;
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 关注动态列表
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DynamicFeedData {
+    pub has_more: bool,
+    #[serde(default)]
+    pub items: Vec<DynamicFeedItem>,
+    /// 下一页的 `offset`
+    pub offset: String,
+    /// 用于检测新动态的 `update_baseline`
+    pub update_baseline: String,
+    /// 新动态数量
+    pub update_num: i64,
+}
+
+/// 关注动态列表中的一条动态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DynamicFeedItem {
+    pub basic: Basic,
+    pub id_str: Option<String>,
+    pub modules: FeedModules,
+    /// 动态类型，如 `DYNAMIC_TYPE_AV`
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub visible: bool,
+    /// 被转发的动态，仅转发动态
+    pub orig: Option<Box<DynamicFeedItem>>,
+}
+
+/// 动态的各个模块
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeedModules {
+    /// 作者模块
+    pub module_author: ModuleAuthor,
+    /// 动态内容模块
+    pub module_dynamic: Option<FeedModuleDynamic>,
+    /// 统计模块，被转发的动态中不存在
+    pub module_stat: Option<ModuleStat>,
+}
+
+/// 动态内容模块
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeedModuleDynamic {
+    /// 动态文字
+    pub desc: Option<FeedDesc>,
+    /// 动态主体
+    pub major: Option<FeedMajor>,
+    /// 附加卡片，如投票、预约
+    pub additional: Option<serde_json::Value>,
+    /// 话题
+    pub topic: Option<serde_json::Value>,
+}
+
+/// 动态文字
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeedDesc {
+    /// 纯文本
+    pub text: String,
+    /// 富文本节点
+    #[serde(default)]
+    pub rich_text_nodes: Vec<serde_json::Value>,
+}
+
+/// 动态主体，按 `type` 只有对应的字段存在
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeedMajor {
+    /// 主体类型，如 `MAJOR_TYPE_ARCHIVE`、`MAJOR_TYPE_OPUS`
+    #[serde(rename = "type")]
+    pub type_field: String,
+    /// 视频
+    pub archive: Option<FeedArchive>,
+    /// 旧版图文
+    pub draw: Option<FeedDraw>,
+    /// 新版图文与纯文字
+    pub opus: Option<FeedOpus>,
+    /// 直播推荐
+    pub live_rcmd: Option<FeedLiveRcmd>,
+}
+
+/// 视频主体
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeedArchive {
+    pub aid: String,
+    pub bvid: String,
+    pub title: String,
+    pub cover: String,
+    pub desc: String,
+    /// 时长，如 `05:20`
+    pub duration_text: String,
+    pub jump_url: String,
+    pub stat: FeedArchiveStat,
+}
+
+/// 视频统计，均为显示文本
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeedArchiveStat {
+    pub danmaku: String,
+    pub play: String,
+}
+
+/// 旧版图文主体
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeedDraw {
+    pub id: i64,
+    #[serde(default)]
+    pub items: Vec<FeedDrawItem>,
+}
+
+/// 图片
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeedDrawItem {
+    /// 图片地址，旧版图文为 `src`，新版为 `url`
+    #[serde(alias = "url")]
+    pub src: String,
+    pub width: i64,
+    pub height: i64,
+    /// 大小，单位 KB
+    pub size: f64,
+}
+
+/// 新版图文主体
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeedOpus {
+    pub jump_url: String,
+    pub title: Option<String>,
+    pub summary: Option<FeedDesc>,
+    #[serde(default)]
+    pub pics: Vec<FeedDrawItem>,
+}
+
+/// 直播推荐主体
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeedLiveRcmd {
+    /// 直播间信息的 JSON 字符串
+    pub content: String,
+    pub reserve_type: i64,
+}
+
+/// 直播推荐中的直播间信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LiveRcmdInfo {
+    pub room_id: i64,
+    pub uid: i64,
+    pub title: String,
+    pub cover: String,
+    /// 直播状态，1 直播中
+    pub live_status: i64,
+    pub area_name: String,
+    pub parent_area_name: String,
+    pub link: String,
+    /// 开播时间戳
+    pub live_start_time: i64,
+    /// 观看人数文本
+    pub watched_show: serde_json::Value,
+}
+
+impl FeedLiveRcmd {
+    /// 解析 `content` 中的直播间信息
+    pub fn info(&self) -> Result<LiveRcmdInfo, BpiError> {
+        #[derive(Deserialize)]
+        struct Content {
+            live_play_info: LiveRcmdInfo,
+        }
+        let content: Content = serde_json::from_str(&self.content)?;
+        Ok(content.live_play_info)
+    }
+}
+
+/// 按动态类型取出的内容
+#[derive(Debug, Clone)]
+pub enum DynamicContent {
+    /// 视频，`DYNAMIC_TYPE_AV`
+    Av(FeedArchive),
+    /// 图文，`DYNAMIC_TYPE_DRAW`
+    Draw { text: String, pics: Vec<FeedDrawItem> },
+    /// 转发，`DYNAMIC_TYPE_FORWARD`，原动态被删除时为 `None`
+    Forward { text: String, orig: Option<Box<DynamicFeedItem>> },
+    /// 纯文字，`DYNAMIC_TYPE_WORD`
+    Word(String),
+    /// 直播推荐，`DYNAMIC_TYPE_LIVE_RCMD`
+    LiveRcmd(LiveRcmdInfo),
+    /// 其他类型，保留类型名
+    Other(String),
+}
+
+impl DynamicFeedItem {
+    /// 动态主体
+    pub fn major(&self) -> Option<&FeedMajor> {
+        self.modules.module_dynamic.as_ref()?.major.as_ref()
+    }
+
+    /// 动态文字，新版图文与纯文字的文字在 `major.opus.summary` 中
+    pub fn text(&self) -> String {
+        let module = self.modules.module_dynamic.as_ref();
+        module
+            .and_then(|m| m.desc.as_ref())
+            .or_else(|| self.major()?.opus.as_ref()?.summary.as_ref())
+            .map(|desc| desc.text.clone())
+            .unwrap_or_default()
+    }
+
+    /// 按动态类型解析内容
+    pub fn content(&self) -> Result<DynamicContent, BpiError> {
+        let major = self.major();
+        Ok(match self.type_field.as_str() {
+            "DYNAMIC_TYPE_AV" => {
+                let archive = major.and_then(|m| m.archive.clone()).ok_or_else(BpiError::missing_data)?;
+                DynamicContent::Av(archive)
+            }
+            "DYNAMIC_TYPE_DRAW" => {
+                let pics = match major {
+                    Some(FeedMajor { draw: Some(draw), .. }) => draw.items.clone(),
+                    Some(FeedMajor { opus: Some(opus), .. }) => opus.pics.clone(),
+                    _ => Vec::new(),
+                };
+                DynamicContent::Draw { text: self.text(), pics }
+            }
+            "DYNAMIC_TYPE_FORWARD" => {
+                DynamicContent::Forward { text: self.text(), orig: self.orig.clone() }
+            }
+            "DYNAMIC_TYPE_WORD" => DynamicContent::Word(self.text()),
+            "DYNAMIC_TYPE_LIVE_RCMD" => {
+                let live = major.and_then(|m| m.live_rcmd.as_ref()).ok_or_else(BpiError::missing_data)?;
+                DynamicContent::LiveRcmd(live.info()?)
+            }
+            other => DynamicContent::Other(other.to_string()),
+        })
+    }
+}
+
+impl BpiClient {
+    /// 获取关注动态列表（类型化）
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/dynamic)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `offset` | `Option<&str>` | 上一页返回的 `offset`，首页为 `None` |
+    /// | `update_baseline` | `Option<&str>` | 上次返回的 `update_baseline`，用于统计新动态 |
+    pub async fn dynamic_feed_all(
+        &self,
+        offset: Option<&str>,
+        update_baseline: Option<&str>
+    ) -> Result<BpiResponse<DynamicFeedData>, BpiError> {
+        let query = Query::new()
+            .with("type", "all")
+            .with("features", OpusFeatures::DYNAMIC_FEED)
+            .with_opt("offset", offset)
+            .with_opt("update_baseline", update_baseline)
+            .with("web_location", self.web_location(WebLocation::DYNAMIC));
+
+        self
+            .get("https://api.bilibili.com/x/polymer/web-dynamic/v1/feed/all")
+            .query(&query)
+            .send_bpi("获取关注动态列表").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(type_field: &str, module_dynamic: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "basic": { "comment_id_str": "1", "comment_type": 17, "like_icon": {}, "rid_str": "1" },
+            "id_str": "100000000000000001",
+            "modules": {
+                "module_author": {
+                    "face": "", "face_nft": false, "following": true, "jump_url": "", "label": "",
+                    "mid": 2, "name": "碧诗", "pub_time": "1小时前", "pub_ts": 1700000000, "views_text": ""
+                },
+                "module_dynamic": module_dynamic,
+                "module_stat": {
+                    "comment": { "count": 1, "forbidden": false },
+                    "forward": { "count": 2, "forbidden": false },
+                    "like": { "count": 3, "forbidden": false, "status": false }
+                }
+            },
+            "type": type_field,
+            "visible": true
+        })
+    }
+
+    #[test]
+    fn test_feed_content() {
+        let live_content = serde_json::json!({
+            "live_play_info": { "room_id": 1, "uid": 2, "title": "直播", "live_status": 1 }
+        });
+        let mut forward = item(
+            "DYNAMIC_TYPE_FORWARD",
+            serde_json::json!({ "desc": { "text": "转发", "rich_text_nodes": [] }, "major": null })
+        );
+        forward["orig"] = item(
+            "DYNAMIC_TYPE_WORD",
+            serde_json::json!({ "desc": { "text": "原动态", "rich_text_nodes": [] }, "major": null })
+        );
+        let data: DynamicFeedData = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "has_more": true, "offset": "1", "update_baseline": "1", "update_num": 0,
+                    "items": [
+                        item("DYNAMIC_TYPE_AV", serde_json::json!({
+                            "desc": null,
+                            "major": { "type": "MAJOR_TYPE_ARCHIVE", "archive": {
+                                "aid": "10001", "bvid": "BV1bx411c7us", "title": "视频",
+                                "stat": { "danmaku": "1", "play": "2" }
+                            } }
+                        })),
+                        item("DYNAMIC_TYPE_DRAW", serde_json::json!({
+                            "major": { "type": "MAJOR_TYPE_OPUS", "opus": {
+                                "jump_url": "", "summary": { "text": "图文", "rich_text_nodes": [] },
+                                "pics": [{ "url": "https://i0.hdslb.com/1.jpg", "width": 1, "height": 1 }]
+                            } }
+                        })),
+                        item("DYNAMIC_TYPE_LIVE_RCMD", serde_json::json!({
+                            "major": { "type": "MAJOR_TYPE_LIVE_RCMD", "live_rcmd": {
+                                "content": live_content.to_string(), "reserve_type": 0
+                            } }
+                        })),
+                        forward
+                    ]
+                })
+            )
+            .unwrap();
+
+        let contents: Vec<_> = data.items
+            .iter()
+            .map(|item| item.content().unwrap())
+            .collect();
+        assert!(matches!(&contents[0], DynamicContent::Av(a) if a.bvid == "BV1bx411c7us"));
+        assert!(
+            matches!(&contents[1], DynamicContent::Draw { text, pics } if text == "图文" && pics.len() == 1)
+        );
+        assert!(matches!(&contents[2], DynamicContent::LiveRcmd(l) if l.room_id == 1));
+        let DynamicContent::Forward { text, orig: Some(orig) } = &contents[3] else {
+            panic!("{:?}", contents[3]);
+        };
+        assert_eq!(text, "转发");
+        assert!(matches!(orig.content().unwrap(), DynamicContent::Word(t) if t == "原动态"));
+        assert_eq!(data.items[0].modules.module_stat.as_ref().unwrap().like.count, 3);
+    }
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_dynamic_feed_all() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Authed);
+        let data = bpi.dynamic_feed_all(None, None).await?.into_data()?;
+        for item in &data.items {
+            tracing::info!("{} {:?}", item.type_field, item.content().map(|_| ()));
+        }
+        Ok(())
+    }
+}
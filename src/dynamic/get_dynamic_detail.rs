@@ -12,6 +12,7 @@ pub struct DynamicCardData {
 pub struct DynamicCard {
     pub desc: Desc,
     pub card: String,
+    #[serde(default)]
     pub extend_json: String,
     pub display: serde_json::Value,
 }
@@ -22,28 +23,44 @@ pub struct Desc {
     #[serde(rename = "type")]
     pub type_field: i64,
     pub rid: i64,
+    #[serde(default)]
     pub acl: i64,
+    #[serde(default)]
     pub view: i64,
     pub repost: i64,
     pub comment: i64,
     pub like: i64,
+    #[serde(default)]
     pub is_liked: i64,
     pub dynamic_id: i64,
     pub timestamp: i64,
+    #[serde(default)]
     pub pre_dy_id: i64,
+    #[serde(default)]
     pub orig_dy_id: i64,
+    #[serde(default)]
     pub orig_type: i64,
     pub user_profile: UserProfile,
+    #[serde(default)]
     pub spec_type: i64,
+    #[serde(default)]
     pub uid_type: i64,
+    #[serde(default)]
     pub stype: i64,
+    #[serde(default)]
     pub r_type: i64,
+    #[serde(default)]
     pub inner_id: i64,
+    #[serde(default)]
     pub status: i64,
     pub dynamic_id_str: String,
+    #[serde(default)]
     pub pre_dy_id_str: String,
+    #[serde(default)]
     pub orig_dy_id_str: String,
+    #[serde(default)]
     pub rid_str: String,
+    #[serde(default)]
     pub bvid: String,
 }
 
@@ -161,18 +178,18 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_dynamic_get_card_detail() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.dynamic_card_detail("1099138163191840776").await;
         assert!(resp.is_ok());
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_dynamic_recent_up_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.dynamic_recent_up_list().await;
         assert!(resp.is_ok());
         if let Ok(res) = resp {
@@ -0,0 +1,216 @@
+//! 旧版动态（dynamic_svr）兼容
+//!
+//! 早期转发、互动抽奖等动态在新版 `x/polymer/web-dynamic` 接口中内容不完整，
+//! 旧版 `dynamic_svr` 接口仍能返回原始卡片。这里提供旧版空间动态历史的获取，
+//! 以及将 [`DynamicCard`] 转换为新版 [`DynamicItem`] 的方法，便于与新接口的数据统一存档。
+//!
+//! 转换只填充作者、统计与文字内容模块，原始卡片仍可通过 [`DynamicCard::card_json`] 读取。
+use serde::{ Deserialize, Serialize };
+
+use super::all::{ Basic, DynamicItem };
+use super::get_dynamic_detail::DynamicCard;
+use super::module::{ ModuleAuthor, ModuleStat, StatData };
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 旧版空间动态历史
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyHistoryData {
+    /// 是否还有更多，0 否 1 是
+    pub has_more: i32,
+    /// 动态卡片，没有动态时不存在
+    #[serde(default)]
+    pub cards: Option<Vec<DynamicCard>>,
+    /// 下一页的 `offset_dynamic_id`
+    #[serde(default)]
+    pub next_offset: i64,
+}
+
+impl LegacyHistoryData {
+    /// 转换为新版动态列表
+    pub fn into_items(self) -> Vec<DynamicItem> {
+        self.cards.unwrap_or_default().iter().map(DynamicCard::to_item).collect()
+    }
+}
+
+/// 旧版动态类型代码对应的新版类型名
+pub fn legacy_type_name(type_code: i64) -> &'static str {
+    match type_code {
+        1 => "DYNAMIC_TYPE_FORWARD",
+        2 => "DYNAMIC_TYPE_DRAW",
+        4 => "DYNAMIC_TYPE_WORD",
+        8 => "DYNAMIC_TYPE_AV",
+        64 => "DYNAMIC_TYPE_ARTICLE",
+        256 => "DYNAMIC_TYPE_MUSIC",
+        512 | 4097..=4101 => "DYNAMIC_TYPE_PGC",
+        2048 => "DYNAMIC_TYPE_COMMON_SQUARE",
+        4200 => "DYNAMIC_TYPE_LIVE",
+        4300 => "DYNAMIC_TYPE_MEDIALIST",
+        4302 => "DYNAMIC_TYPE_COURSES_SEASON",
+        4308 => "DYNAMIC_TYPE_LIVE_RCMD",
+        4310 => "DYNAMIC_TYPE_UGC_SEASON",
+        _ => "DYNAMIC_TYPE_NONE",
+    }
+}
+
+impl DynamicCard {
+    /// 解析 `card` 字段中的 JSON 字符串
+    pub fn card_json(&self) -> Result<serde_json::Value, BpiError> {
+        Ok(serde_json::from_str(&self.card)?)
+    }
+
+    /// 转换为新版动态
+    pub fn to_item(&self) -> DynamicItem {
+        let desc = &self.desc;
+        let card = self.card_json().unwrap_or_default();
+        let id_str = if desc.dynamic_id_str.is_empty() {
+            desc.dynamic_id.to_string()
+        } else {
+            desc.dynamic_id_str.clone()
+        };
+        let rid_str = if desc.rid_str.is_empty() { desc.rid.to_string() } else { desc.rid_str.clone() };
+
+        // 视频、图文、专栏、音频的评论区挂在稿件上，其余挂在动态本身
+        let (comment_type, comment_id_str) = match desc.type_field {
+            2 => (11, rid_str.clone()),
+            8 => (1, rid_str.clone()),
+            64 => (12, rid_str.clone()),
+            256 => (14, rid_str.clone()),
+            _ => (17, id_str.clone()),
+        };
+
+        let info = &desc.user_profile.info;
+        let author = ModuleAuthor {
+            face: info.face.clone(),
+            jump_url: format!("//space.bilibili.com/{}/dynamic", info.uid),
+            mid: info.uid,
+            name: info.uname.clone(),
+            pub_ts: desc.timestamp,
+            type_field: Some("AUTHOR_TYPE_NORMAL".to_string()),
+            ..Default::default()
+        };
+        let stat = ModuleStat {
+            comment: StatData { count: desc.comment, ..Default::default() },
+            forward: StatData { count: desc.repost, ..Default::default() },
+            like: StatData { count: desc.like, ..Default::default() },
+            ..Default::default()
+        };
+
+        // 转发与纯文字在 item.content，图文在 item.description，视频在 dynamic
+        let text = ["/item/content", "/item/description", "/dynamic"]
+            .iter()
+            .find_map(|pointer| card.pointer(pointer).and_then(|v| v.as_str()))
+            .unwrap_or_default();
+
+        DynamicItem {
+            basic: Basic {
+                comment_id_str,
+                comment_type,
+                like_icon: serde_json::Value::Null,
+                rid_str,
+                is_only_fans: None,
+                jump_url: Some(format!("//t.bilibili.com/{}", id_str)),
+            },
+            id_str,
+            modules: serde_json::json!({
+                "module_author": author,
+                "module_dynamic": {
+                    "desc": { "text": text, "rich_text_nodes": [] },
+                    "major": null,
+                },
+                "module_stat": stat,
+            }),
+            type_field: legacy_type_name(desc.type_field).to_string(),
+            visible: true,
+        }
+    }
+}
+
+impl BpiClient {
+    /// 获取用户空间动态历史（旧版接口）
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/dynamic)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `uid` | u64 | 用户 UID |
+    /// | `offset` | `Option<&str>` | 上一页返回的 `next_offset`，首页为 `None` |
+    pub async fn dynamic_history_legacy(
+        &self,
+        uid: u64,
+        offset: Option<&str>
+    ) -> Result<BpiResponse<LegacyHistoryData>, BpiError> {
+        self
+            .get("https://api.vc.bilibili.com/dynamic_svr/v1/dynamic_svr/space_history")
+            .query(
+                &[
+                    ("host_uid", uid.to_string()),
+                    ("offset_dynamic_id", offset.unwrap_or("0").to_string()),
+                    ("need_top", "0".to_string()),
+                    ("platform", "web".to_string()),
+                ]
+            )
+            .send_bpi("获取空间动态历史").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(type_code: i64, card: serde_json::Value) -> DynamicCard {
+        serde_json
+            ::from_value(
+                serde_json::json!({
+                    "desc": {
+                        "uid": 2, "type": type_code, "rid": 1234, "repost": 3, "comment": 4, "like": 5,
+                        "dynamic_id": 100000000000000001_i64, "timestamp": 1500000000,
+                        "dynamic_id_str": "100000000000000001",
+                        "user_profile": {
+                            "info": { "uid": 2, "uname": "碧诗", "face": "https://i0.hdslb.com/face.jpg" },
+                            "card": { "official_verify": { "type": -1 } },
+                            "vip": {
+                                "vipType": 0, "vipStatus": 0, "vipDueDate": 0, "nickname_color": "",
+                                "label": { "text": "", "label_theme": "", "text_color": "", "bg_style": 0, "bg_color": "" }
+                            },
+                            "pendant": { "pid": 0, "name": "", "image": "", "expire": 0 },
+                            "rank": "10000", "sign": "",
+                            "level_info": { "current_level": 6, "current_min": 0, "current_exp": 0, "next_exp": 0 }
+                        }
+                    },
+                    "card": card.to_string(),
+                    "display": {}
+                })
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_legacy_card_to_item() {
+        let item = card(2, serde_json::json!({ "item": { "description": "抽奖" } })).to_item();
+        assert_eq!(item.type_field, "DYNAMIC_TYPE_DRAW");
+        assert_eq!(item.id_str, "100000000000000001");
+        assert_eq!((item.basic.comment_type, item.basic.comment_id_str.as_str()), (11, "1234"));
+        assert_eq!(item.modules["module_dynamic"]["desc"]["text"], "抽奖");
+        assert_eq!(item.modules["module_stat"]["forward"]["count"], 3);
+        assert_eq!(item.published_at(-480).unwrap().timestamp(), 1_500_000_000);
+
+        let forward = card(1, serde_json::json!({ "item": { "content": "转发" } })).to_item();
+        assert_eq!(forward.type_field, "DYNAMIC_TYPE_FORWARD");
+        assert_eq!(forward.basic.comment_id_str, "100000000000000001");
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_dynamic_history_legacy() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi.dynamic_history_legacy(2, None).await?.into_data()?;
+        tracing::info!("旧版接口获取到 {} 条动态，next_offset: {}", data.cards.as_ref().map_or(0, Vec::len), data.next_offset);
+        for item in data.into_items() {
+            tracing::info!("{} {}", item.id_str, item.type_field);
+        }
+        Ok(())
+    }
+}
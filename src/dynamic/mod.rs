@@ -7,10 +7,12 @@ pub mod card_info;
 pub mod content;
 pub mod detail;
 pub mod dynamic_enum;
+pub mod feed;
 pub mod get_dynamic_detail;
+pub mod legacy;
 pub mod nav;
 pub mod publish;
 pub mod space;
 pub mod topic;
 
-mod module;
+pub mod module;
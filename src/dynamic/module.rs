@@ -1,8 +1,11 @@
 use crate::models::{ Pendant, VipLabel };
+use crate::utils::time::timestamp_to_datetime;
+use chrono::{ DateTime, FixedOffset };
 use serde::{ Deserialize, Serialize };
 
 /// 作者模块 √
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ModuleAuthor {
     /// 头像信息，主要用于网页渲染
     pub avatar: Option<serde_json::Value>,
@@ -44,6 +47,15 @@ pub struct ModuleAuthor {
     pub type_field: Option<String>,
 }
 
+impl ModuleAuthor {
+    /// 发布时间
+    ///
+    /// `timezone_offset` 为分钟偏移，含义同接口参数（UTC+8 为 `-480`）。
+    pub fn published_at(&self, timezone_offset: i32) -> Option<DateTime<FixedOffset>> {
+        timestamp_to_datetime(self.pub_ts, timezone_offset)
+    }
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct AuthorOfficial {
     /// 认证说明
@@ -88,8 +88,9 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_dynamic_nav_feed() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.dynamic_nav_feed(None, None).await?;
         let data = resp.into_data()?;
 
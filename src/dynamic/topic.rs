@@ -1 +1,209 @@
-// https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/dynamic/topic.md
+//! 话题
+//!
+//! 活动话题通过在动态中携带话题参与：发布动态时传入 [`DynamicTopic`]，
+//! 可由 [`TopicItem`] 转换得到；[`BpiClient::dynamic_topic_checkin`] 一步完成查询话题与发布打卡动态。
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/dynamic/topic.md)
+
+use serde::{ Deserialize, Serialize };
+
+use super::publish::{ CreateComplexDynamicData, DynamicContentItem, DynamicTopic };
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 话题
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TopicItem {
+    /// 话题 ID
+    pub id: u64,
+    /// 话题名
+    pub name: String,
+    /// 浏览数
+    pub view: u64,
+    /// 讨论数
+    pub discuss: u64,
+    /// 收藏数
+    pub fav: u64,
+    /// 动态数
+    pub dynamics: u64,
+    /// 话题页链接
+    pub jump_url: String,
+    /// 简介
+    pub description: String,
+}
+
+impl From<&TopicItem> for DynamicTopic {
+    fn from(item: &TopicItem) -> Self {
+        DynamicTopic {
+            id: item.id,
+            name: item.name.clone(),
+            from_source: None,
+            from_topic_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TopicTopDetails {
+    pub topic_item: TopicItem,
+    /// 是否已收藏
+    pub has_fav: bool,
+}
+
+/// 话题详情
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TopicDetailData {
+    pub top_details: TopicTopDetails,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TopicPageInfo {
+    pub page_num: u32,
+    pub page_size: u32,
+    pub has_more: bool,
+}
+
+/// 话题搜索结果
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TopicSearchData {
+    pub topic_items: Vec<TopicItem>,
+    pub page_info: TopicPageInfo,
+}
+
+impl BpiClient {
+    /// 获取话题详情
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `topic_id` | u64 | 话题 ID |
+    pub async fn dynamic_topic_info(
+        &self,
+        topic_id: u64
+    ) -> Result<BpiResponse<TopicDetailData>, BpiError> {
+        self
+            .get("https://app.bilibili.com/x/topic/web/details/top")
+            .query(&[("topic_id", topic_id)])
+            .send_bpi("获取话题详情").await
+    }
+
+    /// 搜索发布动态时可选的话题
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `keywords` | &str | 关键词 |
+    /// | `page_num` | `Option<u32>` | 页码，默认 1，每页 20 条 |
+    pub async fn dynamic_topic_search(
+        &self,
+        keywords: &str,
+        page_num: Option<u32>
+    ) -> Result<BpiResponse<TopicSearchData>, BpiError> {
+        self
+            .get("https://app.bilibili.com/x/topic/pub/search")
+            .query(
+                &[
+                    ("keywords", keywords.to_string()),
+                    ("page_size", "20".to_string()),
+                    ("page_num", page_num.unwrap_or(1).to_string()),
+                ]
+            )
+            .send_bpi("搜索话题").await
+    }
+
+    /// 收藏或取消收藏话题
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `topic_id` | u64 | 话题 ID |
+    /// | `fav` | bool | `true` 收藏，`false` 取消收藏 |
+    pub async fn dynamic_topic_fav(
+        &self,
+        topic_id: u64,
+        fav: bool
+    ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        let csrf = self.csrf()?;
+        let url = if fav {
+            "https://app.bilibili.com/x/topic/fav/sub/add"
+        } else {
+            "https://app.bilibili.com/x/topic/fav/sub/cancel"
+        };
+
+        self
+            .post(url)
+            .form(
+                &[
+                    ("topic_id", topic_id.to_string()),
+                    ("csrf", csrf),
+                ]
+            )
+            .send_bpi(if fav { "收藏话题" } else { "取消收藏话题" }).await
+    }
+
+    /// 话题打卡：发布一条携带该话题的纯文本动态
+    ///
+    /// 先查询话题以取得准确的话题名，话题不存在或已下线时返回对应的错误。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `topic_id` | u64 | 话题 ID |
+    /// | `text` | &str | 动态正文 |
+    pub async fn dynamic_topic_checkin(
+        &self,
+        topic_id: u64,
+        text: &str
+    ) -> Result<BpiResponse<CreateComplexDynamicData>, BpiError> {
+        let topic = self.dynamic_topic_info(topic_id).await?.into_data()?.top_details.topic_item;
+        let contents = vec![DynamicContentItem {
+            type_num: 1,
+            biz_id: None,
+            raw_text: text.to_string(),
+        }];
+
+        self.dynamic_create_complex(1, contents, None, Some(DynamicTopic::from(&topic))).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topic_to_dynamic_topic() {
+        let data: TopicDetailData = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "top_details": {
+                        "topic_item": { "id": 1028, "name": "每日打卡", "view": 10, "discuss": 2, "jump_url": "" },
+                        "has_fav": false
+                    }
+                })
+            )
+            .unwrap();
+
+        let topic = DynamicTopic::from(&data.top_details.topic_item);
+        assert_eq!((topic.id, topic.name.as_str()), (1028, "每日打卡"));
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_dynamic_topic_search() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi.dynamic_topic_search("打卡", None).await?.into_data()?;
+        tracing::info!("{:#?}", data);
+        if let Some(item) = data.topic_items.first() {
+            let detail = bpi.dynamic_topic_info(item.id).await?.into_data()?;
+            assert_eq!(detail.top_details.topic_item.id, item.id);
+        }
+        Ok(())
+    }
+}
@@ -74,11 +74,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_electric_bcoin_quick_pay_min() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi
             .electric_bcoin_quick_pay(2, true, 107997089, "up", 107997089)
             .await;
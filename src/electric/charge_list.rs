@@ -309,27 +309,29 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use chrono::{ Duration, Utc };
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_electric_month_up_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.electric_month_up_list(53456).await;
         assert!(resp.is_ok());
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_electric_video_show() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.electric_video_show(53456, None, Some("BV1Dh411S7sS")).await;
         assert!(resp.is_ok());
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_recharge_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 测试获取第一页，每页10条记录
         let resp = bpi.electric_recharge_list(1, 10, None, None).await;
         info!("响应: {:?}", resp);
@@ -346,8 +348,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_recharge_list_with_dates() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let now = Utc::now().date_naive();
         let start_date = now - Duration::days(30);
         let end_date = now;
@@ -362,8 +365,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_elec_rank_recent() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 测试获取第一页，每页10条记录
         let resp = bpi.electric_rank_recent(Some(1), Some(10)).await;
         info!("响应: {:?}", resp);
@@ -215,13 +215,13 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "write"]
     /// 未测试
     async fn test_send_elec_message() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         // 替换为有效的 order_id 和留言
         let resp = bpi.electric_message_send("ORDER_ID_HERE", "测试留言").await;
         info!("响应: {:?}", resp);
@@ -229,8 +229,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_elec_remark_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let resp = bpi.electric_remark_list(Some(1), Some(10), None, None).await;
         info!("响应: {:?}", resp);
         assert!(resp.is_ok());
@@ -243,8 +244,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_elec_remark_detail() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         // 替换为有效的留言id
         let resp = bpi.electric_remark_detail(6507563).await;
         info!("响应: {:?}", resp);
@@ -252,8 +254,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_reply_elec_remark() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         // 替换为有效的留言id和回复内容
         let resp = bpi.electric_remark_reply(6507563, "测试回复").await;
         info!("响应: {:?}", resp);
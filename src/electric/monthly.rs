@@ -426,12 +426,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_charge_record() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         // 获取自己使用中的包月充电列表
         let resp = bpi.electric_charge_record(1, 1).await;
         info!("响应: {:?}", resp);
@@ -447,8 +447,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_upower_item_detail() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 替换为有效的UP主mid
         let up_mid = 1265680561;
         let resp = bpi.electric_upower_item_detail(up_mid).await;
@@ -462,8 +463,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_charge_follow_info() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let up_mid = 293793435;
         let resp = bpi.electric_charge_follow_info(up_mid).await;
         info!("响应: {:?}", resp);
@@ -476,8 +478,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_upower_member_rank() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         // 替换为有效的UP主mid
         let up_mid = 1265680561;
         // 获取所有档位的用户排名
@@ -79,6 +79,7 @@ define_errors! {
         (-688, "地理区域限制"),
         (-689, "版权限制"),
         (-701, "扣节操失败"),
+        (-10403, "抱歉您所在地区不可观看"),
     ],
 
     Request: [
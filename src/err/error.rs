@@ -58,6 +58,12 @@ pub enum BpiError {
         field: &'static str,
         message: &'static str,
     },
+
+    /// 本地文件读写失败
+    #[error("IO错误: {message}")]
+    Io {
+        message: String,
+    },
 }
 
 impl BpiError {
@@ -137,6 +143,7 @@ impl BpiError {
             BpiError::Parse { .. } => ErrorCategory::Request,
             BpiError::InvalidParameter { .. } => ErrorCategory::Request,
             BpiError::Authentication { .. } => ErrorCategory::Auth,
+            BpiError::Io { .. } => ErrorCategory::Unknown,
         }
     }
 }
@@ -162,6 +169,18 @@ impl BpiError {
         }
     }
 
+    /// 创建本地 IO 错误
+    pub fn io(message: impl Into<String>) -> Self {
+        BpiError::Io {
+            message: message.into(),
+        }
+    }
+
+    /// 对 `path` 执行 `action`（如“写入”）失败时的 IO 错误
+    pub(crate) fn io_at(action: &str, path: &std::path::Path, e: impl std::fmt::Display) -> Self {
+        Self::io(format!("{} {} 失败: {}", action, path.display(), e))
+    }
+
     /// 创建参数错误
     pub fn invalid_parameter(field: &'static str, message: &'static str) -> Self {
         BpiError::InvalidParameter { field, message }
@@ -194,6 +213,11 @@ impl BpiError {
         matches!(self.code(), Some(-106) | Some(-650))
     }
 
+    /// 判断是否为地区限制
+    pub fn is_area_restricted(&self) -> bool {
+        matches!(self.code(), Some(-10403) | Some(-688))
+    }
+
     /// 判断是否为业务逻辑错误
     pub fn is_business_error(&self) -> bool {
         matches!(self.category(), ErrorCategory::Business)
@@ -26,6 +26,13 @@ impl<T> From<crate::response::BpiResponse<T>> for BpiError {
     }
 }
 
+// 从IO错误转换
+impl From<std::io::Error> for BpiError {
+    fn from(err: std::io::Error) -> Self {
+        BpiError::io(err.to_string())
+    }
+}
+
 // 从JSON序列化错误转换
 impl From<serde_json::Error> for BpiError {
     fn from(err: serde_json::Error) -> Self {
@@ -1,4 +1,5 @@
 use super::info::FavFolderInfo;
+use crate::utils::query::Query;
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 
 impl BpiClient {
@@ -115,6 +116,54 @@ impl BpiClient {
             .send_bpi("删除收藏夹").await
     }
 
+    /// 收藏或取消收藏内容
+    ///
+    /// 同一内容可同时加入和移出多个收藏夹。
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/fav)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `rid` | u64 | 内容 id，视频为 avid |
+    /// | `typ` | u8 | 内容类型：2 视频，12 音频，21 视频合集 |
+    /// | `add_media_ids` | &[u64] | 要加入的收藏夹 media_id |
+    /// | `del_media_ids` | &[u64] | 要移出的收藏夹 media_id |
+    pub async fn fav_resource_deal(
+        &self,
+        rid: u64,
+        typ: u8,
+        add_media_ids: &[u64],
+        del_media_ids: &[u64]
+    ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        if add_media_ids.is_empty() && del_media_ids.is_empty() {
+            return Err(BpiError::invalid_parameter("media_ids", "请至少指定一个收藏夹"));
+        }
+        let csrf = self.csrf()?;
+
+        let join = |ids: &[u64]| {
+            ids.iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let form = Query::new()
+            .with("rid", rid)
+            .with("type", typ)
+            .with("add_media_ids", join(add_media_ids))
+            .with("del_media_ids", join(del_media_ids))
+            .with("platform", "web")
+            .with("csrf", csrf);
+
+        self
+            .post("https://api.bilibili.com/x/v3/fav/resource/deal")
+            .with_bilibili_headers()
+            .form(&form)
+            .send_bpi("收藏内容").await
+    }
+
     /// 批量复制内容
     /// `resources`: "{内容id}:{内容类型},..."
     ///
@@ -254,8 +303,9 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_fav_folder_add_and_del() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let title = "Test_Fav_Folder_Add2";
         let intro = "This is a test folder2.";
 
@@ -276,8 +326,23 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
+    async fn test_fav_resource_deal() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Write);
+        let media_id = 3717139570;
+
+        bpi.fav_resource_deal(10001, 2, &[media_id], &[]).await?;
+        tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+        bpi.fav_resource_deal(10001, 2, &[], &[media_id]).await?;
+
+        assert!(bpi.fav_resource_deal(10001, 2, &[], &[]).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "write"]
     async fn test_fav_folder_edit() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         // 替换为你的测试收藏夹ID
         let media_id = 3717139570;
         let title = "Edited Title";
@@ -289,8 +354,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_fav_resource_operations() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         // 替换为你的源/目标收藏夹ID和资源ID
         let src_media_id = 3717139570;
         let tar_media_id = 3641682570;
@@ -326,8 +392,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_fav_resource_clean() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         // 替换为你的测试收藏夹ID
         let media_id = 3717139570;
         let resp = bpi.fav_resource_clean(media_id).await;
@@ -0,0 +1,473 @@
+//! 收藏夹导出与导入
+//!
+//! 将收藏夹内容导出为固定格式的 JSON 或 CSV，用于备份或迁移到其他账号；
+//! 导入时跳过已失效和目标收藏夹中已存在的稿件。
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{ Deserialize, Serialize };
+
+use super::list::FavListMedia;
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 导出格式版本，字段变动时递增
+pub const FAV_EXPORT_VERSION: u32 = 1;
+
+/// 两次收藏请求之间的间隔，避免触发风控
+const IMPORT_INTERVAL: Duration = Duration::from_millis(800);
+
+/// CSV 表头，列顺序固定
+const CSV_HEADER: [&str; 9] = [
+    "bvid",
+    "aid",
+    "type",
+    "title",
+    "upper_mid",
+    "upper_name",
+    "fav_time",
+    "intro",
+    "invalid",
+];
+
+/// 导出文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FavExportFormat {
+    Json,
+    Csv,
+}
+
+impl FavExportFormat {
+    /// 根据文件扩展名判断格式，`.csv` 为 CSV，其余视为 JSON
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => Self::Csv,
+            _ => Self::Json,
+        }
+    }
+}
+
+/// 导出的单个收藏内容
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FavExportItem {
+    /// 稿件 bvid，非视频内容为空
+    #[serde(default)]
+    pub bvid: String,
+    /// 内容 id，视频为 avid
+    pub aid: u64,
+    /// 内容类型 2: 视频稿件 12: 音频 21: 视频合集
+    #[serde(rename = "type")]
+    pub type_name: u8,
+    pub title: String,
+    /// UP 主 mid
+    pub upper_mid: u64,
+    /// UP 主昵称
+    pub upper_name: String,
+    /// 收藏时间戳
+    pub fav_time: u64,
+    /// 简介
+    #[serde(default)]
+    pub intro: String,
+    /// 导出时是否已失效
+    #[serde(default)]
+    pub invalid: bool,
+}
+
+impl From<&FavListMedia> for FavExportItem {
+    fn from(media: &FavListMedia) -> Self {
+        Self {
+            bvid: media.bvid.clone().or_else(|| media.bv_id.clone()).unwrap_or_default(),
+            aid: media.id,
+            type_name: media.type_name,
+            title: media.title.clone(),
+            upper_mid: media.upper.mid,
+            upper_name: media.upper.name.clone(),
+            fav_time: media.fav_time,
+            intro: media.intro.clone(),
+            // 0: 正常 1: 其他原因删除 9: UP 主自己删除
+            invalid: media.attr & 1 != 0,
+        }
+    }
+}
+
+/// 收藏夹导出内容
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FavExport {
+    /// 导出格式版本
+    pub version: u32,
+    /// 源收藏夹 media_id
+    pub media_id: u64,
+    /// 源收藏夹标题
+    pub title: String,
+    /// 导出时间戳
+    pub exported_at: u64,
+    pub items: Vec<FavExportItem>,
+}
+
+impl FavExport {
+    /// 序列化为 JSON
+    pub fn to_json(&self) -> Result<String, BpiError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// 从 JSON 解析
+    pub fn from_json(s: &str) -> Result<Self, BpiError> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// 序列化为 CSV，仅包含内容列表
+    pub fn to_csv(&self) -> String {
+        let mut out = CSV_HEADER.join(",");
+        out.push('\n');
+
+        for item in &self.items {
+            let fields = [
+                item.bvid.clone(),
+                item.aid.to_string(),
+                item.type_name.to_string(),
+                item.title.clone(),
+                item.upper_mid.to_string(),
+                item.upper_name.clone(),
+                item.fav_time.to_string(),
+                item.intro.clone(),
+                item.invalid.to_string(),
+            ];
+            let line = fields
+                .iter()
+                .map(|field| csv_escape(field))
+                .collect::<Vec<_>>()
+                .join(",");
+            out.push_str(&line);
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// 从 CSV 解析，收藏夹元数据不在 CSV 中，`media_id` 与 `title` 为空
+    pub fn from_csv(s: &str) -> Result<Self, BpiError> {
+        let mut rows = csv_parse(s)?.into_iter();
+
+        match rows.next() {
+            Some(header) if header == CSV_HEADER => {}
+            _ => {
+                return Err(BpiError::parse("CSV 表头不匹配"));
+            }
+        }
+
+        let items = rows
+            .filter(|row| !(row.len() == 1 && row[0].is_empty()))
+            .map(|row| csv_item(&row))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            version: FAV_EXPORT_VERSION,
+            media_id: 0,
+            title: String::new(),
+            exported_at: 0,
+            items,
+        })
+    }
+
+    /// 写入文件
+    pub fn write_to(&self, path: impl AsRef<Path>, format: FavExportFormat) -> Result<(), BpiError> {
+        let content = match format {
+            FavExportFormat::Json => self.to_json()?,
+            FavExportFormat::Csv => self.to_csv(),
+        };
+        std::fs
+            ::write(path, content)
+            .map_err(|e| BpiError::parse(format!("写入导出文件失败: {}", e)))
+    }
+
+    /// 读取文件，格式由扩展名决定
+    pub fn read_from(path: impl AsRef<Path>) -> Result<Self, BpiError> {
+        let path = path.as_ref();
+        let content = std::fs
+            ::read_to_string(path)
+            .map_err(|e| BpiError::parse(format!("读取导出文件失败: {}", e)))?;
+
+        match FavExportFormat::from_path(path) {
+            FavExportFormat::Json => Self::from_json(&content),
+            FavExportFormat::Csv => Self::from_csv(&content),
+        }
+    }
+}
+
+/// 导入结果
+#[derive(Debug, Default, Serialize)]
+pub struct FavImportReport {
+    /// 成功加入的内容 id
+    pub added: Vec<u64>,
+    /// 已失效、非视频或目标收藏夹中已存在而跳过的内容 id
+    pub skipped: Vec<u64>,
+    /// 加入失败的内容 id 及错误
+    pub failed: Vec<(u64, BpiError)>,
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// 解析 CSV，支持引号包裹的字段内出现逗号、换行与转义引号
+fn csv_parse(s: &str) -> Result<Vec<Vec<String>>, BpiError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => {
+                    quoted = false;
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => {
+                quoted = true;
+            }
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(BpiError::parse("CSV 引号未闭合"));
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+fn csv_item(row: &[String]) -> Result<FavExportItem, BpiError> {
+    if row.len() != CSV_HEADER.len() {
+        return Err(BpiError::parse(format!("CSV 列数错误: {}", row.len())));
+    }
+
+    fn num<T: std::str::FromStr>(field: &str, name: &str) -> Result<T, BpiError> {
+        field.parse().map_err(|_| BpiError::parse(format!("CSV 字段 {} 无效: {}", name, field)))
+    }
+
+    Ok(FavExportItem {
+        bvid: row[0].clone(),
+        aid: num(&row[1], "aid")?,
+        type_name: num(&row[2], "type")?,
+        title: row[3].clone(),
+        upper_mid: num(&row[4], "upper_mid")?,
+        upper_name: row[5].clone(),
+        fav_time: num(&row[6], "fav_time")?,
+        intro: row[7].clone(),
+        invalid: num(&row[8], "invalid")?,
+    })
+}
+
+impl BpiClient {
+    /// 导出收藏夹全部内容
+    ///
+    /// 结果可通过 [`FavExport::write_to`] 保存为 JSON 或 CSV。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `media_id` | u64 | 收藏夹 media_id |
+    pub async fn fav_export(&self, media_id: u64) -> Result<FavExport, BpiError> {
+        let mut export = FavExport {
+            version: FAV_EXPORT_VERSION,
+            media_id,
+            title: String::new(),
+            exported_at: chrono::Utc::now().timestamp() as u64,
+            items: Vec::new(),
+        };
+
+        for pn in 1.. {
+            let data = self
+                .fav_list_detail(media_id, None, None, Some("mtime"), None, 20, Some(pn)).await?
+                .into_data()?;
+
+            if pn == 1 {
+                export.title = data.info.title.clone();
+            }
+            export.items.extend(data.medias.iter().map(FavExportItem::from));
+
+            if !data.has_more || data.medias.is_empty() {
+                break;
+            }
+        }
+
+        tracing::info!("收藏夹 {} 导出 {} 条", media_id, export.items.len());
+        Ok(export)
+    }
+
+    /// 从导出文件将仍有效的视频重新加入收藏夹
+    ///
+    /// 已失效、非视频稿件以及目标收藏夹中已存在的内容会被跳过。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `file` | `impl AsRef<Path>` | 导出文件，`.csv` 按 CSV 解析，其余按 JSON |
+    /// | `target_folder` | u64 | 目标收藏夹 media_id |
+    pub async fn fav_import(
+        &self,
+        file: impl AsRef<Path>,
+        target_folder: u64
+    ) -> Result<FavImportReport, BpiError> {
+        let export = FavExport::read_from(file)?;
+        let existing: Vec<u64> = self
+            .fav_resource_ids(target_folder).await?
+            .into_data()?
+            .into_iter()
+            .filter(|item| item.type_name == 2)
+            .map(|item| item.id)
+            .collect();
+
+        let mut report = FavImportReport::default();
+
+        // 按收藏时间先后加入，使目标收藏夹的顺序与原收藏夹一致
+        let mut items = export.items;
+        items.sort_by_key(|item| item.fav_time);
+
+        for item in items {
+            if item.invalid || item.type_name != 2 || existing.contains(&item.aid) {
+                report.skipped.push(item.aid);
+                continue;
+            }
+
+            match self.fav_import_resource(item.aid, target_folder).await {
+                Ok(_) => report.added.push(item.aid),
+                Err(e) => {
+                    tracing::warn!("稿件 av{} 导入收藏夹失败: {}", item.aid, e);
+                    report.failed.push((item.aid, e));
+                }
+            }
+            tokio::time::sleep(IMPORT_INTERVAL).await;
+        }
+
+        tracing::info!(
+            "收藏夹导入完成: 成功 {} 条, 跳过 {} 条, 失败 {} 条",
+            report.added.len(),
+            report.skipped.len(),
+            report.failed.len()
+        );
+
+        Ok(report)
+    }
+
+    async fn fav_import_resource(
+        &self,
+        aid: u64,
+        media_id: u64
+    ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        let csrf = self.csrf()?;
+
+        let form = [
+            ("rid", aid.to_string()),
+            ("type", "2".to_string()),
+            ("add_media_ids", media_id.to_string()),
+            ("csrf", csrf),
+        ];
+
+        self
+            .post("https://api.bilibili.com/x/v3/fav/resource/deal")
+            .with_bilibili_headers()
+            .form(&form)
+            .send_bpi("导入收藏夹").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> FavExport {
+        FavExport {
+            version: FAV_EXPORT_VERSION,
+            media_id: 1,
+            title: "默认收藏夹".to_string(),
+            exported_at: 0,
+            items: vec![
+                FavExportItem {
+                    bvid: "BV1xx411c7mD".to_string(),
+                    aid: 2,
+                    type_name: 2,
+                    title: "标题, 带 \"引号\"".to_string(),
+                    upper_mid: 1,
+                    upper_name: "UP".to_string(),
+                    fav_time: 1700000000,
+                    intro: "第一行\r\n第二行".to_string(),
+                    invalid: false,
+                },
+                FavExportItem {
+                    bvid: String::new(),
+                    aid: 3,
+                    type_name: 2,
+                    title: "已失效视频".to_string(),
+                    upper_mid: 0,
+                    upper_name: String::new(),
+                    fav_time: 1600000000,
+                    intro: String::new(),
+                    invalid: true,
+                }
+            ],
+        }
+    }
+
+    #[test]
+    fn test_fav_export_csv_roundtrip() {
+        let export = sample();
+        let parsed = FavExport::from_csv(&export.to_csv()).unwrap();
+        assert_eq!(parsed.items, export.items);
+
+        assert!(FavExport::from_csv("aid,title\n1,x\n").is_err());
+        assert!(FavExport::from_csv(&format!("{}\n\"未闭合", CSV_HEADER.join(","))).is_err());
+    }
+
+    #[test]
+    fn test_fav_export_json_roundtrip() {
+        let export = sample();
+        let parsed = FavExport::from_json(&export.to_json().unwrap()).unwrap();
+        assert_eq!(parsed.items, export.items);
+        assert_eq!(parsed.title, export.title);
+    }
+
+    #[test]
+    fn test_fav_export_format_from_path() {
+        assert_eq!(FavExportFormat::from_path(Path::new("fav.CSV")), FavExportFormat::Csv);
+        assert_eq!(FavExportFormat::from_path(Path::new("fav.json")), FavExportFormat::Json);
+        assert_eq!(FavExportFormat::from_path(Path::new("fav")), FavExportFormat::Json);
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_fav_export() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let export = bpi.fav_export(1572769770).await?;
+        tracing::info!("{}: {} 条", export.title, export.items.len());
+        assert!(!export.items.is_empty());
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use crate::utils::web_location::WebLocation;
 use serde::{ Deserialize, Serialize };
 
 // --- 获取收藏夹元数据 ---
@@ -62,6 +63,9 @@ pub struct CreatedFolderItem {
     pub media_count: u32,
 }
 
+/// 收藏夹，即 [`CreatedFolderItem`]
+pub type FavFolder = CreatedFolderItem;
+
 /// 用户创建的收藏夹信息数据
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CreatedFolderListData {
@@ -197,7 +201,7 @@ impl BpiClient {
         }
 
         request
-            .query(&[("web_location", "333.1387")])
+            .query(&[("web_location", self.web_location(WebLocation::SPACE))])
             .send_bpi("获取指定用户创建的所有收藏夹信息").await
     }
 
@@ -261,12 +265,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_fav_folder_info() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 替换为一个公开收藏夹的media_id
         let media_id = 3717139570;
         let resp = bpi.fav_folder_info(media_id).await;
@@ -284,8 +288,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_fav_created_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let up_mid = 4279370;
         let resp = bpi.fav_created_list(up_mid, None, None).await;
@@ -302,8 +307,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_fav_collected_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let up_mid = 4279370;
         let resp = bpi.fav_collected_list(up_mid, 1, 20).await;
@@ -320,8 +326,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_fav_resource_infos() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resources = "115087859779103:2";
         let resp = bpi.fav_resource_infos(resources).await;
 
@@ -1,4 +1,6 @@
+use crate::pagination::{ Paginated, paginate };
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use futures_util::{ Stream, TryStreamExt };
 use serde::{ Deserialize, Serialize };
 
 // --- 获取收藏夹内容明细列表 ---
@@ -78,6 +80,25 @@ pub struct FavListMedia {
     pub season: Option<serde_json::Value>,
 }
 
+/// 收藏夹中的内容，即 [`FavListMedia`]
+pub type FavMedia = FavListMedia;
+
+impl FavListMedia {
+    /// 批量复制、移动、删除接口使用的 `{内容id}:{内容类型}`
+    pub fn resource(&self) -> String {
+        format!("{}:{}", self.id, self.type_name)
+    }
+}
+
+/// 拼接多个内容为 `resources` 参数
+pub fn fav_resources<'a>(medias: impl IntoIterator<Item = &'a FavListMedia>) -> String {
+    medias
+        .into_iter()
+        .map(FavListMedia::resource)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 /// 收藏夹内容明细列表数据
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct FavListDetailData {
@@ -153,6 +174,33 @@ impl BpiClient {
         request.send_bpi("获取收藏夹内容明细列表").await
     }
 
+    /// 按收藏时间逐页输出收藏夹内容
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `media_id` | u64 | 收藏夹 media_id |
+    pub fn fav_list_stream(&self, media_id: u64) -> impl Stream<Item = Result<FavMedia, BpiError>> + '_ {
+        paginate(1u32, move |pn| async move {
+            let data = self
+                .fav_list_detail(media_id, None, None, Some("mtime"), None, 20, Some(pn)).await?
+                .into_data()?;
+            Ok(Paginated::new(data.medias, data.has_more.then_some(pn + 1)))
+        })
+    }
+
+    /// 逐页获取收藏夹全部内容
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `media_id` | u64 | 收藏夹 media_id |
+    pub async fn fav_list_all(&self, media_id: u64) -> Result<Vec<FavMedia>, BpiError> {
+        self.fav_list_stream(media_id).try_collect().await
+    }
+
     /// 获取收藏夹全部内容id
     ///
     /// # 文档
@@ -184,9 +232,28 @@ mod tests {
     use super::*;
     use tracing::info;
 
+    #[test]
+    fn test_fav_resources() {
+        let media = |id: u64, typ: u8| -> FavMedia {
+            serde_json
+                ::from_value(
+                    serde_json::json!({
+                        "id": id, "type": typ, "title": "", "cover": "", "intro": "", "page": 1,
+                        "duration": 60, "upper": { "mid": 2, "name": "碧诗", "face": "" }, "attr": 0,
+                        "cnt_info": { "collect": 0, "play": 0 }, "link": "", "ctime": 0, "pubtime": 0,
+                        "fav_time": 0, "bv_id": null, "bvid": null, "season": null
+                    })
+                )
+                .unwrap()
+        };
+        let medias = [media(10001, 2), media(233, 12)];
+        assert_eq!(fav_resources(&medias), "10001:2,233:12");
+    }
+
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_fav_list_detail() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let media_id = 1572769770;
         let resp = bpi.fav_list_detail(
             media_id,
@@ -212,8 +279,18 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_fav_list_all() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let medias = bpi.fav_list_all(1572769770).await?;
+        info!("共 {} 条内容", medias.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_fav_resource_ids() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let media_id = 1572769770;
         let resp = bpi.fav_resource_ids(media_id).await;
 
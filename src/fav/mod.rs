@@ -1,5 +1,6 @@
 //! 收藏夹
 
 pub mod action;
+pub mod export;
 pub mod info;
 pub mod list;
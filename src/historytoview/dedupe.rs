@@ -0,0 +1,253 @@
+//! 稍后再看整理
+//!
+//! 列出稍后再看队列，结合历史记录找出重复条目与已看完的稿件并移除。
+
+use std::collections::{ HashMap, HashSet };
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::toview::ToViewVideoItem;
+use crate::{ BpiClient, BpiError };
+
+/// 整理选项
+#[derive(Debug, Clone)]
+pub struct ToViewDedupeOptions {
+    /// 仅生成报告不修改
+    pub dry_run: bool,
+    /// 是否移除已看完的稿件
+    pub remove_watched: bool,
+    /// 观看进度达到时长的该比例即视为看完
+    pub watched_ratio: f64,
+    /// 最多扫描的历史记录页数
+    pub history_pages: usize,
+    /// 两次修改请求之间的间隔，避免触发风控
+    pub interval: Duration,
+}
+
+impl Default for ToViewDedupeOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            remove_watched: true,
+            watched_ratio: 0.95,
+            history_pages: 10,
+            interval: Duration::from_millis(500),
+        }
+    }
+}
+
+/// 整理结果
+#[derive(Debug, Default, Serialize)]
+pub struct ToViewDedupeReport {
+    /// 重复出现的稿件 avid（保留一份）
+    pub duplicates: Vec<u64>,
+    /// 已看完的稿件
+    pub watched: Vec<ToViewVideoItem>,
+    /// 已移除的稿件 avid
+    pub removed: Vec<u64>,
+    /// 操作失败的稿件 avid 及错误
+    pub failed: Vec<(u64, BpiError)>,
+}
+
+/// 进度是否视为看完，`-1` 表示已看完
+fn is_watched(progress: i64, duration: u32, ratio: f64) -> bool {
+    progress == -1 || (duration > 0 && (progress as f64) >= (duration as f64) * ratio)
+}
+
+/// 根据队列与历史进度计算重复与已看完的稿件
+fn plan(
+    items: &[ToViewVideoItem],
+    history: &HashMap<u64, i64>,
+    options: &ToViewDedupeOptions
+) -> (Vec<u64>, Vec<ToViewVideoItem>) {
+    let mut seen = HashSet::new();
+    let mut duplicates = Vec::new();
+    let mut watched = Vec::new();
+
+    for item in items {
+        if !seen.insert(item.aid) {
+            if !duplicates.contains(&item.aid) {
+                duplicates.push(item.aid);
+            }
+            continue;
+        }
+
+        if !options.remove_watched {
+            continue;
+        }
+        let progress = history.get(&item.aid).copied().unwrap_or(item.progress as i64);
+        if is_watched(progress, item.duration, options.watched_ratio) {
+            watched.push(item.clone());
+        }
+    }
+
+    (duplicates, watched)
+}
+
+impl BpiClient {
+    /// 整理稍后再看：去除重复条目，并移除历史记录中已看完的稿件
+    ///
+    /// 重复条目通过删除后重新添加一次来只保留一份，重新添加的稿件会移到队列最前。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `options` | `ToViewDedupeOptions` | 整理选项 |
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+    /// use bpi_rs::historytoview::dedupe::ToViewDedupeOptions;
+    ///
+    /// let report = bpi.dedupe_toview(ToViewDedupeOptions { dry_run: true, ..Default::default() }).await?;
+    /// println!("重复 {} 个，已看完 {} 个", report.duplicates.len(), report.watched.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dedupe_toview(
+        &self,
+        options: ToViewDedupeOptions
+    ) -> Result<ToViewDedupeReport, BpiError> {
+        let items = self.toview_list().await?.into_data()?.list;
+
+        let history = if options.remove_watched {
+            self.history_progress(&items, options.history_pages).await?
+        } else {
+            HashMap::new()
+        };
+
+        let (duplicates, watched) = plan(&items, &history, &options);
+        let mut report = ToViewDedupeReport {
+            duplicates,
+            watched,
+            ..Default::default()
+        };
+
+        if options.dry_run {
+            return Ok(report);
+        }
+
+        let watched: Vec<u64> = report.watched
+            .iter()
+            .map(|item| item.aid)
+            .collect();
+        for &aid in report.duplicates.iter().chain(&watched) {
+            let mut result = self.toview_delete(Some(aid), None).await.map(|_| ());
+            if result.is_ok() && !watched.contains(&aid) {
+                tokio::time::sleep(options.interval).await;
+                result = self.toview_add_video(Some(aid), None).await.map(|_| ());
+            }
+
+            match result {
+                Ok(()) => report.removed.push(aid),
+                Err(e) => {
+                    tracing::warn!("整理稍后再看 av{} 失败: {}", aid, e);
+                    report.failed.push((aid, e));
+                }
+            }
+            tokio::time::sleep(options.interval).await;
+        }
+
+        tracing::info!(
+            "稍后再看整理完成: 重复 {} 个, 已看完 {} 个, 失败 {} 个",
+            report.duplicates.len(),
+            report.watched.len(),
+            report.failed.len()
+        );
+
+        Ok(report)
+    }
+
+    /// 扫描历史记录，返回队列中稿件的观看进度
+    async fn history_progress(
+        &self,
+        items: &[ToViewVideoItem],
+        max_pages: usize
+    ) -> Result<HashMap<u64, i64>, BpiError> {
+        let wanted: HashSet<u64> = items
+            .iter()
+            .map(|item| item.aid)
+            .collect();
+        let mut progress = HashMap::new();
+        let mut max = None;
+        let mut view_at = None;
+
+        for _ in 0..max_pages {
+            let data = self
+                .history_list(max, Some("archive"), view_at, Some("archive"), Some(30)).await?
+                .into_data()?;
+
+            for item in &data.list {
+                if item.history.business == "archive" && wanted.contains(&item.history.oid) {
+                    progress.entry(item.history.oid).or_insert(item.progress as i64);
+                }
+            }
+
+            if data.list.is_empty() || data.cursor.max == 0 || progress.len() == wanted.len() {
+                break;
+            }
+            max = Some(data.cursor.max);
+            view_at = Some(data.cursor.view_at);
+        }
+
+        Ok(progress)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(aid: u64, duration: u32, progress: u32) -> ToViewVideoItem {
+        serde_json::from_value(
+            serde_json::json!({
+                "aid": aid, "videos": 1, "tid": 0, "tname": "", "copyright": 1, "pic": "",
+                "title": "", "pubdate": 0, "ctime": 0, "desc": "", "state": 0, "attribute": null,
+                "duration": duration,
+                "rights": {
+                    "bp": 0, "elec": 0, "download": 0, "movie": 0, "pay": 0, "hd5": 0,
+                    "no_reprint": 0, "autoplay": 0, "ugc_pay": 0, "is_cooperation": 0,
+                    "ugc_pay_preview": 0, "no_background": 0
+                },
+                "owner": { "mid": 0, "name": "", "face": "" },
+                "stat": {
+                    "aid": aid, "view": 0, "danmaku": 0, "reply": 0, "favorite": 0, "coin": 0,
+                    "share": 0, "now_rank": 0, "his_rank": 0, "like": 0, "dislike": 0, "vt": 0, "vv": 0
+                },
+                "dimension": { "width": 0, "height": 0, "rotate": 0 },
+                "cid": 0, "progress": progress, "add_at": 0, "bvid": ""
+            })
+        ).unwrap()
+    }
+
+    #[test]
+    fn test_plan_dedupe() {
+        let items = vec![item(1, 100, 0), item(2, 100, 0), item(1, 100, 0), item(3, 100, 0)];
+        let history = HashMap::from([(2, 98), (3, -1)]);
+
+        let (duplicates, watched) = plan(&items, &history, &ToViewDedupeOptions::default());
+        assert_eq!(duplicates, vec![1]);
+        let watched: Vec<u64> = watched
+            .iter()
+            .map(|i| i.aid)
+            .collect();
+        assert_eq!(watched, vec![2, 3]);
+
+        let options = ToViewDedupeOptions { remove_watched: false, ..Default::default() };
+        let (_, watched) = plan(&items, &history, &options);
+        assert!(watched.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_dedupe_toview_dry_run() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Authed);
+        let report = bpi.dedupe_toview(ToViewDedupeOptions { dry_run: true, ..Default::default() }).await?;
+        tracing::info!("{:?}", report);
+        assert!(report.removed.is_empty());
+        Ok(())
+    }
+}
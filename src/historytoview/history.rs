@@ -1,4 +1,6 @@
+use crate::pagination::{ Paginated, paginate };
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use futures_util::Stream;
 use serde::{ Deserialize, Serialize };
 
 /// 历史记录列表的页面信息
@@ -104,6 +106,20 @@ pub struct HistoryListItem {
     pub live_status: Option<u8>,
 }
 
+impl HistoryListItem {
+    /// 删除该条记录时使用的 kid，格式为 `{business}_{kid}`
+    pub fn delete_kid(&self) -> String {
+        format!("{}_{}", self.history.business, self.kid)
+    }
+}
+
+impl HistoryCursor {
+    /// 是否已到最后一页
+    pub fn is_end(&self) -> bool {
+        self.max == 0
+    }
+}
+
 /// 历史记录列表数据
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryListData {
@@ -159,6 +175,36 @@ impl BpiClient {
         request.send_bpi("获取历史记录列表").await
     }
 
+    /// 从最近的记录开始逐页输出历史记录
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `typ` | `Option<&str>` | 分类筛选：all/archive/live/article 等 |
+    pub fn history_stream(
+        &self,
+        typ: Option<&str>
+    ) -> impl Stream<Item = Result<HistoryListItem, BpiError>> + '_ {
+        let typ = typ.map(str::to_string);
+        paginate(None::<HistoryCursor>, move |cursor| {
+            let typ = typ.clone();
+            async move {
+                let data = self
+                    .history_list(
+                        cursor.as_ref().map(|c| c.max),
+                        cursor.as_ref().map(|c| c.business.as_str()),
+                        cursor.as_ref().map(|c| c.view_at),
+                        typ.as_deref(),
+                        Some(20)
+                    ).await?
+                    .into_data()?;
+                let next = (!data.cursor.is_end()).then_some(Some(data.cursor));
+                Ok(Paginated::new(data.list, next))
+            }
+        })
+    }
+
     /// 删除历史记录
     ///
     /// # 文档
@@ -168,7 +214,7 @@ impl BpiClient {
     ///
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
-    /// | `kid` | &str | 记录目标 id |
+    /// | `kid` | &str | 记录目标 id，格式为 `{business}_{oid}`，见 [`HistoryListItem::delete_kid`] |
     pub async fn history_delete(
         &self,
         kid: &str
@@ -228,6 +274,39 @@ impl BpiClient {
             .send_bpi("停用历史记录").await
     }
 
+    /// 上报视频观看进度，同步到历史记录
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/historytoview)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `aid` | u64 | 稿件 avid |
+    /// | `cid` | u64 | 视频 cid |
+    /// | `progress` | u64 | 观看进度，单位为秒 |
+    pub async fn history_report(
+        &self,
+        aid: u64,
+        cid: u64,
+        progress: u64
+    ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        let csrf = self.csrf()?;
+
+        let payload = [
+            ("aid", aid.to_string()),
+            ("cid", cid.to_string()),
+            ("progress", progress.to_string()),
+            ("csrf", csrf),
+        ];
+
+        self
+            .post("https://api.bilibili.com/x/v2/history/report")
+            .form(&payload)
+            .send_bpi("上报观看进度").await
+    }
+
     /// 查询历史记录停用状态
     ///
     /// # 文档
@@ -244,9 +323,43 @@ mod tests {
     use super::*;
     use tracing::info;
 
+    #[test]
+    fn test_delete_kid() {
+        let item: HistoryListItem = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "title": "测试", "history": { "oid": 10001, "business": "archive", "dt": 2 },
+                    "view_at": 1700000000, "progress": 30, "is_fav": 0, "kid": 10001
+                })
+            )
+            .unwrap();
+        assert_eq!(item.delete_kid(), "archive_10001");
+    }
+
+    #[tokio::test]
+    #[ignore = "write"]
+    async fn test_history_report() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Write);
+        bpi.history_report(10001, 16546, 30).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_history_stream() -> Result<(), BpiError> {
+        use futures_util::{ StreamExt, TryStreamExt };
+
+        let bpi = crate::test_client!(Authed);
+        let items: Vec<HistoryListItem> = bpi.history_stream(Some("archive")).take(50).try_collect().await?;
+        info!("获取历史记录数: {}", items.len());
+        assert!(items.iter().all(|item| item.history.business == "archive"));
+        Ok(())
+    }
+
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_history_get_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let resp = bpi.history_list(None, None, None, None, Some(10)).await;
 
         info!("{:?}", resp);
@@ -263,8 +376,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_history_shadow_set_and_get() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         // 获取当前状态
         let current_status_resp = bpi.history_shadow_get().await.unwrap();
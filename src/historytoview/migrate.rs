@@ -0,0 +1,159 @@
+//! 历史记录批量迁移到收藏夹
+//!
+//! 按游标遍历历史记录，使用调用方提供的筛选条件过滤后逐条加入收藏夹。
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::history::HistoryListItem;
+use crate::{ BpiClient, BpiError };
+
+/// 迁移选项
+#[derive(Debug, Clone)]
+pub struct HistoryMigrateOptions {
+    /// 两次收藏请求之间的间隔，避免触发风控
+    pub interval: Duration,
+    /// 仅筛选不写入
+    pub dry_run: bool,
+    /// 最多处理的匹配条目数，`None` 表示遍历全部历史记录
+    pub limit: Option<usize>,
+    /// 每页拉取的历史记录数
+    pub page_size: u32,
+}
+
+impl Default for HistoryMigrateOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(800),
+            dry_run: false,
+            limit: None,
+            page_size: 30,
+        }
+    }
+}
+
+/// 迁移结果
+#[derive(Debug, Default, Serialize)]
+pub struct HistoryMigrateReport {
+    /// 筛选命中的稿件
+    pub matched: Vec<HistoryListItem>,
+    /// 成功加入收藏夹的稿件 avid
+    pub added: Vec<u64>,
+    /// 加入失败的稿件 avid 及错误
+    pub failed: Vec<(u64, BpiError)>,
+}
+
+impl BpiClient {
+    /// 将历史记录中的稿件批量迁移到收藏夹
+    ///
+    /// 仅处理稿件视频（`business == "archive"`），`filter` 返回 `true` 的条目会被加入目标收藏夹。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `filter` | `Fn(&HistoryListItem) -> bool` | 筛选条件，如 UP 主 mid、标题关键词、时长 |
+    /// | `media_id` | u64 | 目标收藏夹 media_id |
+    /// | `options` | `HistoryMigrateOptions` | 迁移选项 |
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+    /// use bpi_rs::historytoview::migrate::HistoryMigrateOptions;
+    ///
+    /// let report = bpi.migrate_history_to_fav(
+    ///     |item| item.author_mid == Some(2) && item.duration.unwrap_or(0) > 60,
+    ///     123456,
+    ///     HistoryMigrateOptions { dry_run: true, ..Default::default() }
+    /// ).await?;
+    /// println!("命中 {} 条", report.matched.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn migrate_history_to_fav<F>(
+        &self,
+        filter: F,
+        media_id: u64,
+        options: HistoryMigrateOptions
+    ) -> Result<HistoryMigrateReport, BpiError>
+        where F: Fn(&HistoryListItem) -> bool
+    {
+        let mut report = HistoryMigrateReport::default();
+        let mut max = None;
+        let mut view_at = None;
+
+        'pages: loop {
+            let data = self
+                .history_list(max, Some("archive"), view_at, Some("archive"), Some(options.page_size)).await?
+                .into_data()?;
+
+            if data.list.is_empty() {
+                break;
+            }
+
+            for item in data.list {
+                if item.history.business != "archive" || !filter(&item) {
+                    continue;
+                }
+                if options.limit.is_some_and(|limit| report.matched.len() >= limit) {
+                    break 'pages;
+                }
+
+                let aid = item.history.oid;
+                report.matched.push(item);
+
+                if options.dry_run {
+                    continue;
+                }
+
+                match self.fav_resource_deal(aid, 2, &[media_id], &[]).await {
+                    Ok(_) => report.added.push(aid),
+                    Err(e) => {
+                        tracing::warn!("稿件 av{} 加入收藏夹失败: {}", aid, e);
+                        report.failed.push((aid, e));
+                    }
+                }
+                tokio::time::sleep(options.interval).await;
+            }
+
+            if data.cursor.max == 0 {
+                break;
+            }
+            max = Some(data.cursor.max);
+            view_at = Some(data.cursor.view_at);
+        }
+
+        tracing::info!(
+            "历史记录迁移完成: 命中 {} 条, 成功 {} 条, 失败 {} 条",
+            report.matched.len(),
+            report.added.len(),
+            report.failed.len()
+        );
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::info;
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_migrate_history_to_fav_dry_run() {
+        let bpi = crate::test_client!(Authed);
+        let options = HistoryMigrateOptions {
+            dry_run: true,
+            limit: Some(5),
+            ..Default::default()
+        };
+
+        let resp = bpi.migrate_history_to_fav(|_| true, 0, options).await;
+        info!("{:?}", resp);
+        assert!(resp.is_ok());
+        assert!(resp.unwrap().added.is_empty());
+    }
+}
@@ -1,4 +1,6 @@
 //! 观看历史与稍后再看
 
+pub mod dedupe;
 pub mod history;
+pub mod migrate;
 pub mod toview;
@@ -77,7 +77,8 @@ pub struct ToViewVideoItem {
     pub dimension: ToViewDimension,
     pub count: Option<u32>,
     pub cid: u64,
-    pub progress: u32,
+    /// 观看进度（秒），-1 表示已看完
+    pub progress: i32,
     pub add_at: u64,
     pub bvid: String,
 }
@@ -186,12 +187,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_toview_add_and_get() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let aid = 10001;
 
         // 1. 添加视频
@@ -43,6 +43,9 @@ pub mod live;
 #[cfg(feature = "login")]
 pub mod login;
 
+#[cfg(feature = "mall")]
+pub mod mall;
+
 #[cfg(feature = "manga")]
 pub mod manga;
 
@@ -81,19 +84,32 @@ pub mod web_widget;
 
 pub mod models;
 
+// 常用类型统一导入
+pub mod prelude;
+
 pub mod auth;
+pub mod background;
 
+pub mod cache;
 pub mod client;
+pub mod device;
 pub mod err;
 pub mod log;
+pub mod pagination;
+pub mod protection;
+pub mod rate_limit;
 pub mod request;
 pub mod response;
+pub mod session;
+
+// 集成测试分类与账号检查
+pub mod testing;
 
 // bv aid互转, 以及生成wbi
 pub mod utils;
 
-pub use auth::Account;
-pub use client::BpiClient;
+pub use auth::{ Account, AccountSource, AppToken };
+pub use client::{ BpiClient, BpiClientBuilder, RetryConfig, RetryOn };
 pub use err::error::BpiError;
 pub use request::BilibiliRequest;
 pub use response::BpiResponse;
@@ -0,0 +1,306 @@
+//! 直播消息流背压控制
+//!
+//! 在 WebSocket 读取端与消费端之间放置有界队列，消费过慢（如礼物刷屏）时按策略丢弃或暂停读取，
+//! 避免内存无限增长。直播信息流默认使用该队列，可通过
+//! [`LiveStreamOptions`](super::message_stream::LiveStreamOptions) 调整，见
+//! [`BpiClient::live_message_stream_with`](crate::BpiClient::live_message_stream_with)。
+
+use std::collections::VecDeque;
+use std::sync::atomic::{ AtomicBool, AtomicU64, AtomicUsize, Ordering };
+use std::sync::{ Arc, Mutex };
+
+use futures_util::Stream;
+use serde::{ Deserialize, Serialize };
+use tokio::sync::Notify;
+
+/// 队列满时的处理策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum OverflowStrategy {
+    /// 丢弃队列中最旧的消息
+    #[default]
+    DropOldest,
+    /// 丢弃新到达的消息
+    DropNewest,
+    /// 暂停读取端，直到消费端腾出空间
+    Pause,
+}
+
+/// 背压配置
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BackpressureConfig {
+    /// 队列容量
+    pub capacity: usize,
+    /// 队列满时的处理策略
+    pub strategy: OverflowStrategy,
+}
+
+impl Default for BackpressureConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 1024,
+            strategy: OverflowStrategy::DropOldest,
+        }
+    }
+}
+
+/// 背压统计
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackpressureStats {
+    /// 已投递的消息数
+    pub delivered: u64,
+    /// 因 `DropOldest` 丢弃的消息数
+    pub dropped_oldest: u64,
+    /// 因 `DropNewest` 丢弃的消息数
+    pub dropped_newest: u64,
+    /// 因 `Pause` 暂停读取的次数
+    pub paused: u64,
+}
+
+impl BackpressureStats {
+    /// 丢弃总数
+    pub fn dropped(&self) -> u64 {
+        self.dropped_oldest + self.dropped_newest
+    }
+}
+
+struct Shared<T> {
+    config: BackpressureConfig,
+    queue: Mutex<VecDeque<T>>,
+    item_ready: Notify,
+    space_ready: Notify,
+    senders: AtomicUsize,
+    receiver_closed: AtomicBool,
+    delivered: AtomicU64,
+    dropped_oldest: AtomicU64,
+    dropped_newest: AtomicU64,
+    paused: AtomicU64,
+}
+
+impl<T> Shared<T> {
+    fn stats(&self) -> BackpressureStats {
+        BackpressureStats {
+            delivered: self.delivered.load(Ordering::Relaxed),
+            dropped_oldest: self.dropped_oldest.load(Ordering::Relaxed),
+            dropped_newest: self.dropped_newest.load(Ordering::Relaxed),
+            paused: self.paused.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// 创建有界消息队列
+pub fn bounded<T>(config: BackpressureConfig) -> (EventSender<T>, EventReceiver<T>) {
+    let capacity = config.capacity.max(1);
+    let shared = Arc::new(Shared {
+        config: BackpressureConfig { capacity, ..config },
+        queue: Mutex::new(VecDeque::with_capacity(capacity)),
+        item_ready: Notify::new(),
+        space_ready: Notify::new(),
+        senders: AtomicUsize::new(1),
+        receiver_closed: AtomicBool::new(false),
+        delivered: AtomicU64::new(0),
+        dropped_oldest: AtomicU64::new(0),
+        dropped_newest: AtomicU64::new(0),
+        paused: AtomicU64::new(0),
+    });
+
+    (EventSender { shared: shared.clone() }, EventReceiver { shared })
+}
+
+/// 队列写入端，由 WebSocket 读取任务持有
+pub struct EventSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> EventSender<T> {
+    /// 写入一条消息
+    ///
+    /// 消费端已关闭时返回 `Err(item)`。
+    pub async fn send(&self, item: T) -> Result<(), T> {
+        let shared = &self.shared;
+        let mut item = Some(item);
+
+        loop {
+            if shared.receiver_closed.load(Ordering::Acquire) {
+                return Err(item.take().unwrap());
+            }
+
+            {
+                let mut queue = shared.queue.lock().unwrap_or_else(|e| e.into_inner());
+                if queue.len() < shared.config.capacity {
+                    queue.push_back(item.take().unwrap());
+                    drop(queue);
+                    shared.item_ready.notify_one();
+                    return Ok(());
+                }
+
+                match shared.config.strategy {
+                    OverflowStrategy::DropOldest => {
+                        queue.pop_front();
+                        queue.push_back(item.take().unwrap());
+                        drop(queue);
+                        shared.dropped_oldest.fetch_add(1, Ordering::Relaxed);
+                        shared.item_ready.notify_one();
+                        return Ok(());
+                    }
+                    OverflowStrategy::DropNewest => {
+                        shared.dropped_newest.fetch_add(1, Ordering::Relaxed);
+                        return Ok(());
+                    }
+                    OverflowStrategy::Pause => {
+                        shared.paused.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+
+            shared.space_ready.notified().await;
+        }
+    }
+
+    /// 当前统计
+    pub fn stats(&self) -> BackpressureStats {
+        self.shared.stats()
+    }
+
+    /// 消费端是否已关闭
+    pub fn is_closed(&self) -> bool {
+        self.shared.receiver_closed.load(Ordering::Acquire)
+    }
+}
+
+impl<T> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::AcqRel);
+        Self { shared: self.shared.clone() }
+    }
+}
+
+impl<T> Drop for EventSender<T> {
+    fn drop(&mut self) {
+        if self.shared.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.shared.item_ready.notify_one();
+        }
+    }
+}
+
+/// 队列读取端
+pub struct EventReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> EventReceiver<T> {
+    /// 读取一条消息，所有写入端关闭且队列为空时返回 `None`
+    pub async fn recv(&mut self) -> Option<T> {
+        let shared = &self.shared;
+
+        loop {
+            {
+                let mut queue = shared.queue.lock().unwrap_or_else(|e| e.into_inner());
+                if let Some(item) = queue.pop_front() {
+                    drop(queue);
+                    shared.delivered.fetch_add(1, Ordering::Relaxed);
+                    shared.space_ready.notify_one();
+                    return Some(item);
+                }
+            }
+
+            if shared.senders.load(Ordering::Acquire) == 0 {
+                return None;
+            }
+
+            shared.item_ready.notified().await;
+        }
+    }
+
+    /// 当前队列长度
+    pub fn len(&self) -> usize {
+        self.shared.queue.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// 队列是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 当前统计
+    pub fn stats(&self) -> BackpressureStats {
+        self.shared.stats()
+    }
+
+    /// 转换为 `Stream`
+    pub fn into_stream(self) -> impl Stream<Item = T> {
+        futures_util::stream::unfold(self, |mut rx| async move {
+            rx.recv().await.map(|item| (item, rx))
+        })
+    }
+}
+
+impl<T> Drop for EventReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.receiver_closed.store(true, Ordering::Release);
+        self.shared.space_ready.notify_waiters();
+        self.shared.space_ready.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(strategy: OverflowStrategy) -> BackpressureConfig {
+        BackpressureConfig { capacity: 2, strategy }
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest() {
+        let (tx, mut rx) = bounded(config(OverflowStrategy::DropOldest));
+        for i in 0..4 {
+            tx.send(i).await.unwrap();
+        }
+        drop(tx);
+
+        assert_eq!(rx.recv().await, Some(2));
+        assert_eq!(rx.recv().await, Some(3));
+        assert_eq!(rx.recv().await, None);
+        assert_eq!(rx.stats().dropped_oldest, 2);
+    }
+
+    #[tokio::test]
+    async fn test_drop_newest() {
+        let (tx, mut rx) = bounded(config(OverflowStrategy::DropNewest));
+        for i in 0..4 {
+            tx.send(i).await.unwrap();
+        }
+        drop(tx);
+
+        assert_eq!(rx.recv().await, Some(0));
+        assert_eq!(rx.recv().await, Some(1));
+        assert_eq!(rx.recv().await, None);
+        assert_eq!(rx.stats().dropped_newest, 2);
+    }
+
+    #[tokio::test]
+    async fn test_pause() {
+        let (tx, mut rx) = bounded(config(OverflowStrategy::Pause));
+        let writer = tokio::spawn(async move {
+            for i in 0..4 {
+                tx.send(i).await.unwrap();
+            }
+        });
+
+        let mut received = Vec::new();
+        while let Some(i) = rx.recv().await {
+            received.push(i);
+        }
+        writer.await.unwrap();
+
+        assert_eq!(received, vec![0, 1, 2, 3]);
+        assert_eq!(rx.stats().dropped(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_receiver_closed() {
+        let (tx, rx) = bounded::<u32>(config(OverflowStrategy::Pause));
+        drop(rx);
+        assert_eq!(tx.send(1).await, Err(1));
+    }
+}
@@ -129,8 +129,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_live_emoticons() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_emoticons(14047, "pc").await?;
 
         let data = resp.data.unwrap();
@@ -0,0 +1,249 @@
+//! 直播间消息流事件数据
+//!
+//! 对应信息流（WebSocket）中各命令的 `data` 字段。
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/live/message_stream.md)
+
+use serde::{ Deserialize, Serialize };
+
+/// 礼物货币类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CoinType {
+    /// 金瓜子（电池），1000 金瓜子 = 1 元
+    #[default]
+    Gold,
+    /// 银瓜子，免费礼物
+    Silver,
+}
+
+/// 送礼 `SEND_GIFT`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiftEvent {
+    /// 送礼用户 mid
+    pub uid: u64,
+    /// 送礼用户昵称
+    #[serde(default)]
+    pub uname: String,
+    /// 礼物 id
+    #[serde(rename = "giftId", default)]
+    pub gift_id: u64,
+    /// 礼物名称
+    #[serde(rename = "giftName", default)]
+    pub gift_name: String,
+    /// 礼物数量
+    #[serde(default)]
+    pub num: u32,
+    /// 单价（瓜子）
+    #[serde(default)]
+    pub price: u64,
+    /// 货币类型
+    #[serde(default)]
+    pub coin_type: CoinType,
+    /// 总价值（瓜子）
+    #[serde(default)]
+    pub total_coin: u64,
+    /// 秒级时间戳
+    #[serde(default)]
+    pub timestamp: i64,
+}
+
+/// 醒目留言用户信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SuperChatUser {
+    #[serde(default)]
+    pub uname: String,
+    #[serde(default)]
+    pub face: String,
+}
+
+/// 醒目留言 `SUPER_CHAT_MESSAGE`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuperChatEvent {
+    /// 醒目留言 id
+    pub id: u64,
+    /// 发送用户 mid
+    pub uid: u64,
+    /// 价格（元）
+    pub price: u64,
+    /// 留言内容
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub user_info: SuperChatUser,
+    /// 开始时间秒级时间戳
+    #[serde(default)]
+    pub start_time: i64,
+}
+
+/// 上舰 `GUARD_BUY`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardBuyEvent {
+    /// 用户 mid
+    pub uid: u64,
+    /// 用户昵称
+    #[serde(default)]
+    pub username: String,
+    /// 大航海等级 1:总督 2:提督 3:舰长
+    pub guard_level: u8,
+    /// 数量（月）
+    #[serde(default)]
+    pub num: u32,
+    /// 价格（金瓜子）
+    #[serde(default)]
+    pub price: u64,
+    /// 名称
+    #[serde(default)]
+    pub gift_name: String,
+    /// 开始时间秒级时间戳
+    #[serde(default)]
+    pub start_time: i64,
+}
+
+/// 弹幕 `DANMU_MSG`
+///
+/// 该命令的数据在 `info` 数组中而非 `data` 字段，由 [`LiveEvent::from_command`] 解析。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DanmakuEvent {
+    /// 发送用户 mid，未登录连接时可能为 0
+    pub uid: u64,
+    /// 发送用户昵称
+    pub uname: String,
+    /// 弹幕内容
+    pub text: String,
+    /// 十进制 RGB 颜色
+    pub color: u32,
+    /// 毫秒级时间戳
+    pub timestamp: i64,
+    /// 粉丝勋章名称与等级
+    pub medal: Option<(String, u32)>,
+}
+
+/// 进场、关注、分享 `INTERACT_WORD`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractWordEvent {
+    /// 用户 mid
+    pub uid: u64,
+    /// 用户昵称
+    #[serde(default)]
+    pub uname: String,
+    /// 1: 进场 2: 关注 3: 分享 4: 特别关注 5: 互粉
+    #[serde(default)]
+    pub msg_type: u8,
+    /// 秒级时间戳
+    #[serde(default)]
+    pub timestamp: i64,
+}
+
+/// 直播间消息流事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum LiveEvent {
+    Danmaku(DanmakuEvent),
+    Gift(GiftEvent),
+    SuperChat(SuperChatEvent),
+    GuardBuy(GuardBuyEvent),
+    InteractWord(InteractWordEvent),
+    /// 心跳回复中的人气值
+    Popularity(u32),
+    /// 未单独解析的命令，保留原始 JSON
+    Other {
+        cmd: String,
+        raw: serde_json::Value,
+    },
+}
+
+impl LiveEvent {
+    /// 解析命令包（操作码 5）中的 JSON
+    ///
+    /// 已知命令的数据格式不符时同样作为 [`LiveEvent::Other`] 返回，不会丢失消息。
+    pub fn from_command(raw: serde_json::Value) -> Self {
+        let cmd = raw
+            .get("cmd")
+            .and_then(|cmd| cmd.as_str())
+            .unwrap_or_default()
+            .to_string();
+        // 部分命令带有后缀，如 `DANMU_MSG:4:0:2:2:2:0`
+        let name = cmd.split(':').next().unwrap_or_default();
+
+        let parsed = match name {
+            "DANMU_MSG" => parse_danmaku(&raw).map(Self::Danmaku),
+            "SEND_GIFT" => data(&raw).map(Self::Gift),
+            "SUPER_CHAT_MESSAGE" => data(&raw).map(Self::SuperChat),
+            "GUARD_BUY" => data(&raw).map(Self::GuardBuy),
+            "INTERACT_WORD" => data(&raw).map(Self::InteractWord),
+            _ => None,
+        };
+
+        parsed.unwrap_or(Self::Other { cmd, raw })
+    }
+}
+
+fn data<T: serde::de::DeserializeOwned>(raw: &serde_json::Value) -> Option<T> {
+    serde_json::from_value(raw.get("data")?.clone()).ok()
+}
+
+fn parse_danmaku(raw: &serde_json::Value) -> Option<DanmakuEvent> {
+    let info = raw.get("info")?.as_array()?;
+    let meta = info.first()?.as_array()?;
+    let user = info.get(2)?.as_array()?;
+    let medal = info
+        .get(3)
+        .and_then(|medal| medal.as_array())
+        .filter(|medal| medal.len() >= 2)
+        .and_then(|medal| Some((medal[1].as_str()?.to_string(), medal[0].as_u64()? as u32)));
+
+    Some(DanmakuEvent {
+        uid: user.first()?.as_u64().unwrap_or(0),
+        uname: user.get(1)?.as_str().unwrap_or_default().to_string(),
+        text: info.get(1)?.as_str()?.to_string(),
+        color: meta
+            .get(3)
+            .and_then(|color| color.as_u64())
+            .unwrap_or(16777215) as u32,
+        timestamp: meta
+            .get(4)
+            .and_then(|ts| ts.as_i64())
+            .unwrap_or(0),
+        medal,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_live_event_from_command() {
+        let danmaku = LiveEvent::from_command(
+            json!({
+                "cmd": "DANMU_MSG:4:0:2:2:2:0",
+                "info": [[0, 1, 25, 16777215, 1700000000000i64], "你好", [123, "用户", 0], [21, "勋章", "主播", 1]]
+            })
+        );
+        match danmaku {
+            LiveEvent::Danmaku(dm) => {
+                assert_eq!(dm.uid, 123);
+                assert_eq!(dm.text, "你好");
+                assert_eq!(dm.medal, Some(("勋章".to_string(), 21)));
+            }
+            other => panic!("{:?}", other),
+        }
+
+        let gift = LiveEvent::from_command(
+            json!({
+                "cmd": "SEND_GIFT",
+                "data": { "uid": 1, "uname": "a", "giftId": 1, "giftName": "辣条", "num": 2, "price": 100, "coin_type": "silver" }
+            })
+        );
+        assert!(matches!(gift, LiveEvent::Gift(g) if g.num == 2 && g.coin_type == CoinType::Silver));
+
+        let unknown = LiveEvent::from_command(json!({ "cmd": "ONLINE_RANK_COUNT", "data": {} }));
+        assert!(matches!(unknown, LiveEvent::Other { ref cmd, .. } if cmd == "ONLINE_RANK_COUNT"));
+
+        // 格式不符的已知命令不丢弃
+        let broken = LiveEvent::from_command(json!({ "cmd": "DANMU_MSG", "info": [] }));
+        assert!(matches!(broken, LiveEvent::Other { .. }));
+    }
+}
@@ -192,18 +192,18 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_follow_up_live_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_follow_up_list(Some(1), Some(2), Some(1), Some(true)).await.unwrap();
         tracing::info!("{:?}", resp);
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_follow_up_live_web_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_follow_up_web_list(Some(false)).await.unwrap();
         tracing::info!("{:?}", resp);
     }
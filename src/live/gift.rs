@@ -151,8 +151,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_room_gift_list() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_room_gift_list(23174842, None, None).await?;
 
         let data = resp.data.unwrap();
@@ -161,8 +162,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_blind_gift_info() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_blind_gift_info(32251).await?;
 
         let data = resp.data.unwrap();
@@ -223,8 +223,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_guard_list() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_guard_list(23174842, 504140200, None, None, None).await?;
 
         let data = resp.data.unwrap();
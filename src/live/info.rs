@@ -121,7 +121,51 @@ pub struct RoomInfoData {
     pub studio_info: Option<RoomStudioInfo>,
 }
 
+/// 直播间初始化信息
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct RoomInitData {
+    /// 真实直播间号
+    pub room_id: i64,
+    /// 短号，没有时为 0
+    pub short_id: i64,
+    /// 主播 mid
+    pub uid: i64,
+    /// 是否隐藏
+    #[serde(default)]
+    pub is_hidden: bool,
+    /// 是否被封禁
+    #[serde(default)]
+    pub is_locked: bool,
+    /// 是否竖屏
+    #[serde(default)]
+    pub is_portrait: bool,
+    /// 直播状态：0 未开播，1 直播中，2 轮播中
+    pub live_status: i32,
+    /// 是否加密
+    #[serde(default)]
+    pub encrypted: bool,
+    /// 开播时间戳，未开播时为 0
+    #[serde(default)]
+    pub live_time: i64,
+}
+
 impl BpiClient {
+    /// 获取直播间初始化信息，可将短号转换为真实直播间号
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/live)
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `id` | i64 | 直播间号，可为短号 |
+    pub async fn live_room_init(&self, id: i64) -> Result<BpiResponse<RoomInitData>, BpiError> {
+        self
+            .get("https://api.live.bilibili.com/room/v1/Room/room_init")
+            .query(&[("id", id.to_string())])
+            .send_bpi("获取直播间初始化信息").await
+    }
+
     /// 获取直播间信息
     ///
 
@@ -147,12 +191,23 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_room_info() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.live_room_info(23174842).await?;
 
         let data = result.data.unwrap();
         assert_eq!(data.room_id, 23174842);
         Ok(())
     }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_live_room_init() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi.live_room_init(1).await?.into_data()?;
+        assert_eq!(data.room_id, 5440);
+        assert_eq!(data.short_id, 1);
+        Ok(())
+    }
 }
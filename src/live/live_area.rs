@@ -40,7 +40,116 @@ pub struct LiveParentArea {
     pub list: Vec<LiveSubArea>,
 }
 
+/// 直播子分区
+///
+/// 由 [`LiveAreaCatalog`] 解析得到，id 已转换为数字，可直接用于开播、修改分区等接口。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LiveArea {
+    /// 子分区id（area_v2）
+    pub id: u64,
+    /// 子分区名
+    pub name: String,
+    /// 父分区id
+    pub parent_id: u64,
+    /// 父分区名
+    pub parent_name: String,
+}
+
+impl LiveArea {
+    fn from_sub(parent: &LiveParentArea, sub: &LiveSubArea) -> Option<Self> {
+        Some(Self {
+            id: sub.id.parse().ok()?,
+            name: sub.name.clone(),
+            parent_id: parent.id as u64,
+            parent_name: parent.name.clone(),
+        })
+    }
+}
+
+/// 直播分区目录
+///
+/// ```no_run
+/// # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+/// let catalog = bpi.live_area_catalog().await?;
+/// if let Some(area) = catalog.find_by_name("英雄联盟") {
+///     println!("{} / {} = {}", area.parent_name, area.name, area.id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LiveAreaCatalog {
+    parents: Vec<LiveParentArea>,
+}
+
+impl LiveAreaCatalog {
+    pub fn new(parents: Vec<LiveParentArea>) -> Self {
+        Self { parents }
+    }
+
+    /// 全部父分区
+    pub fn parents(&self) -> &[LiveParentArea] {
+        &self.parents
+    }
+
+    /// 按 id 查找父分区
+    pub fn parent(&self, parent_id: u64) -> Option<&LiveParentArea> {
+        self.parents.iter().find(|p| p.id as u64 == parent_id)
+    }
+
+    /// 父分区下的全部子分区
+    pub fn children(&self, parent_id: u64) -> Vec<LiveArea> {
+        self.parent(parent_id)
+            .map(|p| {
+                p.list
+                    .iter()
+                    .filter_map(|sub| LiveArea::from_sub(p, sub))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 全部子分区
+    pub fn areas(&self) -> impl Iterator<Item = LiveArea> + '_ {
+        self.parents.iter().flat_map(|p| p.list.iter().filter_map(move |sub| LiveArea::from_sub(p, sub)))
+    }
+
+    /// 按 id 查找子分区
+    pub fn get(&self, id: u64) -> Option<LiveArea> {
+        self.areas().find(|a| a.id == id)
+    }
+
+    /// 按名称精确查找子分区
+    pub fn find_by_name(&self, name: &str) -> Option<LiveArea> {
+        let name = name.trim();
+        self.areas().find(|a| a.name == name)
+    }
+
+    /// 按关键词模糊搜索子分区，父分区名匹配时返回其全部子分区
+    pub fn search(&self, keyword: &str) -> Vec<LiveArea> {
+        let keyword = keyword.trim().to_lowercase();
+        if keyword.is_empty() {
+            return Vec::new();
+        }
+
+        self.areas()
+            .filter(|a| {
+                a.name.to_lowercase().contains(&keyword) ||
+                    a.parent_name.to_lowercase().contains(&keyword)
+            })
+            .collect()
+    }
+}
+
 impl BpiClient {
+    /// 获取直播分区目录
+    ///
+    /// 在 [`live_area_list`](Self::live_area_list) 的基础上提供按名称查找与父子分区导航。
+    pub async fn live_area_catalog(&self) -> Result<LiveAreaCatalog, BpiError> {
+        let parents = self.live_area_list().await?.into_data()?;
+        Ok(LiveAreaCatalog::new(parents))
+    }
+
     /// 获取全部直播间分区列表
     ///
 
@@ -59,9 +168,56 @@ impl BpiClient {
 mod tests {
     use super::*;
 
+    fn catalog() -> LiveAreaCatalog {
+        let sub = |id: &str, parent_id: i32, name: &str, parent_name: &str| LiveSubArea {
+            id: id.to_string(),
+            parent_id: parent_id.to_string(),
+            old_area_id: "0".to_string(),
+            name: name.to_string(),
+            act_id: "0".to_string(),
+            pk_status: "0".to_string(),
+            hot_status: 0,
+            lock_status: "0".to_string(),
+            pic: String::new(),
+            parent_name: parent_name.to_string(),
+            area_type: 0,
+        };
+
+        LiveAreaCatalog::new(
+            vec![
+                LiveParentArea {
+                    id: 2,
+                    name: "网游".to_string(),
+                    list: vec![sub("86", 2, "英雄联盟", "网游"), sub("88", 2, "无畏契约", "网游")],
+                },
+                LiveParentArea {
+                    id: 6,
+                    name: "单机游戏".to_string(),
+                    list: vec![sub("236", 6, "主机游戏", "单机游戏")],
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn test_live_area_catalog() {
+        let catalog = catalog();
+
+        let lol = catalog.find_by_name("英雄联盟").unwrap();
+        assert_eq!(lol.id, 86);
+        assert_eq!(lol.parent_id, 2);
+        assert_eq!(catalog.parent(lol.parent_id).unwrap().name, "网游");
+        assert_eq!(catalog.children(2).len(), 2);
+        assert_eq!(catalog.get(236).unwrap().name, "主机游戏");
+        assert!(catalog.find_by_name("英雄").is_none());
+        assert_eq!(catalog.search("英雄").len(), 1);
+        assert_eq!(catalog.search("单机").len(), 1);
+    }
+
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_live_area_list() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_area_list().await?;
 
         let data = resp.data.unwrap();
@@ -80,6 +80,71 @@ pub struct ReplayListData {
     pub pagination: Pagination,
 }
 
+/// 直播间公开的直播录像
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LiveRecord {
+    /// 录像 id，获取播放地址时使用
+    pub rid: String,
+    /// 直播间id
+    pub room_id: i64,
+    /// 直播标题
+    #[serde(default)]
+    pub title: String,
+    /// 封面
+    #[serde(default)]
+    pub cover: String,
+    /// 直播开始秒时间戳
+    #[serde(default)]
+    pub start_timestamp: i64,
+    /// 直播结束秒时间戳
+    #[serde(default)]
+    pub end_timestamp: i64,
+    /// 人气峰值
+    #[serde(default)]
+    pub online: i64,
+    /// 弹幕数
+    #[serde(default)]
+    pub danmu_num: i64,
+    /// 时长（毫秒）
+    #[serde(default)]
+    pub length: i64,
+}
+
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LiveRecordListData {
+    /// 录像总数
+    #[serde(default)]
+    pub count: i32,
+    #[serde(default)]
+    pub list: Vec<LiveRecord>,
+}
+
+/// 录像分段
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LiveRecordPart {
+    /// 分段地址
+    pub url: String,
+    /// 时长（毫秒）
+    #[serde(default)]
+    pub length: i64,
+    /// 大小（字节）
+    #[serde(default)]
+    pub size: i64,
+}
+
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LiveRecordUrlData {
+    /// 按顺序排列的分段
+    #[serde(default)]
+    pub list: Vec<LiveRecordPart>,
+    /// 总时长（毫秒）
+    #[serde(default)]
+    pub length: i64,
+    /// 总大小（字节）
+    #[serde(default)]
+    pub size: i64,
+}
+
 impl BpiClient {
     /// 获取直播回放列表
     ///
@@ -106,6 +171,50 @@ impl BpiClient {
             .query(&query)
             .send_bpi("获取直播回放列表").await
     }
+
+    /// 获取直播间公开的直播录像列表
+    ///
+    /// 仅主播开启录像回放的直播间有内容；主播本人的回放管理见 [`live_replay_list`](Self::live_replay_list)。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `room_id` | i64 | 直播间id |
+    /// | `page` | `Option<i32>` | 页码，默认 1，每页 20 条 |
+    pub async fn live_replays(
+        &self,
+        room_id: i64,
+        page: Option<i32>
+    ) -> Result<BpiResponse<LiveRecordListData>, BpiError> {
+        self
+            .get("https://api.live.bilibili.com/xlive/web-room/v1/record/getList")
+            .query(
+                &[
+                    ("room_id", room_id.to_string()),
+                    ("page", page.unwrap_or(1).to_string()),
+                    ("page_size", "20".to_string()),
+                ]
+            )
+            .send_bpi("获取直播录像列表").await
+    }
+
+    /// 获取直播录像的播放地址
+    ///
+    /// 录像按时长切分为多个 flv 分段，按 `list` 顺序拼接即为完整录像。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `rid` | &str | 录像 id，见 [`LiveRecord::rid`] |
+    pub async fn live_replay_playurl(
+        &self,
+        rid: &str
+    ) -> Result<BpiResponse<LiveRecordUrlData>, BpiError> {
+        self
+            .get("https://api.live.bilibili.com/xlive/web-room/v1/record/getLiveRecordUrl")
+            .query(&[("rid", rid), ("platform", "html5")])
+            .send_bpi("获取直播录像播放地址").await
+    }
 }
 
 #[cfg(test)]
@@ -113,9 +222,41 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_live_replay_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_replay_list(Some(1), Some(2)).await.unwrap();
         tracing::info!("{:?}", resp);
     }
+
+    #[test]
+    fn test_parse_record_url() {
+        let data: LiveRecordUrlData = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "list": [
+                        { "url": "https://example.com/0.flv", "length": 1800000, "size": 1024 },
+                        { "url": "https://example.com/1.flv", "length": 600000, "size": 512 }
+                    ],
+                    "length": 2400000,
+                    "size": 1536
+                })
+            )
+            .unwrap();
+        assert_eq!(data.list.len(), 2);
+        assert_eq!(data.list.iter().map(|p| p.length).sum::<i64>(), data.length);
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_live_replays() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi.live_replays(3, Some(1)).await?.into_data()?;
+        tracing::info!("{:#?}", data);
+        if let Some(record) = data.list.first() {
+            let url = bpi.live_replay_playurl(&record.rid).await?.into_data()?;
+            tracing::info!("{:#?}", url);
+        }
+        Ok(())
+    }
 }
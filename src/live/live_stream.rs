@@ -1,5 +1,6 @@
 use serde::{ Deserialize, Serialize };
 
+use crate::models::VideoCodec;
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 
 #[derive(Debug, Serialize, Clone, Deserialize)]
@@ -36,7 +37,262 @@ pub struct LiveStreamData {
     pub durl: Vec<LiveStreamUrl>,
 }
 
+/// 直播流协议
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum LiveProtocol {
+    /// HTTP-FLV，`http_stream`
+    HttpStream,
+    /// HLS，`http_hls`
+    HttpHls,
+    Other(String),
+}
+
+impl From<String> for LiveProtocol {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "http_stream" => Self::HttpStream,
+            "http_hls" => Self::HttpHls,
+            _ => Self::Other(name),
+        }
+    }
+}
+
+impl From<LiveProtocol> for String {
+    fn from(protocol: LiveProtocol) -> Self {
+        match protocol {
+            LiveProtocol::HttpStream => "http_stream".to_string(),
+            LiveProtocol::HttpHls => "http_hls".to_string(),
+            LiveProtocol::Other(name) => name,
+        }
+    }
+}
+
+/// 直播流封装格式
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum LiveFormat {
+    Flv,
+    Ts,
+    Fmp4,
+    Other(String),
+}
+
+impl From<String> for LiveFormat {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "flv" => Self::Flv,
+            "ts" => Self::Ts,
+            "fmp4" => Self::Fmp4,
+            _ => Self::Other(name),
+        }
+    }
+}
+
+impl From<LiveFormat> for String {
+    fn from(format: LiveFormat) -> Self {
+        match format {
+            LiveFormat::Flv => "flv".to_string(),
+            LiveFormat::Ts => "ts".to_string(),
+            LiveFormat::Fmp4 => "fmp4".to_string(),
+            LiveFormat::Other(name) => name,
+        }
+    }
+}
+
+/// 画质描述
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LiveQnDesc {
+    /// 画质代码
+    pub qn: i32,
+    /// 画质名称
+    pub desc: String,
+    #[serde(default)]
+    pub hdr_desc: String,
+    #[serde(default)]
+    pub attr_desc: Option<String>,
+}
+
+/// 直播流线路
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LiveUrlInfo {
+    /// 域名
+    pub host: String,
+    /// URL 参数
+    pub extra: String,
+    /// 有效时间，秒
+    #[serde(default)]
+    pub stream_ttl: i64,
+}
+
+/// 某一编码的直播流
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LiveStreamCodec {
+    /// 编码名，如 `avc`、`hevc`、`av1`
+    pub codec_name: String,
+    /// 当前画质代码
+    pub current_qn: i32,
+    /// 该编码可选的画质代码
+    pub accept_qn: Vec<i32>,
+    /// 路径
+    pub base_url: String,
+    /// 线路列表
+    pub url_info: Vec<LiveUrlInfo>,
+    #[serde(default)]
+    pub hdr_qn: Option<i32>,
+    #[serde(default)]
+    pub dolby_type: i32,
+    #[serde(default)]
+    pub attr_name: String,
+}
+
+impl LiveStreamCodec {
+    /// 编码，未收录的编码为 `None`
+    pub fn codec(&self) -> Option<VideoCodec> {
+        VideoCodec::from_name(&self.codec_name)
+    }
+
+    /// 各线路的完整地址
+    pub fn urls(&self) -> Vec<String> {
+        self.url_info
+            .iter()
+            .map(|info| format!("{}{}{}", info.host, self.base_url, info.extra))
+            .collect()
+    }
+}
+
+/// 某一封装格式的直播流
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LiveStreamFormat {
+    pub format_name: LiveFormat,
+    pub codec: Vec<LiveStreamCodec>,
+}
+
+/// 某一协议的直播流
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LiveStreamProtocol {
+    pub protocol_name: LiveProtocol,
+    pub format: Vec<LiveStreamFormat>,
+}
+
+/// 直播流地址
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LivePlayUrl {
+    pub cid: i64,
+    /// 画质描述
+    pub g_qn_desc: Vec<LiveQnDesc>,
+    /// 按协议、格式、编码分组的直播流
+    pub stream: Vec<LiveStreamProtocol>,
+}
+
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LivePlayUrlInfo {
+    /// 播放器配置 JSON 字符串
+    #[serde(default)]
+    pub conf_json: String,
+    pub playurl: LivePlayUrl,
+}
+
+/// 直播间播放信息
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct LiveRoomPlayInfo {
+    /// 真实直播间号
+    pub room_id: i64,
+    /// 短号，没有时为 0
+    pub short_id: i64,
+    /// 主播 mid
+    pub uid: i64,
+    /// 直播状态：0 未开播，1 直播中，2 轮播中
+    pub live_status: i32,
+    /// 开播时间戳
+    #[serde(default)]
+    pub live_time: i64,
+    /// 是否竖屏
+    #[serde(default)]
+    pub is_portrait: bool,
+    /// 直播流信息，未开播时为 `null`
+    #[serde(default)]
+    pub playurl_info: Option<LivePlayUrlInfo>,
+}
+
+/// 展平后的一条直播流
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveStreamCandidate {
+    pub protocol: LiveProtocol,
+    pub format: LiveFormat,
+    /// 编码，未收录的编码为 `None`
+    pub codec: Option<VideoCodec>,
+    /// 画质代码
+    pub qn: i32,
+    /// 完整地址
+    pub url: String,
+}
+
+impl LiveRoomPlayInfo {
+    /// 画质描述
+    pub fn qualities(&self) -> &[LiveQnDesc] {
+        self.playurl_info.as_ref().map_or(&[], |info| &info.playurl.g_qn_desc)
+    }
+
+    /// 所有可用的直播流，按接口返回顺序展平，未开播时为空
+    pub fn candidates(&self) -> Vec<LiveStreamCandidate> {
+        let Some(info) = &self.playurl_info else {
+            return Vec::new();
+        };
+        let mut candidates = Vec::new();
+        for protocol in &info.playurl.stream {
+            for format in &protocol.format {
+                for codec in &format.codec {
+                    candidates.extend(
+                        codec.urls().into_iter().map(|url| LiveStreamCandidate {
+                            protocol: protocol.protocol_name.clone(),
+                            format: format.format_name.clone(),
+                            codec: codec.codec(),
+                            qn: codec.current_qn,
+                            url,
+                        })
+                    );
+                }
+            }
+        }
+        candidates
+    }
+}
+
 impl BpiClient {
+    /// 获取直播间播放信息（v2），包含 FLV、HLS（TS / fMP4）各编码的直播流
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/live)
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `room_id` | i64 | 直播间号，可为短号 |
+    /// | `qn` | `Option<i32>` | 画质代码，默认 10000 原画 |
+    pub async fn live_room_play_info(
+        &self,
+        room_id: i64,
+        qn: Option<i32>
+    ) -> Result<BpiResponse<LiveRoomPlayInfo>, BpiError> {
+        self
+            .get("https://api.live.bilibili.com/xlive/web-room/v2/index/getRoomPlayInfo")
+            .query(
+                &[
+                    ("room_id", room_id.to_string()),
+                    ("protocol", "0,1".to_string()),
+                    ("format", "0,1,2".to_string()),
+                    ("codec", "0,1,2".to_string()),
+                    ("qn", qn.unwrap_or(10000).to_string()),
+                    ("platform", "web".to_string()),
+                    ("ptype", "8".to_string()),
+                    ("dolby", "5".to_string()),
+                    ("panorama", "1".to_string()),
+                ]
+            )
+            .send_bpi("获取直播间播放信息").await
+    }
+
     /// 根据真实直播间号获取直播视频流
     ///
     /// # 参数
@@ -94,9 +350,60 @@ impl BpiClient {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_play_info_candidates() {
+        let data: LiveRoomPlayInfo = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "room_id": 5440, "short_id": 1, "uid": 9617619, "live_status": 1,
+                    "playurl_info": {
+                        "conf_json": "{}",
+                        "playurl": {
+                            "cid": 5440,
+                            "g_qn_desc": [{ "qn": 10000, "desc": "原画", "hdr_desc": "" }],
+                            "stream": [{
+                                "protocol_name": "http_hls",
+                                "format": [{
+                                    "format_name": "fmp4",
+                                    "codec": [{
+                                        "codec_name": "hevc", "current_qn": 10000, "accept_qn": [10000, 400],
+                                        "base_url": "/live-bvc/index.m3u8?",
+                                        "url_info": [
+                                            { "host": "https://a.bilivideo.com", "extra": "expires=1", "stream_ttl": 3600 },
+                                            { "host": "https://b.bilivideo.com", "extra": "expires=2", "stream_ttl": 3600 }
+                                        ]
+                                    }]
+                                }]
+                            }]
+                        }
+                    }
+                })
+            )
+            .unwrap();
+
+        assert_eq!(data.qualities()[0].desc, "原画");
+        let candidates = data.candidates();
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].protocol, LiveProtocol::HttpHls);
+        assert_eq!(candidates[0].format, LiveFormat::Fmp4);
+        assert_eq!(candidates[0].codec, Some(VideoCodec::Hevc));
+        assert_eq!(candidates[1].url, "https://b.bilivideo.com/live-bvc/index.m3u8?expires=2");
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_live_room_play_info() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi.live_room_play_info(1, None).await?.into_data()?;
+        assert_eq!(data.room_id, 5440);
+        tracing::info!("直播流 {} 条", data.candidates().len());
+        Ok(())
+    }
+
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_live_stream() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_stream(14073662, Some("web"), None, Some(10000)).await.unwrap();
         tracing::info!("{:?}", resp);
     }
@@ -1,7 +1,9 @@
 use crate::{BilibiliRequest, BpiClient, BpiError, BpiResponse};
-use reqwest::multipart::Form;
+use reqwest::multipart::{Form, Part};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::path::Path;
+use super::live_area::LiveArea;
 
 // --- 直播间管理 API 结构体 ---
 
@@ -67,6 +69,36 @@ pub struct UpdatePreLiveInfoData {
     pub audit_info: Option<AuditInfo>,
 }
 
+/// 直播间封面上传响应数据
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LiveCoverUploadData {
+    /// 已上传图片 URL
+    pub location: String,
+    pub etag: Option<String>,
+}
+
+/// 更换直播间封面结果
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LiveCoverUpdate {
+    /// 新封面 URL
+    pub url: String,
+    pub audit_info: Option<AuditInfo>,
+}
+
+/// 根据扩展名推断封面图片 MIME 类型
+fn cover_mime(file_path: &Path) -> Result<&'static str, BpiError> {
+    let ext = file_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase());
+    match ext.as_deref() {
+        Some("jpg" | "jpeg") => Ok("image/jpeg"),
+        Some("png") => Ok("image/png"),
+        Some("webp") => Ok("image/webp"),
+        _ => Err(BpiError::invalid_parameter("image", "封面仅支持 jpg/png/webp 格式")),
+    }
+}
+
 /// PC直播姬版本号响应数据
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PcLiveVersionData {
@@ -137,6 +169,19 @@ impl BpiClient {
             .await
     }
 
+    /// 修改直播间分区
+    ///
+    /// # 参数
+    /// * `room_id` - 直播间 ID
+    /// * `area` - 目标分区，可由 [`LiveAreaCatalog`](super::live_area::LiveAreaCatalog) 查找
+    pub async fn live_update_room_area(
+        &self,
+        room_id: u64,
+        area: &LiveArea,
+    ) -> Result<BpiResponse<UpdateRoomData>, BpiError> {
+        self.live_update_room_info(room_id, None, Some(area.id), None, None).await
+    }
+
     /// 开始直播 (目前仅支持直播姬开播)
     ///
     /// # 参数
@@ -218,6 +263,60 @@ impl BpiClient {
             .await
     }
 
+    /// 上传直播间封面图片
+    ///
+    /// 仅上传图片，需再调用 [`live_update_pre_live_info`](Self::live_update_pre_live_info)
+    /// 才会生效，也可直接使用 [`live_update_cover`](Self::live_update_cover)。
+    ///
+    /// # 参数
+    /// * `image` - 图片文件路径，支持 jpg/png/webp
+    pub async fn live_upload_cover(
+        &self,
+        image: &Path,
+    ) -> Result<BpiResponse<LiveCoverUploadData>, BpiError> {
+        let mime = cover_mime(image)?;
+        let csrf = self.csrf()?;
+
+        let bytes = tokio::fs::read(image)
+            .await
+            .map_err(|e| BpiError::parse(format!("读取封面文件失败: {}", e)))?;
+        let file_name = image
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "cover".to_string());
+        let part = Part::bytes(bytes).file_name(file_name).mime_str(mime)?;
+
+        let form = Form::new()
+            .part("file", part)
+            .text("bucket", "live")
+            .text("dir", "new_room_cover")
+            .text("csrf", csrf);
+
+        self.post("https://api.bilibili.com/x/upload/web/image")
+            .multipart(form)
+            .send_bpi("上传直播间封面")
+            .await
+    }
+
+    /// 更换直播间封面
+    ///
+    /// 上传图片后立即设为直播间封面，新封面需经过审核才会对观众展示。
+    ///
+    /// # 参数
+    /// * `image` - 图片文件路径，支持 jpg/png/webp
+    pub async fn live_update_cover(&self, image: &Path) -> Result<LiveCoverUpdate, BpiError> {
+        let url = self.live_upload_cover(image).await?.into_data()?.location;
+        let data = self
+            .live_update_pre_live_info(None, Some(&url))
+            .await?
+            .into_data()?;
+
+        Ok(LiveCoverUpdate {
+            url,
+            audit_info: data.audit_info,
+        })
+    }
+
     /// 更新直播间公告
     ///
     /// # 参数
@@ -259,9 +358,10 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "write"]
 
     async fn test_live_create_room() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         match bpi.live_create_room().await {
             Ok(resp) => resp,
             Err(err) => {
@@ -279,9 +379,10 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
 
     async fn test_live_update_room_info() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let room_id = 3818081;
         let resp = bpi
             .live_update_room_info(room_id, Some("测试新标题"), None, None, None)
@@ -296,9 +397,10 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
 
     async fn test_live_stop() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         // 替换为您的直播间 ID
         let room_id = 3818081;
         let resp = bpi.live_stop(room_id, "pc_link").await?;
@@ -311,9 +413,10 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
 
     async fn test_live_update_pre_live_info() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi
             .live_update_pre_live_info(Some("测试预更新标题"), None)
             .await?;
@@ -326,10 +429,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cover_mime() {
+        assert_eq!(cover_mime(Path::new("a/cover.JPG")).unwrap(), "image/jpeg");
+        assert_eq!(cover_mime(Path::new("cover.webp")).unwrap(), "image/webp");
+        assert!(cover_mime(Path::new("cover.gif")).is_err());
+        assert!(cover_mime(Path::new("cover")).is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "write"]
+    async fn test_live_update_cover() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Write);
+        let update = bpi.live_update_cover(Path::new("./assets/test.jpg")).await?;
+        info!("新封面: {}, 审核: {:?}", update.url, update.audit_info);
+
+        Ok(())
+    }
+
     #[tokio::test]
+    #[ignore = "write"]
 
     async fn test_live_update_room_news() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         // 替换为您的直播间 ID
         let room_id = 3818081;
         let uid = 4279370;
@@ -343,8 +465,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_live_version() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_version().await?;
         assert_eq!(resp.code, 0);
         let data = resp.into_data()?;
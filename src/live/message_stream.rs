@@ -1 +1,504 @@
-// https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/live/message_stream.md
+//! 直播间信息流（弹幕 WebSocket）
+//!
+//! 连接弹幕服务器后发送认证包，每 30 秒发送一次心跳，服务器下发的命令包解析为 [`LiveEvent`]。
+//!
+//! 数据包由 16 字节头部与正文组成，头部依次为：包总长 `u32`、头部长 `u16`、
+//! 协议版本 `u16`（0: JSON 1: 人气值 2: zlib 压缩 3: brotli 压缩）、操作码 `u32`、序号 `u32`，均为大端序。
+//! 压缩包解压后仍是若干完整数据包。
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/live/message_stream.md)
+
+use std::io::Read;
+use std::time::Duration;
+
+use futures_util::stream::SplitStream;
+use futures_util::{ SinkExt, Stream, StreamExt };
+use serde::{ Deserialize, Serialize };
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{ MaybeTlsStream, WebSocketStream };
+
+use super::backpressure::{ self, BackpressureConfig, EventReceiver, EventSender };
+use super::event::LiveEvent;
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use crate::utils::web_location::WebLocation;
+
+/// 数据包头部长度
+const HEADER_LEN: usize = 16;
+
+/// 心跳间隔
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 获取服务器列表失败时使用的默认地址
+const DEFAULT_HOST: &str = "broadcastlv.chat.bilibili.com";
+
+/// 心跳
+pub const OP_HEARTBEAT: u32 = 2;
+/// 心跳回复，正文为人气值
+pub const OP_HEARTBEAT_REPLY: u32 = 3;
+/// 命令
+pub const OP_COMMAND: u32 = 5;
+/// 认证
+pub const OP_AUTH: u32 = 7;
+/// 认证回复
+pub const OP_AUTH_REPLY: u32 = 8;
+
+type WsRead = SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>;
+
+/// 信息流数据包
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    /// 协议版本
+    pub protover: u16,
+    /// 操作码
+    pub operation: u32,
+    pub sequence: u32,
+    pub body: Vec<u8>,
+}
+
+impl Packet {
+    pub fn new(operation: u32, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            protover: 1,
+            operation,
+            sequence: 1,
+            body: body.into(),
+        }
+    }
+
+    /// 编码为二进制
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(HEADER_LEN + self.body.len());
+        buf.extend_from_slice(&((HEADER_LEN + self.body.len()) as u32).to_be_bytes());
+        buf.extend_from_slice(&(HEADER_LEN as u16).to_be_bytes());
+        buf.extend_from_slice(&self.protover.to_be_bytes());
+        buf.extend_from_slice(&self.operation.to_be_bytes());
+        buf.extend_from_slice(&self.sequence.to_be_bytes());
+        buf.extend_from_slice(&self.body);
+        buf
+    }
+
+    /// 解码一帧中的所有数据包，压缩包会被解压并展开
+    pub fn decode_all(mut data: &[u8]) -> Result<Vec<Packet>, BpiError> {
+        let mut packets = Vec::new();
+
+        while !data.is_empty() {
+            if data.len() < HEADER_LEN {
+                return Err(BpiError::parse("信息流数据包头部不完整"));
+            }
+            let total = u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize;
+            let header = u16::from_be_bytes([data[4], data[5]]) as usize;
+            if total < header || header < HEADER_LEN || data.len() < total {
+                return Err(BpiError::parse("信息流数据包长度错误"));
+            }
+
+            let packet = Packet {
+                protover: u16::from_be_bytes([data[6], data[7]]),
+                operation: u32::from_be_bytes([data[8], data[9], data[10], data[11]]),
+                sequence: u32::from_be_bytes([data[12], data[13], data[14], data[15]]),
+                body: data[header..total].to_vec(),
+            };
+            data = &data[total..];
+
+            match packet.protover {
+                2 => {
+                    let mut buf = Vec::new();
+                    flate2::read::ZlibDecoder
+                        ::new(packet.body.as_slice())
+                        .read_to_end(&mut buf)
+                        .map_err(|e| BpiError::parse(format!("zlib 解压失败: {}", e)))?;
+                    packets.extend(Self::decode_all(&buf)?);
+                }
+                3 => {
+                    let mut buf = Vec::new();
+                    brotli_decompressor::Decompressor
+                        ::new(packet.body.as_slice(), 4096)
+                        .read_to_end(&mut buf)
+                        .map_err(|e| BpiError::parse(format!("brotli 解压失败: {}", e)))?;
+                    packets.extend(Self::decode_all(&buf)?);
+                }
+                _ => packets.push(packet),
+            }
+        }
+
+        Ok(packets)
+    }
+
+    /// 转换为事件，心跳回复与命令以外的包返回 `None`
+    pub fn into_event(self) -> Option<Result<LiveEvent, BpiError>> {
+        match self.operation {
+            OP_HEARTBEAT_REPLY => {
+                let bytes: [u8; 4] = self.body.get(..4)?.try_into().ok()?;
+                Some(Ok(LiveEvent::Popularity(u32::from_be_bytes(bytes))))
+            }
+            OP_COMMAND =>
+                Some(
+                    serde_json
+                        ::from_slice(&self.body)
+                        .map(LiveEvent::from_command)
+                        .map_err(BpiError::from)
+                ),
+            _ => None,
+        }
+    }
+}
+
+/// 弹幕服务器地址
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DanmuHost {
+    pub host: String,
+    pub port: u16,
+    pub wss_port: u16,
+    pub ws_port: u16,
+}
+
+/// 信息流认证信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DanmuInfoData {
+    /// 认证密钥
+    pub token: String,
+    #[serde(default)]
+    pub host_list: Vec<DanmuHost>,
+}
+
+/// 认证包正文
+#[derive(Debug, Serialize)]
+struct AuthBody<'a> {
+    uid: u64,
+    roomid: i64,
+    protover: u8,
+    buvid: &'a str,
+    platform: &'a str,
+    #[serde(rename = "type")]
+    auth_type: u8,
+    key: &'a str,
+}
+
+/// 信息流选项
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LiveStreamOptions {
+    /// 读取端与消费端之间的队列，消费过慢时按策略丢弃消息或暂停读取
+    pub backpressure: BackpressureConfig,
+}
+
+/// 流被丢弃时停止读取与心跳任务
+struct AbortOnDrop(tokio::task::AbortHandle);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+struct StreamState {
+    events: EventReceiver<Result<LiveEvent, BpiError>>,
+    _reader: AbortOnDrop,
+    _heartbeat: AbortOnDrop,
+}
+
+impl BpiClient {
+    /// 获取信息流认证密钥与服务器地址
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `room_id` | i64 | 直播间号，可为短号 |
+    pub async fn live_danmu_info(
+        &self,
+        room_id: i64
+    ) -> Result<BpiResponse<DanmuInfoData>, BpiError> {
+        self
+            .get("https://api.live.bilibili.com/xlive/web-room/v1/index/getDanmuInfo")
+            .query(
+                &[
+                    ("id", room_id.to_string()),
+                    ("type", "0".to_string()),
+                    ("web_location", self.web_location(WebLocation::LIVE_ROOM)),
+                ]
+            )
+            .send_bpi_wbi("获取信息流认证密钥").await
+    }
+
+    /// 连接直播间信息流
+    ///
+    /// 已登录时以当前账号认证，否则以游客身份连接（游客收到的弹幕用户名会打码）。
+    /// 连接使用 client 的 User-Agent、Cookie 与代理设置（不支持 `custom_proxy`）。
+    /// 丢弃返回的流即断开连接；连接断开或出错后流结束，需要时由调用方重新连接。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `room_id` | i64 | 直播间号，可为短号 |
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+    /// use futures_util::StreamExt;
+    /// use bpi_rs::live::event::LiveEvent;
+    ///
+    /// let mut events = Box::pin(bpi.live_message_stream(21733448).await?);
+    /// while let Some(event) = events.next().await {
+    ///     if let LiveEvent::Danmaku(dm) = event? {
+    ///         println!("{}: {}", dm.uname, dm.text);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn live_message_stream(
+        &self,
+        room_id: i64
+    ) -> Result<impl Stream<Item = Result<LiveEvent, BpiError>> + Send + 'static, BpiError> {
+        self.live_message_stream_with(room_id, LiveStreamOptions::default()).await
+    }
+
+    /// 以指定选项连接直播间信息流
+    ///
+    /// 读取任务把事件写入有界队列，返回的流从队列中读取；消费过慢时按
+    /// [`BackpressureConfig`] 的策略丢弃消息或暂停读取，默认容量 1024、丢弃最旧的消息。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `room_id` | i64 | 直播间号，可为短号 |
+    /// | `options` | LiveStreamOptions | 信息流选项 |
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+    /// use bpi_rs::live::backpressure::{ BackpressureConfig, OverflowStrategy };
+    /// use bpi_rs::live::message_stream::LiveStreamOptions;
+    ///
+    /// let options = LiveStreamOptions {
+    ///     backpressure: BackpressureConfig { capacity: 4096, strategy: OverflowStrategy::Pause },
+    /// };
+    /// let events = bpi.live_message_stream_with(21733448, options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn live_message_stream_with(
+        &self,
+        room_id: i64,
+        options: LiveStreamOptions
+    ) -> Result<impl Stream<Item = Result<LiveEvent, BpiError>> + Send + 'static, BpiError> {
+        // 认证需要真实房间号
+        let room_id = self.live_room_info(room_id).await?.into_data()?.room_id;
+        let info = self.live_danmu_info(room_id).await?.into_data()?;
+
+        let url = match info.host_list.first() {
+            Some(host) => format!("wss://{}:{}/sub", host.host, host.wss_port),
+            None => format!("wss://{}/sub", DEFAULT_HOST),
+        };
+
+        let account = self.account_snapshot();
+        let uid = account
+            .as_ref()
+            .and_then(|acc| acc.dede_user_id.parse().ok())
+            .unwrap_or(0);
+        let buvid = match self.device() {
+            Some(device) => device.buvid3.clone(),
+            None =>
+                account
+                    .as_ref()
+                    .map(|acc| acc.buvid3.clone())
+                    .unwrap_or_default(),
+        };
+
+        let auth = serde_json::to_vec(
+            &(AuthBody {
+                uid,
+                roomid: room_id,
+                protover: 3,
+                buvid: &buvid,
+                platform: "web",
+                auth_type: 2,
+                key: &info.token,
+            })
+        )?;
+
+        let ws = super::ws::connect(self, &url).await?;
+        let (mut write, mut read) = ws.split();
+
+        write
+            .send(Message::Binary(Packet::new(OP_AUTH, auth).encode().into())).await
+            .map_err(|e| BpiError::network(format!("发送认证包失败: {}", e)))?;
+        wait_auth_reply(&mut read).await?;
+        tracing::info!("已连接直播间 {} 信息流: {}", room_id, url);
+
+        let tasks = self.background_tasks().clone();
+        let heartbeat = tasks.spawn("live_heartbeat", async move {
+            let packet = Packet::new(OP_HEARTBEAT, "[object Object]").encode();
+            loop {
+                if write.send(Message::Binary(packet.clone().into())).await.is_err() {
+                    break;
+                }
+                tokio::time::sleep(HEARTBEAT_INTERVAL).await;
+            }
+        });
+
+        let (sender, events) = backpressure::bounded(options.backpressure);
+        let reader = tasks.spawn("live_reader", read_events(read, sender));
+
+        let state = StreamState {
+            events,
+            _reader: AbortOnDrop(reader),
+            _heartbeat: AbortOnDrop(heartbeat),
+        };
+
+        Ok(
+            futures_util::stream::unfold(state, |mut state| async move {
+                state.events.recv().await.map(|event| (event, state))
+            })
+        )
+    }
+}
+
+/// 读取信息流并写入队列，连接关闭、出错或消费端关闭时结束
+async fn read_events<R>(mut read: R, sender: EventSender<Result<LiveEvent, BpiError>>)
+    where R: Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin
+{
+    loop {
+        let events = match read.next().await {
+            Some(Ok(Message::Binary(data))) => {
+                match Packet::decode_all(&data) {
+                    Ok(packets) => packets.into_iter().filter_map(Packet::into_event).collect(),
+                    Err(e) => vec![Err(e)],
+                }
+            }
+            Some(Ok(Message::Close(_))) | None => {
+                tracing::info!("信息流连接已关闭");
+                break;
+            }
+            Some(Ok(_)) => {
+                continue;
+            }
+            Some(Err(e)) => {
+                let _ = sender.send(Err(BpiError::network(format!("读取信息流失败: {}", e)))).await;
+                break;
+            }
+        };
+
+        for event in events {
+            if sender.send(event).await.is_err() {
+                tracing::debug!("信息流已被丢弃，停止读取");
+                return;
+            }
+        }
+    }
+
+    let stats = sender.stats();
+    if stats.dropped() > 0 {
+        tracing::warn!("信息流消费过慢，共丢弃 {} 条消息", stats.dropped());
+    }
+}
+
+/// 等待认证回复，认证失败时返回错误
+async fn wait_auth_reply(read: &mut WsRead) -> Result<(), BpiError> {
+    while let Some(message) = read.next().await {
+        let data = match message.map_err(|e| BpiError::network(format!("读取认证回复失败: {}", e)))? {
+            Message::Binary(data) => data,
+            Message::Close(_) => {
+                break;
+            }
+            _ => {
+                continue;
+            }
+        };
+
+        for packet in Packet::decode_all(&data)? {
+            if packet.operation != OP_AUTH_REPLY {
+                continue;
+            }
+            let reply: serde_json::Value = serde_json::from_slice(&packet.body)?;
+            return match reply.get("code").and_then(|code| code.as_i64()) {
+                Some(0) => Ok(()),
+                code => Err(BpiError::auth(format!("信息流认证失败: {:?}", code))),
+            };
+        }
+    }
+
+    Err(BpiError::network("信息流在认证前关闭"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn command(json: &str) -> Vec<u8> {
+        let mut packet = Packet::new(OP_COMMAND, json);
+        packet.protover = 0;
+        packet.encode()
+    }
+
+    #[test]
+    fn test_packet_roundtrip() {
+        let packet = Packet::new(OP_HEARTBEAT, "[object Object]");
+        let decoded = Packet::decode_all(&packet.encode()).unwrap();
+        assert_eq!(decoded, vec![packet]);
+
+        assert!(Packet::decode_all(&[0, 0, 0, 1]).is_err());
+    }
+
+    #[test]
+    fn test_packet_decompress() {
+        let mut inner = command(r#"{"cmd":"SEND_GIFT","data":{"uid":1,"num":1}}"#);
+        inner.extend(command(r#"{"cmd":"ONLINE_RANK_COUNT","data":{}}"#));
+
+        let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&inner).unwrap();
+        let mut zlib = Packet::new(OP_COMMAND, encoder.finish().unwrap());
+        zlib.protover = 2;
+
+        let mut frame = zlib.encode();
+        frame.extend(Packet::new(OP_HEARTBEAT_REPLY, 1234u32.to_be_bytes()).encode());
+
+        let events: Vec<LiveEvent> = Packet::decode_all(&frame)
+            .unwrap()
+            .into_iter()
+            .filter_map(Packet::into_event)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], LiveEvent::Gift(_)));
+        assert!(matches!(events[1], LiveEvent::Other { .. }));
+        assert!(matches!(events[2], LiveEvent::Popularity(1234)));
+    }
+
+    #[tokio::test]
+    async fn test_read_events_backpressure() {
+        use crate::live::backpressure::OverflowStrategy;
+
+        let frames: Vec<Result<Message, tokio_tungstenite::tungstenite::Error>> = (0..4u32)
+            .map(|i| Ok(Message::Binary(Packet::new(OP_HEARTBEAT_REPLY, i.to_be_bytes()).encode().into())))
+            .chain([Ok(Message::Close(None))])
+            .collect();
+        let (sender, mut events) = backpressure::bounded(BackpressureConfig {
+            capacity: 2,
+            strategy: OverflowStrategy::DropOldest,
+        });
+
+        read_events(futures_util::stream::iter(frames), sender).await;
+
+        let mut received = Vec::new();
+        while let Some(event) = events.recv().await {
+            received.push(event.unwrap());
+        }
+        assert!(matches!(received[..], [LiveEvent::Popularity(2), LiveEvent::Popularity(3)]));
+        assert_eq!(events.stats().dropped_oldest, 2);
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_live_message_stream() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let events = bpi.live_message_stream(21733448).await?;
+        let events: Vec<_> = events.take(5).collect().await;
+        for event in events {
+            tracing::info!("{:?}", event?);
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 //! 直播
 
+pub mod backpressure;
 pub mod danmaku;
 pub mod emoticons;
+pub mod event;
 pub mod follow_up_live;
 pub mod gift;
 pub mod guard;
@@ -15,5 +17,9 @@ pub mod message_stream;
 pub mod recommend;
 pub mod redpocket;
 pub mod report;
+pub mod revenue;
+pub mod room_admin;
 pub mod silent_user_manage;
+pub mod sink;
 pub mod user;
+mod ws;
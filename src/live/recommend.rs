@@ -1,6 +1,7 @@
 use serde::{ Deserialize, Serialize };
 
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use crate::utils::web_location::WebLocation;
 
 // ================= 数据结构 =================
 
@@ -107,8 +108,8 @@ impl BpiClient {
     /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/live)
     pub async fn live_recommend(&self) -> Result<BpiResponse<RecommendData>, BpiError> {
         let params = [
-            ("platform", "web"),
-            ("web_location", "333.1007"),
+            ("platform", "web".to_string()),
+            ("web_location", self.web_location(WebLocation::HOME)),
         ];
 
         let resp = self
@@ -125,8 +126,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_live_recommend() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_recommend().await?;
 
         let data = resp.data.unwrap();
@@ -109,8 +109,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_live_lottery_info() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         bpi.live_lottery_info(23174842).await?;
 
         // 注意：直播间可能没有红包，所以不做额外断言
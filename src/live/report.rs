@@ -55,8 +55,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_web_heart_beat() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.live_web_heart_beat(23174842, None, None).await?;
 
         let data = resp.data.unwrap();
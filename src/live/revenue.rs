@@ -0,0 +1,269 @@
+//! 直播收益统计
+//!
+//! 汇总一场直播中的礼物、醒目留言与大航海收入，按货币类型统计总额并维护用户贡献榜，
+//! 可定时输出快照供主播面板展示。
+//!
+//! 金额统一以瓜子计：醒目留言价格为元，按 1 元 = 1000 金瓜子折算；大航海按 `price * num` 计入金瓜子。
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use futures_util::Stream;
+use serde::Serialize;
+
+use super::event::{ CoinType, GiftEvent, GuardBuyEvent, LiveEvent, SuperChatEvent };
+
+/// 单个用户的贡献
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct UserRevenue {
+    pub uid: u64,
+    pub uname: String,
+    /// 金瓜子
+    pub gold: u64,
+    /// 银瓜子
+    pub silver: u64,
+    /// 送礼次数
+    pub gift_count: u32,
+    /// 醒目留言次数
+    pub super_chat_count: u32,
+    /// 上舰次数
+    pub guard_count: u32,
+}
+
+/// 收益快照
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RevenueSnapshot {
+    /// 统计开始的秒级时间戳
+    pub started_at: i64,
+    /// 快照生成的秒级时间戳
+    pub taken_at: i64,
+    /// 按货币类型统计的总额（瓜子）
+    pub totals: HashMap<CoinType, u64>,
+    /// 礼物收入（金瓜子）
+    pub gift_gold: u64,
+    /// 醒目留言收入（金瓜子）
+    pub super_chat_gold: u64,
+    /// 大航海收入（金瓜子）
+    pub guard_gold: u64,
+    /// 按金瓜子从高到低排序的贡献榜
+    pub leaderboard: Vec<UserRevenue>,
+}
+
+impl RevenueSnapshot {
+    /// 金瓜子总额折合人民币（元）
+    pub fn gold_yuan(&self) -> f64 {
+        (self.totals.get(&CoinType::Gold).copied().unwrap_or(0) as f64) / 1000.0
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    totals: HashMap<CoinType, u64>,
+    gift_gold: u64,
+    super_chat_gold: u64,
+    guard_gold: u64,
+    users: HashMap<u64, UserRevenue>,
+}
+
+impl State {
+    fn user(&mut self, uid: u64, uname: &str) -> &mut UserRevenue {
+        let user = self.users.entry(uid).or_insert_with(|| UserRevenue {
+            uid,
+            ..Default::default()
+        });
+        if !uname.is_empty() {
+            user.uname = uname.to_string();
+        }
+        user
+    }
+}
+
+/// 直播收益统计器
+///
+/// 内部加锁，可在读取消息流的任务与展示任务之间通过 `Arc` 共享。
+#[derive(Debug)]
+pub struct RevenueTracker {
+    started_at: i64,
+    /// 快照中保留的贡献榜人数
+    leaderboard_size: usize,
+    state: Mutex<State>,
+}
+
+impl Default for RevenueTracker {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+impl RevenueTracker {
+    /// 创建统计器，`leaderboard_size` 为快照中保留的贡献榜人数
+    pub fn new(leaderboard_size: usize) -> Self {
+        Self {
+            started_at: chrono::Utc::now().timestamp(),
+            leaderboard_size,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// 记录送礼
+    pub fn record_gift(&self, event: &GiftEvent) {
+        let coin = if event.total_coin > 0 {
+            event.total_coin
+        } else {
+            event.price * (event.num as u64)
+        };
+
+        let mut state = self.state.lock().unwrap();
+        *state.totals.entry(event.coin_type).or_default() += coin;
+        if event.coin_type == CoinType::Gold {
+            state.gift_gold += coin;
+        }
+
+        let user = state.user(event.uid, &event.uname);
+        match event.coin_type {
+            CoinType::Gold => {
+                user.gold += coin;
+            }
+            CoinType::Silver => {
+                user.silver += coin;
+            }
+        }
+        user.gift_count += 1;
+    }
+
+    /// 记录醒目留言
+    pub fn record_super_chat(&self, event: &SuperChatEvent) {
+        let coin = event.price * 1000;
+
+        let mut state = self.state.lock().unwrap();
+        *state.totals.entry(CoinType::Gold).or_default() += coin;
+        state.super_chat_gold += coin;
+
+        let user = state.user(event.uid, &event.user_info.uname);
+        user.gold += coin;
+        user.super_chat_count += 1;
+    }
+
+    /// 记录上舰
+    pub fn record_guard(&self, event: &GuardBuyEvent) {
+        let coin = event.price * (event.num.max(1) as u64);
+
+        let mut state = self.state.lock().unwrap();
+        *state.totals.entry(CoinType::Gold).or_default() += coin;
+        state.guard_gold += coin;
+
+        let user = state.user(event.uid, &event.username);
+        user.gold += coin;
+        user.guard_count += 1;
+    }
+
+    /// 记录信息流事件，与收益无关的事件忽略
+    pub fn record(&self, event: &LiveEvent) {
+        match event {
+            LiveEvent::Gift(gift) => self.record_gift(gift),
+            LiveEvent::SuperChat(sc) => self.record_super_chat(sc),
+            LiveEvent::GuardBuy(guard) => self.record_guard(guard),
+            _ => {}
+        }
+    }
+
+    /// 生成当前快照
+    pub fn snapshot(&self) -> RevenueSnapshot {
+        let state = self.state.lock().unwrap();
+
+        let mut leaderboard: Vec<UserRevenue> = state.users.values().cloned().collect();
+        leaderboard.sort_by(|a, b| b.gold.cmp(&a.gold).then(b.silver.cmp(&a.silver)));
+        leaderboard.truncate(self.leaderboard_size);
+
+        RevenueSnapshot {
+            started_at: self.started_at,
+            taken_at: chrono::Utc::now().timestamp(),
+            totals: state.totals.clone(),
+            gift_gold: state.gift_gold,
+            super_chat_gold: state.super_chat_gold,
+            guard_gold: state.guard_gold,
+            leaderboard,
+        }
+    }
+
+    /// 清空统计，开始新一场
+    pub fn reset(&mut self) {
+        self.started_at = chrono::Utc::now().timestamp();
+        *self.state.get_mut().unwrap() = State::default();
+    }
+
+    /// 每隔 `period` 输出一次快照
+    ///
+    /// ```no_run
+    /// # async fn run() {
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use futures_util::StreamExt;
+    /// use bpi_rs::live::revenue::RevenueTracker;
+    ///
+    /// let tracker = Arc::new(RevenueTracker::default());
+    /// let mut snapshots = Box::pin(tracker.clone().snapshots(Duration::from_secs(10)));
+    /// while let Some(snapshot) = snapshots.next().await {
+    ///     println!("本场收入 {:.2} 元", snapshot.gold_yuan());
+    /// }
+    /// # }
+    /// ```
+    pub fn snapshots(
+        self: std::sync::Arc<Self>,
+        period: Duration
+    ) -> impl Stream<Item = RevenueSnapshot> {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        futures_util::stream::unfold((self, interval), |(tracker, mut interval)| async move {
+            interval.tick().await;
+            Some((tracker.snapshot(), (tracker, interval)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gift(uid: u64, coin_type: CoinType, total_coin: u64) -> GiftEvent {
+        GiftEvent {
+            uid,
+            uname: format!("user{}", uid),
+            gift_id: 1,
+            gift_name: "礼物".to_string(),
+            num: 1,
+            price: total_coin,
+            coin_type,
+            total_coin,
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_revenue_tracker() {
+        let tracker = RevenueTracker::new(2);
+        tracker.record_gift(&gift(1, CoinType::Gold, 1000));
+        tracker.record_gift(&gift(2, CoinType::Silver, 500));
+        tracker.record_gift(&gift(3, CoinType::Gold, 100));
+        let sc: SuperChatEvent = serde_json::from_value(
+            serde_json::json!({ "id": 1, "uid": 3, "price": 30 })
+        ).unwrap();
+        let guard: GuardBuyEvent = serde_json::from_value(
+            serde_json::json!({ "uid": 2, "guard_level": 3, "num": 1, "price": 198000 })
+        ).unwrap();
+        tracker.record_super_chat(&sc);
+        tracker.record_guard(&guard);
+
+        let snapshot = tracker.snapshot();
+        assert_eq!(snapshot.totals[&CoinType::Gold], 1000 + 100 + 30_000 + 198_000);
+        assert_eq!(snapshot.totals[&CoinType::Silver], 500);
+        assert_eq!(snapshot.super_chat_gold, 30_000);
+        assert_eq!(snapshot.leaderboard.len(), 2);
+        assert_eq!(snapshot.leaderboard[0].uid, 2);
+        assert_eq!(snapshot.leaderboard[0].silver, 500);
+        assert_eq!(snapshot.leaderboard[1].uid, 3);
+        assert_eq!(snapshot.leaderboard[1].super_chat_count, 1);
+    }
+}
@@ -0,0 +1,183 @@
+//! 直播间房管
+//!
+//! 查询直播间房管列表，以及主播任命、撤销自己直播间的房管。
+use serde::{ Deserialize, Serialize };
+
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 房管信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomAdmin {
+    /// 房管 mid
+    pub uid: u64,
+    /// 房管昵称
+    #[serde(default)]
+    pub uname: String,
+    /// 房管头像
+    #[serde(default)]
+    pub face: String,
+    /// 任命时间
+    #[serde(default)]
+    pub ctime: Option<String>,
+}
+
+/// 分页信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomAdminPage {
+    /// 当前页码
+    pub page: i32,
+    /// 每页数量
+    #[serde(default)]
+    pub page_size: i32,
+    /// 总页数
+    pub total_page: i32,
+    /// 房管总数
+    pub total_count: i32,
+}
+
+/// 房管列表
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomAdminListData {
+    pub page: RoomAdminPage,
+    /// 房管列表，没有房管时为 `null`
+    #[serde(default)]
+    pub data: Option<Vec<RoomAdmin>>,
+}
+
+impl BpiClient {
+    /// 分页获取直播间房管列表
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `room_id` | i64 | 直播间号 |
+    /// | `page` | i32 | 页码，从 1 开始 |
+    /// | `page_size` | i32 | 每页数量 |
+    pub async fn live_room_admin_list(
+        &self,
+        room_id: i64,
+        page: i32,
+        page_size: i32
+    ) -> Result<BpiResponse<RoomAdminListData>, BpiError> {
+        self
+            .get("https://api.live.bilibili.com/xlive/web-room/v1/roomAdmin/get_by_room")
+            .query(
+                &[
+                    ("roomid", room_id.to_string()),
+                    ("page", page.to_string()),
+                    ("page_size", page_size.to_string()),
+                ]
+            )
+            .send_bpi("获取直播间房管列表").await
+    }
+
+    /// 获取直播间全部房管
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `room_id` | i64 | 直播间号 |
+    pub async fn live_room_admins(&self, room_id: i64) -> Result<Vec<RoomAdmin>, BpiError> {
+        let mut admins = Vec::new();
+        let mut page = 1;
+        loop {
+            let data = self.live_room_admin_list(room_id, page, 20).await?.into_data()?;
+            let page_admins = data.data.unwrap_or_default();
+            let empty = page_admins.is_empty();
+            admins.extend(page_admins);
+            if empty || page >= data.page.total_page {
+                return Ok(admins);
+            }
+            page += 1;
+        }
+    }
+
+    /// 任命房管，只能操作自己的直播间
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `uid` | u64 | 被任命用户 mid |
+    pub async fn live_room_appoint_admin(&self, uid: u64) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        let csrf = self.csrf()?;
+
+        let form = [
+            ("admin", uid.to_string()),
+            ("admin_level", "1".to_string()),
+            ("csrf_token", csrf.clone()),
+            ("csrf", csrf),
+        ];
+
+        self
+            .post("https://api.live.bilibili.com/xlive/web-ucenter/v1/roomAdmin/appoint")
+            .form(&form)
+            .send_bpi("任命房管").await
+    }
+
+    /// 撤销房管，只能操作自己的直播间
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `uid` | u64 | 房管 mid |
+    pub async fn live_room_dismiss_admin(&self, uid: u64) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        let csrf = self.csrf()?;
+
+        let form = [
+            ("uid", uid.to_string()),
+            ("csrf_token", csrf.clone()),
+            ("csrf", csrf),
+        ];
+
+        self
+            .post("https://api.live.bilibili.com/xlive/web-ucenter/v1/roomAdmin/dismiss")
+            .form(&form)
+            .send_bpi("撤销房管").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_room_admin_list() {
+        let data: RoomAdminListData = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "page": { "page": 1, "page_size": 20, "total_page": 1, "total_count": 1 },
+                    "data": [{ "uid": 2, "uname": "碧诗", "face": "https://i0.hdslb.com/face.jpg", "ctime": "2024-01-01 00:00:00" }]
+                })
+            )
+            .unwrap();
+        assert_eq!(data.data.unwrap()[0].uid, 2);
+
+        let empty: RoomAdminListData = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "page": { "page": 1, "total_page": 0, "total_count": 0 },
+                    "data": null
+                })
+            )
+            .unwrap();
+        assert!(empty.data.is_none());
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_live_room_admins() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let admins = bpi.live_room_admins(3818081).await?;
+        tracing::info!("房管数: {}", admins.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "write"]
+    async fn test_live_room_appoint_dismiss_admin() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Write);
+        bpi.live_room_appoint_admin(316183842).await?;
+        bpi.live_room_dismiss_admin(316183842).await?;
+        Ok(())
+    }
+}
@@ -2,6 +2,28 @@ use serde::{ Deserialize, Serialize };
 
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 
+/// 禁言时长
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SilenceDuration {
+    /// 本场直播
+    CurrentLive,
+    /// 指定小时数
+    Hours(u32),
+    /// 永久
+    Permanent,
+}
+
+impl SilenceDuration {
+    /// 接口的 `hour` 参数
+    pub fn hour(self) -> i32 {
+        match self {
+            Self::CurrentLive => 0,
+            Self::Hours(hours) => hours.min(i32::MAX as u32) as i32,
+            Self::Permanent => -1,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Clone, Deserialize)]
 pub struct SilentUserInfo {
     /// 禁言者uid
@@ -67,6 +89,7 @@ impl BpiClient {
 
     /// 查询直播间禁言列表
     ///
+    /// `ps` 为页码，从 1 开始
     pub async fn live_list_silent_users(
         &self,
         room_id: i64,
@@ -87,6 +110,58 @@ impl BpiClient {
             .send_bpi("查询直播间禁言列表").await
     }
 
+    /// 禁言直播间观众，`uid` 可直接使用弹幕事件中的 [`DanmakuEvent::uid`](super::event::DanmakuEvent::uid)
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `room_id` | i64 | 直播间号 |
+    /// | `uid` | u64 | 被禁言用户 mid |
+    /// | `duration` | SilenceDuration | 禁言时长 |
+    pub async fn live_room_silence_user(
+        &self,
+        room_id: i64,
+        uid: u64,
+        duration: SilenceDuration
+    ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        self.live_add_silent_user(room_id, uid as i64, duration.hour()).await
+    }
+
+    /// 获取直播间全部禁言观众
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `room_id` | i64 | 直播间号 |
+    pub async fn live_room_silenced_users(&self, room_id: i64) -> Result<Vec<SilentUserInfo>, BpiError> {
+        let mut users = Vec::new();
+        let mut page = 1;
+        loop {
+            let data = self.live_list_silent_users(room_id, page).await?.into_data()?;
+            let empty = data.data.is_empty();
+            users.extend(data.data);
+            if empty || page >= data.total_page {
+                return Ok(users);
+            }
+            page += 1;
+        }
+    }
+
+    /// 解除直播间观众禁言
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `room_id` | i64 | 直播间号 |
+    /// | `uid` | u64 | 被禁言用户 mid |
+    pub async fn live_room_unsilence_user(
+        &self,
+        room_id: i64,
+        uid: u64
+    ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        self.live_del_block_user(room_id, uid as i64).await
+    }
+
     /// 解除禁言
     ///
     pub async fn live_del_block_user(
@@ -114,23 +189,42 @@ impl BpiClient {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_silence_duration_hour() {
+        assert_eq!(SilenceDuration::CurrentLive.hour(), 0);
+        assert_eq!(SilenceDuration::Hours(24).hour(), 24);
+        assert_eq!(SilenceDuration::Permanent.hour(), -1);
+    }
+
+    #[tokio::test]
+    #[ignore = "write"]
+    async fn test_live_room_silenced_users() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Write);
+        let users = bpi.live_room_silenced_users(3818081).await?;
+        tracing::info!("禁言观众数: {}", users.len());
+        Ok(())
+    }
+
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_get_silent_user_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi.live_list_silent_users(3818081, 1).await.unwrap();
         tracing::info!("{:?}", resp);
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_add_silent_user() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi.live_add_silent_user(3818081, 316183842, 0).await.unwrap();
         tracing::info!("{:?}", resp);
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_del_silent_user_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi.live_del_block_user(3818081, 316183842).await.unwrap();
         tracing::info!("{:?}", resp);
     }
@@ -0,0 +1,495 @@
+//! 直播消息持久化
+//!
+//! 将消息流中的事件连同房间号、接收时间写入本地文件，供录播、数据分析等场景留存。
+//!
+//! - [`JsonlSink`]：每个事件一行 JSON
+//! - [`SqliteSink`]：写入 SQLite 数据库，需启用 `live_sqlite` 特性
+//!
+//! 两者都支持按天或按文件大小轮转，写入先进入缓冲区（SQLite 为未提交的事务），
+//! 调用 [`EventSink::flush`] 或丢弃写入器时写入磁盘。
+//! [`persist`] 可直接挂在事件流上，在单独的线程中写入并定期落盘，不阻塞异步运行时。
+
+use std::fs::{ self, File, OpenOptions };
+use std::io::{ BufWriter, Write };
+use std::path::{ Path, PathBuf };
+use std::sync::mpsc::{ self, RecvTimeoutError };
+use std::time::{ Duration, Instant };
+
+use futures_util::{ Stream, StreamExt };
+use serde::Serialize;
+
+use crate::BpiError;
+
+/// 文件轮转策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    /// 始终写入同一文件
+    #[default]
+    Never,
+    /// 按本地日期每天一个文件，如 `room.2025-01-01.jsonl`
+    Daily,
+    /// 文件超过指定字节数后写入下一个文件，如 `room.1.jsonl`
+    Size(u64),
+}
+
+/// 持久化记录
+#[derive(Debug, Serialize)]
+pub struct SinkRecord<'a, T> {
+    /// 房间号
+    pub room_id: u64,
+    /// 接收时的毫秒级时间戳
+    pub received_at: i64,
+    pub event: &'a T,
+}
+
+/// 事件写入器
+pub trait EventSink<T> {
+    /// 写入一个事件
+    fn write(&mut self, room_id: u64, event: &T) -> Result<(), BpiError>;
+
+    /// 将缓冲内容写入磁盘
+    fn flush(&mut self) -> Result<(), BpiError> {
+        Ok(())
+    }
+}
+
+/// [`persist`] 的落盘间隔
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 按轮转策略计算当前应写入的文件
+#[derive(Debug)]
+struct RotatingPath {
+    base: PathBuf,
+    rotation: Rotation,
+    /// 当前文件对应的日期或序号
+    segment: Option<String>,
+    index: u32,
+}
+
+impl RotatingPath {
+    fn new(base: PathBuf, rotation: Rotation) -> Self {
+        Self {
+            base,
+            rotation,
+            segment: None,
+            index: 0,
+        }
+    }
+
+    fn with_segment(&self, segment: &str) -> PathBuf {
+        let stem = self.base
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let name = match self.base.extension() {
+            Some(ext) => format!("{}.{}.{}", stem, segment, ext.to_string_lossy()),
+            None => format!("{}.{}", stem, segment),
+        };
+        self.base.with_file_name(name)
+    }
+
+    /// 写入前检查是否需要切换文件，需要时返回新文件路径
+    ///
+    /// `written` 为当前文件已写入的字节数。
+    fn next(&mut self, written: u64) -> Option<PathBuf> {
+        match self.rotation {
+            Rotation::Never => {
+                if self.segment.is_some() {
+                    return None;
+                }
+                self.segment = Some(String::new());
+                Some(self.base.clone())
+            }
+            Rotation::Daily => {
+                let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+                if self.segment.as_deref() == Some(today.as_str()) {
+                    return None;
+                }
+                let path = self.with_segment(&today);
+                self.segment = Some(today);
+                Some(path)
+            }
+            Rotation::Size(max) => {
+                if self.segment.is_some() && written < max {
+                    return None;
+                }
+                if self.segment.is_some() {
+                    self.index += 1;
+                }
+                // 跳过已写满的旧文件，便于重启后续写
+                loop {
+                    let path = self.with_segment(&self.index.to_string());
+                    let len = fs::metadata(&path).map_or(0, |m| m.len());
+                    if len < max {
+                        self.segment = Some(self.index.to_string());
+                        return Some(path);
+                    }
+                    self.index += 1;
+                }
+            }
+        }
+    }
+}
+
+fn create_parent(path: &Path) -> Result<(), BpiError> {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => {
+            fs::create_dir_all(dir).map_err(|e| BpiError::io_at("创建目录", dir, e))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// JSON Lines 写入器
+///
+/// 写入经过缓冲，轮转、调用 [`EventSink::flush`] 或丢弃时写入文件。
+///
+/// ```no_run
+/// use bpi_rs::live::sink::{ EventSink, JsonlSink, Rotation };
+/// use bpi_rs::live::event::GiftEvent;
+///
+/// # fn run(gift: &GiftEvent) -> Result<(), bpi_rs::BpiError> {
+/// let mut sink = JsonlSink::new("logs/gift.jsonl").rotation(Rotation::Daily);
+/// sink.write(3818081, gift)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct JsonlSink {
+    path: RotatingPath,
+    writer: Option<BufWriter<File>>,
+    current: PathBuf,
+    written: u64,
+}
+
+impl JsonlSink {
+    /// 写入 `path`，文件不存在时创建，已存在时追加
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: RotatingPath::new(path.into(), Rotation::Never),
+            writer: None,
+            current: PathBuf::new(),
+            written: 0,
+        }
+    }
+
+    /// 设置轮转策略，默认不轮转
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.path.rotation = rotation;
+        self
+    }
+
+    /// 当前写入的文件，尚未写入时为空
+    pub fn current_path(&self) -> &Path {
+        &self.current
+    }
+
+    fn writer(&mut self) -> Result<&mut BufWriter<File>, BpiError> {
+        if let Some(path) = self.path.next(self.written) {
+            if let Some(mut old) = self.writer.take() {
+                old.flush().map_err(|e| BpiError::io_at("写入", &self.current, e))?;
+            }
+            create_parent(&path)?;
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .map_err(|e| BpiError::io_at("打开", &path, e))?;
+            self.written = file.metadata().map_or(0, |m| m.len());
+            self.writer = Some(BufWriter::new(file));
+            self.current = path;
+        }
+        Ok(self.writer.as_mut().expect("writer opened above"))
+    }
+}
+
+impl<T: Serialize> EventSink<T> for JsonlSink {
+    fn write(&mut self, room_id: u64, event: &T) -> Result<(), BpiError> {
+        let record = SinkRecord {
+            room_id,
+            received_at: chrono::Utc::now().timestamp_millis(),
+            event,
+        };
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+
+        let writer = self.writer()?;
+        writer.write_all(&line).map_err(|e| BpiError::io_at("写入", &self.current, e))?;
+        self.written += line.len() as u64;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), BpiError> {
+        if let Some(writer) = self.writer.as_mut() {
+            writer.flush().map_err(|e| BpiError::io_at("写入", &self.current, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// SQLite 写入器
+///
+/// 事件写入 `events` 表：`room_id`、`received_at`（毫秒）与 JSON 格式的 `payload`。
+/// 写入在事务中进行，轮转、调用 [`EventSink::flush`] 或丢弃时提交。
+#[cfg(feature = "live_sqlite")]
+#[derive(Debug)]
+pub struct SqliteSink {
+    path: RotatingPath,
+    conn: Option<rusqlite::Connection>,
+    current: PathBuf,
+    written: u64,
+    /// 是否有未提交的事务
+    in_transaction: bool,
+}
+
+#[cfg(feature = "live_sqlite")]
+impl SqliteSink {
+    /// 写入 `path` 数据库，不存在时创建
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: RotatingPath::new(path.into(), Rotation::Never),
+            conn: None,
+            current: PathBuf::new(),
+            written: 0,
+            in_transaction: false,
+        }
+    }
+
+    /// 设置轮转策略，默认不轮转；按大小轮转时以数据库文件大小计，只在提交后更新
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.path.rotation = rotation;
+        self
+    }
+
+    /// 当前写入的数据库文件，尚未写入时为空
+    pub fn current_path(&self) -> &Path {
+        &self.current
+    }
+
+    /// 按需轮转并打开数据库
+    fn open(&mut self) -> Result<(), BpiError> {
+        if let Some(path) = self.path.next(self.written) {
+            self.commit()?;
+            create_parent(&path)?;
+            let conn = rusqlite::Connection
+                ::open(&path)
+                .map_err(|e| BpiError::io_at("打开", &path, e))?;
+            conn
+                .execute_batch(
+                    "CREATE TABLE IF NOT EXISTS events (
+                        id INTEGER PRIMARY KEY AUTOINCREMENT,
+                        room_id INTEGER NOT NULL,
+                        received_at INTEGER NOT NULL,
+                        payload TEXT NOT NULL
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_events_room_time ON events (room_id, received_at);"
+                )
+                .map_err(|e| BpiError::io_at("初始化", &path, e))?;
+            self.conn = Some(conn);
+            self.current = path;
+        }
+        Ok(())
+    }
+
+    /// 提交未提交的事务
+    fn commit(&mut self) -> Result<(), BpiError> {
+        if !self.in_transaction {
+            return Ok(());
+        }
+        if let Some(conn) = &self.conn {
+            conn.execute_batch("COMMIT").map_err(|e| BpiError::io_at("提交", &self.current, e))?;
+        }
+        self.in_transaction = false;
+        self.written = fs::metadata(&self.current).map_or(0, |m| m.len());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "live_sqlite")]
+impl Drop for SqliteSink {
+    fn drop(&mut self) {
+        if let Err(e) = self.commit() {
+            tracing::warn!("{}", e);
+        }
+    }
+}
+
+#[cfg(feature = "live_sqlite")]
+impl<T: Serialize> EventSink<T> for SqliteSink {
+    fn write(&mut self, room_id: u64, event: &T) -> Result<(), BpiError> {
+        let payload = serde_json::to_string(event)?;
+        let received_at = chrono::Utc::now().timestamp_millis();
+
+        self.open()?;
+        let conn = self.conn.as_ref().expect("connection opened above");
+        if !self.in_transaction {
+            conn.execute_batch("BEGIN").map_err(|e| BpiError::io_at("写入", &self.current, e))?;
+            self.in_transaction = true;
+        }
+        conn
+            .execute(
+                "INSERT INTO events (room_id, received_at, payload) VALUES (?1, ?2, ?3)",
+                rusqlite::params![room_id as i64, received_at, payload]
+            )
+            .map_err(|e| BpiError::io_at("写入", &self.current, e))?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), BpiError> {
+        self.commit()
+    }
+}
+
+/// 将事件流中的每个事件写入 `sink` 后原样输出
+///
+/// 写入在单独的线程中进行，不阻塞异步运行时；每秒及事件流结束或被丢弃时落盘。
+/// 写入失败只记录日志，不影响事件流本身。
+///
+/// ```no_run
+/// # fn run(events: impl futures_util::Stream<Item = bpi_rs::live::event::GiftEvent>) {
+/// use bpi_rs::live::sink::{ JsonlSink, Rotation, persist };
+///
+/// let sink = JsonlSink::new("logs/gift.jsonl").rotation(Rotation::Size(64 << 20));
+/// let events = persist(events, 3818081, sink);
+/// # }
+/// ```
+pub fn persist<S, T, K>(events: S, room_id: u64, sink: K) -> impl Stream<Item = T>
+    where S: Stream<Item = T>, T: Serialize + Clone + Send + 'static, K: EventSink<T> + Send + 'static
+{
+    let (sender, receiver) = mpsc::channel::<T>();
+    std::thread::spawn(move || write_loop(receiver, room_id, sink));
+
+    events.map(move |event| {
+        // 写入线程只会在写入器 panic 时退出
+        if sender.send(event.clone()).is_err() {
+            tracing::warn!("直播间 {} 事件持久化线程已退出", room_id);
+        }
+        event
+    })
+}
+
+/// 写入线程：逐个写入，距上次落盘超过 [`FLUSH_INTERVAL`] 时落盘，发送端关闭后落盘并退出
+fn write_loop<T, K: EventSink<T>>(receiver: mpsc::Receiver<T>, room_id: u64, mut sink: K) {
+    let mut last_flush = Instant::now();
+    let mut dirty = false;
+
+    loop {
+        match receiver.recv_timeout(FLUSH_INTERVAL) {
+            Ok(event) => {
+                if let Err(e) = sink.write(room_id, &event) {
+                    tracing::warn!("直播间 {} 事件持久化失败: {}", room_id, e);
+                }
+                dirty = true;
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                break;
+            }
+        }
+
+        if dirty && last_flush.elapsed() >= FLUSH_INTERVAL {
+            if let Err(e) = sink.flush() {
+                tracing::warn!("直播间 {} 事件落盘失败: {}", room_id, e);
+            }
+            dirty = false;
+            last_flush = Instant::now();
+        }
+    }
+
+    if let Err(e) = sink.flush() {
+        tracing::warn!("直播间 {} 事件落盘失败: {}", room_id, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::live::event::SuperChatEvent;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bpi-sink-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn super_chat(id: u64) -> SuperChatEvent {
+        SuperChatEvent {
+            id,
+            uid: 2,
+            price: 30,
+            message: "测试".to_string(),
+            user_info: Default::default(),
+            start_time: 0,
+        }
+    }
+
+    #[test]
+    fn test_jsonl_sink_rotation() {
+        let dir = temp_dir("jsonl");
+        let mut sink = JsonlSink::new(dir.join("room.jsonl")).rotation(Rotation::Size(1));
+
+        for id in 0..3 {
+            sink.write(100, &super_chat(id)).unwrap();
+        }
+        assert_eq!(sink.current_path(), dir.join("room.2.jsonl"));
+        drop(sink);
+
+        let line = fs::read_to_string(dir.join("room.0.jsonl")).unwrap();
+        let value: serde_json::Value = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(value["room_id"], 100);
+        assert_eq!(value["event"]["id"], 0);
+        assert!(value["received_at"].as_i64().unwrap() > 0);
+
+        // 重启后跳过已写满的文件
+        let mut sink = JsonlSink::new(dir.join("room.jsonl")).rotation(Rotation::Size(1));
+        sink.write(100, &super_chat(3)).unwrap();
+        assert_eq!(sink.current_path(), dir.join("room.3.jsonl"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_persist() {
+        let dir = temp_dir("persist");
+        let path = dir.join("room.jsonl");
+
+        let events = futures_util::stream::iter((0..3).map(super_chat));
+        let ids: Vec<u64> = persist(events, 100, JsonlSink::new(&path))
+            .map(|e| e.id)
+            .collect().await;
+        assert_eq!(ids, vec![0, 1, 2]);
+
+        // 事件流结束后写入线程落盘
+        let mut lines = 0;
+        for _ in 0..100 {
+            lines = fs::read_to_string(&path).map_or(0, |s| s.lines().count());
+            if lines == 3 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert_eq!(lines, 3);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "live_sqlite")]
+    #[test]
+    fn test_sqlite_sink() {
+        let dir = temp_dir("sqlite");
+        let path = dir.join("room.db");
+        let mut sink = SqliteSink::new(&path);
+        for id in 0..3 {
+            sink.write(100, &super_chat(id)).unwrap();
+        }
+        EventSink::<SuperChatEvent>::flush(&mut sink).unwrap();
+
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM events WHERE room_id = 100", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 3);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
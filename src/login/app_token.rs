@@ -0,0 +1,95 @@
+//! APP 登录凭证刷新
+//!
+//! access_key 有效期通常为 180 天，过期前可用刷新令牌换取新的凭证，旧凭证随即失效。
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/login/login_action/README.md)
+
+use serde::{ Deserialize, Serialize };
+
+use super::login_action::tv_qr::TvCookieInfo;
+use crate::auth::AppToken;
+use crate::{ BilibiliRequest, BpiClient, BpiError };
+
+/// 刷新后的凭证
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppTokenInfo {
+    pub mid: u64,
+    pub access_token: String,
+    pub refresh_token: String,
+    /// 有效期（秒）
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppTokenRefreshData {
+    pub token_info: AppTokenInfo,
+    /// 同时下发的 Web Cookie
+    #[serde(default)]
+    pub cookie_info: Option<TvCookieInfo>,
+}
+
+impl AppTokenRefreshData {
+    /// 以原凭证的 appkey 组装新的 [`AppToken`]
+    pub fn to_app_token(&self, appkey: &str) -> AppToken {
+        AppToken {
+            access_key: self.token_info.access_token.clone(),
+            refresh_token: self.token_info.refresh_token.clone(),
+            appkey: appkey.to_string(),
+            mid: self.token_info.mid,
+            expires_at: chrono::Utc::now().timestamp() + self.token_info.expires_in,
+        }
+    }
+}
+
+impl BpiClient {
+    /// 刷新 APP 登录凭证（需已设置 [`AppToken`]）
+    ///
+    /// 刷新成功后新凭证写入客户端并返回；旧凭证失效，需要持久化时保存返回值。
+    pub async fn login_refresh_app_token(&self) -> Result<AppToken, BpiError> {
+        let token = self.require_app_token()?;
+        if token.app_key().is_none() {
+            return Err(BpiError::invalid_parameter("appkey", "未知的 appkey，无法签名"));
+        }
+
+        let data: AppTokenRefreshData = self
+            .post("https://passport.bilibili.com/x/passport-login/oauth2/refresh_token")
+            .form(&[("refresh_token", token.refresh_token.as_str())])
+            .with_access_key(&token)
+            .send_bpi::<AppTokenRefreshData>("刷新APP登录凭证").await?
+            .into_data()?;
+
+        let refreshed = data.to_app_token(&token.appkey);
+        self.set_app_token(refreshed.clone());
+        Ok(refreshed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::app_sign::AppKey;
+
+    #[test]
+    fn test_to_app_token() {
+        let data: AppTokenRefreshData = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "status": 0,
+                    "token_info": { "mid": 2, "access_token": "new", "refresh_token": "r", "expires_in": 15552000 }
+                })
+            )
+            .unwrap();
+
+        let token = data.to_app_token(AppKey::TV.appkey);
+        assert_eq!(token.app_key(), Some(AppKey::TV));
+        assert!(!token.expires_within(86400));
+        assert!(token.expires_within(15552000));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_requires_token() {
+        let bpi = BpiClient::builder().build().unwrap();
+        let err = bpi.login_refresh_app_token().await.unwrap_err();
+        assert!(matches!(err, BpiError::Api { code: 401, .. }));
+    }
+}
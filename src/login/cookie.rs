@@ -0,0 +1,161 @@
+//! 登录 Cookie 解析
+//!
+//! 扫码、短信等登录方式成功后，Web 登录信息都通过响应的 `Set-Cookie` 下发。
+//! 这里统一从中组装 [`Account`]，缺少 `buvid3` 时补充获取，并写入客户端。
+
+use reqwest::header::{ HeaderMap, SET_COOKIE };
+use serde::Deserialize;
+
+use crate::auth::{ parse_sessdata_expiry, Account, AccountSource };
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+#[derive(Debug, Deserialize)]
+struct BuvidData {
+    buvid: String,
+}
+
+/// 从响应头的 `Set-Cookie` 中取出 `name=value`
+pub(crate) fn set_cookie_pairs(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .get_all(SET_COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .filter_map(|v| v.split(';').next())
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+/// 从 Cookie 键值对组装账号信息，`buvid3` 为 Cookie 中没有时的默认值
+pub(crate) fn account_from_cookies<'a>(
+    cookies: impl IntoIterator<Item = (&'a str, &'a str)>,
+    buvid3: String
+) -> Account {
+    let mut account = Account::new(
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        buvid3
+    );
+
+    for (name, value) in cookies {
+        let value = value.to_string();
+        match name {
+            "DedeUserID" => {
+                account.dede_user_id = value;
+            }
+            "DedeUserID__ckMd5" => {
+                account.dede_user_id_ckmd5 = value;
+            }
+            "SESSDATA" => {
+                account.sessdata = value;
+            }
+            "bili_jct" => {
+                account.bili_jct = value;
+            }
+            "buvid3" => {
+                account.buvid3 = value;
+            }
+            _ => {}
+        }
+    }
+    account.sessdata_expires_at = parse_sessdata_expiry(&account.sessdata);
+
+    account
+}
+
+impl BpiClient {
+    /// 获取 buvid3
+    async fn login_fetch_buvid3(&self) -> Result<String, BpiError> {
+        let resp: BpiResponse<BuvidData> = self
+            .get("https://api.bilibili.com/x/web-frontend/getbuvid")
+            .send_bpi("获取 buvid3").await?;
+        Ok(resp.into_data()?.buvid)
+    }
+
+    /// 根据登录响应的 Cookie 组装账号信息并写入客户端
+    ///
+    /// Cookie 中没有 `buvid3` 时沿用当前账号的值，仍为空则重新获取。
+    /// `source` 为 `Some` 时视为新的登录，记录登录方式与时间；
+    /// 为 `None` 时视为刷新 Cookie，沿用当前账号的登录方式、登录时间与令牌。
+    pub(crate) async fn login_apply_cookies(
+        &self,
+        cookies: &[(String, String)],
+        source: Option<AccountSource>
+    ) -> Result<Account, BpiError> {
+        let current = self.account_snapshot();
+        let buvid3 = current
+            .as_ref()
+            .map(|acc| acc.buvid3.clone())
+            .unwrap_or_default();
+        let mut account = account_from_cookies(
+            cookies.iter().map(|(k, v)| (k.as_str(), v.as_str())),
+            buvid3
+        );
+        match (source, current) {
+            (Some(source), _) => {
+                account.source = Some(source);
+                account.login_at = Some(chrono::Utc::now().timestamp());
+            }
+            (None, Some(current)) => {
+                account.source = current.source;
+                account.login_at = current.login_at;
+                account.refresh_token = current.refresh_token.clone();
+                account.app_token = current.app_token.clone();
+            }
+            (None, None) => {}
+        }
+
+        if account.sessdata.is_empty() || account.bili_jct.is_empty() {
+            return Err(BpiError::auth("登录响应中缺少 SESSDATA 或 bili_jct"));
+        }
+        if account.buvid3.is_empty() {
+            account.buvid3 = self.login_fetch_buvid3().await?;
+        }
+
+        self.set_account(account.clone());
+        Ok(account)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_account_from_set_cookie() {
+        let mut headers = HeaderMap::new();
+        for cookie in [
+            "SESSDATA=a%2C1700000000%2Cb*11; Path=/; Domain=bilibili.com; HttpOnly",
+            "bili_jct=csrf; Path=/",
+            "DedeUserID=2",
+            "DedeUserID__ckMd5=md5",
+            "sid=x",
+        ] {
+            headers.append(SET_COOKIE, HeaderValue::from_static(cookie));
+        }
+
+        let cookies = set_cookie_pairs(&headers);
+        assert_eq!(cookies.len(), 5);
+
+        let account = account_from_cookies(
+            cookies.iter().map(|(k, v)| (k.as_str(), v.as_str())),
+            "buvid".to_string()
+        );
+        assert_eq!(account.sessdata, "a%2C1700000000%2Cb*11");
+        assert_eq!(account.sessdata_expires_at, Some(1700000000));
+        assert_eq!(account.bili_jct, "csrf");
+        assert_eq!(account.dede_user_id, "2");
+        assert_eq!(account.buvid3, "buvid");
+    }
+
+    #[tokio::test]
+    async fn test_login_apply_cookies_incomplete() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        let cookies = vec![("DedeUserID".to_string(), "2".to_string())];
+        assert!(bpi.login_apply_cookies(&cookies, Some(AccountSource::Qr)).await.is_err());
+        assert!(bpi.account_snapshot().is_none());
+    }
+}
@@ -1 +1,284 @@
-// https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/login/cookie_refresh.md
+//! Web 端 Cookie 刷新
+//!
+//! `SESSDATA` 等登录 Cookie 会过期，需要用登录时下发的 `refresh_token` 定期刷新。
+//! 流程：检查是否需要刷新 → 用公钥加密时间戳生成 `correspondPath` → 从页面获取 `refresh_csrf`
+//! → 刷新 Cookie → 确认刷新使旧 Cookie 失效。
+//!
+//! [参考文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/login/cookie_refresh.md)
+
+use std::ops::Deref;
+use std::time::Duration;
+
+use rsa::pkcs8::DecodePublicKey;
+use rsa::{ Oaep, RsaPublicKey };
+use serde::Deserialize;
+use tokio::task::AbortHandle;
+
+use crate::auth::Account;
+use crate::login::cookie::set_cookie_pairs;
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 生成 `correspondPath` 所用的公钥
+const CORRESPOND_PUBLIC_KEY: &str =
+    "-----BEGIN PUBLIC KEY-----
+MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQDLgd2OAkcGVtoE3ThUREbio0Eg
+Uc/prcajMKXvkCKFCWhJYJcLkcM2DKKcSeFpD/j6Boy538YXnR6VhcuUJOhH2x71
+nzPjfdTcqMz7djHum0qSZA0AyCBDABUqCrfNgCiJ00Ra7GmRj+YCK1NJEuewlb40
+JNrRuoEUXpabUzGB8QIDAQAB
+-----END PUBLIC KEY-----";
+
+/// 检查是否需要刷新的结果
+#[derive(Debug, Clone, Deserialize)]
+pub struct CookieInfoData {
+    /// 是否需要刷新
+    pub refresh: bool,
+    /// 当前毫秒时间戳，用于生成 `correspondPath`
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct CookieRefreshData {
+    refresh_token: String,
+}
+
+/// 刷新后的登录信息
+#[derive(Debug, Clone)]
+pub struct RefreshedCookies {
+    /// 新的账号信息，已写入客户端
+    pub account: Account,
+    /// 新的刷新令牌，已写入客户端，旧令牌失效
+    pub refresh_token: String,
+}
+
+/// 用公钥加密 `refresh_{timestamp}`，得到小写十六进制的 `correspondPath`
+pub fn correspond_path(timestamp: i64) -> Result<String, BpiError> {
+    let key = RsaPublicKey::from_public_key_pem(CORRESPOND_PUBLIC_KEY).map_err(|e|
+        BpiError::parse(format!("解析公钥失败: {}", e))
+    )?;
+    let encrypted = key
+        .encrypt(
+            &mut rsa::rand_core::OsRng,
+            Oaep::new::<sha2::Sha256>(),
+            format!("refresh_{}", timestamp).as_bytes()
+        )
+        .map_err(|e| BpiError::parse(format!("生成 correspondPath 失败: {}", e)))?;
+    Ok(hex::encode(encrypted))
+}
+
+/// 从 correspond 页面中取出 `<div id="1-name">` 的内容
+fn extract_refresh_csrf(html: &str) -> Option<&str> {
+    let start = html.find(r#"<div id="1-name">"#)? + r#"<div id="1-name">"#.len();
+    let len = html[start..].find("</div>")?;
+    Some(html[start..start + len].trim()).filter(|csrf| !csrf.is_empty())
+}
+
+impl BpiClient {
+    /// 检查 Cookie 是否需要刷新
+    ///
+    /// # 文档
+    /// [检查是否需要刷新](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/login/cookie_refresh.md#检查是否需要刷新)
+    pub async fn login_cookie_info(&self) -> Result<BpiResponse<CookieInfoData>, BpiError> {
+        let csrf = self.csrf()?;
+        self
+            .get("https://passport.bilibili.com/x/passport-login/web/cookie/info")
+            .query(&[("csrf", csrf)])
+            .send_bpi("检查 Cookie 是否需要刷新").await
+    }
+
+    /// 检查并在需要时刷新 Cookie
+    ///
+    /// 使用客户端中的刷新令牌（见 [`BpiClient::set_refresh_token`]），刷新成功后新的账号信息与
+    /// 刷新令牌都会写入客户端，旧的 Cookie 随即失效。无需刷新时返回 `None`。
+    pub async fn refresh_cookies(&self) -> Result<Option<RefreshedCookies>, BpiError> {
+        let info = self.login_cookie_info().await?.into_data()?;
+        if !info.refresh {
+            return Ok(None);
+        }
+        self.refresh_cookies_at(info.timestamp).await.map(Some)
+    }
+
+    /// 不检查是否需要刷新，直接刷新 Cookie
+    pub async fn refresh_cookies_force(&self) -> Result<RefreshedCookies, BpiError> {
+        self.refresh_cookies_at(chrono::Utc::now().timestamp_millis()).await
+    }
+
+    /// 按固定间隔检查并刷新 Cookie，直到 client 关闭（见 [`BpiClient::shutdown`]）
+    ///
+    /// 每次刷新成功后调用 `on_refresh`，可在其中持久化新的账号信息与刷新令牌；
+    /// 失败时记录日志并在下次继续。需要登记为后台任务时使用 [`BpiClient::spawn_cookie_refresh_loop`]。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `interval` | Duration | 检查间隔，建议 1 小时以上 |
+    /// | `on_refresh` | `FnMut(&RefreshedCookies)` | 刷新成功回调 |
+    pub async fn cookie_refresh_loop<F>(&self, interval: Duration, mut on_refresh: F)
+        where F: FnMut(&RefreshedCookies)
+    {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                biased;
+                _ = self.background_tasks().cancelled() => {
+                    tracing::debug!("客户端已关闭，停止刷新 Cookie");
+                    return;
+                }
+                _ = ticker.tick() => {}
+            }
+            match self.refresh_cookies().await {
+                Ok(Some(refreshed)) => {
+                    tracing::info!("Cookie 已刷新");
+                    on_refresh(&refreshed);
+                }
+                Ok(None) => tracing::debug!("Cookie 无需刷新"),
+                Err(e) => tracing::warn!("刷新 Cookie 失败: {}", e),
+            }
+        }
+    }
+
+    /// 在 client 的后台任务登记表中启动 [`cookie_refresh_loop`](Self::cookie_refresh_loop)
+    ///
+    /// `client` 可为 [`BpiClient::new`] 返回的单例或 `Arc<BpiClient>`，
+    /// [`BpiClient::shutdown`] 时任务随之结束。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// # async fn run() {
+    /// use std::time::Duration;
+    /// use bpi_rs::BpiClient;
+    ///
+    /// let bpi = BpiClient::new();
+    /// BpiClient::spawn_cookie_refresh_loop(bpi, Duration::from_secs(6 * 3600), |refreshed| {
+    ///     println!("新的刷新令牌: {}", refreshed.refresh_token);
+    /// });
+    /// # }
+    /// ```
+    pub fn spawn_cookie_refresh_loop<C, F>(client: C, interval: Duration, on_refresh: F) -> AbortHandle
+        where
+            C: Deref<Target = BpiClient> + Send + Sync + 'static,
+            F: FnMut(&RefreshedCookies) + Send + 'static
+    {
+        let tasks = client.background_tasks().clone();
+        tasks.spawn("cookie_refresh", async move {
+            client.cookie_refresh_loop(interval, on_refresh).await;
+        })
+    }
+
+    async fn refresh_cookies_at(&self, timestamp: i64) -> Result<RefreshedCookies, BpiError> {
+        let old_account = self.account_snapshot().ok_or_else(|| BpiError::auth("未登录"))?;
+        let old_token = self
+            .refresh_token()
+            .ok_or(BpiError::invalid_parameter("refresh_token", "未设置 Cookie 刷新令牌"))?;
+
+        // 获取 refresh_csrf
+        let path = correspond_path(timestamp)?;
+        let html = self
+            .get(&format!("https://www.bilibili.com/correspond/1/{}", path))
+            .send().await?
+            .text().await?;
+        let refresh_csrf = extract_refresh_csrf(&html)
+            .ok_or_else(|| BpiError::parse("correspond 页面中缺少 refresh_csrf"))?
+            .to_string();
+
+        // 刷新 Cookie
+        let response = self
+            .post("https://passport.bilibili.com/x/passport-login/web/cookie/refresh")
+            .form(
+                &[
+                    ("csrf", old_account.bili_jct.as_str()),
+                    ("refresh_csrf", refresh_csrf.as_str()),
+                    ("source", "main_web"),
+                    ("refresh_token", old_token.as_str()),
+                ]
+            )
+            .send().await?;
+        let new_cookies = set_cookie_pairs(response.headers());
+        let resp: BpiResponse<CookieRefreshData> = response.json().await?;
+        if resp.code != 0 {
+            return Err(BpiError::from_code_message(resp.code, resp.message));
+        }
+        let refresh_token = resp.into_data()?.refresh_token;
+
+        // 响应中未下发的 Cookie 沿用旧值
+        let mut cookies: Vec<(String, String)> = [
+            ("DedeUserID", &old_account.dede_user_id),
+            ("DedeUserID__ckMd5", &old_account.dede_user_id_ckmd5),
+            ("buvid3", &old_account.buvid3),
+        ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect();
+        cookies.extend(new_cookies);
+        let account = self.login_apply_cookies(&cookies, None).await?;
+        self.set_refresh_token(refresh_token.clone());
+
+        // 确认刷新，使旧 Cookie 失效
+        let _: BpiResponse<serde_json::Value> = self
+            .post("https://passport.bilibili.com/x/passport-login/web/confirm/refresh")
+            .form(
+                &[
+                    ("csrf", account.bili_jct.as_str()),
+                    ("refresh_token", old_token.as_str()),
+                ]
+            )
+            .send_bpi("确认刷新 Cookie").await?;
+
+        Ok(RefreshedCookies { account, refresh_token })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_cookie_refresh_loop_cancelled() {
+        let bpi = Arc::new(BpiClient::try_new_local().unwrap());
+        BpiClient::spawn_cookie_refresh_loop(bpi.clone(), Duration::from_secs(3600), |_| {});
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(bpi.background_tasks().active(), ["cookie_refresh"]);
+
+        tokio::time::timeout(Duration::from_secs(1), bpi.shutdown()).await.unwrap();
+        assert!(bpi.background_tasks().active().is_empty());
+
+        // 直接调用时同样在关闭后返回
+        tokio::time::timeout(Duration::from_secs(1), bpi.cookie_refresh_loop(Duration::from_secs(3600), |_| {}))
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_correspond_path() {
+        let path = correspond_path(1684466082773).unwrap();
+        assert_eq!(path.len(), 256);
+        assert!(path.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+    }
+
+    #[test]
+    fn test_extract_refresh_csrf() {
+        let html = r#"<html><body><div id="1-name">b0cc8411ded2f9db2cff2edb3123acac</div></body></html>"#;
+        assert_eq!(extract_refresh_csrf(html), Some("b0cc8411ded2f9db2cff2edb3123acac"));
+        assert_eq!(extract_refresh_csrf("<div></div>"), None);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_without_token() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        bpi.set_account_from_cookie_str("DedeUserID=1;DedeUserID__ckMd5=md5;SESSDATA=sess;bili_jct=jct;buvid3=buvid");
+        let err = bpi.refresh_cookies_at(0).await.unwrap_err();
+        assert!(matches!(err, BpiError::InvalidParameter { field: "refresh_token", .. }));
+    }
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_login_cookie_info() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Authed);
+        let info = bpi.login_cookie_info().await?.into_data()?;
+        tracing::info!("需要刷新: {}, 时间戳: {}", info.refresh, info.timestamp);
+        Ok(())
+    }
+}
@@ -49,8 +49,9 @@ impl BpiClient {
 }
 
 #[tokio::test]
+#[ignore = "guest"]
 async fn test_generate_captcha() {
-    let bpi = BpiClient::new();
+    let bpi = crate::test_client!(Guest);
     match bpi.login_generate_captcha().await {
         Ok(captcha) => {
             tracing::info!("验证码请求成功！");
@@ -1,5 +1,6 @@
 pub mod captcha;
 pub mod password;
 pub mod qr;
+pub mod tv_qr;
 
 pub mod sms;
@@ -1,5 +1,9 @@
+use std::time::Duration;
+
+use futures_util::Stream;
 use serde::{ Deserialize, Serialize };
 
+use crate::auth::{ Account, AccountSource };
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 
 /// 生成 QRCode 数据
@@ -25,6 +29,10 @@ pub struct CheckQrCodeStatusData {
     /// sessdata
     #[serde(default)]
     pub cookies: Vec<(String, String)>,
+
+    /// 由 cookie 组装的账号信息，扫码成功后已写入客户端
+    #[serde(skip)]
+    pub account: Option<Account>,
 }
 
 /// 二维码图片数据
@@ -34,6 +42,26 @@ pub struct QrcodeImageData {
     pub expires_in: u64, // 过期时间（秒）
 }
 
+/// 二维码登录状态
+#[derive(Debug, Clone)]
+pub enum QrLoginState {
+    /// 未扫码
+    WaitingScan,
+    /// 已扫码，等待手机端确认
+    Scanned,
+    /// 二维码已失效（有效期 180 秒）
+    Expired,
+    /// 登录成功，账号信息与 Cookie 刷新令牌已写入客户端
+    Success(Box<Account>),
+}
+
+impl QrLoginState {
+    /// 是否为终止状态，之后无需继续轮询
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Expired | Self::Success(_))
+    }
+}
+
 impl BpiClient {
     /// 发送二维码请求
     pub async fn login_send_qrcode(&self) -> Result<BpiResponse<GenerateQrCodeData>, BpiError> {
@@ -43,9 +71,150 @@ impl BpiClient {
     }
 
     /// 检查二维码状态
+    ///
+    /// 扫码确认后自动从响应 cookie 组装账号信息并调用 `set_account`，
+    /// 账号信息见返回数据的 `account` 字段。
     pub async fn login_check_qrcode_status(
         &self,
         qrcode_key: &str
+    ) -> Result<BpiResponse<CheckQrCodeStatusData>, BpiError> {
+        let mut qr_response = self.login_poll_qrcode(qrcode_key).await?;
+
+        if let Some(ref mut data) = qr_response.data {
+            if data.code == 0 {
+                let mut account = self.login_apply_cookies(&data.cookies, Some(AccountSource::Qr)).await?;
+                self.set_refresh_token(data.refresh_token.clone());
+                account.refresh_token = Some(data.refresh_token.clone());
+                data.account = Some(account);
+                Ok(qr_response)
+            } else {
+                Err(BpiError::from_code_message(data.code, data.message.clone()))
+            }
+        } else {
+            Err(BpiError::missing_data())
+        }
+    }
+
+    /// 查询二维码登录状态
+    ///
+    /// 与 [`login_check_qrcode_status`](Self::login_check_qrcode_status) 不同，
+    /// 未扫码、未确认与失效都作为状态返回而非错误；登录成功时同样会写入账号信息。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `qrcode_key` | &str | 扫码登录标识 |
+    pub async fn login_qrcode_state(&self, qrcode_key: &str) -> Result<QrLoginState, BpiError> {
+        let data = self.login_poll_qrcode(qrcode_key).await?.into_data()?;
+
+        match data.code {
+            0 => {
+                let mut account = self.login_apply_cookies(&data.cookies, Some(AccountSource::Qr)).await?;
+                self.set_refresh_token(data.refresh_token.clone());
+                account.refresh_token = Some(data.refresh_token);
+                Ok(QrLoginState::Success(Box::new(account)))
+            }
+            86101 => Ok(QrLoginState::WaitingScan),
+            86090 => Ok(QrLoginState::Scanned),
+            86038 => Ok(QrLoginState::Expired),
+            code => Err(BpiError::from_code_message(code, data.message)),
+        }
+    }
+
+    /// 按固定间隔轮询二维码状态，状态变化时输出，到达终止状态后结束
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `qrcode_key` | &str | 扫码登录标识 |
+    /// | `interval` | Duration | 轮询间隔，建议 1~3 秒 |
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+    /// use std::time::Duration;
+    /// use futures_util::StreamExt;
+    /// use bpi_rs::login::login_action::qr::QrLoginState;
+    ///
+    /// let qr = bpi.login_send_qrcode().await?.into_data()?;
+    /// println!("请扫码: {}", qr.url);
+    ///
+    /// let states = bpi.login_qrcode_states(&qr.qrcode_key, Duration::from_secs(2));
+    /// futures_util::pin_mut!(states);
+    /// while let Some(state) = states.next().await {
+    ///     match state? {
+    ///         QrLoginState::Scanned => println!("已扫码，请在手机上确认"),
+    ///         QrLoginState::Success(account) => println!("登录成功: {}", account.dede_user_id),
+    ///         QrLoginState::Expired => println!("二维码已失效"),
+    ///         QrLoginState::WaitingScan => {}
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn login_qrcode_states<'a>(
+        &'a self,
+        qrcode_key: &'a str,
+        interval: Duration
+    ) -> impl Stream<Item = Result<QrLoginState, BpiError>> + 'a {
+        // (上次输出的状态码, 是否结束)
+        futures_util::stream::unfold((None, false), move |(last, done)| async move {
+            if done {
+                return None;
+            }
+
+            loop {
+                let state = match self.login_qrcode_state(qrcode_key).await {
+                    Ok(state) => state,
+                    Err(e) => {
+                        return Some((Err(e), (last, true)));
+                    }
+                };
+
+                let kind = std::mem::discriminant(&state);
+                if state.is_finished() || last != Some(kind) {
+                    let finished = state.is_finished();
+                    return Some((Ok(state), (Some(kind), finished)));
+                }
+                tokio::time::sleep(interval).await;
+            }
+        })
+    }
+
+    /// 轮询直到登录成功，返回写入客户端的账号信息
+    ///
+    /// 二维码失效时返回错误码 86038。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `qrcode_key` | &str | 扫码登录标识 |
+    /// | `interval` | Duration | 轮询间隔 |
+    pub async fn login_qrcode_wait(
+        &self,
+        qrcode_key: &str,
+        interval: Duration
+    ) -> Result<Account, BpiError> {
+        loop {
+            match self.login_qrcode_state(qrcode_key).await? {
+                QrLoginState::Success(account) => {
+                    return Ok(*account);
+                }
+                QrLoginState::Expired => {
+                    return Err(BpiError::from_code_message(86038, "二维码已失效".to_string()));
+                }
+                QrLoginState::WaitingScan | QrLoginState::Scanned => {
+                    tokio::time::sleep(interval).await;
+                }
+            }
+        }
+    }
+
+    /// 请求扫码状态，附带响应中的 cookie
+    async fn login_poll_qrcode(
+        &self,
+        qrcode_key: &str
     ) -> Result<BpiResponse<CheckQrCodeStatusData>, BpiError> {
         let response = self
             .get("https://passport.bilibili.com/x/passport-login/web/qrcode/poll")
@@ -61,20 +230,14 @@ impl BpiClient {
             .json().await
             .map_err(|e| BpiError::parse(e.to_string()))?;
 
-        if qr_response.code == 0 {
-            if let Some(ref mut data) = qr_response.data {
-                if data.code == 0 {
-                    data.cookies = cookies;
-                    Ok(qr_response)
-                } else {
-                    Err(BpiError::from_code_message(data.code, data.message.clone()))
-                }
-            } else {
-                Err(BpiError::missing_data())
-            }
-        } else {
-            Err(BpiError::from_code(qr_response.code))
+        if qr_response.code != 0 {
+            return Err(BpiError::from_code(qr_response.code));
+        }
+        if let Some(ref mut data) = qr_response.data {
+            data.cookies = cookies;
         }
+
+        Ok(qr_response)
     }
 }
 
@@ -84,12 +247,25 @@ mod tests {
     use tokio;
 
     #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_login_qrcode_state() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let qr = bpi.login_send_qrcode().await?.into_data()?;
+
+        let state = bpi.login_qrcode_state(&qr.qrcode_key).await?;
+        assert!(matches!(state, QrLoginState::WaitingScan));
+        assert!(!state.is_finished());
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
     async fn test_send_qrcode() {
         use tokio::time::{ Duration, sleep };
 
         tracing::info!("获取二维码...");
 
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         match bpi.login_send_qrcode().await {
             Ok(response) => {
                 tracing::info!("Code: {}", response.code);
@@ -1,8 +1,8 @@
-use reqwest::header::SET_COOKIE;
-
 use serde::{Deserialize, Serialize};
 use tracing::{error, info};
 
+use crate::auth::{ Account, AccountSource };
+use crate::login::cookie::set_cookie_pairs;
 use crate::{BilibiliRequest, BpiClient, BpiError, BpiResponse};
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -61,6 +61,8 @@ impl BpiClient {
 
     /// 短信登录
     ///
+    /// 登录成功后自动从响应 cookie 组装账号信息并调用 `set_account`。
+    ///
     /// * `cid` - 国际冠字码
     /// * `tel` - 手机号码
     /// * `captcha_key` - 短信登录 token(基于login_send_sms_code)
@@ -71,7 +73,7 @@ impl BpiClient {
         tel: u32,
         captcha_key: &str,
         code: &str,
-    ) -> Result<(), String> {
+    ) -> Result<Account, BpiError> {
         let form = vec![
             ("cid", cid.to_string()),
             ("tel", tel.to_string()),
@@ -82,54 +84,35 @@ impl BpiClient {
             ("keep", true.to_string()),
         ];
 
-        let response = BpiClient::new()
+        let response = self
             .post("https://passport.bilibili.com/x/passport-login/web/login/sms")
             .form(&form)
             .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .await?;
 
-        if let Some(cookies) = response
-            .headers()
-            .get_all(SET_COOKIE)
-            .iter()
-            .map(|v| v.to_str().unwrap_or(""))
-            .collect::<Vec<_>>()
-            .join("; ")
-            .into()
-        {
-            info!("登录返回的 Cookie: {}", cookies);
-        }
+        let cookies = set_cookie_pairs(response.headers());
 
         let resp = response
             .json::<BpiResponse<SMSLoginData>>()
             .await
             .map_err(|e| {
                 error!("解析短信登录响应失败: {:?}", e);
-                e.to_string()
+                BpiError::parse(e.to_string())
             })?;
 
         if resp.code != 0 {
             error!("短信登录失败: code={}, message={}", resp.code, resp.message);
-            return Err(resp.message);
+            let msg = match resp.code {
+                -400 => "请求错误".to_string(),
+                1006 => "请输入正确的短信验证码".to_string(),
+                1007 => "短信验证码已过期".to_string(),
+                _ => resp.message,
+            };
+            return Err(BpiError::from_code_message(resp.code, msg));
         }
 
-        match resp.code {
-            0 => {
-                info!("短信登录成功");
-                Ok(())
-            }
-            code => {
-                error!("验证码发送失败: code={}, message={}", code, resp.message);
-                let msg = match code {
-                    -400 => "请求错误".to_string(),
-                    1006 => "请输入正确的短信验证码".to_string(),
-                    1007 => "短信验证码已过期".to_string(),
-
-                    _ => resp.message,
-                };
-                Err(msg)
-            }
-        }
+        let account = self.login_apply_cookies(&cookies, Some(AccountSource::Sms)).await?;
+        info!("短信登录成功");
+        Ok(account)
     }
 }
@@ -0,0 +1,231 @@
+//! TV 端扫码登录
+//!
+//! 以云视听小电视的身份申请二维码，扫码确认后直接返回 APP `access_key` 与 Web Cookie。
+//! 两个接口都需要 APP 签名，见 [`AppKey::TV`]。得到的 access_key 可用于只接受 access_key 的 APP 接口。
+//!
+//! ```no_run
+//! # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+//! use std::time::Duration;
+//!
+//! let data = bpi.login_tv_qrcode(Duration::from_secs(2), |qr| println!("请扫码: {}", qr.url)).await?;
+//! println!("mid: {}, access_key: {}", data.mid, data.access_token);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/login/login_action/QR.md)
+
+use std::time::Duration;
+
+use serde::{ Deserialize, Serialize };
+
+use crate::auth::{ Account, AccountSource, AppToken };
+use crate::login::sso::AccessKeyInfo;
+use crate::utils::app_sign::AppKey;
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// TV 端二维码
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TvQrCodeData {
+    /// 二维码内容
+    pub url: String,
+    /// 扫码登录标识
+    pub auth_code: String,
+}
+
+/// TV 端登录结果
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TvLoginData {
+    pub mid: u64,
+    /// APP 登录凭证，调用接口时需以 [`AppKey::TV`] 签名
+    pub access_token: String,
+    pub refresh_token: String,
+    /// 有效期（秒）
+    pub expires_in: u64,
+    /// Web Cookie
+    #[serde(default)]
+    pub cookie_info: Option<TvCookieInfo>,
+
+    /// 由 Cookie 组装的账号信息，仅 [`BpiClient::login_tv_qrcode`] 写入客户端后存在
+    #[serde(skip)]
+    pub account: Option<Account>,
+}
+
+impl TvLoginData {
+    /// Cookie 名称与值
+    pub fn cookie_pairs(&self) -> Vec<(String, String)> {
+        self.cookie_info
+            .iter()
+            .flat_map(|info| &info.cookies)
+            .map(|c| (c.name.clone(), c.value.clone()))
+            .collect()
+    }
+
+    /// APP 登录凭证，可用 [`BpiClient::set_app_token`] 写入客户端
+    pub fn app_token(&self) -> AppToken {
+        AppToken {
+            access_key: self.access_token.clone(),
+            refresh_token: self.refresh_token.clone(),
+            appkey: AppKey::TV.appkey.to_string(),
+            mid: self.mid,
+            expires_at: chrono::Utc::now().timestamp() + (self.expires_in as i64),
+        }
+    }
+
+    /// APP 登录凭证，签名时使用 [`AppKey::TV`]
+    pub fn access_key_info(&self) -> AccessKeyInfo {
+        AccessKeyInfo {
+            access_key: self.access_token.clone(),
+            mid: self.mid,
+            appkey: AppKey::TV.appkey.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TvCookieInfo {
+    pub cookies: Vec<TvCookie>,
+    #[serde(default)]
+    pub domains: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TvCookie {
+    pub name: String,
+    pub value: String,
+    #[serde(default)]
+    pub http_only: i32,
+    /// 过期时间戳
+    #[serde(default)]
+    pub expires: i64,
+}
+
+/// TV 端扫码状态
+#[derive(Debug, Clone)]
+pub enum TvQrLoginState {
+    /// 未扫码
+    WaitingScan,
+    /// 已扫码，等待手机端确认
+    Scanned,
+    /// 二维码已失效
+    Expired,
+    /// 登录成功
+    Success(Box<TvLoginData>),
+}
+
+impl BpiClient {
+    /// 申请 TV 端登录二维码
+    ///
+    /// # 文档
+    /// [TV端扫码登录](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/login/login_action/QR.md#tv端扫码登录)
+    pub async fn login_tv_send_qrcode(&self) -> Result<BpiResponse<TvQrCodeData>, BpiError> {
+        self
+            .post("https://passport.bilibili.com/x/passport-tv-login/qrcode/auth_code")
+            .form(&[("local_id", "0")])
+            .with_app_sign(AppKey::TV)
+            .send_bpi("申请TV端登录二维码").await
+    }
+
+    /// 查询 TV 端扫码状态
+    ///
+    /// 登录结果不会写入客户端，可按需使用其中的 Cookie 或 access_key。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `auth_code` | &str | 扫码登录标识 |
+    pub async fn login_tv_qrcode_state(&self, auth_code: &str) -> Result<TvQrLoginState, BpiError> {
+        let result = self
+            .post("https://passport.bilibili.com/x/passport-tv-login/qrcode/poll")
+            .form(&[("auth_code", auth_code), ("local_id", "0")])
+            .with_app_sign(AppKey::TV)
+            .send_bpi::<TvLoginData>("查询TV端扫码状态").await;
+
+        match result {
+            Ok(resp) => Ok(TvQrLoginState::Success(Box::new(resp.into_data()?))),
+            Err(e) =>
+                match e.code() {
+                    Some(86039) => Ok(TvQrLoginState::WaitingScan),
+                    Some(86090) => Ok(TvQrLoginState::Scanned),
+                    Some(86038) => Ok(TvQrLoginState::Expired),
+                    _ => Err(e),
+                }
+        }
+    }
+
+    /// TV 端扫码登录：申请二维码、轮询直到确认，并将 Cookie 与 APP 登录凭证写入客户端
+    ///
+    /// 二维码失效时返回错误码 86038。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `interval` | Duration | 轮询间隔，建议 1~3 秒 |
+    /// | `on_qrcode` | `FnOnce(&TvQrCodeData)` | 拿到二维码后调用，用于展示二维码 |
+    pub async fn login_tv_qrcode(
+        &self,
+        interval: Duration,
+        on_qrcode: impl FnOnce(&TvQrCodeData)
+    ) -> Result<TvLoginData, BpiError> {
+        let qr = self.login_tv_send_qrcode().await?.into_data()?;
+        on_qrcode(&qr);
+
+        loop {
+            match self.login_tv_qrcode_state(&qr.auth_code).await? {
+                TvQrLoginState::Success(data) => {
+                    let mut data = *data;
+                    data.account = Some(self.login_apply_cookies(&data.cookie_pairs(), Some(AccountSource::Tv)).await?);
+                    self.set_app_token(data.app_token());
+                    return Ok(data);
+                }
+                TvQrLoginState::Expired => {
+                    return Err(BpiError::from_code_message(86038, "二维码已失效".to_string()));
+                }
+                TvQrLoginState::WaitingScan | TvQrLoginState::Scanned => {
+                    tokio::time::sleep(interval).await;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tv_login_data() {
+        let data: TvLoginData = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "is_new": false, "mid": 2, "access_token": "token", "refresh_token": "refresh",
+                    "expires_in": 15552000,
+                    "cookie_info": {
+                        "cookies": [
+                            { "name": "SESSDATA", "value": "s", "http_only": 1, "expires": 1 },
+                            { "name": "bili_jct", "value": "j", "http_only": 0, "expires": 1 }
+                        ],
+                        "domains": [".bilibili.com"]
+                    }
+                })
+            )
+            .unwrap();
+
+        assert_eq!(data.cookie_pairs()[1], ("bili_jct".to_string(), "j".to_string()));
+        let key = data.access_key_info();
+        assert_eq!((key.mid, key.appkey.as_str()), (2, "4409e2ce8ffd12b8"));
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_login_tv_qrcode() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let qr = bpi.login_tv_send_qrcode().await?.into_data()?;
+        tracing::info!("{:#?}", qr);
+        assert!(!qr.auth_code.is_empty());
+
+        let state = bpi.login_tv_qrcode_state(&qr.auth_code).await?;
+        assert!(matches!(state, TvQrLoginState::WaitingScan));
+        Ok(())
+    }
+}
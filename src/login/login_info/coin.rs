@@ -24,11 +24,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_coin() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         match bpi.login_info_coin().await {
             Ok(resp) => {
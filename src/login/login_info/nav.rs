@@ -188,9 +188,10 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "authed"]
     /// 测试登录
     async fn test_bilibili_uinfo() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         let resp = bpi.login_info_nav_info().await?;
 
@@ -203,8 +204,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_user_info() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         let user_info = bpi.login_info_user_info().await?;
 
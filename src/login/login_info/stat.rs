@@ -28,11 +28,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_user_stat() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         match bpi.login_info_user_stat().await {
             Ok(resp) => {
@@ -1,4 +1,5 @@
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use crate::utils::web_location::WebLocation;
 use serde::{ Deserialize, Serialize };
 use std::collections::HashMap;
 
@@ -67,8 +68,8 @@ impl BpiClient {
             .get("https://api.bilibili.com/x/member/web/login/log")
             .query(
                 &[
-                    ("jsonp", "jsonp"),
-                    ("web_location", "333.33"),
+                    ("jsonp", "jsonp".to_string()),
+                    ("web_location", self.web_location(WebLocation::ACCOUNT)),
                 ]
             )
             .send_bpi("查询最近一周登录情况").await
@@ -80,8 +81,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_login_notice() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let mid = 4279370;
 
         let resp = bpi.login_notice(mid, None).await?;
@@ -100,8 +102,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_login_log() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         let resp = bpi.login_log().await?;
         let data = resp.into_data()?;
@@ -55,11 +55,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_account_info() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         match bpi.member_center_account_info().await {
             Ok(resp) => {
@@ -18,11 +18,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_today_coin_exp() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         match bpi.member_center_today_coin_exp().await {
             Ok(resp) => {
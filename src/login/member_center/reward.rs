@@ -56,8 +56,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_daily_reward() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         let result = bpi.member_center_daily_reward().await?;
         println!("{:?}", result);
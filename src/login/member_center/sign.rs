@@ -41,9 +41,10 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "write"]
     /// 更新签名测试
     async fn test_update_user_sign() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         // 测试修改签名
         let test_sign = "这是一个测试签名 - Powered by Rust";
@@ -53,8 +54,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_sign_length_validation() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         // 测试超长签名
         let long_sign = "a".repeat(71);
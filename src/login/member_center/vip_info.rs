@@ -51,12 +51,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_vip_info() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         match bpi.member_center_vip_info().await {
             Ok(resp) => {
@@ -81,8 +81,9 @@ mod tests {
         }
     }
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_is_vip() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         match bpi.is_vip().await {
             true => info!("是大会员"),
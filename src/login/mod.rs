@@ -1,9 +1,12 @@
 //! 登录相关
 
+pub mod app_token;
+mod cookie;
 pub mod cookie_refresh;
 pub mod exit;
 
 pub mod login_action;
 pub mod login_info;
 pub mod login_notice;
+pub mod sso;
 pub mod member_center;
@@ -0,0 +1,223 @@
+//! Web Cookie 与 APP access_key 互换
+//!
+//! - Cookie → access_key：以当前 Cookie 请求 `login/app/third` 获取确认地址，访问确认地址后
+//!   从重定向地址中取出 `access_key`，之后即可调用需要 APP 签名的接口，无需重新登录。
+//! - access_key → Cookie：携带 access_key 请求 SSO 接口，从响应的 `Set-Cookie` 中取出 Web 登录信息。
+//!
+//! 两步都依赖重定向响应本身，因此使用不跟随重定向的请求。
+
+use std::collections::BTreeMap;
+
+use reqwest::{ RequestBuilder, Url, header };
+use serde::{ Deserialize, Serialize };
+
+use super::cookie::{ account_from_cookies, set_cookie_pairs };
+use crate::auth::{ Account, AccountSource };
+use crate::utils::app_sign::{ AppKey, app_sign };
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 授权回调地址，仅用于承载重定向参数
+const THIRD_LOGIN_API: &str = "https://www.bilibili.com/";
+
+/// 第三方授权信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThirdLoginData {
+    /// 回调地址
+    pub api: String,
+    /// 确认授权地址
+    pub confirm_uri: String,
+    /// 是否已登录 0:否 1:是
+    #[serde(default)]
+    pub has_login: u8,
+    /// 是否可直接授权 0:否 1:是
+    #[serde(default)]
+    pub direct_login: u8,
+}
+
+/// APP 登录凭证
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessKeyInfo {
+    pub access_key: String,
+    pub mid: u64,
+    /// 签发该凭证的 appkey，调用 APP 接口时需用同一组 appkey 签名
+    pub appkey: String,
+}
+
+impl BpiClient {
+    /// 不跟随重定向的 GET 请求，携带当前账号 Cookie
+    fn no_redirect_get(&self, url: &str) -> RequestBuilder {
+        let mut req = self.get_no_redirect(url);
+        if let Some(account) = self.account_snapshot() {
+            req = req.header(header::COOKIE, account.cookie_header());
+        }
+        req
+    }
+
+    /// 获取第三方授权确认地址（需登录）。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `key` | AppKey | 授权的 APP |
+    /// | `api` | &str | 授权后重定向的回调地址 |
+    pub async fn login_app_third(
+        &self,
+        key: AppKey,
+        api: &str
+    ) -> Result<BpiResponse<ThirdLoginData>, BpiError> {
+        self
+            .get("https://passport.bilibili.com/login/app/third")
+            .query(&[("api", api)])
+            .with_app_sign(key)
+            .send_bpi("获取第三方授权地址").await
+    }
+
+    /// 访问授权确认地址，从重定向地址中取出 access_key（需登录）。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `key` | AppKey | 授权的 APP，需与获取确认地址时一致 |
+    /// | `confirm_uri` | &str | [`ThirdLoginData::confirm_uri`] |
+    pub async fn login_confirm_access_key(
+        &self,
+        key: AppKey,
+        confirm_uri: &str
+    ) -> Result<AccessKeyInfo, BpiError> {
+        let resp = self
+            .no_redirect_get(confirm_uri)
+            .send().await
+            .map_err(|e| BpiError::network(format!("确认授权失败: {}", e)))?;
+
+        let location = resp
+            .headers()
+            .get(header::LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| BpiError::auth("确认授权未返回重定向地址，Cookie 可能已失效"))?;
+
+        parse_access_key(location, key)
+    }
+
+    /// 使用当前 Web Cookie 换取 APP access_key（需登录）。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+    /// use bpi_rs::utils::app_sign::AppKey;
+    ///
+    /// let info = bpi.login_cookie_to_access_key(AppKey::LIVE_HIME).await?;
+    /// println!("mid {} access_key {}", info.mid, info.access_key);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn login_cookie_to_access_key(&self, key: AppKey) -> Result<AccessKeyInfo, BpiError> {
+        let third = self.login_app_third(key, THIRD_LOGIN_API).await?.into_data()?;
+        if third.has_login == 0 {
+            return Err(BpiError::auth("未登录"));
+        }
+
+        self.login_confirm_access_key(key, &third.confirm_uri).await
+    }
+
+    /// 使用 APP access_key 换取 Web Cookie。
+    ///
+    /// 返回的账号信息不会自动写入客户端，可按需调用 `set_account`；
+    /// 响应中不含 `buvid3` 时沿用当前账号的值。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `key` | AppKey | 签发 access_key 的 APP |
+    /// | `access_key` | &str | APP 登录凭证 |
+    pub async fn login_sso(&self, key: AppKey, access_key: &str) -> Result<Account, BpiError> {
+        let mut params = BTreeMap::from([
+            ("access_key".to_string(), access_key.to_string()),
+            ("gourl".to_string(), "https://account.bilibili.com/account/home".to_string()),
+        ]);
+        app_sign(&mut params, key);
+
+        let resp = self
+            .no_redirect_get("https://passport.bilibili.com/api/login/sso")
+            .query(&params)
+            .send().await
+            .map_err(|e| BpiError::network(format!("SSO 登录失败: {}", e)))?;
+
+        let cookies = set_cookie_pairs(resp.headers());
+        let buvid3 = self
+            .account_snapshot()
+            .map(|acc| acc.buvid3.clone())
+            .unwrap_or_default();
+        let mut account = account_from_cookies(
+            cookies.iter().map(|(k, v)| (k.as_str(), v.as_str())),
+            buvid3
+        );
+        account.source = Some(AccountSource::Sso);
+        account.login_at = Some(chrono::Utc::now().timestamp());
+
+        if account.sessdata.is_empty() || account.bili_jct.is_empty() {
+            return Err(BpiError::auth("SSO 登录未返回 Cookie，access_key 可能已失效"));
+        }
+        Ok(account)
+    }
+}
+
+/// 从授权重定向地址中解析 access_key 与 mid
+fn parse_access_key(location: &str, key: AppKey) -> Result<AccessKeyInfo, BpiError> {
+    let url = Url::parse(location).map_err(|e|
+        BpiError::parse(format!("重定向地址无效: {}", e))
+    )?;
+
+    let mut access_key = None;
+    let mut mid = None;
+    for (k, v) in url.query_pairs() {
+        match k.as_ref() {
+            "access_key" => {
+                access_key = Some(v.into_owned());
+            }
+            "mid" => {
+                mid = v.parse().ok();
+            }
+            _ => {}
+        }
+    }
+
+    Ok(AccessKeyInfo {
+        access_key: access_key.ok_or_else(|| BpiError::parse("重定向地址中缺少 access_key"))?,
+        mid: mid.unwrap_or(0),
+        appkey: key.appkey.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_access_key() {
+        let info = parse_access_key(
+            "https://www.bilibili.com/?access_key=abc123&mid=2&code=xyz",
+            AppKey::LIVE_HIME
+        ).unwrap();
+        assert_eq!(info.access_key, "abc123");
+        assert_eq!(info.mid, 2);
+        assert_eq!(info.appkey, "27eb53fc9058f8c3");
+
+        assert!(parse_access_key("https://www.bilibili.com/?mid=2", AppKey::LIVE_HIME).is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_cookie_to_access_key() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Authed);
+
+        let info = bpi.login_cookie_to_access_key(AppKey::LIVE_HIME).await?;
+        tracing::info!("mid: {}", info.mid);
+        assert!(!info.access_key.is_empty());
+
+        Ok(())
+    }
+}
@@ -9,6 +9,7 @@ async fn main() {
         sessdata: "".to_string(),
         bili_jct: "".to_string(),
         buvid3: "".to_string(),
+        ..Default::default()
     });
 
     // bpi.set_account_from_cookie_str("dede_user_id=123;bili_jct=456...");
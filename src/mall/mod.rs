@@ -0,0 +1,4 @@
+//! 会员购
+//!
+//! 仅提供只读查询，如演出与漫展票务的场次、票种与开售状态。
+pub mod ticket;
@@ -0,0 +1,279 @@
+//! 会员购票务
+//!
+//! 查询演出与漫展项目的场次、票种及开售状态，可用于余票提醒。不涉及下单。
+
+use serde::{ Deserialize, Serialize };
+
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 会员购接口的响应格式，状态码字段为 `errno`
+#[derive(Debug, Deserialize)]
+struct ShowResponse<T> {
+    #[serde(default)]
+    errno: i32,
+    #[serde(default)]
+    msg: String,
+    data: Option<T>,
+}
+
+impl<T> From<ShowResponse<T>> for BpiResponse<T> {
+    fn from(resp: ShowResponse<T>) -> Self {
+        BpiResponse {
+            code: resp.errno,
+            data: resp.data,
+            message: resp.msg,
+            status: resp.errno == 0,
+        }
+    }
+}
+
+/// 售卖状态
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SaleFlag {
+    /// 1: 未开售 2: 预售中 3: 已停售 4: 已售罄 5: 不可售 6: 库存紧张 8: 暂时售罄
+    #[serde(default)]
+    pub number: i32,
+    #[serde(default)]
+    pub display_name: String,
+}
+
+impl SaleFlag {
+    /// 当前是否可购买
+    pub fn is_on_sale(&self) -> bool {
+        matches!(self.number, 2 | 6)
+    }
+}
+
+/// 票种
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketSku {
+    /// 票种 id
+    pub id: u64,
+    /// 票种名称
+    #[serde(default)]
+    pub desc: String,
+    /// 价格（分）
+    #[serde(default)]
+    pub price: u64,
+    /// 开售时间
+    #[serde(default)]
+    pub sale_start: String,
+    /// 停售时间
+    #[serde(default)]
+    pub sale_end: String,
+    /// 是否可选
+    #[serde(default)]
+    pub clickable: bool,
+    #[serde(default)]
+    pub sale_flag: SaleFlag,
+}
+
+impl TicketSku {
+    /// 是否有票可买
+    pub fn is_available(&self) -> bool {
+        self.clickable && self.sale_flag.is_on_sale()
+    }
+}
+
+/// 场次
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketScreen {
+    /// 场次 id
+    pub id: u64,
+    /// 场次名称
+    #[serde(default)]
+    pub name: String,
+    /// 开始时间秒级时间戳
+    #[serde(default)]
+    pub start_time: i64,
+    #[serde(default)]
+    pub sale_flag: SaleFlag,
+    /// 票种列表
+    #[serde(default)]
+    pub ticket_list: Vec<TicketSku>,
+}
+
+/// 场馆
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TicketVenue {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub address_detail: String,
+}
+
+/// 票务项目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketProject {
+    /// 项目 id
+    pub id: u64,
+    /// 项目名称
+    #[serde(default)]
+    pub name: String,
+    /// 售卖状态文案，如 `预售中`
+    #[serde(default)]
+    pub sale_flag: String,
+    /// 开始时间秒级时间戳
+    #[serde(default)]
+    pub start_time: i64,
+    /// 结束时间秒级时间戳
+    #[serde(default)]
+    pub end_time: i64,
+    /// 最低价（分）
+    #[serde(default)]
+    pub price_low: u64,
+    /// 最高价（分）
+    #[serde(default)]
+    pub price_high: u64,
+    #[serde(default)]
+    pub venue_info: TicketVenue,
+    /// 场次列表
+    #[serde(default)]
+    pub screen_list: Vec<TicketScreen>,
+}
+
+/// 可购买的票种
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TicketAvailability {
+    pub screen_id: u64,
+    pub screen_name: String,
+    pub sku_id: u64,
+    pub sku_desc: String,
+    /// 价格（分）
+    pub price: u64,
+    /// 售卖状态文案
+    pub status: String,
+}
+
+impl TicketProject {
+    /// 当前可购买的所有票种
+    pub fn available(&self) -> Vec<TicketAvailability> {
+        self.screen_list
+            .iter()
+            .flat_map(|screen| {
+                screen.ticket_list
+                    .iter()
+                    .filter(|sku| sku.is_available())
+                    .map(move |sku| TicketAvailability {
+                        screen_id: screen.id,
+                        screen_name: screen.name.clone(),
+                        sku_id: sku.id,
+                        sku_desc: sku.desc.clone(),
+                        price: sku.price,
+                        status: sku.sale_flag.display_name.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    /// 查找场次
+    pub fn screen(&self, screen_id: u64) -> Option<&TicketScreen> {
+        self.screen_list.iter().find(|screen| screen.id == screen_id)
+    }
+}
+
+impl BpiClient {
+    /// 获取票务项目详情，包含全部场次与票种
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `project_id` | u64 | 项目 id，即 `show.bilibili.com/platform/detail.html?id=` 中的 id |
+    pub async fn mall_ticket_project(
+        &self,
+        project_id: u64
+    ) -> Result<BpiResponse<TicketProject>, BpiError> {
+        let bytes = self
+            .get("https://show.bilibili.com/api/ticket/project/getV2")
+            .with_bilibili_headers()
+            .query(
+                &[
+                    ("version", "134".to_string()),
+                    ("id", project_id.to_string()),
+                    ("project_id", project_id.to_string()),
+                ]
+            )
+            .send_request("获取票务项目详情").await?;
+
+        let resp: BpiResponse<TicketProject> = serde_json
+            ::from_slice::<ShowResponse<TicketProject>>(&bytes)?
+            .into();
+        if resp.code != 0 {
+            return Err(BpiError::from_code_message(resp.code, resp.message));
+        }
+
+        Ok(resp)
+    }
+
+    /// 获取票务项目的场次列表
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `project_id` | u64 | 项目 id |
+    pub async fn mall_ticket_screens(&self, project_id: u64) -> Result<Vec<TicketScreen>, BpiError> {
+        Ok(self.mall_ticket_project(project_id).await?.into_data()?.screen_list)
+    }
+
+    /// 获取票务项目当前可购买的票种
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `project_id` | u64 | 项目 id |
+    pub async fn mall_ticket_available(
+        &self,
+        project_id: u64
+    ) -> Result<Vec<TicketAvailability>, BpiError> {
+        Ok(self.mall_ticket_project(project_id).await?.into_data()?.available())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticket_available() {
+        let resp: ShowResponse<TicketProject> = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "errno": 0,
+                    "msg": "",
+                    "data": {
+                        "id": 1, "name": "漫展",
+                        "screen_list": [{
+                            "id": 10, "name": "第一天",
+                            "ticket_list": [
+                                { "id": 100, "desc": "普通票", "price": 8000, "clickable": true,
+                                  "sale_flag": { "number": 2, "display_name": "预售中" } },
+                                { "id": 101, "desc": "VIP票", "price": 20000, "clickable": false,
+                                  "sale_flag": { "number": 4, "display_name": "已售罄" } }
+                            ]
+                        }]
+                    }
+                })
+            )
+            .unwrap();
+        let resp: BpiResponse<TicketProject> = resp.into();
+        let project = resp.into_data().unwrap();
+
+        let available = project.available();
+        assert_eq!(available.len(), 1);
+        assert_eq!(available[0].sku_id, 100);
+        assert_eq!(available[0].screen_name, "第一天");
+        assert!(project.screen(10).is_some());
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_mall_ticket_project() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let project = bpi.mall_ticket_project(85939).await?.into_data()?;
+        tracing::info!("{}: {} 个场次", project.name, project.screen_list.len());
+        for ticket in project.available() {
+            tracing::info!("{:?}", ticket);
+        }
+        Ok(())
+    }
+}
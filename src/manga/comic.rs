@@ -129,8 +129,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_manga_buy_episode_with_coupon() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let coupon_id = 12553634;
         let ep_id = 484360;
@@ -0,0 +1,220 @@
+//! 通知的评论上下文
+//!
+//! "回复我的"与"@我的"通知只带有评论 id 和截断的内容，[`BpiClient::resolve_feed_item`]
+//! 按通知中的评论区与 rpid 获取根评论、被回复的评论以及通知对应的评论，
+//! 便于通知界面一次展示完整的对话。
+//!
+//! ```no_run
+//! # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+//! let feed = bpi.message_reply_feed(None, None).await?.into_data()?;
+//! if let Some(item) = feed.items.first() {
+//!     let context = bpi.resolve_feed_item(item).await?;
+//!     for comment in context.thread() {
+//!         println!("{}: {}", comment.member.uname, comment.content.message);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::msg::{ AtItem, ReplyItem };
+use crate::comment::resource::CommentResourceType;
+use crate::comment::types::Comment;
+use crate::{ BpiClient, BpiError };
+
+/// 查找评论时最多翻阅的子评论页数
+const MAX_PAGES: i32 = 5;
+
+/// 通知指向的评论位置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedTarget {
+    /// 评论区类型
+    pub business_id: u32,
+    /// 评论区 oid
+    pub subject_id: u64,
+    /// 根评论 rpid，通知对应一级评论时为 0
+    pub root_id: u64,
+    /// 通知对应的评论 rpid
+    pub source_id: u64,
+    /// 被回复的评论 rpid
+    pub target_id: u64,
+    /// 评论区所属内容的标题
+    pub title: String,
+    /// 评论区所属内容的链接
+    pub uri: String,
+}
+
+impl From<&ReplyItem> for FeedTarget {
+    fn from(item: &ReplyItem) -> Self {
+        let detail = &item.item;
+        Self {
+            business_id: detail.business_id,
+            subject_id: detail.subject_id,
+            root_id: detail.root_id,
+            source_id: detail.source_id,
+            target_id: detail.target_id,
+            title: detail.title.clone(),
+            uri: detail.uri.clone(),
+        }
+    }
+}
+
+impl From<&AtItem> for FeedTarget {
+    fn from(item: &AtItem) -> Self {
+        let detail = &item.item;
+        Self {
+            business_id: detail.business_id,
+            subject_id: detail.subject_id,
+            root_id: detail.root_id,
+            source_id: detail.source_id,
+            target_id: detail.target_id,
+            title: detail.title.clone(),
+            uri: detail.uri.clone(),
+        }
+    }
+}
+
+/// 通知的评论上下文
+#[derive(Debug, Clone)]
+pub struct FeedContext {
+    /// 评论区类型
+    pub resource_type: CommentResourceType,
+    /// 评论区 oid
+    pub oid: i64,
+    /// 评论区所属内容的标题
+    pub title: String,
+    /// 评论区所属内容的链接
+    pub uri: String,
+    /// 根评论
+    pub root: Option<Comment>,
+    /// 被回复的评论，直接回复根评论时与根评论相同
+    pub parent: Option<Comment>,
+    /// 通知对应的评论，超出查找范围时为 `None`
+    pub source: Option<Comment>,
+}
+
+impl FeedContext {
+    /// 按对话顺序排列的评论：根评论、被回复的评论、通知对应的评论，重复的只保留一次
+    pub fn thread(&self) -> Vec<&Comment> {
+        let mut thread: Vec<&Comment> = Vec::new();
+        for comment in [&self.root, &self.parent, &self.source].into_iter().flatten() {
+            if !thread.iter().any(|c| c.rpid == comment.rpid) {
+                thread.push(comment);
+            }
+        }
+        thread
+    }
+}
+
+impl BpiClient {
+    /// 获取回复或@通知所在的评论对话
+    ///
+    /// 从通知对应的根评论下逐页查找被回复的评论与通知对应的评论，最多查找 5 页。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `item` | `impl Into<FeedTarget>` | [`ReplyItem`] 或 [`AtItem`] |
+    pub async fn resolve_feed_item(
+        &self,
+        item: impl Into<FeedTarget>
+    ) -> Result<FeedContext, BpiError> {
+        let target = item.into();
+        let resource_type = i32::try_from(target.business_id)
+            .map(CommentResourceType::from_code)
+            .map_err(|_| BpiError::parse(format!("未知的评论区类型: {}", target.business_id)))?;
+        let oid = target.subject_id as i64;
+        let root_id = if target.root_id == 0 { target.source_id } else { target.root_id } as i64;
+        let source_id = target.source_id as i64;
+        let parent_id = target.target_id as i64;
+
+        let mut root = None;
+        let mut parent = None;
+        let mut source = None;
+        for pn in 1..=MAX_PAGES {
+            let data = self
+                .comment_replies(resource_type, oid, root_id, Some(pn), Some(20)).await?
+                .into_data()?;
+            if root.is_none() {
+                root = data.root;
+            }
+            let replies = data.replies.unwrap_or_default();
+            if replies.is_empty() {
+                break;
+            }
+            for reply in replies {
+                if reply.rpid == parent_id {
+                    parent = Some(reply.clone());
+                }
+                if reply.rpid == source_id {
+                    source = Some(reply);
+                }
+            }
+            if source.is_some() && (parent.is_some() || parent_id == 0 || parent_id == root_id) {
+                break;
+            }
+        }
+
+        let is_root = |id: i64| id == root_id && id != 0;
+        if is_root(parent_id) {
+            parent = root.clone();
+        }
+        if is_root(source_id) {
+            source = root.clone();
+        }
+
+        Ok(FeedContext {
+            resource_type,
+            oid,
+            title: target.title,
+            uri: target.uri,
+            root,
+            parent,
+            source,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feed_target_from_reply() {
+        let item: ReplyItem = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "id": 1, "counts": 1, "is_multi": 0, "reply_time": 1700000000,
+                    "user": { "mid": 2, "nickname": "碧诗", "avatar": "", "follow": false },
+                    "item": {
+                        "subject_id": 10001, "root_id": 100, "source_id": 102, "target_id": 101,
+                        "type": "reply", "business_id": 1, "business": "评论", "title": "视频标题",
+                        "desc": "", "uri": "https://www.bilibili.com/video/BV1bx411c7us", "native_uri": "",
+                        "root_reply_content": "", "source_content": "回复", "target_reply_content": "",
+                        "at_details": [], "hide_reply_button": false, "hide_like_button": false, "like_state": 0
+                    }
+                })
+            )
+            .unwrap();
+
+        let target = FeedTarget::from(&item);
+        assert_eq!((target.subject_id, target.root_id, target.source_id, target.target_id), (10001, 100, 102, 101));
+        assert_eq!(target.title, "视频标题");
+    }
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_resolve_feed_item() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Authed);
+        let feed = bpi.message_reply_feed(None, None).await?.into_data()?;
+        if let Some(item) = feed.items.first() {
+            let context = bpi.resolve_feed_item(item).await?;
+            tracing::info!("{} {}", context.title, context.uri);
+            for comment in context.thread() {
+                tracing::info!("{}: {}", comment.member.uname, comment.content.message);
+            }
+        }
+        Ok(())
+    }
+}
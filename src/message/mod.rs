@@ -1,6 +1,10 @@
 //! 消息
 
+#[cfg(feature = "comment")]
+pub mod context;
 pub mod msg;
 pub mod private_msg;
 pub mod private_msg_content;
+pub mod session;
 pub mod settings;
+pub mod watch;
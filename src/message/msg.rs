@@ -1,3 +1,4 @@
+use crate::utils::query::Query;
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 use serde::{ Deserialize, Serialize };
 use std::collections::HashMap;
@@ -88,6 +89,49 @@ pub struct AtUserDetail {
     pub follow: bool,
 }
 
+/// "@我的"信息
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AtFeedData {
+    pub cursor: ReplyCursor,
+    pub items: Vec<AtItem>,
+}
+
+/// 单条@通知
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AtItem {
+    pub id: u64,
+    pub user: ReplyUser,
+    pub item: AtDetail,
+    pub at_time: u64,
+}
+
+/// @通知详情
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AtDetail {
+    /// 评论区 oid
+    pub subject_id: u64,
+    /// 根评论 rpid，@出现在一级评论中时为 0
+    pub root_id: u64,
+    /// @所在评论 rpid
+    pub source_id: u64,
+    /// 被回复的评论 rpid
+    pub target_id: u64,
+    #[serde(rename = "type")]
+    pub at_type: String,
+    /// 评论区类型
+    pub business_id: u32,
+    pub business: String,
+    pub title: String,
+    #[serde(default)]
+    pub image: String,
+    pub uri: String,
+    #[serde(default)]
+    pub native_uri: String,
+    pub source_content: String,
+    #[serde(default)]
+    pub at_details: Vec<AtUserDetail>,
+}
+
 impl BpiClient {
     /// 获取未读消息数。
     ///
@@ -139,6 +183,35 @@ impl BpiClient {
             .query(&params)
             .send_bpi("获取回复我的信息").await
     }
+
+    /// 获取"@我的"信息列表。
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/message)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `start_id` | `Option<u64>` | 起始 ID（上次返回的 cursor.id） |
+    /// | `start_time` | `Option<u64>` | 起始时间戳（上次返回的 cursor.time） |
+    pub async fn message_at_feed(
+        &self,
+        start_id: Option<u64>,
+        start_time: Option<u64>
+    ) -> Result<BpiResponse<AtFeedData>, BpiError> {
+        let query = Query::new()
+            .with("build", 0)
+            .with("mobi_app", "web")
+            .with("platform", "web")
+            .with_opt("id", start_id)
+            .with_opt("at_time", start_time);
+
+        self
+            .get("https://api.bilibili.com/x/msgfeed/at")
+            .query(&query)
+            .send_bpi("获取@我的信息").await
+    }
 }
 
 #[cfg(test)]
@@ -146,8 +219,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_unread_count() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         let new_resp = bpi.message_unread_count().await?;
         let new_data = new_resp.into_data()?;
@@ -156,8 +230,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_reply_feed() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         let resp = bpi.message_reply_feed(None, None).await?;
         let data = resp.into_data()?;
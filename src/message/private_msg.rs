@@ -121,7 +121,8 @@ impl BpiClient {
     ) -> Result<BpiResponse<SendMsgData>, BpiError> {
         // 1. 获取必需的参数
         let csrf = self.csrf()?;
-        let sender_uid = &self.get_account().ok_or(BpiError::auth("未登录"))?.dede_user_id;
+        let account = self.account_snapshot().ok_or(BpiError::auth("未登录"))?;
+        let sender_uid = &account.dede_user_id;
         let dev_id = Uuid::new_v4().to_string();
         let timestamp = Utc::now().timestamp();
 
@@ -178,8 +179,9 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_single_unread() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
 
         // 默认查询所有未读私信数
         let all_unread_resp = bpi.message_single_unread(None, None, None).await?;
@@ -192,8 +194,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_send_text_message() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let receiver_id = 107997089; // 替换为你要发送消息的目标用户mid
         // let message_content = "这是一个测试消息。";
         //
@@ -0,0 +1,308 @@
+//! 私信会话
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/message/private_msg.md)
+
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use serde::{ Deserialize, Serialize };
+use serde_json::Value;
+
+use super::private_msg::Image;
+
+/// 私信消息
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PrivateMessage {
+    /// 发送者 mid
+    pub sender_uid: u64,
+    /// 接收者类型 1:用户 2:粉丝团
+    pub receiver_type: u32,
+    /// 接收者 id
+    pub receiver_id: u64,
+    /// 消息类型 1:文字 2:图片 5:撤回 其他见文档
+    pub msg_type: u32,
+    /// 消息内容（JSON 字符串）
+    pub content: String,
+    /// 消息序号，会话内递增
+    pub msg_seqno: u64,
+    /// 秒级时间戳
+    pub timestamp: u64,
+    #[serde(default)]
+    pub at_uids: Option<Vec<u64>>,
+    /// 消息唯一 id
+    #[serde(default)]
+    pub msg_key: u64,
+    /// 消息状态 0:正常 1:已撤回 2:被系统撤回 50:图片失效
+    #[serde(default)]
+    pub msg_status: u32,
+    #[serde(default)]
+    pub notify_code: String,
+    #[serde(default)]
+    pub msg_source: u32,
+}
+
+/// 解析后的私信内容
+#[derive(Debug, Clone)]
+pub enum MessageContent {
+    /// 文字消息
+    Text(String),
+    /// 图片消息
+    Image(Image),
+    /// 撤回消息，值为被撤回消息的 `msg_key`
+    Withdraw(u64),
+    /// 其他类型，保留原始内容
+    Other {
+        msg_type: u32,
+        content: Value,
+    },
+}
+
+impl PrivateMessage {
+    /// 按 `msg_type` 解析 `content`
+    pub fn parse_content(&self) -> MessageContent {
+        let value: Value = serde_json::from_str(&self.content)
+            .unwrap_or_else(|_| Value::String(self.content.clone()));
+
+        match self.msg_type {
+            1 => {
+                if let Some(text) = value.get("content").and_then(Value::as_str) {
+                    return MessageContent::Text(text.to_string());
+                }
+            }
+            2 => {
+                if let Ok(image) = serde_json::from_value::<Image>(value.clone()) {
+                    return MessageContent::Image(image);
+                }
+            }
+            5 => {
+                // 撤回消息的内容为被撤回消息的 msg_key 字符串
+                if let Some(key) = value.as_u64().or_else(|| value.as_str()?.parse().ok()) {
+                    return MessageContent::Withdraw(key);
+                }
+            }
+            _ => {}
+        }
+
+        MessageContent::Other {
+            msg_type: self.msg_type,
+            content: value,
+        }
+    }
+}
+
+/// 私信会话
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SessionInfo {
+    /// 聊天对象 id
+    pub talker_id: u64,
+    /// 会话类型 1:用户 2:粉丝团
+    pub session_type: u32,
+    /// 已读的最大消息序号
+    #[serde(default)]
+    pub ack_seqno: u64,
+    /// 已读时间戳（微秒）
+    #[serde(default)]
+    pub ack_ts: u64,
+    /// 会话时间戳（微秒）
+    #[serde(default)]
+    pub session_ts: u64,
+    /// 未读消息数
+    #[serde(default)]
+    pub unread_count: u32,
+    /// 会话中最大消息序号
+    #[serde(default)]
+    pub max_seqno: u64,
+    /// 最近一条消息
+    #[serde(default)]
+    pub last_msg: Option<PrivateMessage>,
+    /// 是否置顶，非 0 为置顶时间戳
+    #[serde(default)]
+    pub top_ts: u64,
+    /// 是否关注 0:否 1:是
+    #[serde(default)]
+    pub is_follow: u32,
+    /// 是否免打扰 0:否 1:是
+    #[serde(default)]
+    pub is_dnd: u32,
+    /// 粉丝团名称
+    #[serde(default)]
+    pub group_name: String,
+}
+
+/// 新会话列表
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SessionListData {
+    #[serde(default)]
+    pub session_list: Option<Vec<SessionInfo>>,
+    /// 是否有更多 0:否 1:是
+    #[serde(default)]
+    pub has_more: u32,
+}
+
+/// 会话消息
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SessionMsgsData {
+    /// 按序号从大到小排列的消息
+    #[serde(default)]
+    pub messages: Option<Vec<PrivateMessage>>,
+    /// 是否有更多 0:否 1:是
+    #[serde(default)]
+    pub has_more: u32,
+    #[serde(default)]
+    pub min_seqno: u64,
+    #[serde(default)]
+    pub max_seqno: u64,
+}
+
+impl BpiClient {
+    /// 获取指定时间后有更新的私信会话。
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/message/private_msg.md)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `begin_ts` | u64 | 起始时间戳（微秒），0 为全部 |
+    pub async fn message_new_sessions(
+        &self,
+        begin_ts: u64
+    ) -> Result<BpiResponse<SessionListData>, BpiError> {
+        self
+            .get("https://api.vc.bilibili.com/session_svr/v1/session_svr/new_sessions")
+            .query(
+                &[
+                    ("begin_ts", begin_ts.to_string()),
+                    ("build", "0".to_string()),
+                    ("mobi_app", "web".to_string()),
+                ]
+            )
+            .send_bpi("获取新私信会话").await
+    }
+
+    /// 获取会话中的私信消息。
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/message/private_msg_content.md)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `talker_id` | u64 | 聊天对象 id |
+    /// | `session_type` | u32 | 会话类型 1:用户 2:粉丝团 |
+    /// | `begin_seqno` | `Option<u64>` | 只返回序号大于该值的消息 |
+    /// | `size` | `Option<u32>` | 返回条数，默认 20，最大 2000 |
+    pub async fn message_session_msgs(
+        &self,
+        talker_id: u64,
+        session_type: u32,
+        begin_seqno: Option<u64>,
+        size: Option<u32>
+    ) -> Result<BpiResponse<SessionMsgsData>, BpiError> {
+        let mut params = vec![
+            ("talker_id", talker_id.to_string()),
+            ("session_type", session_type.to_string()),
+            ("size", size.unwrap_or(20).to_string()),
+            ("sender_device_id", "1".to_string()),
+            ("build", "0".to_string()),
+            ("mobi_app", "web".to_string())
+        ];
+        if let Some(seqno) = begin_seqno {
+            params.push(("begin_seqno", seqno.to_string()));
+        }
+
+        self
+            .get("https://api.vc.bilibili.com/svr_sync/v1/svr_sync/fetch_session_msgs")
+            .query(&params)
+            .send_bpi("获取私信消息").await
+    }
+
+    /// 设置会话已读。
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/message/private_msg.md)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `talker_id` | u64 | 聊天对象 id |
+    /// | `session_type` | u32 | 会话类型 1:用户 2:粉丝团 |
+    /// | `ack_seqno` | u64 | 已读到的消息序号 |
+    pub async fn message_update_ack(
+        &self,
+        talker_id: u64,
+        session_type: u32,
+        ack_seqno: u64
+    ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        let csrf = self.csrf()?;
+
+        self
+            .post("https://api.vc.bilibili.com/session_svr/v1/session_svr/update_ack")
+            .form(
+                &[
+                    ("talker_id", talker_id.to_string()),
+                    ("session_type", session_type.to_string()),
+                    ("ack_seqno", ack_seqno.to_string()),
+                    ("build", "0".to_string()),
+                    ("mobi_app", "web".to_string()),
+                    ("csrf_token", csrf.clone()),
+                    ("csrf", csrf),
+                ]
+            )
+            .send_bpi("设置私信已读").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_content() {
+        let message = |msg_type: u32, content: &str| PrivateMessage {
+            sender_uid: 1,
+            receiver_type: 1,
+            receiver_id: 2,
+            msg_type,
+            content: content.to_string(),
+            msg_seqno: 1,
+            timestamp: 0,
+            at_uids: None,
+            msg_key: 0,
+            msg_status: 0,
+            notify_code: String::new(),
+            msg_source: 0,
+        };
+
+        assert!(
+            matches!(message(1, r#"{"content":"你好"}"#).parse_content(), MessageContent::Text(t) if t == "你好")
+        );
+        assert!(
+            matches!(message(5, "7212345678901234567").parse_content(), MessageContent::Withdraw(7212345678901234567))
+        );
+        assert!(
+            matches!(
+                message(2, r#"{"url":"https://i0.hdslb.com/a.jpg","height":1,"width":1,"size":1.5}"#).parse_content(),
+                MessageContent::Image(_)
+            )
+        );
+        assert!(matches!(message(7, "{}").parse_content(), MessageContent::Other { msg_type: 7, .. }));
+    }
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_new_sessions() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Authed);
+
+        let data = bpi.message_new_sessions(0).await?.into_data()?;
+        for session in data.session_list.iter().flatten().take(3) {
+            let msgs = bpi
+                .message_session_msgs(session.talker_id, session.session_type, None, Some(5)).await?
+                .into_data()?;
+            tracing::info!("{}: {} 条消息", session.talker_id, msgs.messages.map_or(0, |m| m.len()));
+        }
+
+        Ok(())
+    }
+}
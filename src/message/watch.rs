@@ -0,0 +1,170 @@
+//! 私信轮询
+//!
+//! 定时拉取有更新的私信会话，按会话记录已处理的消息序号，输出收到的新私信，
+//! 可选在处理后自动设置已读，用于私信客服机器人等场景。
+
+use std::collections::{ HashMap, VecDeque };
+use std::time::Duration;
+
+use futures_util::Stream;
+use tokio::time::{ Interval, MissedTickBehavior };
+
+use super::session::{ MessageContent, PrivateMessage };
+use crate::{ BpiClient, BpiError };
+
+/// 收到的私信
+#[derive(Debug, Clone)]
+pub struct MessageEvent {
+    /// 聊天对象 id
+    pub talker_id: u64,
+    /// 会话类型 1:用户 2:粉丝团
+    pub session_type: u32,
+    /// 解析后的内容
+    pub content: MessageContent,
+    pub message: PrivateMessage,
+}
+
+struct WatchState<'a> {
+    bpi: &'a BpiClient,
+    interval: Interval,
+    auto_ack: bool,
+    /// 下次拉取会话的起始时间戳（微秒），首次轮询前为 `None`
+    begin_ts: Option<u64>,
+    /// 每个会话已处理到的消息序号
+    seen: HashMap<(u64, u32), u64>,
+    pending: VecDeque<MessageEvent>,
+}
+
+impl WatchState<'_> {
+    async fn poll(&mut self) -> Result<(), BpiError> {
+        let account = self.bpi.account_snapshot().ok_or(BpiError::auth("未登录"))?;
+        let self_mid: u64 = account.dede_user_id.parse().unwrap_or(0);
+
+        let begin_ts = self.begin_ts.unwrap_or(0);
+        let sessions = self.bpi
+            .message_new_sessions(begin_ts).await?
+            .into_data()?
+            .session_list.unwrap_or_default();
+
+        let mut next_ts = begin_ts;
+        for session in sessions {
+            next_ts = next_ts.max(session.session_ts);
+
+            let key = (session.talker_id, session.session_type);
+            let seen = *self.seen.entry(key).or_insert(session.ack_seqno);
+            if session.max_seqno <= seen {
+                continue;
+            }
+
+            let mut messages = self.bpi
+                .message_session_msgs(session.talker_id, session.session_type, Some(seen), Some(100)).await?
+                .into_data()?
+                .messages.unwrap_or_default();
+            messages.sort_by_key(|m| m.msg_seqno);
+
+            let mut latest = seen;
+            for message in messages.into_iter().filter(|m| m.msg_seqno > seen) {
+                latest = latest.max(message.msg_seqno);
+                if message.sender_uid == self_mid {
+                    continue;
+                }
+                self.pending.push_back(MessageEvent {
+                    talker_id: session.talker_id,
+                    session_type: session.session_type,
+                    content: message.parse_content(),
+                    message,
+                });
+            }
+            self.seen.insert(key, latest);
+
+            if self.auto_ack && latest > session.ack_seqno {
+                self.bpi.message_update_ack(session.talker_id, session.session_type, latest).await?;
+            }
+        }
+
+        self.begin_ts = Some(next_ts);
+        Ok(())
+    }
+}
+
+impl BpiClient {
+    /// 轮询私信，输出收到的新私信
+    ///
+    /// 首次轮询输出各会话中尚未读的私信，之后每隔 `interval` 拉取一次有更新的会话；
+    /// 自己发送的消息不会输出。请求失败时输出 `Err` 并在下次继续轮询。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `interval` | Duration | 轮询间隔 |
+    /// | `auto_ack` | bool | 拉取后自动设置会话已读 |
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// # async fn run(bpi: &bpi_rs::BpiClient) {
+    /// use std::time::Duration;
+    /// use futures_util::StreamExt;
+    /// use bpi_rs::message::private_msg::MessageType;
+    /// use bpi_rs::message::session::MessageContent;
+    ///
+    /// let mut watch = Box::pin(bpi.msg_watch(Duration::from_secs(10), true));
+    /// while let Some(Ok(event)) = watch.next().await {
+    ///     if let MessageContent::Text(text) = &event.content {
+    ///         let reply = format!("收到：{}", text);
+    ///         let _ = bpi.message_send(event.talker_id, 1, MessageType::Text(reply)).await;
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn msg_watch(
+        &self,
+        interval: Duration,
+        auto_ack: bool
+    ) -> impl Stream<Item = Result<MessageEvent, BpiError>> + '_ {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let state = WatchState {
+            bpi: self,
+            interval,
+            auto_ack,
+            begin_ts: None,
+            seen: HashMap::new(),
+            pending: VecDeque::new(),
+        };
+
+        futures_util::stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    return Some((Ok(event), state));
+                }
+
+                state.interval.tick().await;
+                if let Err(e) = state.poll().await {
+                    tracing::warn!("私信轮询失败: {}", e);
+                    return Some((Err(e), state));
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_msg_watch() {
+        let bpi = crate::test_client!(Authed);
+        let mut watch = Box::pin(bpi.msg_watch(Duration::from_secs(5), false));
+
+        // 没有未读私信时不会输出，超时即视为正常
+        let next = tokio::time::timeout(Duration::from_secs(12), watch.next()).await;
+        if let Ok(Some(item)) = next {
+            tracing::info!("私信: {:?}", item.map(|e| e.content));
+        }
+    }
+}
@@ -76,11 +76,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_generate_short_link() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         match bpi.misc_b23_short_link(10001).await {
             Ok(resp) => {
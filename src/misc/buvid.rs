@@ -46,11 +46,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_buvid3() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         match bpi.misc_buvid3().await {
             Ok(resp) => {
@@ -68,8 +67,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_buvid() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         match bpi.misc_buvid().await {
             Ok(resp) => {
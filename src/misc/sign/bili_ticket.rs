@@ -3,16 +3,39 @@
 //! bili_ticket 位于请求头 Cookie 中, 非必需, 但存在可降低风控概率
 //! 是 JWT 令牌，有效时长为 259200 秒，即 3 天。
 //!
+//! 部分 WBI 接口（如搜索）缺少 bili_ticket 时容易触发风控。[`BpiClient::misc_sign_ensure_bili_ticket`]
+//! 在 Cookie 缺失或即将过期时重新生成并写入 cookie jar，长期运行的程序可启动
+//! [`BpiClient::spawn_bili_ticket_refresh_loop`] 自动续期，[`BpiClient::shutdown`] 时随之结束：
+//!
+//! ```no_run
+//! # async fn run() {
+//! use bpi_rs::BpiClient;
+//!
+//! let bpi = BpiClient::new();
+//! BpiClient::spawn_bili_ticket_refresh_loop(bpi);
+//! // ...
+//! bpi.shutdown().await;
+//! # }
+//! ```
+//!
 //! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/misc/sign/bili_ticket.md)
 
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 use hmac::{ Hmac, Mac };
 use serde::{ Deserialize, Serialize };
 use sha2::Sha256;
-use std::time::{ SystemTime, UNIX_EPOCH };
+use std::ops::Deref;
+use std::time::{ Duration, SystemTime, UNIX_EPOCH };
+use tokio::task::AbortHandle;
 
 type HmacSha256 = Hmac<Sha256>;
 
+/// 距过期不足该秒数时重新生成
+const REFRESH_MARGIN_SECS: i64 = 60 * 60;
+
+/// 生成失败后的重试间隔
+const RETRY_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
 /// bili_ticket 响应数据
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TicketData {
@@ -40,10 +63,12 @@ pub struct NavData {
 impl BpiClient {
     /// 生成 bili_ticket
     ///
+    /// 登录时携带 csrf，游客模式下同样可以生成。
+    ///
     /// # 文档
     /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/misc)
     pub async fn misc_sign_bili_ticket(&self) -> Result<BpiResponse<TicketData>, BpiError> {
-        let csrf = self.csrf()?;
+        let csrf = self.csrf().unwrap_or_default();
         // 获取当前时间戳
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -78,6 +103,67 @@ impl BpiClient {
         Ok(data.ticket)
     }
 
+    /// 确保 cookie jar 中有可用的 bili_ticket，返回过期秒时间戳
+    ///
+    /// 按 Cookie `bili_ticket_expires` 判断，缺失或 1 小时内过期时重新生成，
+    /// 并与网页端一样写入 `bili_ticket` 与 `bili_ticket_expires` 两个 Cookie。
+    pub async fn misc_sign_ensure_bili_ticket(&self) -> Result<i64, BpiError> {
+        let cookies = self.jar_cookies();
+        let expires = cookies
+            .get("bili_ticket_expires")
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|_| cookies.get("bili_ticket").is_some_and(|v| !v.is_empty()));
+        if let Some(expires) = expires && !ticket_expiring(expires, now_secs()) {
+            return Ok(expires);
+        }
+
+        let data = self.misc_sign_bili_ticket().await?.into_data()?;
+        let expires = data.created_at + (data.ttl as i64);
+        self.add_cookies([
+            ("bili_ticket", data.ticket),
+            ("bili_ticket_expires", expires.to_string()),
+        ]);
+        tracing::info!("bili_ticket 已更新，过期时间 {}", expires);
+        Ok(expires)
+    }
+
+    /// 持续为 bili_ticket 续期，在过期前 1 小时重新生成，失败时 10 分钟后重试
+    ///
+    /// 直到 client 关闭（见 [`BpiClient::shutdown`]）才返回，通常通过
+    /// [`BpiClient::spawn_bili_ticket_refresh_loop`] 登记为后台任务运行。
+    pub async fn bili_ticket_refresh_loop(&self) {
+        let tasks = self.background_tasks();
+        while !tasks.is_shutdown() {
+            let wait = match self.misc_sign_ensure_bili_ticket().await {
+                Ok(expires) => {
+                    let secs = expires - REFRESH_MARGIN_SECS - now_secs();
+                    Duration::from_secs(secs.max(60) as u64)
+                }
+                Err(e) => {
+                    tracing::warn!("生成 bili_ticket 失败: {}", e);
+                    RETRY_INTERVAL
+                }
+            };
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {}
+                _ = tasks.cancelled() => {}
+            }
+        }
+        tracing::debug!("客户端已关闭，停止续期 bili_ticket");
+    }
+
+    /// 在 client 的后台任务登记表中启动 [`bili_ticket_refresh_loop`](Self::bili_ticket_refresh_loop)
+    ///
+    /// `client` 可为 [`BpiClient::new`] 返回的单例或 `Arc<BpiClient>`。
+    pub fn spawn_bili_ticket_refresh_loop<C>(client: C) -> AbortHandle
+        where C: Deref<Target = BpiClient> + Send + Sync + 'static
+    {
+        let tasks = client.background_tasks().clone();
+        tasks.spawn("bili_ticket_refresh", async move {
+            client.bili_ticket_refresh_loop().await;
+        })
+    }
+
     /// 使用 HMAC-SHA256 算法计算哈希
     fn hmac_sha256(&self, key: &str, message: &str) -> Result<String, BpiError> {
         let mut mac = HmacSha256::new_from_slice(key.as_bytes()).map_err(|e|
@@ -90,24 +176,61 @@ impl BpiClient {
     }
 }
 
+fn now_secs() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+/// 过期时间 `expires` 在 `now` 时是否需要重新生成
+fn ticket_expiring(expires: i64, now: i64) -> bool {
+    expires - now <= REFRESH_MARGIN_SECS
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_ticket_expiring() {
+        let now = 1_700_000_000;
+        assert!(!ticket_expiring(now + 259200, now));
+        assert!(ticket_expiring(now + 1800, now));
+        assert!(ticket_expiring(now - 1, now));
+    }
+
     #[tokio::test]
-    async fn test_hmac_sha256() {
-        let bpi = BpiClient::new();
-        let result = bpi.hmac_sha256("XgwSnGZ1p", "ts1234567890").unwrap();
+    async fn test_ensure_bili_ticket_cached() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        let expires = now_secs() + 86400;
+        bpi.add_cookies([("bili_ticket", "a.b.c".to_string()), ("bili_ticket_expires", expires.to_string())]);
+        assert_eq!(bpi.misc_sign_ensure_bili_ticket().await.unwrap(), expires);
+    }
+
+    #[tokio::test]
+    async fn test_bili_ticket_refresh_loop_cancelled() {
+        let bpi = std::sync::Arc::new(BpiClient::try_new_local().unwrap());
+        let expires = now_secs() + 86400;
+        bpi.add_cookies([("bili_ticket", "a.b.c".to_string()), ("bili_ticket_expires", expires.to_string())]);
 
-        // 验证结果是64位十六进制字符串
-        assert_eq!(result.len(), 64);
-        assert!(result.chars().all(|c| c.is_ascii_hexdigit()));
-        tracing::info!("HMAC-SHA256 测试通过: {}", result);
+        BpiClient::spawn_bili_ticket_refresh_loop(bpi.clone());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(bpi.background_tasks().active(), ["bili_ticket_refresh"]);
+
+        tokio::time::timeout(Duration::from_secs(1), bpi.shutdown()).await.unwrap();
+        assert!(bpi.background_tasks().active().is_empty());
+        tokio::time::timeout(Duration::from_secs(1), bpi.bili_ticket_refresh_loop()).await.unwrap();
+    }
+
+    #[test]
+    fn test_hmac_sha256() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        let result = bpi.hmac_sha256("XgwSnGZ1p", "ts1234567890").unwrap();
+        assert_eq!(result, "a7da9d971f117aa2b439c4b6cc46c7afbba8ade9f3ca959578af1bcfb37ebd2f");
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_generate_bili_ticket() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         match bpi.misc_sign_bili_ticket().await {
             Ok(resp) => {
@@ -138,8 +261,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_bili_ticket_string() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         match bpi.misc_sign_bili_ticket_string().await {
             Ok(ticket) => {
@@ -160,8 +284,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_with_csrf() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         // 测试带 CSRF 的情况
         match bpi.misc_sign_bili_ticket().await {
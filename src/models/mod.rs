@@ -2,6 +2,8 @@ pub mod label;
 pub mod level;
 pub mod nameplate;
 pub mod official;
+pub mod opus_features;
+pub mod opus_state;
 pub mod pendant;
 
 // 已有的
@@ -17,11 +19,13 @@ pub use label::LabelGoto;
 pub use level::{LevelInfo, NextExp};
 pub use nameplate::Nameplate;
 pub use official::{Official, OfficialVerify};
+pub use opus_features::OpusFeatures;
+pub use opus_state::OpusState;
 pub use pendant::Pendant;
 pub use sign::WbiData;
 pub use user::Account;
 
 pub use stream::{
-    AudioQuality, DashStreams, DashTrack, Durl, Fnval, SupportFormat, VideoCodec, VideoQuality,
-    VideoStreamData,
+    AudioQuality, CodecPreference, DashStreams, DashTrack, Durl, Fnval, SupportFormat, VideoCodec,
+    VideoQuality, VideoStreamData,
 };
@@ -0,0 +1,118 @@
+use std::fmt;
+
+use bitflags::bitflags;
+
+bitflags! {
+    /// 图文 / 动态接口的 `features` 参数
+    ///
+    /// 接口按传入的特性决定返回内容的结构，序列化为逗号分隔的特性名。
+    ///
+    /// ```
+    /// use bpi_rs::models::OpusFeatures;
+    ///
+    /// let features = OpusFeatures::ITEM_OPUS_STYLE | OpusFeatures::ONLYFANS_VOTE;
+    /// assert_eq!(features.to_string(), "itemOpusStyle,onlyfansVote");
+    /// assert_eq!(OpusFeatures::from_param("onlyfansVote,itemOpusStyle,unknown"), features);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct OpusFeatures: u32 {
+        /// 以图文样式返回动态内容（`module_dynamic.major.opus`）
+        const ITEM_OPUS_STYLE      = 1 << 0;
+        /// 列表中包含充电专属动态
+        const LIST_ONLYFANS        = 1 << 1;
+        /// 图文大封面
+        const OPUS_BIG_COVER       = 1 << 2;
+        /// 充电专属投票
+        const ONLYFANS_VOTE        = 1 << 3;
+        /// 装扮卡片
+        const DECORATION_CARD      = 1 << 4;
+        /// 充电专属资源（新版）
+        const ONLYFANS_ASSETS_V2   = 1 << 5;
+        /// 隐藏转发列表
+        const FORWARD_LIST_HIDDEN  = 1 << 6;
+        /// 稿件删除状态
+        const UGC_DELETE           = 1 << 7;
+        /// 新版富文本样式
+        const HTML_NEW_STYLE       = 1 << 8;
+        /// 可编辑状态
+        const EDITABLE             = 1 << 9;
+        /// 私密图文可见状态
+        const OPUS_PRIVATE_VISIBLE = 1 << 10;
+    }
+}
+
+/// 各特性对应的参数名
+const TOKENS: &[(OpusFeatures, &str)] = &[
+    (OpusFeatures::ITEM_OPUS_STYLE, "itemOpusStyle"),
+    (OpusFeatures::LIST_ONLYFANS, "listOnlyfans"),
+    (OpusFeatures::OPUS_BIG_COVER, "opusBigCover"),
+    (OpusFeatures::ONLYFANS_VOTE, "onlyfansVote"),
+    (OpusFeatures::DECORATION_CARD, "decorationCard"),
+    (OpusFeatures::ONLYFANS_ASSETS_V2, "onlyfansAssetsV2"),
+    (OpusFeatures::FORWARD_LIST_HIDDEN, "forwardListHidden"),
+    (OpusFeatures::UGC_DELETE, "ugcDelete"),
+    (OpusFeatures::HTML_NEW_STYLE, "htmlNewStyle"),
+    (OpusFeatures::EDITABLE, "editable"),
+    (OpusFeatures::OPUS_PRIVATE_VISIBLE, "opusPrivateVisible"),
+];
+
+impl OpusFeatures {
+    /// 图文详情网页端使用的特性
+    pub const OPUS_DETAIL: Self = Self::ONLYFANS_VOTE
+        .union(Self::ONLYFANS_ASSETS_V2)
+        .union(Self::DECORATION_CARD)
+        .union(Self::HTML_NEW_STYLE)
+        .union(Self::UGC_DELETE)
+        .union(Self::EDITABLE)
+        .union(Self::OPUS_PRIVATE_VISIBLE);
+
+    /// 动态详情网页端使用的特性
+    pub const DYNAMIC_DETAIL: Self = Self::HTML_NEW_STYLE
+        .union(Self::ITEM_OPUS_STYLE)
+        .union(Self::DECORATION_CARD);
+
+    /// 动态列表网页端使用的特性
+    pub const DYNAMIC_FEED: Self = Self::ITEM_OPUS_STYLE
+        .union(Self::LIST_ONLYFANS)
+        .union(Self::OPUS_BIG_COVER)
+        .union(Self::ONLYFANS_VOTE);
+
+    /// 解析逗号分隔的特性名，忽略未知的特性
+    pub fn from_param(param: &str) -> Self {
+        param
+            .split(',')
+            .filter_map(|token| {
+                TOKENS.iter()
+                    .find(|(_, name)| *name == token.trim())
+                    .map(|(flag, _)| *flag)
+            })
+            .fold(Self::empty(), |acc, flag| acc | flag)
+    }
+}
+
+impl fmt::Display for OpusFeatures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = TOKENS.iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| *name)
+            .collect();
+        f.write_str(&names.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens_round_trip() {
+        assert_eq!(TOKENS.len(), OpusFeatures::all().iter().count());
+        let all = OpusFeatures::all().to_string();
+        assert_eq!(OpusFeatures::from_param(&all), OpusFeatures::all());
+        assert_eq!(OpusFeatures::empty().to_string(), "");
+        assert_eq!(
+            OpusFeatures::DYNAMIC_FEED.to_string(),
+            "itemOpusStyle,listOnlyfans,opusBigCover,onlyfansVote"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::BpiError;
+
+/// 图文 / 动态的可见状态
+///
+/// 详情接口对不可见内容返回的是业务错误码，这里将其归类，方便归档工具记录内容消失的原因。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum OpusState<T> {
+    /// 正常可见
+    Normal(T),
+    /// 仅作者可见
+    Private,
+    /// 已删除或不存在
+    Deleted,
+    /// 审核中
+    UnderReview,
+}
+
+impl<T> OpusState<T> {
+    /// 根据详情接口返回的错误归类，无法归类时返回 `None`
+    pub fn from_error(err: &BpiError) -> Option<Self> {
+        let BpiError::Api { code, message, .. } = err else {
+            return None;
+        };
+
+        match code {
+            -404 | 4101131 | 4128002 => Some(OpusState::Deleted),
+            4101147 | 4128001 => Some(OpusState::Private),
+            4101149 | 4128003 => Some(OpusState::UnderReview),
+            _ if message.contains("审核") => Some(OpusState::UnderReview),
+            _ if message.contains("仅自己可见") || message.contains("私密") => {
+                Some(OpusState::Private)
+            }
+            _ if message.contains("删除") || message.contains("不存在") => {
+                Some(OpusState::Deleted)
+            }
+            _ => None,
+        }
+    }
+
+    /// 将详情接口结果转换为状态，无法归类的错误原样返回
+    pub fn from_result(result: Result<T, BpiError>) -> Result<Self, BpiError> {
+        match result {
+            Ok(data) => Ok(OpusState::Normal(data)),
+            Err(e) => Self::from_error(&e).ok_or(e),
+        }
+    }
+
+    /// 是否正常可见
+    pub fn is_normal(&self) -> bool {
+        matches!(self, OpusState::Normal(_))
+    }
+
+    /// 取出正常可见时的数据
+    pub fn into_normal(self) -> Option<T> {
+        match self {
+            OpusState::Normal(data) => Some(data),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opus_state_from_error() {
+        let deleted = BpiError::from_code_message(4101131, "动态不存在".to_string());
+        assert!(matches!(OpusState::<()>::from_error(&deleted), Some(OpusState::Deleted)));
+
+        let review = BpiError::from_code_message(500, "内容审核中".to_string());
+        assert!(matches!(OpusState::<()>::from_error(&review), Some(OpusState::UnderReview)));
+
+        let other = BpiError::from_code(-352);
+        assert!(OpusState::<()>::from_error(&other).is_none());
+        assert!(OpusState::<()>::from_result(Err(other)).is_err());
+    }
+}
@@ -41,6 +41,26 @@ impl VideoQuality {
     pub fn as_u32(self) -> u32 {
         self as u32
     }
+
+    /// 从清晰度代码解析
+    pub fn from_qn(qn: u32) -> Option<Self> {
+        match qn {
+            6 => Some(VideoQuality::P240),
+            16 => Some(VideoQuality::P360),
+            32 => Some(VideoQuality::P480),
+            64 => Some(VideoQuality::P720),
+            74 => Some(VideoQuality::P720_60),
+            80 => Some(VideoQuality::P1080),
+            100 => Some(VideoQuality::Smart),
+            112 => Some(VideoQuality::P1080Plus),
+            116 => Some(VideoQuality::P1080_60),
+            120 => Some(VideoQuality::P4K),
+            125 => Some(VideoQuality::HDR),
+            126 => Some(VideoQuality::DolbyVision),
+            127 => Some(VideoQuality::P8K),
+            _ => None,
+        }
+    }
 }
 
 bitflags! {
@@ -81,6 +101,97 @@ impl VideoCodec {
     pub fn as_u32(self) -> u32 {
         self as u32
     }
+
+    /// 从 codecid 解析
+    pub fn from_codecid(codecid: u32) -> Option<Self> {
+        match codecid {
+            7 => Some(VideoCodec::Avc),
+            12 => Some(VideoCodec::Hevc),
+            13 => Some(VideoCodec::Av1),
+            _ => None,
+        }
+    }
+
+    /// 从编码名解析，如直播流的 `avc`、`hevc`、`av1`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "avc" => Some(VideoCodec::Avc),
+            "hevc" => Some(VideoCodec::Hevc),
+            "av1" => Some(VideoCodec::Av1),
+            _ => None,
+        }
+    }
+
+    /// 从 codecs 字符串解析，如 `avc1.640032`、`hev1.1.6.L150.90`、`av01.0.00M.10.0.110.01.01.01.0`
+    pub fn from_codecs(codecs: &str) -> Option<Self> {
+        match codecs.split('.').next()? {
+            "avc1" | "avc3" => Some(VideoCodec::Avc),
+            "hev1" | "hvc1" => Some(VideoCodec::Hevc),
+            "av01" => Some(VideoCodec::Av1),
+            _ => None,
+        }
+    }
+}
+
+/// 编码协商规则
+///
+/// 按 `codecs` 顺序选择第一个存在的编码，在该编码下选取最高清晰度的视频流。
+///
+/// ```
+/// use bpi_rs::models::{ CodecPreference, VideoCodec, VideoQuality };
+///
+/// // 优先 AV1，其次 HEVC，最低 1080P
+/// let pref = CodecPreference::new([VideoCodec::Av1, VideoCodec::Hevc])
+///     .min_quality(VideoQuality::P1080);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodecPreference {
+    pub codecs: Vec<VideoCodec>,
+    pub min_quality: Option<VideoQuality>,
+    pub max_quality: Option<VideoQuality>,
+}
+
+impl Default for CodecPreference {
+    fn default() -> Self {
+        Self::new([VideoCodec::Hevc, VideoCodec::Avc, VideoCodec::Av1])
+    }
+}
+
+impl CodecPreference {
+    pub fn new(codecs: impl IntoIterator<Item = VideoCodec>) -> Self {
+        Self {
+            codecs: codecs.into_iter().collect(),
+            min_quality: None,
+            max_quality: None,
+        }
+    }
+
+    /// 最低清晰度
+    pub fn min_quality(mut self, quality: VideoQuality) -> Self {
+        self.min_quality = Some(quality);
+        self
+    }
+
+    /// 最高清晰度
+    pub fn max_quality(mut self, quality: VideoQuality) -> Self {
+        self.max_quality = Some(quality);
+        self
+    }
+
+    fn accepts_quality(&self, id: u32) -> bool {
+        self.min_quality.is_none_or(|q| id >= q.as_u32()) &&
+            self.max_quality.is_none_or(|q| id <= q.as_u32())
+    }
+
+    /// 从视频流中选出符合规则的一条
+    pub fn select<'a>(&self, tracks: &'a [DashTrack]) -> Option<&'a DashTrack> {
+        self.codecs.iter().find_map(|codec| {
+            tracks
+                .iter()
+                .filter(|t| t.codec() == Some(*codec) && self.accepts_quality(t.id))
+                .max_by(|a, b| a.id.cmp(&b.id).then_with(|| a.bandwidth.cmp(&b.bandwidth)))
+        })
+    }
 }
 
 /// 视频伴音音质代码
@@ -168,6 +279,11 @@ impl VideoStreamData {
         })
     }
 
+    /// 按编码协商规则选择视频流
+    pub fn select_video(&self, preference: &CodecPreference) -> Option<&DashTrack> {
+        self.dash.as_ref().and_then(|dash| preference.select(&dash.video))
+    }
+
     /// 获取最佳音频流
     pub fn best_audio(&self) -> Option<&DashTrack> {
         self.dash.as_ref().and_then(|dash| {
@@ -215,6 +331,8 @@ pub struct Durl {
 pub struct SupportFormat {
     pub display_desc: String,
     pub format: String,
+    /// 普通视频不存在
+    #[serde(default)]
     pub description: String,
     pub quality: u32,
     pub new_description: String,
@@ -279,4 +397,109 @@ pub struct DashTrack {
     pub codecid: u32,
     pub size: u64,
     pub md5: Option<String>,
+    /// Widevine PSSH（base64），仅 DRM 流存在
+    #[serde(default)]
+    pub widevine_pssh: Option<String>,
+    /// 密钥 ID（hex），仅 DRM 流存在
+    #[serde(default, alias = "key_id")]
+    pub kid: Option<String>,
+}
+
+impl DashTrack {
+    /// 视频编码，优先使用 codecid，无法识别时解析 codecs 字符串
+    pub fn codec(&self) -> Option<VideoCodec> {
+        VideoCodec::from_codecid(self.codecid).or_else(|| VideoCodec::from_codecs(&self.codecs))
+    }
+
+    /// 主地址与全部备用地址
+    pub fn urls(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.base_url.as_str()).chain(self.backup_url.iter().map(String::as_str))
+    }
+
+    /// 按给定顺序重排主地址与备用地址，未列出的地址保持原有顺序排在最后
+    pub fn prefer_urls<S: AsRef<str>>(&mut self, preferred: &[S]) {
+        let mut urls: Vec<String> = self.urls().map(str::to_string).collect();
+        urls.sort_by_key(|url| {
+            preferred
+                .iter()
+                .position(|p| p.as_ref() == url)
+                .unwrap_or(usize::MAX)
+        });
+
+        let mut urls = urls.into_iter();
+        if let Some(base) = urls.next() {
+            self.base_url = base;
+        }
+        self.backup_url = urls.collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(id: u32, codecid: u32, codecs: &str) -> DashTrack {
+        DashTrack {
+            id,
+            base_url: String::new(),
+            backup_url: Vec::new(),
+            bandwidth: 0,
+            mime_type: "video/mp4".to_string(),
+            codecs: codecs.to_string(),
+            width: 0,
+            height: 0,
+            frame_rate: String::new(),
+            sar: String::new(),
+            start_with_sap: 0,
+            segment_base: SegmentBase {
+                initialization: String::new(),
+                index_range: String::new(),
+            },
+            codecid,
+            size: 0,
+            md5: None,
+            widevine_pssh: None,
+            kid: None,
+        }
+    }
+
+    #[test]
+    fn test_codec_parse() {
+        assert_eq!(VideoCodec::from_codecs("avc1.640032"), Some(VideoCodec::Avc));
+        assert_eq!(VideoCodec::from_codecs("hev1.1.6.L150.90"), Some(VideoCodec::Hevc));
+        assert_eq!(VideoCodec::from_codecs("av01.0.00M.10.0.110.01.01.01.0"), Some(VideoCodec::Av1));
+        assert_eq!(VideoCodec::from_codecs("mp4a.40.2"), None);
+        assert_eq!(track(80, 0, "hvc1.1.6.L150.90").codec(), Some(VideoCodec::Hevc));
+    }
+
+    #[test]
+    fn test_codec_preference_select() {
+        let tracks = vec![
+            track(120, 7, "avc1.640034"),
+            track(80, 12, "hev1.1.6.L150.90"),
+            track(64, 13, "av01.0.00M.10.0.110.01.01.01.0"),
+            track(32, 13, "av01.0.00M.10.0.110.01.01.01.0")
+        ];
+
+        let pref = CodecPreference::new([VideoCodec::Av1, VideoCodec::Hevc]).min_quality(
+            VideoQuality::P1080
+        );
+        assert_eq!(pref.select(&tracks).map(|t| t.id), Some(80));
+
+        let pref = CodecPreference::new([VideoCodec::Av1]);
+        assert_eq!(pref.select(&tracks).map(|t| t.id), Some(64));
+
+        let pref = CodecPreference::new([VideoCodec::Av1]).min_quality(VideoQuality::P4K);
+        assert!(pref.select(&tracks).is_none());
+    }
+
+    #[test]
+    fn test_prefer_urls() {
+        let mut t = track(80, 7, "avc1.640032");
+        t.base_url = "a".to_string();
+        t.backup_url = vec!["b".to_string(), "c".to_string()];
+
+        t.prefer_urls(&["c", "a"]);
+        assert_eq!(t.urls().collect::<Vec<_>>(), vec!["c", "a", "b"]);
+    }
 }
@@ -130,12 +130,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_note_add_and_del() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let oid = 464606672;
         let title = "测试笔记";
         let summary = "这是个测试摘要";
@@ -153,12 +153,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_note_is_forbid() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         // 替换为一个有效的avid
         let aid = 338677252;
         let resp = bpi.note_is_forbid(aid).await;
@@ -174,8 +174,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_note_get_private_info() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let oid = 676931260;
         let note_id = 83577722856540160;
         let resp = bpi.note_get_private_info(oid, note_id).await;
@@ -192,8 +193,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_note_get_public_info() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let cvid = 15160286;
         let resp = bpi.note_get_public_info(cvid).await;
 
@@ -213,12 +213,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_note_list_archive() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         // 替换为一个有效的avid
         let oid = 676931260;
         let resp = bpi.note_list_archive(oid).await;
@@ -234,8 +234,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_note_list_user_private() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let resp = bpi.note_list_user_private(1, 10).await;
 
         info!("{:?}", resp);
@@ -252,8 +253,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_note_list_public_archive() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         // 替换为一个有效的avid
         let oid = 338677252;
         let resp = bpi.note_list_public_archive(oid, 1, 10).await;
@@ -269,8 +271,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_note_list_public_user() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let resp = bpi.note_list_public_user(1, 10).await;
 
         info!("{:?}", resp);
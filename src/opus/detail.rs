@@ -0,0 +1,293 @@
+//! 图文详情
+//!
+//! [图文详情](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/opus/detail.md)
+
+#[cfg(feature = "comment")]
+use crate::comment::{ list::CommentListResponse, resource::{ CommentResourceType, comment_params } };
+use super::module::{ ModuleTitle, ModuleTop, OpusModule };
+use crate::dynamic::module::{ ModuleAuthor, ModuleCollection, ModuleContent, ModuleStat, Paragraph };
+use crate::models::{ OpusFeatures, OpusState };
+use crate::utils::time::{ DEFAULT_TIMEZONE_OFFSET, timestamp_to_datetime };
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use chrono::{ DateTime, FixedOffset };
+use serde::{ Deserialize, Serialize };
+
+/// 图文基本信息
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DetailBasic {
+    /// 评论区 id
+    pub comment_id_str: String,
+    /// 评论区类型
+    pub comment_type: i64,
+    /// 点赞图标
+    pub like_icon: Option<serde_json::Value>,
+    /// 关联 id
+    pub rid_str: String,
+    /// 标题
+    #[serde(default)]
+    pub title: String,
+    /// 作者 mid
+    #[serde(default)]
+    pub uid: u64,
+}
+
+/// 图文详情条目
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DetailItem {
+    /// 基本信息
+    pub basic: DetailBasic,
+    /// opus id
+    pub id_str: String,
+    /// 模块列表
+    pub modules: Vec<OpusModule>,
+    /// 类型
+    #[serde(default)]
+    pub r#type: i64,
+}
+
+/// 在模块列表中查找指定模块
+macro_rules! find_module {
+    ($modules:expr, $variant:ident, $field:ident) => {
+        $modules.iter().find_map(|module| match module {
+            OpusModule::$variant { $field } => Some($field),
+            _ => None,
+        })
+    };
+}
+
+impl DetailItem {
+    /// 发布时间
+    ///
+    /// `timezone_offset` 为分钟偏移，含义同接口参数（UTC+8 为 `-480`）。
+    pub fn published_at(&self, timezone_offset: i32) -> Option<DateTime<FixedOffset>> {
+        timestamp_to_datetime(self.author()?.pub_ts, timezone_offset)
+    }
+
+    /// 标题，图片动态等没有标题时为 `None`
+    pub fn title(&self) -> Option<&str> {
+        find_module!(self.modules, Title, module_title).map(|title: &ModuleTitle| title.text.as_str())
+    }
+
+    /// 作者模块
+    pub fn author(&self) -> Option<&ModuleAuthor> {
+        find_module!(self.modules, Author, module_author).map(|author| author.as_ref())
+    }
+
+    /// 正文模块
+    pub fn content(&self) -> Option<&ModuleContent> {
+        find_module!(self.modules, Content, module_content)
+    }
+
+    /// 统计模块
+    pub fn stat(&self) -> Option<&ModuleStat> {
+        find_module!(self.modules, Stat, module_stat)
+    }
+
+    /// 头图模块
+    pub fn top(&self) -> Option<&ModuleTop> {
+        find_module!(self.modules, Top, module_top)
+    }
+
+    /// 所属文集
+    pub fn collection(&self) -> Option<&ModuleCollection> {
+        find_module!(self.modules, Collection, module_collection)
+    }
+
+    /// 正文纯文本，段落间以换行分隔
+    pub fn plain_text(&self) -> String {
+        self.content()
+            .map(|content| {
+                content.paragraphs
+                    .iter()
+                    .map(Paragraph::plain_text)
+                    .filter(|text| !text.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .unwrap_or_default()
+    }
+
+    /// 评论区参数 `(type, oid)`，取自 `basic.comment_type` 与 `basic.comment_id_str`
+    ///
+    /// 图文的 opus id 与评论区 oid 往往不同（如图片动态使用相簿 id），不能直接混用。
+    #[cfg(feature = "comment")]
+    pub fn comment_params(&self) -> Result<(CommentResourceType, i64), BpiError> {
+        comment_params(self.basic.comment_type, &self.basic.comment_id_str)
+    }
+}
+
+/// 图文详情响应数据
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpusDetailData {
+    pub item: DetailItem,
+}
+
+impl BpiClient {
+    /// 获取图文详情
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/opus)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `id` | &str | opus id |
+    /// | `timezone_offset` | `Option<i32>` | 时区偏移（分钟），默认 -480 |
+    /// | `features` | `Option<OpusFeatures>` | 功能特性，默认 [`OpusFeatures::OPUS_DETAIL`] |
+    pub async fn opus_detail(
+        &self,
+        id: &str,
+        timezone_offset: Option<i32>,
+        features: Option<OpusFeatures>
+    ) -> Result<BpiResponse<OpusDetailData>, BpiError> {
+        let query = vec![
+            ("id", id.to_string()),
+            ("timezone_offset", timezone_offset.unwrap_or(DEFAULT_TIMEZONE_OFFSET).to_string()),
+            ("features", features.unwrap_or(OpusFeatures::OPUS_DETAIL).to_string())
+        ];
+
+        self
+            .get("https://api.bilibili.com/x/polymer/web-dynamic/v1/opus/detail")
+            .query(&query)
+            .send_bpi("获取图文详情").await
+    }
+
+    /// 获取图文详情，并将私密、删除、审核中等情况归类为 [`OpusState`]
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `id` | &str | opus id |
+    pub async fn opus_detail_state(&self, id: &str) -> Result<OpusState<DetailItem>, BpiError> {
+        let result = match self.opus_detail(id, None, None).await {
+            Ok(resp) => resp.into_data().map(|data| data.item),
+            Err(e) => Err(e),
+        };
+        OpusState::from_result(result)
+    }
+
+    /// 获取图文的评论列表
+    ///
+    /// 先获取图文详情取得评论区参数，再按热度获取评论主列表。
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `id` | &str | opus id |
+    /// | `pn` | `Option<i32>` | 页码，默认 1 |
+    #[cfg(feature = "comment")]
+    pub async fn opus_comments(
+        &self,
+        id: &str,
+        pn: Option<i32>
+    ) -> Result<CommentListResponse, BpiError> {
+        let item = self.opus_detail(id, None, None).await?.into_data()?.item;
+        let (r#type, oid) = item.comment_params()?;
+        self.comment_list(r#type, oid, pn, Some(20), Some(1), None).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opus::module::ParagraphKind;
+    use tracing::info;
+
+    #[test]
+    fn test_detail_modules() {
+        let word = |words: &str| {
+            serde_json::json!({
+                "type": "TEXT_NODE_TYPE_WORD", "word": { "font_size": 17, "style": {}, "words": words }
+            })
+        };
+        let item: DetailItem = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "basic": { "comment_id_str": "1", "comment_type": 12, "rid_str": "1", "title": "标题", "uid": 2 },
+                    "id_str": "1099138163191840776",
+                    "type": 1,
+                    "modules": [
+                        { "module_type": "MODULE_TYPE_TITLE", "module_title": { "text": "标题" } },
+                        {
+                            "module_type": "MODULE_TYPE_AUTHOR",
+                            "module_author": { "face": "", "mid": 2, "name": "碧诗", "pub_ts": 1700000000 }
+                        },
+                        {
+                            "module_type": "MODULE_TYPE_TOP",
+                            "module_top": { "display": { "album": { "pics": [{ "url": "https://i0.hdslb.com/1.jpg", "width": 1, "height": 1, "size": 1.5 }] } } }
+                        },
+                        {
+                            "module_type": "MODULE_TYPE_CONTENT",
+                            "module_content": { "paragraphs": [
+                                { "align": 0, "para_type": 1, "text": { "nodes": [word("第一段"), word("。")] } },
+                                { "align": 0, "para_type": 3, "line": { "pic": { "height": 1, "url": "" } } },
+                                { "align": 0, "para_type": 4, "text": { "nodes": [word("引用")] } },
+                                { "align": 0, "para_type": 7, "code": { "content": "fn main() {}", "lang": "language-rust" } }
+                            ] }
+                        },
+                        {
+                            "module_type": "MODULE_TYPE_STAT",
+                            "module_stat": {
+                                "comment": { "count": 1, "forbidden": false },
+                                "forward": { "count": 2, "forbidden": false },
+                                "like": { "count": 3, "forbidden": false }
+                            }
+                        },
+                        { "module_type": "MODULE_TYPE_BLOCKED", "module_blocked": {} }
+                    ]
+                })
+            )
+            .unwrap();
+
+        assert_eq!(item.title(), Some("标题"));
+        assert_eq!(item.author().unwrap().name, "碧诗");
+        assert_eq!(item.published_at(DEFAULT_TIMEZONE_OFFSET).unwrap().timestamp(), 1_700_000_000);
+        assert_eq!(item.top().unwrap().display.album.as_ref().unwrap().pics.len(), 1);
+        assert_eq!(item.stat().unwrap().like.count, 3);
+        assert!(matches!(item.modules.last(), Some(OpusModule::Unknown)));
+
+        let paragraphs = &item.content().unwrap().paragraphs;
+        assert!(matches!(paragraphs[2].kind(), ParagraphKind::Blockquote(_)));
+        assert_eq!(item.plain_text(), "第一段。\n引用\nfn main() {}");
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_opus_detail() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let opus_id = "1099138163191840776";
+        let resp = bpi.opus_detail(opus_id, None, None).await?;
+        let data = resp.into_data()?;
+
+        info!("图文详情: {:?}", data.item.basic);
+        info!("发布时间: {:?}", data.item.published_at(DEFAULT_TIMEZONE_OFFSET));
+        assert_eq!(data.item.id_str, opus_id);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_opus_detail_state() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let state = bpi.opus_detail_state("1099138163191840776").await?;
+
+        assert!(state.is_normal());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "comment")]
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_opus_comments() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi.opus_comments("1099138163191840776", None).await?.into_data()?;
+        info!("评论数: {}", data.replies.unwrap_or_default().len());
+
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
-//! 空间图文
+//! 图文
 
+pub mod detail;
+pub mod module;
 pub mod space;
@@ -0,0 +1,185 @@
+//! 图文详情的模块
+//!
+//! 图文详情的 `modules` 为模块列表，每个模块按 `module_type` 只包含对应的字段，
+//! 这里解析为 [`OpusModule`]。作者、正文、统计等模块的结构与动态共用，见 [`crate::dynamic::module`]。
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/opus/detail.md)
+
+use serde::{ Deserialize, Serialize };
+
+use crate::dynamic::module::{
+    ModuleAuthor,
+    ModuleBottom,
+    ModuleCollection,
+    ModuleContent,
+    ModuleExtend,
+    ModuleMore,
+    ModuleStat,
+    ModuleTopic,
+    Paragraph,
+    ParagraphCode,
+    ParagraphLine,
+    ParagraphList,
+    ParagraphPics,
+    LinkCard,
+    Text,
+    TextNode,
+};
+
+/// 图文详情中的一个模块
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "module_type")]
+pub enum OpusModule {
+    /// 标题
+    #[serde(rename = "MODULE_TYPE_TITLE")]
+    Title {
+        module_title: ModuleTitle,
+    },
+    /// 作者
+    #[serde(rename = "MODULE_TYPE_AUTHOR")]
+    Author {
+        module_author: Box<ModuleAuthor>,
+    },
+    /// 头图，图片动态的图集
+    #[serde(rename = "MODULE_TYPE_TOP")]
+    Top {
+        module_top: ModuleTop,
+    },
+    /// 正文
+    #[serde(rename = "MODULE_TYPE_CONTENT")]
+    Content {
+        module_content: ModuleContent,
+    },
+    /// 话题
+    #[serde(rename = "MODULE_TYPE_TOPIC")]
+    Topic {
+        module_topic: ModuleTopic,
+    },
+    /// 所属文集
+    #[serde(rename = "MODULE_TYPE_COLLECTION")]
+    Collection {
+        module_collection: ModuleCollection,
+    },
+    /// 扩展，如关联的游戏、商品
+    #[serde(rename = "MODULE_TYPE_EXTEND")]
+    Extend {
+        module_extend: ModuleExtend,
+    },
+    /// 底部分享信息
+    #[serde(rename = "MODULE_TYPE_BOTTOM")]
+    Bottom {
+        module_bottom: ModuleBottom,
+    },
+    /// 统计
+    #[serde(rename = "MODULE_TYPE_STAT")]
+    Stat {
+        module_stat: ModuleStat,
+    },
+    /// 三点菜单
+    #[serde(rename = "MODULE_TYPE_MORE")]
+    More {
+        module_more: ModuleMore,
+    },
+    /// 其他未解析的模块
+    #[serde(other)]
+    Unknown,
+}
+
+/// 标题模块
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModuleTitle {
+    /// 标题
+    pub text: String,
+}
+
+/// 头图模块
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModuleTop {
+    pub display: TopDisplay,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TopDisplay {
+    /// 图集，仅图片动态
+    pub album: Option<TopAlbum>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TopAlbum {
+    /// 图片
+    pub pics: Vec<TopPic>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TopPic {
+    /// 图片 URL
+    pub url: String,
+    pub width: i64,
+    pub height: i64,
+    /// 大小，单位 KB
+    pub size: f64,
+}
+
+/// 按 `para_type` 区分的段落内容
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParagraphKind<'a> {
+    Text(&'a Text),
+    Pics(&'a ParagraphPics),
+    Line(&'a ParagraphLine),
+    /// 块引用
+    Blockquote(&'a Text),
+    List(&'a ParagraphList),
+    LinkCard(&'a LinkCard),
+    Code(&'a ParagraphCode),
+    /// 未知类型或缺少对应字段
+    Unknown(i64),
+}
+
+impl Paragraph {
+    /// 按 `para_type` 取出段落内容
+    pub fn kind(&self) -> ParagraphKind<'_> {
+        let kind = match self.para_type {
+            1 => self.text.as_ref().map(ParagraphKind::Text),
+            2 => self.pics.as_ref().map(ParagraphKind::Pics),
+            3 => self.line.as_ref().map(ParagraphKind::Line),
+            4 => self.text.as_ref().map(ParagraphKind::Blockquote),
+            5 => self.list.as_ref().map(ParagraphKind::List),
+            6 => self.link_card.as_ref().map(ParagraphKind::LinkCard),
+            7 => self.code.as_ref().map(ParagraphKind::Code),
+            _ => None,
+        };
+        kind.unwrap_or(ParagraphKind::Unknown(self.para_type))
+    }
+
+    /// 段落的纯文本，图片、分割线等为空
+    pub fn plain_text(&self) -> String {
+        fn words(nodes: &[TextNode]) -> String {
+            nodes
+                .iter()
+                .filter_map(|node| {
+                    match &node.word {
+                        Some(word) => Some(word.words.clone()),
+                        None => node.rich.as_ref()?.get("text")?.as_str().map(str::to_string),
+                    }
+                })
+                .collect()
+        }
+
+        match self.kind() {
+            ParagraphKind::Text(text) | ParagraphKind::Blockquote(text) => words(&text.nodes),
+            ParagraphKind::List(list) => {
+                list.items
+                    .iter()
+                    .map(|item| words(&item.nodes))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            ParagraphKind::Code(code) => code.content.clone(),
+            _ => String::new(),
+        }
+    }
+}
@@ -3,6 +3,8 @@
 //! [空间图文](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/opus/space.md#空间图文)
 
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use crate::utils::query::Query;
+use crate::utils::web_location::WebLocation;
 use serde::{ Deserialize, Serialize };
 
 /// 空间图文封面信息
@@ -53,6 +55,34 @@ pub struct SpaceData {
     pub update_num: u32,
 }
 
+/// 空间图文查询选项，未设置的参数不会发送
+#[derive(Debug, Clone, Default)]
+pub struct OpusSpaceFeedOptions {
+    /// 页码
+    pub page: Option<u32>,
+    /// 上一页返回的 offset
+    pub offset: Option<String>,
+    /// 类型：`all`/`article`/`dynamic`，默认 `all`
+    pub typ: Option<String>,
+}
+
+impl OpusSpaceFeedOptions {
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn offset(mut self, offset: impl Into<String>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    pub fn typ(mut self, typ: impl Into<String>) -> Self {
+        self.typ = Some(typ.into());
+        self
+    }
+}
+
 impl BpiClient {
     /// 获取用户空间图文
     ///
@@ -64,23 +94,18 @@ impl BpiClient {
     /// | 名称 | 类型 | 说明 |
     /// | ---- | ---- | ---- |
     /// | `mid` | u64 | 用户 UID |
-    /// | `page` | `Option<u32>` | 页码，默认 0 |
-    /// | `offset` | `Option<&str>` | 下一页偏移量 |
-    /// | `typ` | `Option<&str>` | 类型：`all`/`article`/`dynamic`，默认 `all` |
+    /// | `options` | `OpusSpaceFeedOptions` | 页码、offset 与类型 |
     pub async fn opus_space_feed(
         &self,
         mid: u64,
-        page: Option<u32>,
-        offset: Option<&str>,
-        typ: Option<&str> // all/article/dynamic
+        options: OpusSpaceFeedOptions
     ) -> Result<BpiResponse<SpaceData>, BpiError> {
-        let query = vec![
-            ("host_mid", mid.to_string()),
-            ("page", page.unwrap_or(0).to_string()),
-            ("offset", offset.unwrap_or("").to_string()),
-            ("type", typ.unwrap_or("all").to_string()),
-            ("web_location", "333.1387".to_string())
-        ];
+        let query = Query::new()
+            .with("host_mid", mid)
+            .with_opt("page", options.page)
+            .with_opt("offset", options.offset)
+            .with("type", options.typ.as_deref().unwrap_or("all"))
+            .with("web_location", self.web_location(WebLocation::SPACE));
 
         self
             .get("https://api.bilibili.com/x/polymer/web-dynamic/v1/opus/feed/space")
@@ -95,9 +120,10 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_opus_space_feed() {
-        let bpi = BpiClient::new();
-        let resp = bpi.opus_space_feed(4279370, Some(1), None, None).await;
+        let bpi = crate::test_client!(Guest);
+        let resp = bpi.opus_space_feed(4279370, OpusSpaceFeedOptions::default().page(1)).await;
         assert!(resp.is_ok());
         if let Ok(r) = resp {
             info!("空间图文返回: {:?}", r);
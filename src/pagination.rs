@@ -0,0 +1,116 @@
+//! 分页列表的异步流
+//!
+//! 评论、粉丝、收藏夹、动态、历史记录等列表接口按页码或游标翻页。各模块的 `*_stream`
+//! 方法基于 [`paginate`] 自动翻页，逐项输出结果，调用方不必自行维护页码与游标：
+//!
+//! ```no_run
+//! # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+//! use futures_util::{ StreamExt, TryStreamExt };
+//!
+//! let mut medias = Box::pin(bpi.fav_list_stream(1052622027));
+//! while let Some(media) = medias.try_next().await? {
+//!     println!("{}", media.title);
+//! }
+//!
+//! // 只取前 100 项
+//! let first: Vec<_> = bpi.history_stream(None).take(100).try_collect().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::future::Future;
+
+use futures_util::Stream;
+
+use crate::BpiError;
+
+/// 一页结果，以及获取下一页所需的页码或游标
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paginated<T, C> {
+    pub items: Vec<T>,
+    /// 下一页的页码或游标，已到末页时为 `None`
+    pub next: Option<C>,
+}
+
+impl<T, C> Paginated<T, C> {
+    pub fn new(items: Vec<T>, next: Option<C>) -> Self {
+        Self { items, next }
+    }
+}
+
+struct PageState<T, C, F> {
+    fetch: F,
+    next: Option<C>,
+    buffer: VecDeque<T>,
+}
+
+/// 从 `start` 开始逐页调用 `fetch`，依次输出每一项
+///
+/// 请求出错时输出该错误后结束；某页为空时同样结束，避免接口返回异常游标时无限翻页。
+/// 流是惰性的，只有消费到当前页末尾时才请求下一页。
+pub fn paginate<T, C, F, Fut>(start: C, fetch: F) -> impl Stream<Item = Result<T, BpiError>>
+    where F: FnMut(C) -> Fut, Fut: Future<Output = Result<Paginated<T, C>, BpiError>>
+{
+    let state = PageState { fetch, next: Some(start), buffer: VecDeque::new() };
+
+    futures_util::stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                return Some((Ok(item), state));
+            }
+
+            let cursor = state.next.take()?;
+            match (state.fetch)(cursor).await {
+                Ok(page) if page.items.is_empty() => {
+                    return None;
+                }
+                Ok(page) => {
+                    state.next = page.next;
+                    state.buffer.extend(page.items);
+                }
+                Err(e) => {
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::{ StreamExt, TryStreamExt };
+
+    #[tokio::test]
+    async fn test_paginate_follows_pages() {
+        let stream = paginate(1u32, |pn| async move {
+            let items = ((pn - 1) * 2..pn * 2).collect();
+            Ok(Paginated::new(items, (pn < 3).then_some(pn + 1)))
+        });
+        let items: Vec<u32> = stream.try_collect().await.unwrap();
+        assert_eq!(items, [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_error_and_empty_page() {
+        let mut calls = 0;
+        let stream = paginate(1u32, |pn| {
+            calls += 1;
+            async move {
+                if pn == 2 {
+                    Err(BpiError::missing_data())
+                } else {
+                    Ok(Paginated::new(vec![pn], Some(pn + 1)))
+                }
+            }
+        });
+        let results: Vec<_> = stream.collect().await;
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok() && results[1].is_err());
+        assert_eq!(calls, 2);
+
+        let empty = paginate(1u32, |_| async { Ok(Paginated::<u32, u32>::new(Vec::new(), Some(2))) });
+        assert_eq!(empty.collect::<Vec<_>>().await.len(), 0);
+    }
+}
@@ -0,0 +1,29 @@
+//! 常用类型一览
+//!
+//! 下游代码只需 `use bpi_rs::prelude::*;` 即可引入客户端、账号、常用枚举与流扩展，
+//! 无需逐个书写导入路径。
+//!
+//! ```no_run
+//! use bpi_rs::prelude::*;
+//!
+//! # async fn run() -> Result<(), BpiError> {
+//! let bpi = BpiClient::new();
+//! let quality = VideoQuality::P1080;
+//! # let _ = (bpi, quality);
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::{ Account, BilibiliRequest, BpiClient, BpiClientBuilder, BpiError, BpiResponse };
+
+pub use crate::models::{ AudioQuality, CodecPreference, Fnval, VideoCodec, VideoQuality };
+
+pub use crate::pagination::Paginated;
+
+#[cfg(feature = "search")]
+pub use crate::search::search_params::{ SearchOrder, SearchType };
+
+#[cfg(feature = "video")]
+pub use crate::video::video_zone_v2::VideoPartitionV2;
+
+pub use futures_util::{ Stream, StreamExt, TryStreamExt };
@@ -0,0 +1,260 @@
+//! 接口防护检测
+//!
+//! B站各接口要求的校验不同：路径含 `/wbi/` 的接口需要 WBI 签名，写操作需要 CSRF，
+//! 部分登录接口需要 APP 签名，搜索等接口缺少 `buvid3` 时会触发风控。
+//! 这里按域名与路径维护一张防护表，[`BilibiliRequest::send_bpi`](crate::BilibiliRequest::send_bpi)
+//! 发送前据此检查请求：缺少 WBI 签名时自动签名，其余无法补全的防护在发出请求前直接返回错误，
+//! 例如游客模式下调用需要 CSRF 的写接口。APP 签名需要 appsec，由
+//! [`BilibiliRequest::with_app_sign`](crate::BilibiliRequest::with_app_sign) 指定后在这里完成。
+//!
+//! Cookie 由 cookie jar 在发送时附加，无法在发送前读取，因此 `buvid3` 与 `bili_ticket`
+//! 只在请求显式携带 `Cookie` 请求头时（如 [`BpiClient::with_credentials`](crate::BpiClient::with_credentials)）检查。
+//!
+//! ```
+//! use bpi_rs::protection::Protection;
+//! use reqwest::{ Method, Url };
+//!
+//! let url = Url::parse("https://api.bilibili.com/x/web-interface/wbi/search/type").unwrap();
+//! let required = Protection::required(&Method::GET, &url);
+//! assert!(required.contains(Protection::WBI | Protection::BUVID));
+//! assert!(!required.contains(Protection::CSRF));
+//! ```
+
+use reqwest::{ Client, Method, Request, Url };
+
+use crate::BpiError;
+use crate::utils::app_sign;
+use crate::utils::wbi::{ NAV_URL, sign_url, wbi_keys_via };
+
+bitflags::bitflags! {
+    /// 接口要求的防护
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Protection: u8 {
+        /// 查询参数 WBI 签名（`w_rid`、`wts`）
+        const WBI = 1 << 0;
+        /// 表单或查询参数中的 CSRF（`bili_jct`）
+        const CSRF = 1 << 1;
+        /// APP 签名（`appkey`、`sign`）
+        const APP_SIGN = 1 << 2;
+        /// Cookie 中的 `buvid3`
+        const BUVID = 1 << 3;
+        /// Cookie 中的 `bili_ticket`，非必需，缺少时风控概率更高
+        const BILI_TICKET = 1 << 4;
+    }
+}
+
+/// 需要 CSRF 的写接口所在域名，POST 请求默认要求 CSRF
+const CSRF_HOSTS: &[&str] = &[
+    "api.bilibili.com",
+    "api.live.bilibili.com",
+    "api.vc.bilibili.com",
+    "member.bilibili.com",
+    "passport.bilibili.com",
+];
+
+/// 上述域名中不需要 CSRF 的 POST 接口（路径前缀）
+const CSRF_EXEMPT: &[&str] = &[
+    "/bapis/bilibili.api.ticket.v1.Ticket/",
+    "/x/passport-login/web/sms/",
+    "/x/passport-login/web/login",
+    "/x/passport-login/oauth2/",
+    "/x/passport-login/web/qrcode/",
+    "/x/passport-tv-login/",
+];
+
+/// 按域名与路径前缀登记的防护
+const RULES: &[(&str, &str, Protection)] = &[
+    ("app.bilibili.com", "/x/v2/", Protection::APP_SIGN),
+    ("passport.bilibili.com", "/login/app/third", Protection::APP_SIGN),
+    ("passport.bilibili.com", "/api/login/sso", Protection::APP_SIGN),
+    ("passport.bilibili.com", "/x/passport-tv-login/", Protection::APP_SIGN),
+    (
+        "api.bilibili.com",
+        "/x/web-interface/wbi/search/",
+        Protection::BUVID.union(Protection::BILI_TICKET),
+    ),
+    (
+        "api.bilibili.com",
+        "/x/web-interface/search/",
+        Protection::BUVID.union(Protection::BILI_TICKET),
+    ),
+];
+
+/// 表单中 CSRF 的字段名，直播接口另需 `csrf_token`，退出登录为 `biliCSRF`
+const CSRF_KEYS: &[&str] = &["csrf", "csrf_token", "biliCSRF"];
+
+impl Protection {
+    /// 请求该接口需要的防护
+    pub fn required(method: &Method, url: &Url) -> Self {
+        let host = url.host_str().unwrap_or_default();
+        let path = url.path();
+
+        let mut required = Self::empty();
+        if path.contains("/wbi/") {
+            required |= Self::WBI;
+        }
+        if
+            method == Method::POST &&
+            CSRF_HOSTS.contains(&host) &&
+            !CSRF_EXEMPT.iter().any(|prefix| path.starts_with(prefix))
+        {
+            required |= Self::CSRF;
+        }
+        for (rule_host, prefix, protection) in RULES {
+            if host == *rule_host && path.starts_with(prefix) {
+                required |= *protection;
+            }
+        }
+        required
+    }
+}
+
+/// 按防护表检查请求，补上 WBI 与 APP 签名，无法满足的防护返回错误
+pub(crate) async fn apply(client: &Client, request: &mut Request) -> Result<(), BpiError> {
+    app_sign::sign_request(request)?;
+
+    let required = Protection::required(request.method(), request.url());
+    if required.is_empty() {
+        return Ok(());
+    }
+
+    // multipart 等流式请求体无法读取，其中的参数无法检查
+    let body_unreadable = request.body().is_some_and(|body| body.as_bytes().is_none());
+    let body = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(String::from_utf8_lossy)
+        .unwrap_or_default()
+        .into_owned();
+    let query = request.url().query().unwrap_or_default().to_string();
+    let has_param = |key: &str| has_param(&query, key) || has_param(&body, key);
+
+    if required.contains(Protection::CSRF) && !CSRF_KEYS.iter().any(|key| has_param(key)) {
+        if !body_unreadable {
+            return Err(BpiError::missing_csrf());
+        }
+        tracing::debug!("{} 的请求体无法读取，跳过 csrf 检查", request.url().path());
+    }
+
+    if
+        required.contains(Protection::APP_SIGN) &&
+        !body_unreadable &&
+        !(has_param("appkey") && has_param("sign"))
+    {
+        return Err(BpiError::invalid_parameter("sign", "该接口需要 APP 签名"));
+    }
+
+    if let Some(cookie) = request.headers().get(reqwest::header::COOKIE) {
+        let cookie = cookie.to_str().unwrap_or_default();
+        if required.contains(Protection::BUVID) && !has_cookie(cookie, "buvid3") {
+            return Err(BpiError::invalid_parameter("buvid3", "该接口需要 Cookie 中的 buvid3"));
+        }
+        if required.contains(Protection::BILI_TICKET) && !has_cookie(cookie, "bili_ticket") {
+            tracing::debug!("{} 未携带 bili_ticket，可能触发风控", request.url().path());
+        }
+    }
+
+    // 已手动签名（查询参数或表单中带 w_rid）时不再重复签名
+    if required.contains(Protection::WBI) && !has_param("w_rid") {
+        let keys = wbi_keys_via(client.get(NAV_URL)).await?;
+        sign_url(request.url_mut(), &keys);
+    }
+
+    Ok(())
+}
+
+/// `a=1&b=2` 形式的参数中是否有非空的 `key`
+fn has_param(params: &str, key: &str) -> bool {
+    params
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .any(|(k, v)| k == key && !v.is_empty())
+}
+
+/// `a=1; b=2` 形式的 Cookie 中是否有非空的 `name`
+fn has_cookie(cookie: &str, name: &str) -> bool {
+    cookie
+        .split(';')
+        .filter_map(|pair| pair.trim().split_once('='))
+        .any(|(k, v)| k == name && !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(url: &str) -> Url {
+        Url::parse(url).unwrap()
+    }
+
+    #[test]
+    fn test_required() {
+        let like = url("https://api.bilibili.com/x/web-interface/archive/like");
+        assert_eq!(Protection::required(&Method::POST, &like), Protection::CSRF);
+        assert!(Protection::required(&Method::GET, &like).is_empty());
+
+        let ticket = url("https://api.bilibili.com/bapis/bilibili.api.ticket.v1.Ticket/GenWebTicket");
+        assert!(Protection::required(&Method::POST, &ticket).is_empty());
+
+        let sso = url("https://passport.bilibili.com/api/login/sso");
+        assert_eq!(Protection::required(&Method::GET, &sso), Protection::APP_SIGN);
+
+        let manga = url("https://manga.bilibili.com/twirp/activity.v1.Activity/ClockIn");
+        assert!(Protection::required(&Method::POST, &manga).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_apply_rejects_before_sending() {
+        let client = Client::new();
+
+        let mut request = client
+            .post("https://api.bilibili.com/x/web-interface/archive/like")
+            .form(&[("aid", "2"), ("like", "1"), ("csrf", "")])
+            .build()
+            .unwrap();
+        let err = apply(&client, &mut request).await.unwrap_err();
+        assert!(matches!(err, BpiError::InvalidParameter { field: "csrf", .. }));
+
+        let mut request = client
+            .post("https://api.live.bilibili.com/msg/send")
+            .form(&[("roomid", "1"), ("csrf_token", "abc"), ("csrf", "abc")])
+            .build()
+            .unwrap();
+        apply(&client, &mut request).await.unwrap();
+
+        let mut request = client
+            .get("https://passport.bilibili.com/login/app/third")
+            .query(&[("api", "x"), ("appkey", "27eb53fc9058f8c3")])
+            .build()
+            .unwrap();
+        let err = apply(&client, &mut request).await.unwrap_err();
+        assert!(matches!(err, BpiError::InvalidParameter { field: "sign", .. }));
+
+        let mut request = client
+            .get("https://api.bilibili.com/x/web-interface/wbi/search/type")
+            .query(&[("keyword", "rust"), ("w_rid", "abc"), ("wts", "1")])
+            .header(reqwest::header::COOKIE, "SESSDATA=1; buvid3=")
+            .build()
+            .unwrap();
+        let err = apply(&client, &mut request).await.unwrap_err();
+        assert!(matches!(err, BpiError::InvalidParameter { field: "buvid3", .. }));
+    }
+
+    #[tokio::test]
+    async fn test_apply_multipart_csrf() {
+        let client = Client::new();
+        let form = reqwest::multipart::Form
+            ::new()
+            .text("fid", "2")
+            .text("act", "1")
+            .text("csrf", "abc");
+
+        let mut request = client
+            .post("https://api.bilibili.com/x/relation/modify")
+            .multipart(form)
+            .build()
+            .unwrap();
+        assert!(request.body().is_some_and(|body| body.as_bytes().is_none()));
+        apply(&client, &mut request).await.unwrap();
+    }
+}
@@ -0,0 +1,283 @@
+//! 请求限速
+//!
+//! 批量遍历粉丝、评论等长列表时请求过快会触发风控（-412），IP 会被拦截一段时间。
+//! 设置限速后，每个域名按令牌桶发放请求配额，配额用完时发送前自动等待。
+//!
+//! 每个 client 有自己的一组令牌桶，[`BpiClient::with_credentials`] 派生的 client 与原 client 共用。
+//! 风控按 IP 统计，同一出口 IP 下使用多个独立 client 时需分别设置、合理分配配额。
+//! 限速在 [`BilibiliRequest::send_request`](crate::BilibiliRequest::send_request) 中生效，
+//! 同样作用于 `send_bpi` 的每次重试。
+//!
+//! ```no_run
+//! use bpi_rs::BpiClient;
+//! use bpi_rs::rate_limit::RateLimit;
+//!
+//! let bpi = BpiClient::new();
+//! // 每个域名每秒最多 5 个请求
+//! bpi.set_rate_limit(Some(RateLimit::per_second(5)));
+//! // 直播接口单独放慢
+//! bpi.set_host_rate_limit("api.live.bilibili.com", Some(RateLimit::per_second(2).burst(1)));
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::BpiClient;
+
+/// 令牌桶限速规则
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// 每秒补充的请求数
+    pub rate: f64,
+    /// 桶容量，即空闲后允许连续发出的请求数
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// 每 `per` 时间内最多 `requests` 个请求，容量为 `requests`
+    pub fn new(requests: u32, per: Duration) -> Self {
+        Self {
+            rate: (requests as f64) / per.as_secs_f64().max(f64::EPSILON),
+            burst: requests.max(1),
+        }
+    }
+
+    /// 每秒最多 `requests` 个请求
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    /// 每分钟最多 `requests` 个请求
+    pub fn per_minute(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(60))
+    }
+
+    /// 修改桶容量，设为 1 时请求严格均匀发出
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+}
+
+struct Bucket {
+    limit: RateLimit,
+    /// 剩余令牌，为负时表示已被预约的等待
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit) -> Self {
+        Self { limit, tokens: limit.burst as f64, updated: Instant::now() }
+    }
+
+    /// 取走一个令牌，返回需要等待的时间
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.limit.rate).min(self.limit.burst as f64);
+        self.updated = now;
+        self.tokens -= 1.0;
+
+        if self.tokens >= 0.0 || self.limit.rate <= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.limit.rate)
+        }
+    }
+}
+
+/// 按域名限速的令牌桶集合
+#[derive(Default)]
+pub struct RateLimiter {
+    state: Mutex<LimiterState>,
+}
+
+#[derive(Default)]
+struct LimiterState {
+    default: Option<RateLimit>,
+    hosts: HashMap<String, Option<RateLimit>>,
+    buckets: HashMap<String, Bucket>,
+}
+
+impl LimiterState {
+    fn limit_for(&self, host: &str) -> Option<RateLimit> {
+        self.hosts.get(host).copied().unwrap_or(self.default)
+    }
+}
+
+impl std::fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        f.debug_struct("RateLimiter")
+            .field("default", &state.default)
+            .field("hosts", &state.hosts)
+            .finish()
+    }
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置未单独配置的域名使用的限速，`None` 表示不限速
+    pub fn set_default(&self, limit: Option<RateLimit>) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.default = limit;
+        state.buckets.clear();
+    }
+
+    /// 单独设置某个域名的限速，`None` 表示该域名不限速
+    pub fn set_host(&self, host: &str, limit: Option<RateLimit>) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.hosts.insert(host.to_string(), limit);
+        state.buckets.remove(host);
+    }
+
+    /// 取消域名的单独设置，恢复使用默认限速
+    pub fn reset_host(&self, host: &str) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.hosts.remove(host);
+        state.buckets.remove(host);
+    }
+
+    /// 域名当前生效的限速
+    pub fn limit_for(&self, host: &str) -> Option<RateLimit> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).limit_for(host)
+    }
+
+    /// 为一次请求预约配额，返回需要等待的时间
+    fn reserve(&self, host: &str) -> Duration {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(limit) = state.limit_for(host) else {
+            return Duration::ZERO;
+        };
+        state.buckets
+            .entry(host.to_string())
+            .or_insert_with(|| Bucket::new(limit))
+            .reserve(Instant::now())
+    }
+
+    /// 等待直到可以向 `host` 发出请求
+    pub async fn acquire(&self, host: &str) {
+        let wait = self.reserve(host);
+        if !wait.is_zero() {
+            tracing::debug!("{} 限速，等待 {:.2?}", host, wait);
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+impl BpiClient {
+    /// 设置该 client 每个域名的请求限速，`None` 表示不限速（默认）
+    ///
+    /// 也可在创建时通过 [`BpiClientBuilder::rate_limit`](crate::BpiClientBuilder::rate_limit) 设置，
+    /// 见 [模块文档](crate::rate_limit)。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `limit` | `Option<RateLimit>` | 限速规则，各域名分别计数 |
+    pub fn set_rate_limit(&self, limit: Option<RateLimit>) {
+        self.rate_limiter().set_default(limit);
+    }
+
+    /// 单独设置某个域名的限速，覆盖 [`BpiClient::set_rate_limit`]
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `host` | &str | 域名，如 `api.bilibili.com` |
+    /// | `limit` | `Option<RateLimit>` | 限速规则，`None` 表示该域名不限速 |
+    pub fn set_host_rate_limit(&self, host: &str, limit: Option<RateLimit>) {
+        self.rate_limiter().set_host(host, limit);
+    }
+
+    /// 该 client 的限速器
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.policy.rate_limiter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_reserve() {
+        let start = Instant::now();
+        let mut bucket = Bucket::new(RateLimit::per_second(2));
+        bucket.updated = start;
+
+        assert_eq!(bucket.reserve(start), Duration::ZERO);
+        assert_eq!(bucket.reserve(start), Duration::ZERO);
+        // 容量用完后按每秒 2 个排队
+        assert_eq!(bucket.reserve(start), Duration::from_millis(500));
+        assert_eq!(bucket.reserve(start), Duration::from_secs(1));
+
+        // 空闲足够久后恢复到满容量
+        let later = start + Duration::from_secs(10);
+        assert_eq!(bucket.reserve(later), Duration::ZERO);
+        assert_eq!(bucket.tokens, 1.0);
+    }
+
+    #[test]
+    fn test_limiter_hosts() {
+        let limiter = RateLimiter::new();
+        assert_eq!(limiter.reserve("api.bilibili.com"), Duration::ZERO);
+        assert!(limiter.limit_for("api.bilibili.com").is_none());
+
+        limiter.set_default(Some(RateLimit::per_second(1)));
+        limiter.set_host("api.live.bilibili.com", None);
+        assert_eq!(limiter.reserve("api.bilibili.com"), Duration::ZERO);
+        assert!(limiter.reserve("api.bilibili.com") > Duration::ZERO);
+        // 各域名分别计数
+        assert_eq!(limiter.reserve("passport.bilibili.com"), Duration::ZERO);
+        assert_eq!(limiter.reserve("api.live.bilibili.com"), Duration::ZERO);
+        assert_eq!(limiter.reserve("api.live.bilibili.com"), Duration::ZERO);
+
+        limiter.reset_host("api.live.bilibili.com");
+        assert_eq!(limiter.limit_for("api.live.bilibili.com"), Some(RateLimit::per_second(1)));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits() {
+        let limiter = RateLimiter::new();
+        limiter.set_default(Some(RateLimit::per_second(20).burst(1)));
+
+        let start = std::time::Instant::now();
+        for _ in 0..3 {
+            limiter.acquire("api.bilibili.com").await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[test]
+    fn test_rate_limit_per_client() {
+        let limited = BpiClient::builder().rate_limit(Some(RateLimit::per_second(1))).build().unwrap();
+        let other = BpiClient::try_new_local().unwrap();
+        let derived = limited.with_credentials(&crate::Account::default());
+
+        assert!(limited.rate_limiter().limit_for("api.bilibili.com").is_some());
+        assert!(derived.rate_limiter().limit_for("api.bilibili.com").is_some());
+        assert!(other.rate_limiter().limit_for("api.bilibili.com").is_none());
+
+        other.set_rate_limit(Some(RateLimit::per_minute(1)));
+        assert_eq!(limited.rate_limiter().limit_for("api.bilibili.com"), Some(RateLimit::per_second(1)));
+    }
+
+    #[tokio::test]
+    async fn test_policy_attached_to_requests() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        bpi.set_rate_limit(Some(RateLimit::per_second(1)));
+        let request = bpi.get("https://api.bilibili.com/x/web-interface/nav").build().unwrap();
+
+        let (request, policy) = crate::request::request_policy(request).unwrap();
+        assert_eq!(request.url().path(), "/x/web-interface/nav");
+        let policy = policy.unwrap();
+        assert!(std::ptr::eq(&policy.rate_limiter, bpi.rate_limiter()));
+    }
+}
@@ -1,13 +1,80 @@
-use crate::{ BpiError, response::BpiResponse };
-use reqwest::RequestBuilder;
+use std::sync::{ Arc, RwLock };
+
+use crate::protection;
+use crate::rate_limit::RateLimiter;
+use crate::AppToken;
+use crate::utils::app_sign::{ self, ACCESS_KEY_HEADER, APP_SIGN_HEADER, AppKey };
+use crate::utils::wbi::{ NAV_URL, sign_url, wbi_keys_via };
+use crate::{ BpiError, RetryConfig, response::BpiResponse };
+use reqwest::{ Request, RequestBuilder };
 use serde::de::DeserializeOwned;
 use tokio::time::Instant;
 use tracing;
 
+/// 默认 User-Agent，可通过 [`BpiClientBuilder::user_agent`](crate::BpiClientBuilder::user_agent) 修改
+pub const DEFAULT_USER_AGENT: &str =
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+/// client 级别的请求设置，由 [`BpiClient::get`](crate::BpiClient::get)/[`BpiClient::post`](crate::BpiClient::post)
+/// 附加到请求上，发送时读取
+///
+/// 以请求扩展而非请求头传递，不会随请求发出。未附加时（如直接使用 `reqwest::Client` 构造的请求）
+/// 不限速，使用默认重试策略。
+#[derive(Debug, Default)]
+pub(crate) struct RequestPolicy {
+    pub(crate) rate_limiter: RateLimiter,
+    pub(crate) retry: RwLock<RetryConfig>,
+}
+
+impl RequestPolicy {
+    pub(crate) fn retry_config(&self) -> RetryConfig {
+        *self.retry.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 把 client 的请求设置附加到请求上，请求已出错时原样返回
+pub(crate) fn attach_policy(req: RequestBuilder, policy: &Arc<RequestPolicy>) -> RequestBuilder {
+    let Some(copy) = req.try_clone() else {
+        return req;
+    };
+    let (client, request) = copy.build_split();
+    let Ok(mut http) = request.and_then(http::Request::<reqwest::Body>::try_from) else {
+        return req;
+    };
+    http.extensions_mut().insert(policy.clone());
+    match Request::try_from(http) {
+        Ok(request) => RequestBuilder::from_parts(client, request),
+        Err(_) => req,
+    }
+}
+
+/// 取出请求上附加的 client 设置
+pub(crate) fn request_policy(request: Request) -> Result<(Request, Option<Arc<RequestPolicy>>), BpiError> {
+    // 无法表示为 http::Uri 的地址不会附加设置
+    if http::Uri::try_from(request.url().as_str()).is_err() {
+        return Ok((request, None));
+    }
+    let http = http::Request::<reqwest::Body>::try_from(request)?;
+    let policy = http.extensions().get::<Arc<RequestPolicy>>().cloned();
+    Ok((Request::try_from(http)?, policy))
+}
+
 pub trait BilibiliRequest {
     fn with_bilibili_headers(self) -> Self;
     fn with_user_agent(self) -> Self;
 
+    /// 标记请求需要 APP 签名，[`send_request`](Self::send_request) 与 [`send_bpi`](Self::send_bpi)
+    /// 发送前对表单或查询参数签名
+    ///
+    /// 签名在发送时进行，因此可以在添加参数之前调用。标记以内部请求头记录，发送前移除，
+    /// 需通过上述方法发送，直接调用 `send` 时不会签名。
+    fn with_app_sign(self, key: AppKey) -> Self;
+
+    /// 以 APP 登录凭证鉴权：发送前写入 `access_key`，并用签发凭证的 appkey 签名
+    ///
+    /// appkey 不在 [`AppKey::KNOWN`] 中时只写入 `access_key`，需另行调用 [`with_app_sign`](Self::with_app_sign)。
+    fn with_access_key(self, token: &AppToken) -> Self;
+
     fn send_request(
         self,
         operation_name: &str
@@ -20,30 +87,57 @@ pub trait BilibiliRequest {
         -> impl std::future::Future<Output = Result<BpiResponse<T>, BpiError>> + Send
         where Self: Sized + Send, T: DeserializeOwned;
 
+    /// 对查询参数进行 WBI 签名后发送，签名使用缓存的密钥，过期时自动刷新
+    ///
+    /// 仅签名 URL 中的查询参数，请求体中的参数需使用 [`BpiClient::get_wbi_sign2`](crate::BpiClient::get_wbi_sign2)。
+    fn send_bpi_wbi<T>(
+        self,
+        operation_name: &str
+    )
+        -> impl std::future::Future<Output = Result<BpiResponse<T>, BpiError>> + Send
+        where Self: Sized + Send, T: DeserializeOwned;
+
     fn log_url(self, operation_name: &str) -> Self;
 }
 
 impl BilibiliRequest for RequestBuilder {
-    /// UserAgent + Referer + Origin
+    /// Referer + Origin，User-Agent 由 client 统一设置
     fn with_bilibili_headers(self) -> Self {
-        self.with_user_agent()
-            .header("Referer", "https://www.bilibili.com/")
+        self.header("Referer", "https://www.bilibili.com/")
             .header("Origin", "https://www.bilibili.com")
     }
 
     fn with_user_agent(self) -> Self {
-        self.header(
-            "User-Agent",
-            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36"
-        )
+        self.header(reqwest::header::USER_AGENT, DEFAULT_USER_AGENT)
+    }
+
+    fn with_app_sign(self, key: AppKey) -> Self {
+        self.header(APP_SIGN_HEADER, format!("{}:{}", key.appkey, key.appsec))
+    }
+
+    fn with_access_key(self, token: &AppToken) -> Self {
+        let req = self.header(ACCESS_KEY_HEADER, token.access_key.as_str());
+        match token.app_key() {
+            Some(key) => req.with_app_sign(key),
+            None => req,
+        }
     }
 
     async fn send_request(self, operation_name: &str) -> Result<bytes::Bytes, BpiError> {
-        // 发送请求
-        let response = self.send().await.map_err(|e| {
+        let log_error = |e: reqwest::Error| {
             tracing::error!("{} 请求失败: {}", operation_name, e);
             BpiError::from(e) // 使用 From trait 自动转换
-        })?;
+        };
+        let (client, request) = self.build_split();
+        let (mut request, policy) = request_policy(request.map_err(log_error)?)?;
+        // 移除内部请求头并完成 APP 签名，appsec 与 access_key 不随请求头发出
+        app_sign::sign_request(&mut request)?;
+
+        // 按 client 的设置按域名限速后发送请求
+        if let (Some(policy), Some(host)) = (&policy, request.url().host_str()) {
+            policy.rate_limiter.acquire(host).await;
+        }
+        let response = client.execute(request).await.map_err(log_error)?;
 
         // 检查响应状态
         let status = response.status();
@@ -60,59 +154,55 @@ impl BilibiliRequest for RequestBuilder {
         })
     }
 
+    /// 发送并解析响应，遇到可重试的错误时按 [`RetryConfig`](crate::RetryConfig) 退避重试
+    ///
+    /// 发送前按 [`protection`](crate::protection) 中的防护表检查请求，缺少 WBI 签名时自动签名。
     async fn send_bpi<T>(self, operation_name: &str) -> Result<BpiResponse<T>, BpiError>
         where T: DeserializeOwned
     {
-        // 开始计时
-        let start = Instant::now();
-        // 请求拿到响应 bytes
-        let bytes = self.log_url(operation_name).send_request(operation_name).await?;
-
-        // 解析JSON响应
-        let result: BpiResponse<T> = serde_json::from_slice(&bytes).map_err(|e| {
-            #[cfg(any(test, debug_assertions))]
-            {
-                let json_str = String::from_utf8_lossy(&bytes);
-                let error_pos = e.column().saturating_sub(1);
-                let start = error_pos.saturating_sub(25);
-                let end = (error_pos + 25).min(json_str.len());
-                let context = &json_str[start..end];
-
-                tracing::error!(
-                    "{} JSON解析失败 (行:{} 列:{}): {}",
-                    operation_name,
-                    e.line(),
-                    e.column(),
-                    e
-                );
-                tracing::error!(
-                    "错误位置: ...{}... ({}^)",
-                    context,
-                    " ".repeat(error_pos.saturating_sub(start))
-                );
-            }
-            #[cfg(not(any(test, debug_assertions)))]
-            {
-                tracing::error!("{} JSON解析失败: {}", operation_name, e);
-            }
-            BpiError::from(e)
+        let (client, request) = self.build_split();
+        let (mut request, policy) = request_policy(request?)?;
+        let config = policy.map(|policy| policy.retry_config()).unwrap_or_default();
+        protection::apply(&client, &mut request).await.inspect_err(|e| {
+            tracing::error!("{} 请求检查失败: {}", operation_name, e);
         })?;
 
-        // 处理API业务错误
-        if result.code != 0 {
-            let err = if result.message.is_empty() || result.message == "0" {
-                BpiError::from_code(result.code)
+        let mut attempt = 0;
+        loop {
+            // 请求体为流时无法复制，只发送一次
+            let retry_request = if config.applies_to(request.method()) {
+                request.try_clone()
             } else {
-                BpiError::from_code_message(result.code, result.message.clone())
+                None
+            };
+            let Some(current) = retry_request else {
+                return send_bpi_once(RequestBuilder::from_parts(client, request), operation_name).await;
             };
 
-            tracing::error!("{} API错误: {}", operation_name, err);
-            return Err(err);
+            let err = match send_bpi_once(RequestBuilder::from_parts(client.clone(), current), operation_name).await {
+                Err(err) if config.should_retry(attempt, &err) => err,
+                result => {
+                    return result;
+                }
+            };
+
+            let delay = config.delay(attempt);
+            attempt += 1;
+            tracing::warn!("{} 第 {} 次重试，等待 {:.2?}: {}", operation_name, attempt, delay, err);
+            tokio::time::sleep(delay).await;
         }
+    }
 
-        let duration = start.elapsed();
-        tracing::info!("{} 请求成功，耗时: {:.2?}", operation_name, duration);
-        Ok(result)
+    async fn send_bpi_wbi<T>(self, operation_name: &str) -> Result<BpiResponse<T>, BpiError>
+        where T: DeserializeOwned
+    {
+        let (client, request) = self.build_split();
+        let mut request = request?;
+
+        let keys = wbi_keys_via(client.get(NAV_URL)).await?;
+        sign_url(request.url_mut(), &keys);
+
+        RequestBuilder::from_parts(client, request).send_bpi(operation_name).await
     }
 
     fn log_url(self, operation_name: &str) -> Self {
@@ -127,3 +217,113 @@ impl BilibiliRequest for RequestBuilder {
         self
     }
 }
+
+/// 发送一次请求并解析响应，业务码非 0 时返回错误
+async fn send_bpi_once<T>(request: RequestBuilder, operation_name: &str) -> Result<BpiResponse<T>, BpiError>
+    where T: DeserializeOwned
+{
+    // 开始计时
+    let start = Instant::now();
+    // 请求拿到响应 bytes
+    let bytes = request.log_url(operation_name).send_request(operation_name).await?;
+
+    // 解析JSON响应
+    let result: BpiResponse<T> = serde_json::from_slice(&bytes).map_err(|e| {
+        #[cfg(any(test, debug_assertions))]
+        {
+            let json_str = String::from_utf8_lossy(&bytes);
+            let error_pos = e.column().saturating_sub(1);
+            let start = error_pos.saturating_sub(25);
+            let end = (error_pos + 25).min(json_str.len());
+            let context = &json_str[start..end];
+
+            tracing::error!(
+                "{} JSON解析失败 (行:{} 列:{}): {}",
+                operation_name,
+                e.line(),
+                e.column(),
+                e
+            );
+            tracing::error!(
+                "错误位置: ...{}... ({}^)",
+                context,
+                " ".repeat(error_pos.saturating_sub(start))
+            );
+        }
+        #[cfg(not(any(test, debug_assertions)))]
+        {
+            tracing::error!("{} JSON解析失败: {}", operation_name, e);
+        }
+        BpiError::from(e)
+    })?;
+
+    // 处理API业务错误
+    if result.code != 0 {
+        let err = if result.message.is_empty() || result.message == "0" {
+            BpiError::from_code(result.code)
+        } else {
+            BpiError::from_code_message(result.code, result.message.clone())
+        };
+
+        tracing::error!("{} API错误: {}", operation_name, err);
+        return Err(err);
+    }
+
+    let duration = start.elapsed();
+    tracing::info!("{} 请求成功，耗时: {:.2?}", operation_name, duration);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BpiClient;
+    use std::io::{ Read, Write };
+
+    /// 在本地接收一次请求，返回请求头部分
+    fn capture_request() -> (String, std::sync::mpsc::Receiver<String>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/x/v2/test", listener.local_addr().unwrap());
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut head = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = stream.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                head.extend_from_slice(&buf[..n]);
+            }
+            let body = r#"{"code":0,"message":"0","ttl":1,"data":null}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+            tx.send(String::from_utf8_lossy(&head).into_owned()).unwrap();
+        });
+        (url, rx)
+    }
+
+    #[tokio::test]
+    async fn test_send_request_strips_internal_headers() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        let token = AppToken::new("secret-access-key", "refresh", AppKey::ANDROID);
+
+        let (url, rx) = capture_request();
+        bpi.get(&url)
+            .query(&[("aid", "1")])
+            .with_access_key(&token)
+            .send_request("测试").await
+            .unwrap();
+        let head = rx.recv().unwrap().to_ascii_lowercase();
+
+        assert!(!head.contains("x-bpi-"), "{}", head);
+        assert!(!head.contains(AppKey::ANDROID.appsec));
+        assert!(head.contains("access_key=secret-access-key"));
+        assert!(head.contains("&sign="));
+    }
+}
@@ -0,0 +1,173 @@
+//! 综合搜索
+//!
+//! 综合搜索一次返回视频、番剧、影视、直播、专栏、用户等多类结果，
+//! 每类结果按 `result_type` 分组，可通过 [`SearchAllGroup::parse`] 解析为对应的类型。
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/search/search_request.md)
+
+use std::collections::HashMap;
+
+use serde::{ Deserialize, Serialize };
+
+use super::result::{ Article, Bangumi, BiliUser, LiveRoom, LiveUser, Movie, Video };
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 综合搜索中的一类结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchAllGroup {
+    /// 结果类型，如 `video`、`media_bangumi`、`bili_user`
+    pub result_type: String,
+    /// 结果列表，结构取决于 `result_type`
+    #[serde(default)]
+    pub data: serde_json::Value,
+}
+
+/// 按类型解析后的综合搜索结果
+#[derive(Debug, Clone)]
+pub enum SearchAllResult {
+    Video(Vec<Video>),
+    Bangumi(Vec<Bangumi>),
+    Movie(Vec<Movie>),
+    LiveRoom(Vec<LiveRoom>),
+    LiveUser(Vec<LiveUser>),
+    Article(Vec<Article>),
+    BiliUser(Vec<BiliUser>),
+    /// 未解析的类型，如 `activity`、`web_game`、`tips`
+    Other(String),
+}
+
+impl SearchAllGroup {
+    /// 按 `result_type` 解析结果列表
+    pub fn parse(&self) -> Result<SearchAllResult, BpiError> {
+        fn list<T: serde::de::DeserializeOwned>(data: &serde_json::Value) -> Result<Vec<T>, BpiError> {
+            if data.is_null() {
+                return Ok(Vec::new());
+            }
+            Ok(serde_json::from_value(data.clone())?)
+        }
+
+        Ok(match self.result_type.as_str() {
+            "video" => SearchAllResult::Video(list(&self.data)?),
+            "media_bangumi" => SearchAllResult::Bangumi(list(&self.data)?),
+            "media_ft" => SearchAllResult::Movie(list(&self.data)?),
+            "live_room" => SearchAllResult::LiveRoom(list(&self.data)?),
+            "live_user" => SearchAllResult::LiveUser(list(&self.data)?),
+            "article" => SearchAllResult::Article(list(&self.data)?),
+            "bili_user" => SearchAllResult::BiliUser(list(&self.data)?),
+            other => SearchAllResult::Other(other.to_string()),
+        })
+    }
+}
+
+/// 综合搜索响应数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchAllData {
+    pub seid: String,
+    pub page: i64,
+    #[serde(rename = "pagesize")]
+    pub page_size: i64,
+    #[serde(rename = "numResults")]
+    pub num_results: i64,
+    #[serde(rename = "numPages")]
+    pub num_pages: i64,
+    /// 各类型的结果数
+    #[serde(default)]
+    pub top_tlist: Option<HashMap<String, i64>>,
+    /// 按类型分组的结果
+    #[serde(default)]
+    pub result: Vec<SearchAllGroup>,
+}
+
+impl SearchAllData {
+    /// 解析所有分组
+    pub fn results(&self) -> Result<Vec<SearchAllResult>, BpiError> {
+        self.result.iter().map(SearchAllGroup::parse).collect()
+    }
+
+    /// 视频结果
+    pub fn videos(&self) -> Result<Vec<Video>, BpiError> {
+        for group in &self.result {
+            if let SearchAllResult::Video(videos) = group.parse()? {
+                return Ok(videos);
+            }
+        }
+        Ok(Vec::new())
+    }
+}
+
+impl BpiClient {
+    /// 综合搜索
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/search)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `keyword` | &str | 搜索关键词 |
+    /// | `page` | `Option<i32>` | 页码（默认1） |
+    pub async fn search_all(
+        &self,
+        keyword: &str,
+        page: Option<i32>
+    ) -> Result<BpiResponse<SearchAllData>, BpiError> {
+        self
+            .get("https://api.bilibili.com/x/web-interface/wbi/search/all/v2")
+            .with_bilibili_headers()
+            .query(
+                &[
+                    ("keyword", keyword.to_string()),
+                    ("page", page.unwrap_or(1).to_string()),
+                ]
+            )
+            .send_bpi_wbi("综合搜索").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_groups() {
+        let data: SearchAllData = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "seid": "1", "page": 1, "pagesize": 20, "numResults": 1000, "numPages": 50,
+                    "top_tlist": { "video": 1000, "bili_user": 3 },
+                    "result": [
+                        { "result_type": "tips", "data": [] },
+                        { "result_type": "media_ft", "data": null },
+                        {
+                            "result_type": "video",
+                            "data": [{
+                                "type": "video", "id": 10001, "author": "碧诗", "mid": 2, "typeid": "1",
+                                "typename": "动画", "arcurl": "", "aid": 10001, "bvid": "BV1bx411c7us",
+                                "title": "<em class=\"keyword\">测试</em>", "pic": "", "play": 1, "danmaku": 0,
+                                "favorites": 0, "like": 0, "tag": "", "review": 0, "pubdate": 0, "duration": "1:00"
+                            }]
+                        }
+                    ]
+                })
+            )
+            .unwrap();
+
+        let results = data.results().unwrap();
+        assert!(matches!(&results[0], SearchAllResult::Other(t) if t == "tips"));
+        assert!(matches!(&results[1], SearchAllResult::Movie(m) if m.is_empty()));
+        assert_eq!(data.videos().unwrap()[0].aid, 10001);
+        assert_eq!(data.top_tlist.unwrap()["bili_user"], 3);
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_search_all() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi.search_all("Rust", None).await?.into_data()?;
+        for group in &data.result {
+            tracing::info!("{}: {:?}", group.result_type, group.parse().map(|_| ()));
+        }
+        Ok(())
+    }
+}
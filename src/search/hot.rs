@@ -45,18 +45,66 @@ pub struct HotWordDataResponse {
     pub list: Vec<HotWordItem>,
 }
 
+/// 热搜榜条目
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TrendingItem {
+    /// 搜索关键词
+    pub keyword: String,
+    /// 显示文字
+    pub show_name: String,
+    /// 图标 url，无图标时为空
+    #[serde(default)]
+    pub icon: String,
+    /// 跳转链接
+    #[serde(default)]
+    pub uri: String,
+    /// 跳转类型，通常为 search
+    #[serde(default)]
+    pub goto: String,
+}
+
+/// 热搜榜
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Trending {
+    /// 标题，如 "bilibili热搜"
+    pub title: String,
+    pub trackid: String,
+    pub list: Vec<TrendingItem>,
+}
+
+/// 热搜榜返回数据
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SearchSquareData {
+    pub trending: Trending,
+}
+
 impl BpiClient {
+    /// 获取热搜榜（web端）
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/search)
+    ///
+    /// # 参数
+    ///
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `limit` | u32 | 返回条数，1-50 |
+    pub async fn search_trending(&self, limit: u32) -> Result<BpiResponse<SearchSquareData>, BpiError> {
+        self
+            .get("https://api.bilibili.com/x/web-interface/wbi/search/square")
+            .query(&[("limit", limit.clamp(1, 50).to_string())])
+            .send_bpi_wbi("获取热搜榜").await
+    }
+
     /// 获取默认搜索内容（web端）
     ///
     /// # 文档
     /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/search)
     pub async fn search_default(&self) -> Result<BpiResponse<DefaultSearchData>, BpiError> {
-        let signed_params = self.get_wbi_sign2(vec![("foo", "bar")]).await?;
-
         self
             .get("https://api.bilibili.com/x/web-interface/wbi/search/default")
-            .query(&signed_params)
-            .send_bpi("获取默认搜索内容").await
+            .query(&[("foo", "bar")])
+            .send_bpi_wbi("获取默认搜索内容").await
     }
 
     /// 获取热搜列表（web端）
@@ -86,8 +134,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_default_search() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.search_default().await?;
         let data = result.into_data()?;
         tracing::info!("{:#?}", data);
@@ -96,8 +145,19 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_search_trending() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi.search_trending(10).await?.into_data()?;
+        tracing::info!("{}: {:?}", data.trending.title, data.trending.list.iter().map(|i| &i.keyword).collect::<Vec<_>>());
+        assert!(data.trending.list.len() <= 10);
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_hotword_list() -> Result<(), Box<BpiError>> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let result = bpi.search_hotwords().await?;
         let data = result.into_data()?;
         tracing::info!("{:#?}", data);
@@ -1,9 +1,11 @@
 //! 搜索
 
+pub mod all;
 pub mod hot;
+pub mod paginate;
 
-mod result;
-mod search_params;
+pub mod result;
+pub mod search_params;
 pub mod suggest;
 
 mod search;
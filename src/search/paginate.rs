@@ -0,0 +1,207 @@
+//! 搜索翻页
+//!
+//! 连续获取多页搜索结果并合并。B 站搜索翻页时常会重复返回上一页的条目，
+//! 部分类型的结果中还会混入活动、推广卡片等非目标类型的条目，
+//! 可通过 [`SearchPageOptions`] 按 aid/mid/season_id 去重并剔除这些条目。
+
+use std::collections::HashSet;
+use std::future::Future;
+
+use super::result::{ Article, Bangumi, BiliUser, LiveRoom, LiveUser, Movie, SearchData, Video };
+use crate::{ BpiError, BpiResponse };
+
+/// 可去重的搜索结果条目
+pub trait SearchItem {
+    /// 该搜索类型的条目类型，如 `video`
+    const TYPE: &'static str;
+
+    /// 去重键，如 aid、mid、season_id
+    fn dedup_key(&self) -> String;
+
+    /// 条目的 `type` 字段
+    fn item_type(&self) -> &str;
+}
+
+macro_rules! impl_search_item {
+    ($ty:ty, $type_name:literal, $type_field:ident, | $item:ident | $key:expr) => {
+        impl SearchItem for $ty {
+            const TYPE: &'static str = $type_name;
+
+            fn dedup_key(&self) -> String {
+                let $item = self;
+                $key.to_string()
+            }
+
+            fn item_type(&self) -> &str {
+                &self.$type_field
+            }
+        }
+    };
+}
+
+impl_search_item!(Video, "video", r#type, |v| v.aid);
+impl_search_item!(Article, "article", type_field, |a| a.id);
+impl_search_item!(Bangumi, "media_bangumi", type_field, |b| b.season_id);
+impl_search_item!(Movie, "media_ft", type_field, |m| m.season_id);
+impl_search_item!(BiliUser, "bili_user", r#type, |u| u.mid);
+impl_search_item!(LiveRoom, "live_room", type_field, |r| r.roomid);
+impl_search_item!(LiveUser, "live_user", type_field, |u| u.uid);
+
+/// 翻页选项
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchPageOptions {
+    /// 按 aid/mid/season_id 去重，默认开启
+    pub dedup: bool,
+    /// 剔除 `type` 与搜索类型不符的活动、卡片条目，默认开启
+    pub clean: bool,
+}
+
+impl Default for SearchPageOptions {
+    fn default() -> Self {
+        Self {
+            dedup: true,
+            clean: true,
+        }
+    }
+}
+
+/// 合并后的搜索结果
+#[derive(Debug, Clone)]
+pub struct SearchPages<T> {
+    pub items: Vec<T>,
+    /// 已获取的页数
+    pub pages: i32,
+    /// 接口返回的总页数
+    pub num_pages: i64,
+    /// 去重丢弃的条目数
+    pub duplicates: usize,
+    /// 剔除的非目标类型条目数
+    pub dropped: usize,
+    options: SearchPageOptions,
+    seen: HashSet<String>,
+}
+
+impl<T: SearchItem> SearchPages<T> {
+    pub fn new(options: SearchPageOptions) -> Self {
+        Self {
+            items: Vec::new(),
+            pages: 0,
+            num_pages: 0,
+            duplicates: 0,
+            dropped: 0,
+            options,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// 合并一页结果，返回新增的条目数
+    pub fn push_page(&mut self, data: SearchData<Vec<T>>) -> usize {
+        self.pages += 1;
+        self.num_pages = data.num_pages;
+
+        let before = self.items.len();
+        for item in data.result.unwrap_or_default() {
+            if self.options.clean && item.item_type() != T::TYPE {
+                self.dropped += 1;
+                continue;
+            }
+            if self.options.dedup && !self.seen.insert(item.dedup_key()) {
+                self.duplicates += 1;
+                continue;
+            }
+            self.items.push(item);
+        }
+        self.items.len() - before
+    }
+
+    /// 是否已到最后一页
+    pub fn is_end(&self) -> bool {
+        self.pages as i64 >= self.num_pages
+    }
+}
+
+/// 连续获取至多 `max_pages` 页搜索结果并合并
+///
+/// `fetch` 接收页码（从 1 开始）并返回该页结果，可直接传入各 `search_*` 方法。
+///
+/// ```no_run
+/// # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+/// use bpi_rs::search::paginate::{ SearchPageOptions, collect_pages };
+///
+/// let pages = collect_pages(5, SearchPageOptions::default(), |page| {
+///     bpi.search_video("Rust", None, None, None, Some(page))
+/// }).await?;
+/// println!("{} 条结果，去重 {} 条", pages.items.len(), pages.duplicates);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn collect_pages<T, F, Fut>(
+    max_pages: i32,
+    options: SearchPageOptions,
+    mut fetch: F
+) -> Result<SearchPages<T>, BpiError>
+    where
+        T: SearchItem,
+        F: FnMut(i32) -> Fut,
+        Fut: Future<Output = Result<BpiResponse<SearchData<Vec<T>>>, BpiError>>
+{
+    let mut pages = SearchPages::new(options);
+
+    for page in 1..=max_pages {
+        let data = fetch(page).await?.into_data()?;
+        let empty = data.result.as_ref().is_none_or(Vec::is_empty);
+        pages.push_page(data);
+
+        if empty || pages.is_end() {
+            break;
+        }
+    }
+
+    Ok(pages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(r#type: &str, mid: i64) -> BiliUser {
+        BiliUser {
+            r#type: r#type.to_string(),
+            mid,
+            ..Default::default()
+        }
+    }
+
+    fn page(items: Vec<BiliUser>) -> SearchData<Vec<BiliUser>> {
+        let mut data = SearchData::default();
+        data.num_pages = 3;
+        data.result = Some(items);
+        data
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_dedup() {
+        let pages = [
+            page(vec![user("bili_user", 1), user("bili_user", 2)]),
+            page(vec![user("bili_user", 2), user("activity", 0), user("bili_user", 3)]),
+            page(vec![user("bili_user", 4)])
+        ];
+
+        let result = collect_pages(5, SearchPageOptions::default(), |p| {
+            let data = pages[(p - 1) as usize].clone();
+            async move {
+                Ok(BpiResponse { code: 0, data: Some(data), message: String::new(), status: true })
+            }
+        }).await.unwrap();
+
+        let mids: Vec<i64> = result.items.iter().map(|u| u.mid).collect();
+        assert_eq!(mids, vec![1, 2, 3, 4]);
+        assert_eq!(result.pages, 3);
+        assert_eq!(result.duplicates, 1);
+        assert_eq!(result.dropped, 1);
+
+        let mut raw = SearchPages::new(SearchPageOptions { dedup: false, clean: false });
+        raw.push_page(pages[1].clone());
+        assert_eq!(raw.items.len(), 3);
+    }
+}
@@ -48,13 +48,11 @@ impl BpiClient {
             ("page", page_str)
         ];
 
-        let signed_params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/web-interface/wbi/search/type")
             .with_bilibili_headers()
-            .query(&signed_params)
-            .send_bpi("搜索专栏").await
+            .query(&params)
+            .send_bpi_wbi("搜索专栏").await
     }
 
     /// 搜索番剧
@@ -81,12 +79,10 @@ impl BpiClient {
             ("page", page_str)
         ];
 
-        let signed_params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/web-interface/wbi/search/type")
-            .query(&signed_params)
-            .send_bpi("搜索番剧").await
+            .query(&params)
+            .send_bpi_wbi("搜索番剧").await
     }
 
     /// 搜索用户
@@ -119,12 +115,10 @@ impl BpiClient {
             ("page", page_str)
         ];
 
-        let signed_params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/web-interface/wbi/search/type")
-            .query(&signed_params)
-            .send_bpi("搜索用户").await
+            .query(&params)
+            .send_bpi_wbi("搜索用户").await
     }
 
     /// 搜索直播间及主播
@@ -151,12 +145,10 @@ impl BpiClient {
             ("page", page_str)
         ];
 
-        let signed_params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/web-interface/wbi/search/type")
-            .query(&signed_params)
-            .send_bpi("搜索直播间及主播").await
+            .query(&params)
+            .send_bpi_wbi("搜索直播间及主播").await
     }
 
     /// 搜索直播间
@@ -186,12 +178,10 @@ impl BpiClient {
             ("page", page_str)
         ];
 
-        let signed_params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/web-interface/wbi/search/type")
-            .query(&signed_params)
-            .send_bpi("搜索直播间").await
+            .query(&params)
+            .send_bpi_wbi("搜索直播间").await
     }
 
     /// 搜索主播
@@ -224,12 +214,10 @@ impl BpiClient {
             ("page", page_str)
         ];
 
-        let signed_params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/web-interface/wbi/search/type")
-            .query(&signed_params)
-            .send_bpi("搜索主播").await
+            .query(&params)
+            .send_bpi_wbi("搜索主播").await
     }
 
     /// 搜索影视
@@ -256,12 +244,10 @@ impl BpiClient {
             ("page", page_str)
         ];
 
-        let signed_params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/web-interface/wbi/search/type")
-            .query(&signed_params)
-            .send_bpi("搜索影视").await
+            .query(&params)
+            .send_bpi_wbi("搜索影视").await
     }
 
     /// 搜索视频
@@ -297,23 +283,21 @@ impl BpiClient {
             ("page", page_str)
         ];
 
-        let signed_params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/web-interface/wbi/search/type")
-            .query(&signed_params)
-            .send_bpi("搜索视频").await
+            .query(&params)
+            .send_bpi_wbi("搜索视频").await
     }
 }
 #[cfg(test)]
 mod tests {
-    use super::*;
     use crate::search::search_params::{ CategoryId, Duration, OrderSort, SearchOrder, UserType };
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_search_article() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.search_article(
             "Rust",
             Some(SearchOrder::PubDate),
@@ -337,8 +321,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_search_bangumi() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.search_bangumi("天气之子", None).await;
         assert!(resp.is_ok());
         if let Ok(r) = resp {
@@ -357,8 +342,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_search_bili_user() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.search_bili_user(
             "老番茄",
             Some(OrderSort::Descending),
@@ -382,8 +368,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_search_live_room() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.search_live_room("游戏", None, None).await;
         assert!(resp.is_ok());
         if let Ok(r) = resp {
@@ -402,8 +389,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_search_live_user() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.search_live_user(
             "散人",
             Some(OrderSort::Descending),
@@ -427,8 +415,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_search_movie() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.search_movie("哈利波特", None).await;
         assert!(resp.is_ok());
         if let Ok(r) = resp {
@@ -447,8 +436,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_search_video() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.search_video(
             "Rust 教程",
             Some(SearchOrder::Online),
@@ -36,13 +36,13 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_search_suggest() {
         // 创建一个 BilibiliRequest 实例
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 传入一个搜索关键词
         let term = "rust";
         let resp = bpi.search_suggest(term).await;
@@ -0,0 +1,173 @@
+//! 登录会话持久化
+//!
+//! 长期运行的程序重启后，只保存 [`Account`] 会丢失 cookie jar 中由服务端下发的
+//! `buvid4`、`bili_ticket` 等 cookie，以及 Cookie 刷新令牌。[`Session`] 保存客户端的完整登录状态，
+//! 启动时恢复即可继续使用，无需重新登录。
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), bpi_rs::BpiError> {
+//! use std::time::Duration;
+//!
+//! let bpi = bpi_rs::BpiClient::new();
+//! if std::path::Path::new("session.toml").exists() {
+//!     bpi.restore_session("session.toml")?;
+//! }
+//!
+//! // 刷新 Cookie 后同步保存
+//! bpi.cookie_refresh_loop(Duration::from_secs(6 * 3600), |_| {
+//!     if let Err(e) = bpi.persist_session("session.toml") {
+//!         eprintln!("保存会话失败: {}", e);
+//!     }
+//! }).await;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::{ Deserialize, Serialize };
+
+use crate::device::DeviceProfile;
+use crate::{ Account, BpiClient, BpiError };
+
+/// 客户端的完整登录状态
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Session {
+    pub account: Option<Account>,
+    /// Cookie 刷新令牌
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    pub device: Option<DeviceProfile>,
+    /// cookie jar 中 bilibili.com 下的全部 cookie
+    #[serde(default)]
+    pub cookies: BTreeMap<String, String>,
+}
+
+impl Session {
+    /// 保存到文件，扩展名为 `.toml` 时保存为 TOML，否则为 JSON
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), BpiError> {
+        write_by_extension(path.as_ref(), self)
+    }
+
+    /// 从 [`Session::save`] 保存的文件读取
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BpiError> {
+        read_by_extension(path.as_ref())
+    }
+}
+
+fn is_toml(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+}
+
+/// 按扩展名序列化为 TOML 或 JSON 并写入文件
+pub(crate) fn write_by_extension<T: Serialize>(path: &Path, value: &T) -> Result<(), BpiError> {
+    let content = if is_toml(path) {
+        toml::to_string_pretty(value).map_err(|e| BpiError::parse(format!("序列化失败: {}", e)))?
+    } else {
+        serde_json::to_string_pretty(value)?
+    };
+    std::fs
+        ::write(path, content)
+        .map_err(|e| BpiError::parse(format!("写入 {} 失败: {}", path.display(), e)))
+}
+
+/// 按扩展名读取 TOML 或 JSON 文件
+pub(crate) fn read_by_extension<T: DeserializeOwned>(path: &Path) -> Result<T, BpiError> {
+    let content = std::fs
+        ::read_to_string(path)
+        .map_err(|e| BpiError::parse(format!("读取 {} 失败: {}", path.display(), e)))?;
+    if is_toml(path) {
+        toml::from_str(&content).map_err(|e| BpiError::parse(format!("解析失败: {}", e)))
+    } else {
+        Ok(serde_json::from_str(&content)?)
+    }
+}
+
+impl BpiClient {
+    /// 当前登录状态
+    pub fn session(&self) -> Session {
+        Session {
+            account: self.get_account(),
+            refresh_token: self.refresh_token(),
+            device: self.device().map(|device| device.as_ref().clone()),
+            cookies: self.jar_cookies(),
+        }
+    }
+
+    /// 将当前登录状态保存到文件，扩展名为 `.toml` 时保存为 TOML，否则为 JSON
+    ///
+    /// 文件包含 SESSDATA 等敏感信息，注意权限。
+    pub fn persist_session(&self, path: impl AsRef<Path>) -> Result<(), BpiError> {
+        self.session().save(path)
+    }
+
+    /// 恢复登录状态
+    ///
+    /// 依次写入 cookie、设备与账号，同名 cookie 以账号中的值为准。
+    pub fn apply_session(&self, session: Session) {
+        self.add_cookies(session.cookies);
+        if let Some(device) = session.device {
+            self.set_device(device);
+        }
+        if let Some(account) = session.account {
+            self.set_account(account);
+        }
+        if let Some(refresh_token) = session.refresh_token {
+            self.set_refresh_token(refresh_token);
+        }
+    }
+
+    /// 从 [`BpiClient::persist_session`] 保存的文件恢复登录状态
+    pub fn restore_session(&self, path: impl AsRef<Path>) -> Result<(), BpiError> {
+        self.apply_session(Session::load(path)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_client() -> BpiClient {
+        let bpi = BpiClient::try_new_local().unwrap();
+        bpi.set_account_from_cookie_str(
+            "DedeUserID=1;DedeUserID__ckMd5=md5;SESSDATA=sess;bili_jct=jct;buvid3=buvid"
+        );
+        bpi.add_cookies([("bili_ticket", "ticket")]);
+        bpi.set_refresh_token("token");
+        bpi
+    }
+
+    #[test]
+    fn test_persist_session() {
+        let dir = std::env::temp_dir();
+        for name in ["bpi_session_test.toml", "bpi_session_test.json"] {
+            let path = dir.join(name);
+            test_client().persist_session(&path).unwrap();
+
+            let restored = BpiClient::try_new_local().unwrap();
+            restored.restore_session(&path).unwrap();
+            std::fs::remove_file(&path).ok();
+
+            let session = restored.session();
+            assert_eq!(session.account.map(|a| a.sessdata), Some("sess".to_string()));
+            assert_eq!(session.refresh_token.as_deref(), Some("token"));
+            assert_eq!(session.cookies.get("bili_ticket").map(String::as_str), Some("ticket"));
+        }
+    }
+
+    #[test]
+    fn test_account_file() {
+        let path = std::env::temp_dir().join("bpi_account_test.toml");
+        let account = test_client().get_account().unwrap();
+        account.save_to_file(&path).unwrap();
+        let loaded = Account::load_from_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(loaded.bili_jct, account.bili_jct);
+        assert_eq!(loaded.refresh_token.as_deref(), Some("token"));
+        assert_eq!(loaded.source, Some(crate::AccountSource::Imported));
+        assert_eq!(loaded.sessdata_expires_at, None);
+    }
+}
@@ -0,0 +1,129 @@
+//! 集成测试工具
+//!
+//! 访问网络的测试默认标记为 `#[ignore = "<类别>"]`，`cargo test` 只运行离线单元测试。
+//! 网络测试分为三类：
+//!
+//! | 类别 | 说明 |
+//! | ---- | ---- |
+//! | `guest` | 只读，无需登录 |
+//! | `authed` | 只读，需要登录 |
+//! | `write` | 会修改账号数据（点赞、评论、关注等） |
+//!
+//! 通过环境变量 `BPI_TEST` 选择要运行的类别（逗号分隔，默认 `guest`），再用 `cargo xtest`
+//! （`.cargo/config.toml` 中的别名）运行被忽略的测试：
+//!
+//! ```text
+//! BPI_TEST=guest,authed cargo xtest video
+//! ```
+//!
+//! `authed` 与 `write` 需要 `account.toml` 中的完整账号信息，缺失时测试直接跳过而不是失败。
+//!
+//! 下游项目的测试同样可以使用 [`test_client!`](crate::test_client)：
+//!
+//! ```no_run
+//! #[tokio::test]
+//! #[ignore = "authed"]
+//! async fn my_test() -> Result<(), bpi_rs::BpiError> {
+//!     let bpi = bpi_rs::test_client!(Authed);
+//!     let _ = bpi.csrf()?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::BpiClient;
+
+/// 选择测试类别的环境变量
+pub const TEST_CATEGORY_ENV: &str = "BPI_TEST";
+
+/// 测试类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestCategory {
+    /// 只读，无需登录
+    Guest,
+    /// 只读，需要登录
+    Authed,
+    /// 会修改账号数据
+    Write,
+}
+
+impl TestCategory {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TestCategory::Guest => "guest",
+            TestCategory::Authed => "authed",
+            TestCategory::Write => "write",
+        }
+    }
+
+    /// 是否需要登录
+    pub fn requires_account(self) -> bool {
+        !matches!(self, TestCategory::Guest)
+    }
+
+    /// 是否在 `BPI_TEST` 中启用
+    pub fn is_enabled(self) -> bool {
+        let enabled = std::env::var(TEST_CATEGORY_ENV).unwrap_or_else(|_| "guest".to_string());
+        enabled
+            .split(',')
+            .map(str::trim)
+            .any(|c| c == "all" || c == self.as_str())
+    }
+}
+
+/// 获取测试用 client，类别未启用或缺少账号时返回 `None`
+pub fn client(category: TestCategory) -> Option<&'static BpiClient> {
+    if !category.is_enabled() {
+        tracing::info!("跳过 [{}] 类测试，可通过 {}={} 启用", category.as_str(), TEST_CATEGORY_ENV, category.as_str());
+        return None;
+    }
+
+    let bpi = BpiClient::new();
+    if category.requires_account() && bpi.account_snapshot().is_none() {
+        tracing::warn!("跳过 [{}] 类测试: 未加载 account.toml 中的账号", category.as_str());
+        return None;
+    }
+
+    Some(bpi)
+}
+
+/// 测试被跳过时的返回值
+pub trait Skipped {
+    fn skipped() -> Self;
+}
+
+impl Skipped for () {
+    fn skipped() -> Self {}
+}
+
+impl<E> Skipped for Result<(), E> {
+    fn skipped() -> Self {
+        Ok(())
+    }
+}
+
+/// 获取测试用 client，类别未启用或缺少账号时直接从测试函数返回
+///
+/// 参数为 [`TestCategory`] 的变体名：`Guest` / `Authed` / `Write`。
+#[macro_export]
+macro_rules! test_client {
+    ($category:ident) => {
+        match $crate::testing::client($crate::testing::TestCategory::$category) {
+            Some(bpi) => bpi,
+            None => {
+                return $crate::testing::Skipped::skipped();
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_requires_account() {
+        assert!(!TestCategory::Guest.requires_account());
+        assert!(TestCategory::Authed.requires_account());
+        assert!(TestCategory::Write.requires_account());
+    }
+}
@@ -0,0 +1,197 @@
+//! 相簿（旧版图文投稿）
+//!
+//! 未迁移到图文动态的绘画、摄影投稿仍可通过 `link_draw` 接口访问。
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/album)
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use serde::{ Deserialize, Serialize };
+
+// --- 响应数据结构体 ---
+
+/// 相簿图片
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AlbumPicture {
+    /// 图片地址
+    pub img_src: String,
+    /// 宽度
+    #[serde(default)]
+    pub img_width: u32,
+    /// 高度
+    #[serde(default)]
+    pub img_height: u32,
+    /// 大小（KB）
+    #[serde(default)]
+    pub img_size: f64,
+}
+
+/// 相簿列表项
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AlbumItem {
+    /// 相簿 id
+    pub doc_id: u64,
+    /// 作者 mid
+    #[serde(default)]
+    pub poster_uid: u64,
+    /// 标题
+    #[serde(default)]
+    pub title: String,
+    /// 简介
+    #[serde(default)]
+    pub description: String,
+    /// 图片
+    #[serde(default)]
+    pub pictures: Vec<AlbumPicture>,
+    /// 图片数
+    #[serde(default)]
+    pub count: u32,
+    /// 发布时间秒级时间戳
+    #[serde(default)]
+    pub ctime: i64,
+    /// 浏览数
+    #[serde(default)]
+    pub view: u64,
+    /// 点赞数
+    #[serde(default)]
+    pub like: u64,
+}
+
+/// 用户相簿列表响应数据
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AlbumListResponseData {
+    #[serde(default)]
+    pub items: Vec<AlbumItem>,
+}
+
+/// 相簿作者
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AlbumUser {
+    /// 用户 mid
+    pub uid: u64,
+    /// 昵称
+    #[serde(default)]
+    pub name: String,
+    /// 头像
+    #[serde(default)]
+    pub head_url: String,
+}
+
+/// 相簿详情
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AlbumDetailItem {
+    /// 相簿 id
+    pub doc_id: u64,
+    /// 标题
+    #[serde(default)]
+    pub title: String,
+    /// 简介
+    #[serde(default)]
+    pub description: String,
+    /// 分区 如 `illustration`、`photo`、`daily`
+    #[serde(default)]
+    pub category: String,
+    /// 图片
+    #[serde(default)]
+    pub pictures: Vec<AlbumPicture>,
+    /// 标签
+    #[serde(default)]
+    pub tags: Vec<serde_json::Value>,
+    /// 发布时间 `YYYY-MM-DD HH:MM:SS`
+    #[serde(default)]
+    pub upload_time: String,
+    /// 浏览数
+    #[serde(default)]
+    pub view_count: u64,
+    /// 点赞数
+    #[serde(default)]
+    pub like_count: u64,
+    /// 收藏数
+    #[serde(default)]
+    pub collect_count: u64,
+}
+
+/// 相簿详情响应数据
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AlbumDetailResponseData {
+    pub user: AlbumUser,
+    pub item: AlbumDetailItem,
+}
+
+// --- API 实现 ---
+
+impl BpiClient {
+    /// 获取用户相簿投稿列表
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/album)
+    ///
+    /// # 参数
+    /// | 名称        | 类型          | 说明                                   |
+    /// | ----------- | ------------- | -------------------------------------- |
+    /// | `uid`       | u64           | 目标用户 mid                           |
+    /// | `page_num`  | `Option<u32>` | 页码，从 0 开始，默认 0                |
+    /// | `page_size` | `Option<u32>` | 每页项数，默认 30                      |
+    pub async fn user_albums(
+        &self,
+        uid: u64,
+        page_num: Option<u32>,
+        page_size: Option<u32>
+    ) -> Result<BpiResponse<AlbumListResponseData>, BpiError> {
+        self
+            .get("https://api.vc.bilibili.com/link_draw/v1/doc/doc_list")
+            .query(
+                &[
+                    ("uid", uid.to_string()),
+                    ("page_num", page_num.unwrap_or(0).to_string()),
+                    ("page_size", page_size.unwrap_or(30).to_string()),
+                    ("biz", "all".to_string()),
+                ]
+            )
+            .send_bpi("获取用户相簿投稿").await
+    }
+
+    /// 获取相簿详情
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/album)
+    ///
+    /// # 参数
+    /// | 名称     | 类型 | 说明    |
+    /// | -------- | ---- | ------- |
+    /// | `doc_id` | u64  | 相簿 id |
+    pub async fn album_detail(
+        &self,
+        doc_id: u64
+    ) -> Result<BpiResponse<AlbumDetailResponseData>, BpiError> {
+        self
+            .get("https://api.vc.bilibili.com/link_draw/v1/doc/detail")
+            .query(&[("doc_id", doc_id.to_string())])
+            .send_bpi("获取相簿详情").await
+    }
+}
+
+// --- 测试模块 ---
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::info;
+
+    const TEST_UID: u64 = 645769214;
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_user_albums() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi.user_albums(TEST_UID, None, Some(10)).await?.into_data()?;
+
+        info!("相簿数: {}", data.items.len());
+
+        if let Some(item) = data.items.first() {
+            let detail = bpi.album_detail(item.doc_id).await?.into_data()?;
+            info!("相簿详情: {:?}", detail.item);
+            assert_eq!(detail.item.doc_id, item.doc_id);
+        }
+
+        Ok(())
+    }
+}
@@ -42,12 +42,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_user_name_to_uid() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_name_to_uid(&["LexBurner", "某科学"]).await;
         assert!(resp.is_ok());
         if let Ok(r) = resp {
@@ -2,6 +2,7 @@
 //!
 //! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
 use crate::models::{ LevelInfo, Nameplate, Official, OfficialVerify, Pendant, Vip, VipLabel };
+use crate::utils::wbi::dm_img_params;
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 use serde::{ Deserialize, Serialize };
 
@@ -380,22 +381,18 @@ pub struct UserOfficial {
 
 impl BpiClient {
     /// 获取用户空间详细信息
-    /// 需要 Wbi 签名认证
+    /// 需要 Wbi 签名认证，自动附带 `dm_img_*` 风控参数
     ///
     /// # 文档
     /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
     ///
     /// - `mid`: 用户 UID
     pub async fn user_space_info(&self, mid: u64) -> Result<BpiResponse<UserSpaceInfo>, BpiError> {
-        // 构建查询参数
-        let params = vec![("mid", mid.to_string())];
-
-        let params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/space/wbi/acc/info")
-            .query(&params)
-            .send_bpi("获取用户空间详细信息").await
+            .query(&[("mid", mid.to_string())])
+            .query(&dm_img_params())
+            .send_bpi_wbi("获取用户空间详细信息").await
     }
 
     /// 获取用户名片信息
@@ -486,13 +483,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_user_space_info() {
         tracing::info!("开始测试获取用户空间详细信息");
 
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let mid = 2; // 测试用户ID
 
         tracing::info!("测试用户ID: {}", mid);
@@ -521,10 +517,11 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_user_space_info_nonexistent() {
         tracing::info!("开始测试获取不存在用户的空间详细信息");
 
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let mid = 0; // 不存在的用户ID
 
         tracing::info!("测试用户ID: {}", mid);
@@ -547,10 +544,11 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_user_card_info() {
         tracing::info!("开始测试获取用户名片信息");
 
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let mid = 2; // 测试用户ID
 
         tracing::info!("测试用户ID: {}", mid);
@@ -599,10 +597,11 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_user_card_info_with_photo() {
         tracing::info!("开始测试获取用户名片信息（包含主页头图）");
 
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let mid = 2; // 测试用户ID
 
         tracing::info!("测试用户ID: {}", mid);
@@ -652,10 +651,11 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_user_card_info_without_photo() {
         tracing::info!("开始测试获取用户名片信息（不包含主页头图）");
 
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let mid = 123456; // 测试用户ID
 
         tracing::info!("测试用户ID: {}", mid);
@@ -688,10 +688,11 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_user_card_info_invalid_user() {
         tracing::info!("开始测试获取不存在用户的名片信息");
 
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let mid = 0; // 不存在的用户ID
 
         tracing::info!("测试用户ID: {}", mid);
@@ -716,10 +717,11 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_user_card_info_banned_user() {
         tracing::info!("开始测试获取被封禁用户的名片信息");
 
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let mid = 999999999; // 假设的被封禁用户ID
 
         tracing::info!("测试用户ID: {}", mid);
@@ -752,8 +754,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_user_cards_and_infos() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         // 测试精简版
         let cards = bpi.user_cards(&[2, 3]).await.unwrap();
@@ -92,12 +92,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_user_medal_wall() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_medal_wall(2).await.unwrap(); // UID=2: 碧诗
         info!("粉丝勋章墙: {:?}", resp.data);
     }
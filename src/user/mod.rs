@@ -2,6 +2,7 @@
 //!
 //! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
 
+pub mod album;
 pub mod batch;
 pub mod contract;
 pub mod info;
@@ -9,6 +10,7 @@ pub mod medals;
 pub mod official_role;
 pub mod register;
 pub mod relation;
-mod search;
+pub mod search;
 pub mod space;
 pub mod status_number;
+pub mod summary;
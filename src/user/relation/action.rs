@@ -126,8 +126,9 @@ mod tests {
     const TEST_FID: u64 = 2;
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_modify_relation_follow() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi.user_modify_relation(
             TEST_FID,
             RelationAction::Follow,
@@ -140,8 +141,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_modify_relation_unfollow() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let resp = bpi.user_modify_relation(TEST_FID, RelationAction::Unfollow, None).await?;
 
@@ -151,8 +153,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_modify_relation_blacklist() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi.user_modify_relation(TEST_FID, RelationAction::Blacklist, None).await?;
 
         info!("拉黑用户结果: {:?}", resp);
@@ -0,0 +1,138 @@
+//! 用户关系属性
+//!
+//! 关注、粉丝与推荐列表中的 `attribute` 为关系代码，`special` 为特别关注标识，
+//! 这里转换为 [`RelationAttribute`] 便于判断互粉、悄悄关注等状态。
+use serde::{ Deserialize, Serialize };
+
+use super::followers;
+use super::following;
+use super::recommend::RecommendUser;
+
+/// 关系属性
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum RelationAttribute {
+    /// 未关注
+    None,
+    /// 悄悄关注
+    Whisper,
+    /// 已关注
+    Following,
+    /// 已互粉
+    Mutual,
+    /// 已拉黑
+    Blocked,
+    /// 未知代码
+    Unknown(u8),
+}
+
+impl From<u8> for RelationAttribute {
+    fn from(code: u8) -> Self {
+        match code {
+            0 => Self::None,
+            1 => Self::Whisper,
+            2 => Self::Following,
+            6 => Self::Mutual,
+            128 => Self::Blocked,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<RelationAttribute> for u8 {
+    fn from(attribute: RelationAttribute) -> Self {
+        match attribute {
+            RelationAttribute::None => 0,
+            RelationAttribute::Whisper => 1,
+            RelationAttribute::Following => 2,
+            RelationAttribute::Mutual => 6,
+            RelationAttribute::Blocked => 128,
+            RelationAttribute::Unknown(code) => code,
+        }
+    }
+}
+
+impl RelationAttribute {
+    /// 是否已关注，包括悄悄关注与互粉
+    pub fn is_following(self) -> bool {
+        matches!(self, Self::Whisper | Self::Following | Self::Mutual)
+    }
+
+    /// 是否互相关注
+    pub fn is_mutual(self) -> bool {
+        self == Self::Mutual
+    }
+
+    /// 是否已拉黑
+    pub fn is_blocked(self) -> bool {
+        self == Self::Blocked
+    }
+}
+
+impl following::RelationListItem {
+    /// 关系属性
+    pub fn relation(&self) -> RelationAttribute {
+        self.attribute.into()
+    }
+
+    /// 是否互相关注
+    pub fn is_mutual(&self) -> bool {
+        self.relation().is_mutual()
+    }
+
+    /// 是否为特别关注
+    pub fn is_special(&self) -> bool {
+        self.special == 1
+    }
+}
+
+impl followers::RelationListItem {
+    /// 关系属性
+    pub fn relation(&self) -> RelationAttribute {
+        self.attribute.into()
+    }
+
+    /// 是否互相关注
+    pub fn is_mutual(&self) -> bool {
+        self.relation().is_mutual()
+    }
+
+    /// 是否为特别关注
+    pub fn is_special(&self) -> bool {
+        self.special == 1
+    }
+}
+
+impl RecommendUser {
+    /// 与自己的关系属性
+    pub fn relation(&self) -> RelationAttribute {
+        self.attribute.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relation_attribute() {
+        for code in [0u8, 1, 2, 6, 128, 3] {
+            assert_eq!(u8::from(RelationAttribute::from(code)), code);
+        }
+        assert!(RelationAttribute::from(6).is_mutual());
+        assert!(RelationAttribute::from(1).is_following());
+        assert!(!RelationAttribute::from(128).is_following());
+        assert_eq!(RelationAttribute::from(3), RelationAttribute::Unknown(3));
+
+        let parsed: RelationAttribute = serde_json::from_str("2").unwrap();
+        assert_eq!(parsed, RelationAttribute::Following);
+    }
+
+    #[test]
+    fn test_recommend_user_relation() {
+        let user: RecommendUser = serde_json
+            ::from_value(serde_json::json!({ "mid": 2, "name": "碧诗", "attribute": 6 }))
+            .unwrap();
+        assert!(user.relation().is_mutual());
+    }
+}
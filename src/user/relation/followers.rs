@@ -2,7 +2,9 @@
 //!
 //! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
 use crate::models::Vip;
+use crate::pagination::{ Paginated, paginate };
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use futures_util::Stream;
 use serde::{ Deserialize, Serialize };
 // --- 响应数据结构体 ---
 
@@ -105,6 +107,28 @@ impl BpiClient {
 
         req.send_bpi("查询用户粉丝明细").await
     }
+
+    /// 逐页输出用户粉丝明细
+    ///
+    /// 非本人只能查看前 1000 名粉丝，超出时输出接口返回的错误后结束。
+    ///
+    /// # 参数
+    /// | 名称   | 类型 | 说明         |
+    /// | ------ | ---- | ------------ |
+    /// | `vmid` | u64  | 目标用户 mid |
+    pub fn user_followers_stream(
+        &self,
+        vmid: u64
+    ) -> impl Stream<Item = Result<RelationListItem, BpiError>> + '_ {
+        const PS: u32 = 50;
+        paginate(1u32, move |pn| async move {
+            let data = self
+                .user_followers(vmid, Some(PS), Some(pn), None, None, None).await?
+                .into_data()?;
+            let next = ((pn as u64) * (PS as u64) < data.total).then_some(pn + 1);
+            Ok(Paginated::new(data.list, next))
+        })
+    }
 }
 
 // --- 测试模块 ---
@@ -117,8 +141,20 @@ mod tests {
     const TEST_VMID: u64 = 4279370;
 
     #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_user_followers_stream() -> Result<(), BpiError> {
+        use futures_util::{ StreamExt, TryStreamExt };
+
+        let bpi = crate::test_client!(Guest);
+        let fans: Vec<RelationListItem> = bpi.user_followers_stream(TEST_VMID).take(120).try_collect().await?;
+        assert_eq!(fans.len(), 120);
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
     async fn test_user_followers() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_followers(TEST_VMID, Some(50), Some(1), None, None, None).await?;
         let data = resp.into_data()?;
 
@@ -144,8 +144,9 @@ mod tests {
     const TEST_VMID: u64 = 293793435;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_user_followings() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_followings(TEST_VMID, None, Some(50), Some(1)).await?;
         let data = resp.into_data()?;
 
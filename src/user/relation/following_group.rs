@@ -13,6 +13,31 @@ pub struct FollowTag {
     pub tip: Option<String>, // 提示信息
 }
 
+/// 分组成员
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FollowTagMember {
+    /// 用户 mid
+    pub mid: u64,
+    /// 用户昵称
+    #[serde(default)]
+    pub uname: String,
+    /// 用户头像 url
+    #[serde(default)]
+    pub face: String,
+    /// 用户签名
+    #[serde(default)]
+    pub sign: String,
+    /// 关注时间，秒级时间戳
+    #[serde(default)]
+    pub mtime: u64,
+    /// 是否为特别关注，0: 否, 1: 是
+    #[serde(default)]
+    pub special: u8,
+    /// 所在分组 id
+    #[serde(default)]
+    pub tag: Option<Vec<i64>>,
+}
+
 impl BpiClient {
     /// 查询关注分组列表
     ///
@@ -21,6 +46,35 @@ impl BpiClient {
     pub async fn user_follow_tags(&self) -> Result<BpiResponse<Vec<FollowTag>>, BpiError> {
         self.get("https://api.bilibili.com/x/relation/tags").send_bpi("查询关注分组列表").await
     }
+
+    /// 查询关注分组成员
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user/relation/group)
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `tag_id` | i64 | 分组 id，-10: 特别关注, 0: 默认分组 |
+    /// | `pn` | `Option<u32>` | 页码，默认1 |
+    /// | `ps` | `Option<u32>` | 每页项数，默认20 |
+    pub async fn user_follow_tag_members(
+        &self,
+        tag_id: i64,
+        pn: Option<u32>,
+        ps: Option<u32>
+    ) -> Result<BpiResponse<Vec<FollowTagMember>>, BpiError> {
+        self
+            .get("https://api.bilibili.com/x/relation/tag")
+            .query(
+                &[
+                    ("tagid", tag_id.to_string()),
+                    ("pn", pn.unwrap_or(1).to_string()),
+                    ("ps", ps.unwrap_or(20).to_string()),
+                ]
+            )
+            .send_bpi("查询关注分组成员").await
+    }
 }
 
 #[cfg(test)]
@@ -29,12 +83,24 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_user_follow_tags_cookie() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_follow_tags().await;
         assert!(resp.is_ok());
 
         let data = resp.unwrap().data.unwrap();
         info!("关注分组列表: {:?}", data);
     }
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_user_follow_tag_members() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Authed);
+        let members = bpi.user_follow_tag_members(0, None, Some(10)).await?.into_data()?;
+        for member in members {
+            info!("{} ({})", member.uname, member.mid);
+        }
+        Ok(())
+    }
 }
@@ -13,6 +13,20 @@ pub struct CreateTagResponseData {
     pub tagid: i64,
 }
 
+/// 分组名最大长度（字符数）
+const TAG_NAME_MAX_LEN: usize = 16;
+
+fn check_tag_name(name: &str) -> Result<(), BpiError> {
+    let len = name.trim().chars().count();
+    if len == 0 {
+        return Err(BpiError::invalid_parameter("group_name", "分组名不能为空"));
+    }
+    if len > TAG_NAME_MAX_LEN {
+        return Err(BpiError::invalid_parameter("group_name", "分组名最长16字"));
+    }
+    Ok(())
+}
+
 // --- API 实现 ---
 
 impl BpiClient {
@@ -29,6 +43,7 @@ impl BpiClient {
         &self,
         group_name: &str
     ) -> Result<BpiResponse<CreateTagResponseData>, BpiError> {
+        check_tag_name(group_name)?;
         let csrf = self.csrf()?;
         let form = reqwest::multipart::Form
             ::new()
@@ -41,6 +56,23 @@ impl BpiClient {
             .send_bpi("创建分组").await
     }
 
+    /// 按名称查找分组，不存在时创建，返回分组 ID
+    ///
+    /// # 参数
+    /// | 名称      | 类型         | 说明           |
+    /// | --------- | ------------| -------------- |
+    /// | `group_name` | &str      | 分组名，最长16字|
+    pub async fn user_group_ensure_tag(&self, group_name: &str) -> Result<i64, BpiError> {
+        check_tag_name(group_name)?;
+
+        let tags = self.user_follow_tags().await?.into_data()?;
+        if let Some(tag) = tags.iter().find(|tag| tag.name == group_name) {
+            return Ok(tag.tagid);
+        }
+
+        Ok(self.user_group_create_tag(group_name).await?.into_data()?.tagid)
+    }
+
     /// 重命名分组
     ///
     /// # 文档
@@ -56,6 +88,7 @@ impl BpiClient {
         tag_id: i64,
         new_name: &str
     ) -> Result<BpiResponse<serde_json::Value>, BpiError> {
+        check_tag_name(new_name)?;
         let csrf = self.csrf()?;
         let form = reqwest::multipart::Form
             ::new()
@@ -263,9 +296,18 @@ mod tests {
 
     const DEFAULT_GROUP_FID: u64 = 3493257409464519;
 
+    #[test]
+    fn test_check_tag_name() {
+        assert!(check_tag_name("测试分组").is_ok());
+        assert!(check_tag_name("  ").is_err());
+        assert!(check_tag_name("一二三四五六七八九十一二三四五六").is_ok());
+        assert!(check_tag_name("一二三四五六七八九十一二三四五六七").is_err());
+    }
+
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_tag_operations() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let test_tag_name = "测试分组1";
         let new_tag_name = "新测试分组2";
 
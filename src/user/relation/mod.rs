@@ -4,6 +4,8 @@
 pub mod followers;
 pub mod following;
 pub mod following_group;
+pub mod recommend;
 
-mod action;
-mod group;
+pub mod action;
+pub mod attribute;
+pub mod group;
@@ -0,0 +1,185 @@
+//! 推荐关注与兴趣标签
+//!
+//! 注册后引导流程中展示的推荐关注用户与兴趣标签，配合批量关注完成新用户引导。
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use serde::{ Deserialize, Serialize };
+
+use super::followers::OfficialVerify;
+
+// --- 响应数据结构体 ---
+
+/// 推荐关注用户
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RecommendUser {
+    /// 用户 mid
+    pub mid: u64,
+    /// 用户昵称
+    #[serde(default, alias = "name")]
+    pub uname: String,
+    /// 用户头像 url
+    #[serde(default)]
+    pub face: String,
+    /// 用户签名
+    #[serde(default)]
+    pub sign: String,
+    /// 推荐理由
+    #[serde(default)]
+    pub rec_reason: String,
+    /// 粉丝数
+    #[serde(default)]
+    pub fans: u64,
+    /// 与自己的关系，0: 未关注, 2: 已关注, 6: 已互粉
+    #[serde(default)]
+    pub attribute: u8,
+    #[serde(default)]
+    pub official_verify: Option<OfficialVerify>,
+}
+
+/// 兴趣标签
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InterestTag {
+    /// 标签 id
+    #[serde(alias = "tag_id")]
+    pub id: u64,
+    /// 标签名
+    #[serde(alias = "tag_name")]
+    pub name: String,
+    /// 标签图标 url
+    #[serde(default)]
+    pub icon: String,
+}
+
+/// 兴趣分类
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InterestCategory {
+    /// 分类 id
+    pub id: u64,
+    /// 分类名
+    pub name: String,
+    /// 分类下的标签
+    #[serde(default)]
+    pub tags: Vec<InterestTag>,
+}
+
+/// 批量关注结果
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BatchModifyData {
+    /// 操作失败的用户 mid
+    #[serde(default)]
+    pub failed_fids: Vec<u64>,
+}
+
+// --- API 实现 ---
+
+impl BpiClient {
+    /// 获取推荐关注用户
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `ps` | `Option<u32>` | 每页项数，默认20 |
+    /// | `pn` | `Option<u32>` | 页码，默认1 |
+    pub async fn user_relation_recommend(
+        &self,
+        ps: Option<u32>,
+        pn: Option<u32>
+    ) -> Result<BpiResponse<Vec<RecommendUser>>, BpiError> {
+        self
+            .get("https://api.bilibili.com/x/relation/recommend")
+            .query(
+                &[
+                    ("ps", ps.unwrap_or(20).to_string()),
+                    ("pn", pn.unwrap_or(1).to_string()),
+                ]
+            )
+            .send_bpi("获取推荐关注").await
+    }
+
+    /// 获取兴趣标签分类
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
+    pub async fn user_interest_tags(&self) -> Result<BpiResponse<Vec<InterestCategory>>, BpiError> {
+        self
+            .get("https://api.bilibili.com/x/web-interface/interest/tags")
+            .send_bpi("获取兴趣标签").await
+    }
+
+    /// 批量关注用户
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `fids` | &[u64] | 目标用户 mid 列表 |
+    pub async fn user_batch_follow(
+        &self,
+        fids: &[u64]
+    ) -> Result<BpiResponse<BatchModifyData>, BpiError> {
+        if fids.is_empty() {
+            return Err(BpiError::invalid_parameter("fids", "至少需要一个用户"));
+        }
+
+        let csrf = self.csrf()?;
+        let fids = fids
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self
+            .post("https://api.bilibili.com/x/relation/batch/modify")
+            .form(
+                &[
+                    ("fids", fids),
+                    ("act", "1".to_string()),
+                    ("re_src", "11".to_string()),
+                    ("csrf", csrf),
+                ]
+            )
+            .send_bpi("批量关注").await
+    }
+}
+
+// --- 测试模块 ---
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::info;
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_user_relation_recommend() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Authed);
+        let users = bpi.user_relation_recommend(Some(10), None).await?.into_data()?;
+        for user in users {
+            info!("{} ({}): {}", user.uname, user.mid, user.rec_reason);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_user_interest_tags() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Authed);
+        let categories = bpi.user_interest_tags().await?.into_data()?;
+        for category in categories {
+            info!("{}: {} 个标签", category.name, category.tags.len());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_user_batch_follow_empty() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        assert!(bpi.user_batch_follow(&[]).await.is_err());
+    }
+}
@@ -1,6 +1,7 @@
 //! B站用户搜索相关接口
 //!
 //! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
+use crate::utils::wbi::dm_img_params;
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 use serde::{ Deserialize, Serialize };
 
@@ -159,6 +160,7 @@ impl BpiClient {
         if let Some(k) = keyword {
             params.push(("keyword", k.to_string()));
         }
+        params.extend(dm_img_params());
 
         let params = self.get_wbi_sign2(params).await?;
 
@@ -180,8 +182,9 @@ mod tests {
     const TEST_KEYWORD: &str = "科技";
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_user_contributed_videos_default() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_contributed_videos(TEST_MID, None, None, None, Some(1), Some(2)).await?;
         let data = resp.into_data()?;
 
@@ -195,8 +198,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_user_contributed_videos_with_keyword() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_contributed_videos(
             TEST_MID,
             None,
@@ -1,6 +1,7 @@
 //! B站用户空间相关接口
 //!
 //! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
+use crate::utils::wbi::dm_img_params;
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 use serde::{ Deserialize, Serialize };
 
@@ -204,6 +205,109 @@ pub struct BangumiFollowListResponseData {
     pub total: u64,
 }
 
+/// 用户投稿专栏统计
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SpaceArticleStats {
+    #[serde(default)]
+    pub view: u64,
+    #[serde(default)]
+    pub favorite: u64,
+    #[serde(default)]
+    pub like: u64,
+    #[serde(default)]
+    pub reply: u64,
+    #[serde(default)]
+    pub share: u64,
+    #[serde(default)]
+    pub coin: u64,
+}
+
+/// 用户投稿专栏条目
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SpaceArticleItem {
+    /// 专栏 cvid
+    pub id: u64,
+    /// 标题
+    pub title: String,
+    /// 摘要
+    #[serde(default)]
+    pub summary: String,
+    /// 头图
+    #[serde(default)]
+    pub banner_url: String,
+    /// 封面图
+    #[serde(default)]
+    pub image_urls: Vec<String>,
+    /// 发布时间秒级时间戳
+    #[serde(default)]
+    pub publish_time: i64,
+    /// 字数
+    #[serde(default)]
+    pub words: u64,
+    #[serde(default)]
+    pub stats: SpaceArticleStats,
+}
+
+/// 用户投稿专栏列表
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SpaceArticleList {
+    #[serde(default)]
+    pub articles: Vec<SpaceArticleItem>,
+    /// 当前页码
+    #[serde(default)]
+    pub pn: u32,
+    /// 每页项数
+    #[serde(default)]
+    pub ps: u32,
+    /// 总计专栏数
+    #[serde(default)]
+    pub count: u64,
+}
+
+/// 关注的 TAG 统计
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct FollowedTagCount {
+    /// 浏览数
+    #[serde(default)]
+    pub view: u64,
+    /// 使用数
+    #[serde(default, rename = "use")]
+    pub use_count: u64,
+    /// 关注数
+    #[serde(default)]
+    pub atten: u64,
+}
+
+/// 关注的 TAG
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FollowedTag {
+    /// TAG id
+    pub tag_id: u64,
+    /// TAG 名称
+    pub name: String,
+    /// 封面
+    #[serde(default)]
+    pub cover: String,
+    /// 简介
+    #[serde(default)]
+    pub content: String,
+    /// 创建时间秒级时间戳
+    #[serde(default)]
+    pub ctime: i64,
+    #[serde(default)]
+    pub count: FollowedTagCount,
+}
+
+/// 用户关注的 TAG 列表
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FollowedTagList {
+    #[serde(default)]
+    pub tags: Vec<FollowedTag>,
+    /// 总计关注数
+    #[serde(default)]
+    pub total: u64,
+}
+
 // --- API 实现 ---
 
 impl BpiClient {
@@ -239,13 +343,14 @@ impl BpiClient {
         &self,
         notice: Option<&str>
     ) -> Result<BpiResponse<()>, BpiError> {
+        if notice.is_some_and(|n| n.chars().count() > 150) {
+            return Err(BpiError::invalid_parameter("notice", "公告内容超出150字限制"));
+        }
+
         let csrf = self.csrf()?;
         let mut form = reqwest::multipart::Form::new().text("csrf", csrf.to_string());
 
         if let Some(n) = notice {
-            if n.len() > 150 {
-                return Err(BpiError::parse("公告内容超出150字符限制"));
-            }
             form = form.text("notice", n.to_string());
         }
 
@@ -255,6 +360,32 @@ impl BpiClient {
             .send_bpi("修改空间公告").await
     }
 
+    /// 同步当前账号的空间公告
+    ///
+    /// 先读取当前公告，内容不同时才提交修改，适合定时任务按直播日程等内容刷新公告。
+    ///
+    /// # 参数
+    /// | 名称    | 类型   | 说明                          |
+    /// | ------- | ------ | ----------------------------- |
+    /// | `notice`| &str   | 目标公告内容，为空时删除公告  |
+    ///
+    /// 返回是否实际进行了修改。
+    pub async fn user_space_notice_sync(&self, notice: &str) -> Result<bool, BpiError> {
+        let mid: u64 = self
+            .account_snapshot()
+            .and_then(|acc| acc.dede_user_id.parse().ok())
+            .ok_or_else(|| BpiError::auth("未登录"))?;
+
+        let current = self.user_space_notice(mid).await?.into_data()?;
+        if current.0 == notice {
+            return Ok(false);
+        }
+
+        let notice = (!notice.is_empty()).then_some(notice);
+        self.user_space_notice_set(notice).await?;
+        Ok(true)
+    }
+
     /// 查询用户追番/追剧明细
     ///
     /// # 文档
@@ -297,6 +428,60 @@ impl BpiClient {
 
         req.send_bpi("查询用户追番/追剧明细").await
     }
+
+    /// 查询用户投稿专栏
+    /// 需要 Wbi 签名认证
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
+    ///
+    /// # 参数
+    /// | 名称   | 类型           | 说明                 |
+    /// | ------ | --------------| -------------------- |
+    /// | `mid`  | u64           | 目标用户 mid         |
+    /// | `pn`   | `Option<u32>`   | 页码，默认1          |
+    /// | `ps`   | `Option<u32>`   | 每页项数，默认12     |
+    pub async fn user_articles(
+        &self,
+        mid: u64,
+        pn: Option<u32>,
+        ps: Option<u32>
+    ) -> Result<BpiResponse<SpaceArticleList>, BpiError> {
+        let params = vec![
+            ("mid", mid.to_string()),
+            ("pn", pn.unwrap_or(1).to_string()),
+            ("ps", ps.unwrap_or(12).to_string()),
+            ("sort", "publish_time".to_string())
+        ];
+
+        self
+            .get("https://api.bilibili.com/x/space/wbi/article")
+            .query(&params)
+            .query(&dm_img_params())
+            .send_bpi_wbi("查询用户投稿专栏").await
+    }
+
+    /// 查询用户关注的 TAG
+    ///
+    /// 用户在隐私设置中关闭了公开关注的 TAG 时会返回错误。
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/user)
+    ///
+    /// # 参数
+    /// | 名称   | 类型   | 说明           |
+    /// | ------ | ------ | -------------- |
+    /// | `mid`  | u64    | 目标用户 mid   |
+    pub async fn user_followed_tags(
+        &self,
+        mid: u64
+    ) -> Result<BpiResponse<FollowedTagList>, BpiError> {
+        self
+            .get("https://space.bilibili.com/ajax/tags/getSubList")
+            .with_bilibili_headers()
+            .query(&[("mid", &mid.to_string())])
+            .send_bpi("查询用户关注的TAG").await
+    }
 }
 
 // --- 测试模块 ---
@@ -312,8 +497,9 @@ mod tests {
     const TEST_MID: u64 = 4279370;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_user_space_notice() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_space_notice(TEST_MID).await?;
         let data = resp.into_data()?;
 
@@ -323,8 +509,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_user_space_notice_set() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let notice = "这是一个通过 API 设置的测试公告。";
         let resp = bpi.user_space_notice_set(Some(notice)).await?;
 
@@ -351,8 +538,16 @@ mod tests {
     }
 
     #[tokio::test]
+    async fn test_user_space_notice_length() {
+        let bpi = BpiClient::try_new_local().unwrap();
+        let err = bpi.user_space_notice_set(Some(&"公".repeat(151))).await.unwrap_err();
+        assert!(matches!(err, BpiError::InvalidParameter { field: "notice", .. }));
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
     async fn test_user_bangumi_follow_list() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 1: 追番, 2: 追剧
         let resp = bpi.user_bangumi_follow_list(TEST_MID, Some(1), Some(15), 1).await?;
         let data = resp.into_data()?;
@@ -364,4 +559,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_user_articles() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let resp = bpi.user_articles(TEST_MID, Some(1), Some(12)).await?;
+        let data = resp.into_data()?;
+
+        info!("专栏总数: {}", data.count);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_user_followed_tags() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let resp = bpi.user_followed_tags(TEST_MID).await?;
+        let data = resp.into_data()?;
+
+        info!("关注的TAG: {:?}", data.tags);
+
+        Ok(())
+    }
 }
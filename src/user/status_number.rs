@@ -108,6 +108,49 @@ pub struct AlbumCountResponseData {
     pub daily_count: u64,
 }
 
+/// 用户空间标签页
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpaceTab {
+    /// 投稿视频
+    Video,
+    /// 投稿音频
+    Audio,
+    /// 投稿专栏
+    Article,
+    /// 投稿图文（相簿）
+    Album,
+    /// 合集和视频列表
+    Collection,
+    /// 投稿课程
+    Course,
+    /// 收藏夹
+    Favourite,
+    /// 追番追剧
+    Bangumi,
+}
+
+impl NavnumResponseData {
+    /// 根据导航栏状态数推断空间页展示的标签页，顺序与网页端一致
+    ///
+    /// 计数为 0 的投稿类标签页在网页端不显示。
+    pub fn visible_tabs(&self) -> Vec<SpaceTab> {
+        [
+            (SpaceTab::Video, self.video),
+            (SpaceTab::Audio, self.audio),
+            (SpaceTab::Article, self.article),
+            (SpaceTab::Album, self.album),
+            (SpaceTab::Collection, self.season_num + self.channel.guest),
+            (SpaceTab::Course, self.pugv),
+            (SpaceTab::Favourite, self.favourite.guest),
+            (SpaceTab::Bangumi, self.bangumi + self.cinema),
+        ]
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(tab, _)| tab)
+            .collect()
+    }
+}
+
 // --- API 实现 ---
 
 impl BpiClient {
@@ -165,6 +208,18 @@ impl BpiClient {
             .send_bpi("获取用户导航栏状态数").await
     }
 
+    /// 获取用户空间展示的标签页
+    ///
+    /// 基于 [`user_navnum`](BpiClient::user_navnum)，见 [`NavnumResponseData::visible_tabs`]。
+    ///
+    /// # 参数
+    /// | 名称   | 类型   | 说明           |
+    /// | ------ | ------ | -------------- |
+    /// | `mid`  | u64    | 目标用户 mid   |
+    pub async fn user_space_tabs(&self, mid: u64) -> Result<Vec<SpaceTab>, BpiError> {
+        Ok(self.user_navnum(mid).await?.into_data()?.visible_tabs())
+    }
+
     /// 获取相簿投稿数
     ///
     /// # 文档
@@ -199,8 +254,9 @@ mod tests {
     const TEST_NAV_MID: u64 = 645769214;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_relation_stat() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_relation_stat(TEST_MID).await?;
         let data = resp.into_data()?;
 
@@ -211,8 +267,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_up_stat() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_up_stat(TEST_UP_MID).await?;
         let data = resp.into_data()?;
 
@@ -223,8 +280,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_nav_num() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_navnum(TEST_NAV_MID).await?;
         let data = resp.into_data()?;
 
@@ -234,9 +292,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_visible_tabs() {
+        let data: NavnumResponseData = serde_json::from_value(
+            serde_json::json!({
+                "video": 10, "bangumi": 0, "cinema": 1,
+                "channel": { "master": 2, "guest": 0 },
+                "favourite": { "master": 3, "guest": 0 },
+                "tag": 0, "article": 2, "playlist": 0, "album": 0, "audio": 0,
+                "pugv": 0, "opus": 5, "season_num": 1
+            })
+        ).unwrap();
+
+        assert_eq!(data.visible_tabs(), vec![
+            SpaceTab::Video,
+            SpaceTab::Article,
+            SpaceTab::Collection,
+            SpaceTab::Bangumi
+        ]);
+    }
+
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_album_count() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.user_album_count(TEST_NAV_MID).await?;
         let data = resp.into_data()?;
 
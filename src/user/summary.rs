@@ -0,0 +1,179 @@
+//! 用户资料概览
+//!
+//! 审核、验证账号时常需要同时查看空间信息、最早与最新的投稿以及最近的动态，
+//! [`BpiClient::user_profile_summary`] 并发获取后汇总为 [`UserProfileSummary`]。
+
+use chrono::{ DateTime, FixedOffset };
+
+use super::info::UserSpaceInfo;
+use super::search::ContributedVideo;
+use crate::utils::time::{ DEFAULT_TIMEZONE_OFFSET, timestamp_to_datetime };
+use crate::{ BpiClient, BpiError };
+
+#[cfg(feature = "dynamic")]
+use crate::dynamic::all::DynamicItem;
+
+/// 用户资料概览
+#[derive(Debug, Clone)]
+pub struct UserProfileSummary {
+    /// 空间信息，含等级、大会员、认证等
+    pub info: UserSpaceInfo,
+    /// 投稿视频数
+    pub video_count: u64,
+    /// 最早的投稿视频
+    pub first_video: Option<ContributedVideo>,
+    /// 最新的投稿视频
+    pub latest_video: Option<ContributedVideo>,
+    /// 发布时间最新的动态，不受置顶影响；获取失败（如未登录时风控）时为 `None`
+    #[cfg(feature = "dynamic")]
+    pub latest_dynamic: Option<DynamicItem>,
+}
+
+impl UserProfileSummary {
+    /// 用户等级
+    pub fn level(&self) -> u8 {
+        self.info.level
+    }
+
+    /// 是否为有效大会员
+    pub fn is_vip(&self) -> bool {
+        self.info.vip.vip_status == 1
+    }
+
+    /// 是否有官方认证
+    pub fn is_official(&self) -> bool {
+        self.info.official.r#type >= 0 && !self.info.official.title.is_empty()
+    }
+
+    /// 注册时间，接口已不再返回时为 `None`
+    pub fn joined_at(&self) -> Option<DateTime<FixedOffset>> {
+        if self.info.jointime == 0 {
+            return None;
+        }
+        timestamp_to_datetime(self.info.jointime as i64, DEFAULT_TIMEZONE_OFFSET)
+    }
+
+    /// 最早投稿时间
+    pub fn first_video_at(&self) -> Option<DateTime<FixedOffset>> {
+        let video = self.first_video.as_ref()?;
+        timestamp_to_datetime(video.created as i64, DEFAULT_TIMEZONE_OFFSET)
+    }
+
+    /// 已知最早的活动时间，取注册时间与最早投稿时间中较早者，可作为账号年龄的下限
+    pub fn earliest_activity(&self) -> Option<DateTime<FixedOffset>> {
+        [self.joined_at(), self.first_video_at()].into_iter().flatten().min()
+    }
+
+    /// 最近的活动时间，取最新投稿与最新动态中较晚者
+    pub fn latest_activity(&self) -> Option<DateTime<FixedOffset>> {
+        let video = self.latest_video
+            .as_ref()
+            .and_then(|video| timestamp_to_datetime(video.created as i64, DEFAULT_TIMEZONE_OFFSET));
+        #[cfg(feature = "dynamic")]
+        let dynamic = self.latest_dynamic
+            .as_ref()
+            .and_then(|item| item.published_at(DEFAULT_TIMEZONE_OFFSET));
+        #[cfg(not(feature = "dynamic"))]
+        let dynamic = None;
+        [video, dynamic].into_iter().flatten().max()
+    }
+}
+
+impl BpiClient {
+    /// 获取用户资料概览
+    ///
+    /// 并发请求空间信息、投稿视频与空间动态。最早投稿通过按发布时间排序的最后一页获取。
+    ///
+    /// # 参数
+    /// | 名称 | 类型 | 说明 |
+    /// | ---- | ---- | ---- |
+    /// | `mid` | u64 | 用户 UID |
+    pub async fn user_profile_summary(&self, mid: u64) -> Result<UserProfileSummary, BpiError> {
+        let info = async { self.user_space_info(mid).await?.into_data() };
+
+        #[cfg(feature = "dynamic")]
+        let (info, (video_count, latest_video, first_video), latest_dynamic) = tokio::try_join!(
+            info,
+            self.user_video_bounds(mid),
+            async { Ok(self.user_latest_dynamic(mid).await) }
+        )?;
+        #[cfg(not(feature = "dynamic"))]
+        let (info, (video_count, latest_video, first_video)) = tokio::try_join!(
+            info,
+            self.user_video_bounds(mid)
+        )?;
+
+        Ok(UserProfileSummary {
+            info,
+            video_count,
+            first_video,
+            latest_video,
+            #[cfg(feature = "dynamic")]
+            latest_dynamic,
+        })
+    }
+
+    /// 投稿总数、最新与最早的投稿
+    async fn user_video_bounds(
+        &self,
+        mid: u64
+    ) -> Result<(u64, Option<ContributedVideo>, Option<ContributedVideo>), BpiError> {
+        let newest = self
+            .user_contributed_videos(mid, Some("pubdate"), None, None, Some(1), Some(1)).await?
+            .into_data()?;
+        let count = newest.page.count;
+        let latest = newest.list.vlist.into_iter().next();
+
+        if count <= 1 {
+            return Ok((count, latest.clone(), latest));
+        }
+
+        let last_page = u32::try_from(count).unwrap_or(u32::MAX);
+        let oldest = self
+            .user_contributed_videos(mid, Some("pubdate"), None, None, Some(last_page), Some(1)).await?
+            .into_data()?;
+        Ok((count, latest, oldest.list.vlist.into_iter().next()))
+    }
+
+    /// 首页中发布时间最新的动态，失败时记录日志并返回 `None`
+    #[cfg(feature = "dynamic")]
+    async fn user_latest_dynamic(&self, mid: u64) -> Option<DynamicItem> {
+        let data = match self.dynamic_space(mid, None).await.and_then(|resp| resp.into_data()) {
+            Ok(data) => data,
+            Err(e) => {
+                tracing::warn!("获取用户 {} 的空间动态失败: {}", mid, e);
+                return None;
+            }
+        };
+        data.items
+            .into_iter()
+            .filter_map(|item| {
+                let published = item.published_at(DEFAULT_TIMEZONE_OFFSET)?;
+                Some((published, item))
+            })
+            .max_by_key(|(published, _)| *published)
+            .map(|(_, item)| item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_user_profile_summary() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let summary = bpi.user_profile_summary(2).await?;
+        tracing::info!(
+            "{} Lv{} 投稿 {} 个，最早活动 {:?}，最近活动 {:?}",
+            summary.info.name,
+            summary.level(),
+            summary.video_count,
+            summary.earliest_activity(),
+            summary.latest_activity()
+        );
+        assert!(summary.video_count == 0 || summary.first_video.is_some());
+        Ok(())
+    }
+}
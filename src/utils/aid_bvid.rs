@@ -1,3 +1,5 @@
+use crate::BpiError;
+
 const XOR_CODE: i64 = 23_442_827_791_579;
 const MAX_CODE: i64 = 2_251_799_813_685_247;
 const CHARTS: &str = "FcwAPNKTMug3GV5Lj7EJnHpWsx4tb8haYeviqBz6rkCy12mUSDQX9RdoZf";
@@ -42,9 +44,157 @@ pub fn avid_to_bvid(avid: i64) -> String {
     swap_string(&swap_string(&raw, 3, 9), 4, 7)
 }
 
+/// bvid 可表示的最大 avid，即 2^51 - 1
+pub const MAX_AID: u64 = MAX_CODE as u64;
+
+/// 是否为格式正确的 bvid，`BV1` 前缀不区分大小写
+fn is_valid_bvid(bvid: &str) -> bool {
+    bvid.len() == 12 &&
+        bvid.get(..3).is_some_and(|prefix| prefix.eq_ignore_ascii_case("BV1")) &&
+        bvid[3..].chars().all(|c| CHARTS.contains(c))
+}
+
+/// bvid 转 avid，本地计算不发请求
+///
+/// 与 [`bvid_to_avid`] 相同，但格式不正确时返回错误而不是 panic。
+///
+/// ```
+/// use bpi_rs::utils::aid_bvid::bvid_to_aid;
+///
+/// assert_eq!(bvid_to_aid("BV1bx411c7us").unwrap(), 10001);
+/// assert!(bvid_to_aid("av10001").is_err());
+/// ```
+pub fn bvid_to_aid(bvid: &str) -> Result<u64, BpiError> {
+    if !is_valid_bvid(bvid) {
+        return Err(BpiError::invalid_parameter("bvid", "无效的 bvid"));
+    }
+    Ok(bvid_to_avid(bvid) as u64)
+}
+
+/// avid 转 bvid，本地计算不发请求
+///
+/// `aid` 不应超过 [`MAX_AID`]，超出部分的高位会被丢弃。
+pub fn aid_to_bvid(aid: u64) -> String {
+    debug_assert!(aid <= MAX_AID, "avid {} 超出 bvid 可表示范围", aid);
+    avid_to_bvid((aid & MAX_AID) as i64)
+}
+
+/// 稿件标识，avid 或 bvid
+///
+/// ```
+/// use bpi_rs::utils::aid_bvid::VideoId;
+///
+/// let id: VideoId = "av10001".parse().unwrap();
+/// assert_eq!(id.bvid(), "BV1bx411c7us");
+/// assert_eq!("BV1bx411c7us".parse::<VideoId>().unwrap().aid(), 10001);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VideoId {
+    Aid(u64),
+    Bvid(String),
+}
+
+impl VideoId {
+    /// avid，bvid 时本地换算
+    pub fn aid(&self) -> u64 {
+        match self {
+            Self::Aid(aid) => *aid,
+            Self::Bvid(bvid) => bvid_to_aid(bvid).unwrap_or_default(),
+        }
+    }
+
+    /// bvid，avid 时本地换算
+    pub fn bvid(&self) -> String {
+        match self {
+            Self::Aid(aid) => aid_to_bvid(*aid),
+            Self::Bvid(bvid) => bvid.clone(),
+        }
+    }
+
+    /// 查询参数，`("aid", ..)` 或 `("bvid", ..)`
+    pub fn query_pair(&self) -> (&'static str, String) {
+        match self {
+            Self::Aid(aid) => ("aid", aid.to_string()),
+            Self::Bvid(bvid) => ("bvid", bvid.clone()),
+        }
+    }
+}
+
+impl From<u64> for VideoId {
+    fn from(aid: u64) -> Self {
+        Self::Aid(aid)
+    }
+}
+
+impl std::str::FromStr for VideoId {
+    type Err = BpiError;
+
+    /// 支持 `BV1bx411c7us`、`av10001` 与纯数字 avid
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || BpiError::invalid_parameter("video_id", "无效的 avid 或 bvid");
+
+        if is_valid_bvid(s) {
+            return Ok(Self::Bvid(format!("BV1{}", &s[3..])));
+        }
+
+        let digits = match s.get(..2) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("av") => &s[2..],
+            _ => s,
+        };
+        digits
+            .parse()
+            .ok()
+            .filter(|&aid| aid > 0)
+            .map(Self::Aid)
+            .ok_or_else(invalid)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn test_video_id_parse() {
+        assert_eq!("av10001".parse::<VideoId>().unwrap(), VideoId::Aid(10001));
+        assert_eq!("10001".parse::<VideoId>().unwrap(), VideoId::Aid(10001));
+        assert_eq!(
+            "bv1bx411c7us".parse::<VideoId>().unwrap(),
+            VideoId::Bvid("BV1bx411c7us".to_string())
+        );
+        assert_eq!(VideoId::Bvid("BV1bx411c7us".to_string()).aid(), 10001);
+        assert_eq!(VideoId::from(10001).query_pair(), ("aid", "10001".to_string()));
+        assert!("BV1bx411c7u0".parse::<VideoId>().is_err());
+        assert!("av".parse::<VideoId>().is_err());
+    }
+
+    #[test]
+    fn test_bvid_to_aid_invalid() {
+        for bvid in ["", "BV1bx411c7u", "BV1bx411c7us1", "BV2bx411c7us", "BV1bx411c7u0", "BV1bx411c7uI"] {
+            assert!(bvid_to_aid(bvid).is_err(), "{}", bvid);
+        }
+        assert_eq!(bvid_to_aid("bv1bx411c7us").unwrap(), 10001);
+    }
+
+    #[test]
+    fn test_aid_bvid_roundtrip() {
+        // 线性同余生成器产生的伪随机 avid，外加边界值
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let samples = (0..2000).map(move |_| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 13) % MAX_AID + 1
+        });
+
+        for aid in [1, 2, 10001, 170001, 1_000_000_000, MAX_AID].into_iter().chain(samples) {
+            let bvid = aid_to_bvid(aid);
+            assert_eq!(bvid.len(), 12, "{}", aid);
+            assert!(bvid.starts_with("BV1"), "{}", aid);
+            assert_eq!(bvid_to_aid(&bvid).unwrap(), aid, "{}", bvid);
+            assert_eq!(bvid.parse::<VideoId>().unwrap().aid(), aid);
+        }
+    }
+
     #[test]
     fn test_bvid_to_avid() {
         assert_eq!(bvid_to_avid("BV1bx411c7ux"), 10000);
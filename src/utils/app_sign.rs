@@ -0,0 +1,206 @@
+//! APP 接口签名
+//!
+//! 参数按键名排序拼接后追加 appsec 计算 MD5，结果作为 `sign` 参数。
+//!
+//! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/misc/sign/APP.md)
+
+use std::collections::BTreeMap;
+
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{ Request, Url };
+
+use super::wbi::url_encode;
+use crate::BpiError;
+
+/// APP 签名所用的 appkey 与 appsec
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppKey {
+    pub appkey: &'static str,
+    pub appsec: &'static str,
+}
+
+impl AppKey {
+    /// Android 粉版
+    pub const ANDROID: AppKey = AppKey {
+        appkey: "1d8b6e7d45233436",
+        appsec: "560c52ccd288fed045859ed18bffd973",
+    };
+
+    /// Android HD 版
+    pub const ANDROID_HD: AppKey = AppKey {
+        appkey: "dfca71928277209b",
+        appsec: "b5475a8825547a4fc26c7d518eaaa02e",
+    };
+
+    /// 云视听小电视，TV 端扫码登录与 TV 端接口使用
+    pub const TV: AppKey = AppKey {
+        appkey: "4409e2ce8ffd12b8",
+        appsec: "59b43e04ad6965f34319062b478f83dd",
+    };
+
+    /// 直播姬，Web Cookie 与 access_key 互换时使用
+    pub const LIVE_HIME: AppKey = AppKey {
+        appkey: "27eb53fc9058f8c3",
+        appsec: "c2ed53a74eeefe3cf99fbd01d8c9c375",
+    };
+
+    /// 已知的 appkey
+    pub const KNOWN: &'static [AppKey] = &[Self::ANDROID, Self::ANDROID_HD, Self::TV, Self::LIVE_HIME];
+
+    /// 按 appkey 查找对应的 appsec
+    pub fn from_appkey(appkey: &str) -> Option<AppKey> {
+        Self::KNOWN.iter().copied().find(|key| key.appkey == appkey)
+    }
+}
+
+/// [`BilibiliRequest::with_app_sign`](crate::BilibiliRequest::with_app_sign) 使用的内部请求头，发送前会被移除
+pub(crate) const APP_SIGN_HEADER: &str = "x-bpi-app-sign";
+
+/// [`BilibiliRequest::with_access_key`](crate::BilibiliRequest::with_access_key) 使用的内部请求头，发送前会被移除
+pub(crate) const ACCESS_KEY_HEADER: &str = "x-bpi-access-key";
+
+/// 对参数进行 APP 签名，写入 `appkey` 与 `sign`，并在缺少时补上 `ts`
+pub fn app_sign(params: &mut BTreeMap<String, String>, key: AppKey) {
+    sign_params(params, key.appkey, key.appsec);
+}
+
+fn sign_params(params: &mut BTreeMap<String, String>, appkey: &str, appsec: &str) {
+    params.insert("appkey".to_string(), appkey.to_string());
+    params
+        .entry("ts".to_string())
+        .or_insert_with(|| chrono::Utc::now().timestamp().to_string());
+    params.remove("sign");
+
+    let query = params
+        .iter()
+        .map(|(k, v)| format!("{}={}", url_encode(k), url_encode(v)))
+        .collect::<Vec<String>>()
+        .join("&");
+
+    let sign = format!("{:x}", md5::compute(format!("{}{}", query, appsec)));
+    params.insert("sign".to_string(), sign);
+}
+
+/// 按内部请求头补上 `access_key` 并签名：有表单时写入表单，否则写入查询参数
+///
+/// [`APP_SIGN_HEADER`] 的值为 `appkey:appsec`，[`ACCESS_KEY_HEADER`] 的值为 access_key，
+/// 两者都没有时不做处理。
+pub(crate) fn sign_request(request: &mut Request) -> Result<(), BpiError> {
+    let marker = request.headers_mut().remove(APP_SIGN_HEADER);
+    let access_key = request.headers_mut().remove(ACCESS_KEY_HEADER);
+    if marker.is_none() && access_key.is_none() {
+        return Ok(());
+    }
+
+    let key = marker
+        .map(|marker| {
+            marker
+                .to_str()
+                .ok()
+                .and_then(|v| v.split_once(':'))
+                .map(|(appkey, appsec)| (appkey.to_string(), appsec.to_string()))
+                .ok_or_else(|| BpiError::invalid_parameter("appkey", "APP 签名的 appkey 无效"))
+        })
+        .transpose()?;
+    let access_key = access_key
+        .map(|v| {
+            v.to_str()
+                .map(str::to_string)
+                .map_err(|_| BpiError::invalid_parameter("access_key", "access_key 无效"))
+        })
+        .transpose()?;
+
+    let update = |url: &mut Url| {
+        let mut params: BTreeMap<String, String> = url
+            .query_pairs()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+        if let Some(access_key) = &access_key {
+            params.insert("access_key".to_string(), access_key.clone());
+        }
+        if let Some((appkey, appsec)) = &key {
+            sign_params(&mut params, appkey, appsec);
+        }
+        url.query_pairs_mut().clear().extend_pairs(&params);
+    };
+
+    let is_form = request.headers()
+        .get(CONTENT_TYPE)
+        .is_some_and(|v| v.as_bytes().starts_with(b"application/x-www-form-urlencoded"));
+    if is_form {
+        let body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+            .unwrap_or_default();
+        // 借助 Url 解析与编码表单
+        let mut form = Url::parse("http://localhost/").expect("静态 URL");
+        form.set_query(Some(&body));
+        update(&mut form);
+        *request.body_mut() = Some(form.query().unwrap_or_default().to_string().into());
+    } else {
+        update(request.url_mut());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_app_sign() {
+        let mut params = BTreeMap::from([
+            ("id".to_string(), "114514".to_string()),
+            ("str".to_string(), "1919810".to_string()),
+            ("test".to_string(), "いいよ，こいよ".to_string()),
+            ("ts".to_string(), "1702204169".to_string()),
+        ]);
+        app_sign(
+            &mut params,
+            AppKey { appkey: "1d8b6e7d45233436", appsec: "560c52ccd288fed045859ed18bffd973" }
+        );
+
+        assert_eq!(params["appkey"], "1d8b6e7d45233436");
+        assert_eq!(params["sign"], "d54317b2dea8f9df3a14f02aeddc2b20");
+    }
+
+    #[test]
+    fn test_sign_request() {
+        let client = reqwest::Client::new();
+        let mut request = client
+            .post("https://passport.bilibili.com/x/passport-tv-login/qrcode/auth_code")
+            .form(&[("local_id", "0"), ("ts", "1702204169")])
+            .header(APP_SIGN_HEADER, format!("{}:{}", AppKey::TV.appkey, AppKey::TV.appsec))
+            .build()
+            .unwrap();
+        sign_request(&mut request).unwrap();
+        assert!(request.headers().get(APP_SIGN_HEADER).is_none());
+
+        let body = String::from_utf8(request.body().unwrap().as_bytes().unwrap().to_vec()).unwrap();
+        let mut params = BTreeMap::from([
+            ("local_id".to_string(), "0".to_string()),
+            ("ts".to_string(), "1702204169".to_string()),
+        ]);
+        app_sign(&mut params, AppKey::TV);
+        assert!(body.contains(&format!("sign={}", params["sign"])));
+        assert!(body.starts_with("appkey=4409e2ce8ffd12b8&local_id=0"));
+
+        let mut request = client
+            .get("https://app.bilibili.com/x/v2/view?aid=2")
+            .header(APP_SIGN_HEADER, "1d8b6e7d45233436:560c52ccd288fed045859ed18bffd973")
+            .build()
+            .unwrap();
+        sign_request(&mut request).unwrap();
+        let query = request.url().query().unwrap();
+        assert!(query.contains("appkey=1d8b6e7d45233436") && query.contains("sign="));
+
+        let mut request = client
+            .get("https://app.bilibili.com/x/v2/view?aid=2")
+            .header(ACCESS_KEY_HEADER, "token")
+            .build()
+            .unwrap();
+        sign_request(&mut request).unwrap();
+        assert_eq!(request.url().query(), Some("access_key=token&aid=2"));
+    }
+}
@@ -0,0 +1,174 @@
+//! 视频流 CDN 测速
+//!
+//! 对 [`DashTrack`] 的主地址与备用地址各发起一次小范围 Range 请求，按延迟与吞吐量排序，
+//! 下载前配合 [`DashTrack::prefer_urls`] 把最快的节点排在前面。
+
+use std::time::{ Duration, Instant };
+
+use futures_util::future::join_all;
+use reqwest::Url;
+use serde::Serialize;
+
+use crate::models::DashTrack;
+use crate::{ BilibiliRequest, BpiClient };
+
+/// 测速选项
+#[derive(Debug, Clone, Copy)]
+pub struct CdnProbeOptions {
+    /// 每个地址下载的字节数
+    pub probe_bytes: u64,
+    /// 单个地址的超时时间
+    pub timeout: Duration,
+}
+
+impl Default for CdnProbeOptions {
+    fn default() -> Self {
+        Self {
+            probe_bytes: 256 * 1024,
+            timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// 单个地址的测速结果
+#[derive(Debug, Clone, Serialize)]
+pub struct CdnProbe {
+    /// 视频流地址
+    pub url: String,
+    /// CDN 节点域名
+    pub host: String,
+    /// 收到响应头的耗时
+    pub latency: Option<Duration>,
+    /// 吞吐量（字节/秒）
+    pub throughput: Option<f64>,
+    /// 失败原因
+    pub error: Option<String>,
+}
+
+impl CdnProbe {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    fn failed(url: &str, error: impl ToString) -> Self {
+        Self {
+            url: url.to_string(),
+            host: host_of(url),
+            latency: None,
+            throughput: None,
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+fn host_of(url: &str) -> String {
+    Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// 按吞吐量从高到低排序，失败的地址排在最后
+pub fn rank_probes(probes: &mut [CdnProbe]) {
+    probes.sort_by(|a, b| {
+        let a = a.throughput.unwrap_or(-1.0);
+        let b = b.throughput.unwrap_or(-1.0);
+        b.total_cmp(&a)
+    });
+}
+
+impl BpiClient {
+    /// 对视频流的全部地址测速，结果按吞吐量从高到低排序
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// # async fn run(bpi: &bpi_rs::BpiClient, mut track: bpi_rs::models::DashTrack) {
+    /// use bpi_rs::utils::cdn::CdnProbeOptions;
+    ///
+    /// let probes = bpi.probe_cdn(&track, CdnProbeOptions::default()).await;
+    /// let fastest: Vec<_> = probes.iter().filter(|p| p.is_ok()).map(|p| p.url.as_str()).collect();
+    /// track.prefer_urls(&fastest);
+    /// # }
+    /// ```
+    pub async fn probe_cdn(&self, track: &DashTrack, options: CdnProbeOptions) -> Vec<CdnProbe> {
+        let mut probes = join_all(track.urls().map(|url| self.probe_url(url, options))).await;
+        rank_probes(&mut probes);
+
+        for probe in &probes {
+            tracing::debug!(
+                "CDN 测速 {}: 延迟 {:?}, 吞吐量 {:?}, 错误 {:?}",
+                probe.host,
+                probe.latency,
+                probe.throughput,
+                probe.error
+            );
+        }
+
+        probes
+    }
+
+    async fn probe_url(&self, url: &str, options: CdnProbeOptions) -> CdnProbe {
+        let start = Instant::now();
+        let resp = self
+            .get(url)
+            .with_bilibili_headers()
+            .header("Range", format!("bytes=0-{}", options.probe_bytes.saturating_sub(1)))
+            .timeout(options.timeout)
+            .send().await;
+
+        let resp = match resp {
+            Ok(resp) if resp.status().is_success() => resp,
+            Ok(resp) => {
+                return CdnProbe::failed(url, format!("HTTP {}", resp.status().as_u16()));
+            }
+            Err(e) => {
+                return CdnProbe::failed(url, e);
+            }
+        };
+        let latency = start.elapsed();
+
+        match resp.bytes().await {
+            Ok(body) => {
+                let elapsed = start.elapsed().as_secs_f64().max(f64::EPSILON);
+                CdnProbe {
+                    url: url.to_string(),
+                    host: host_of(url),
+                    latency: Some(latency),
+                    throughput: Some((body.len() as f64) / elapsed),
+                    error: None,
+                }
+            }
+            Err(e) => CdnProbe::failed(url, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe(url: &str, throughput: Option<f64>) -> CdnProbe {
+        CdnProbe {
+            url: url.to_string(),
+            host: host_of(url),
+            latency: None,
+            throughput,
+            error: throughput.is_none().then(|| "timeout".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_rank_probes() {
+        let mut probes = vec![
+            probe("https://a.bilivideo.com/x.m4s", Some(10.0)),
+            probe("https://b.bilivideo.com/x.m4s", None),
+            probe("https://c.bilivideo.com/x.m4s", Some(30.0))
+        ];
+        rank_probes(&mut probes);
+
+        let hosts: Vec<_> = probes.iter().map(|p| p.host.as_str()).collect();
+        assert_eq!(hosts, vec!["c.bilivideo.com", "a.bilivideo.com", "b.bilivideo.com"]);
+        assert!(!probes[2].is_ok());
+    }
+}
@@ -0,0 +1,281 @@
+//! 下载断点清单
+//!
+//! 分段下载时把每条流的分段进度、字节偏移、所选清晰度/编码以及 ETag 写入输出文件旁的
+//! `<输出文件>.bpi-dl.json`，中断后据此从断点继续；清单为普通 JSON，也方便人工检查。
+//!
+//! ETag 或文件大小变化说明 CDN 上的文件已更换，此时对应流的进度会被清空重新下载。
+
+use std::path::{ Path, PathBuf };
+
+use serde::{ Deserialize, Serialize };
+
+use crate::BpiError;
+use crate::models::DashTrack;
+
+/// 清单文件后缀
+pub const MANIFEST_SUFFIX: &str = ".bpi-dl.json";
+
+/// 当前清单格式版本
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// 流类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackKind {
+    Video,
+    Audio,
+}
+
+/// 分段进度
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentState {
+    /// 起始字节偏移
+    pub start: u64,
+    /// 结束字节偏移（含）
+    pub end: u64,
+    /// 已下载字节数
+    pub downloaded: u64,
+}
+
+impl SegmentState {
+    /// 分段总字节数
+    pub fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.downloaded >= self.len()
+    }
+
+    /// 剩余未下载的字节范围，可直接用于 `Range: bytes=start-end`
+    pub fn remaining_range(&self) -> Option<(u64, u64)> {
+        (!self.is_complete()).then(|| (self.start + self.downloaded, self.end))
+    }
+}
+
+/// 单条流的下载状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestTrack {
+    pub kind: TrackKind,
+    /// 清晰度/音质代码
+    pub id: u32,
+    /// 编码
+    pub codecs: String,
+    /// 下载地址
+    pub url: String,
+    /// 文件总大小
+    pub size: u64,
+    /// 服务器返回的 ETag
+    #[serde(default)]
+    pub etag: Option<String>,
+    /// 输出文件
+    pub output: PathBuf,
+    pub segments: Vec<SegmentState>,
+}
+
+impl ManifestTrack {
+    /// 按分段大小切分新建流状态
+    pub fn new(
+        kind: TrackKind,
+        track: &DashTrack,
+        size: u64,
+        output: impl Into<PathBuf>,
+        segment_size: u64
+    ) -> Self {
+        let segment_size = segment_size.max(1);
+        let segments = (0..size)
+            .step_by(segment_size as usize)
+            .map(|start| SegmentState {
+                start,
+                end: (start + segment_size).min(size) - 1,
+                downloaded: 0,
+            })
+            .collect();
+
+        Self {
+            kind,
+            id: track.id,
+            codecs: track.codecs.clone(),
+            url: track.base_url.clone(),
+            size,
+            etag: None,
+            output: output.into(),
+            segments,
+        }
+    }
+
+    /// 已下载字节数
+    pub fn downloaded(&self) -> u64 {
+        self.segments
+            .iter()
+            .map(|s| s.downloaded.min(s.len()))
+            .sum()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.segments.iter().all(SegmentState::is_complete)
+    }
+
+    /// 校验远端文件是否变化，变化时清空进度并返回 `false`
+    pub fn validate_remote(&mut self, etag: Option<&str>, size: u64) -> bool {
+        let etag_changed = matches!((self.etag.as_deref(), etag), (Some(old), Some(new)) if old != new);
+        if etag_changed || size != self.size {
+            tracing::warn!("{} 远端文件已变化，重新下载", self.output.display());
+            self.size = size;
+            self.segments.iter_mut().for_each(|s| s.downloaded = 0);
+            self.etag = etag.map(str::to_string);
+            return false;
+        }
+
+        if self.etag.is_none() {
+            self.etag = etag.map(str::to_string);
+        }
+        true
+    }
+}
+
+/// 下载断点清单
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadManifest {
+    /// 清单格式版本
+    pub version: u32,
+    /// 下载来源标识，如 BV 号、ep 号
+    pub source: String,
+    pub tracks: Vec<ManifestTrack>,
+    /// 最后更新的秒级时间戳
+    pub updated_at: i64,
+}
+
+impl DownloadManifest {
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            version: MANIFEST_VERSION,
+            source: source.into(),
+            tracks: Vec::new(),
+            updated_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    /// 输出文件对应的清单路径
+    pub fn path_for(output: impl AsRef<Path>) -> PathBuf {
+        let mut path = output.as_ref().as_os_str().to_owned();
+        path.push(MANIFEST_SUFFIX);
+        PathBuf::from(path)
+    }
+
+    /// 读取清单，文件不存在时返回 `Ok(None)`
+    pub fn load(path: impl AsRef<Path>) -> Result<Option<Self>, BpiError> {
+        let path = path.as_ref();
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(None);
+            }
+            Err(e) => {
+                return Err(BpiError::parse(format!("读取下载清单失败: {}", e)));
+            }
+        };
+
+        let manifest: Self = serde_json::from_str(&content)
+            .map_err(|e| BpiError::parse(format!("下载清单格式错误: {}", e)))?;
+
+        if manifest.version > MANIFEST_VERSION {
+            return Err(BpiError::parse(format!("不支持的下载清单版本: {}", manifest.version)));
+        }
+
+        Ok(Some(manifest))
+    }
+
+    /// 保存清单，先写临时文件再重命名，避免中断时留下损坏的清单
+    pub fn save(&mut self, path: impl AsRef<Path>) -> Result<(), BpiError> {
+        let path = path.as_ref();
+        self.updated_at = chrono::Utc::now().timestamp();
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| BpiError::parse(format!("序列化下载清单失败: {}", e)))?;
+
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        std::fs::write(&tmp, content)
+            .and_then(|_| std::fs::rename(&tmp, path))
+            .map_err(|e| BpiError::parse(format!("写入下载清单失败: {}", e)))
+    }
+
+    /// 全部下载完成后删除清单
+    pub fn remove(path: impl AsRef<Path>) -> Result<(), BpiError> {
+        match std::fs::remove_file(path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(BpiError::parse(format!("删除下载清单失败: {}", e))),
+        }
+    }
+
+    pub fn track(&self, kind: TrackKind) -> Option<&ManifestTrack> {
+        self.tracks.iter().find(|t| t.kind == kind)
+    }
+
+    pub fn track_mut(&mut self, kind: TrackKind) -> Option<&mut ManifestTrack> {
+        self.tracks.iter_mut().find(|t| t.kind == kind)
+    }
+
+    /// 记录分段进度
+    pub fn record(&mut self, kind: TrackKind, segment: usize, downloaded: u64) {
+        if let Some(seg) = self.track_mut(kind).and_then(|t| t.segments.get_mut(segment)) {
+            seg.downloaded = downloaded.min(seg.len());
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.tracks.iter().all(ManifestTrack::is_complete)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dash_track() -> DashTrack {
+        serde_json::from_value(serde_json::json!({
+            "id": 80, "base_url": "https://upos.bilivideo.com/v.m4s", "backup_url": [],
+            "bandwidth": 0, "mime_type": "video/mp4", "codecs": "avc1.640032",
+            "width": 1920, "height": 1080, "frame_rate": "30", "sar": "1:1",
+            "start_with_sap": 1,
+            "segment_base": { "initialization": "0-1000", "index_range": "1001-2000" },
+            "codecid": 7, "size": 0, "md5": null
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_manifest_resume() {
+        let dir = std::env::temp_dir().join(format!("bpi-dl-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = DownloadManifest::path_for(dir.join("video.mp4"));
+        assert!(path.to_string_lossy().ends_with("video.mp4.bpi-dl.json"));
+
+        let mut manifest = DownloadManifest::new("BV1xx411c7mD");
+        manifest.tracks.push(ManifestTrack::new(TrackKind::Video, &dash_track(), 25, dir.join("video.m4s"), 10));
+        manifest.record(TrackKind::Video, 0, 10);
+        manifest.record(TrackKind::Video, 1, 4);
+        manifest.save(&path).unwrap();
+
+        let mut loaded = DownloadManifest::load(&path).unwrap().unwrap();
+        let track = loaded.track(TrackKind::Video).unwrap();
+        assert_eq!(track.segments.len(), 3);
+        assert_eq!(track.downloaded(), 14);
+        assert_eq!(track.segments[1].remaining_range(), Some((14, 19)));
+        assert!(track.segments[0].remaining_range().is_none());
+
+        let track = loaded.track_mut(TrackKind::Video).unwrap();
+        assert!(track.validate_remote(Some("\"abc\""), 25));
+        assert!(!track.validate_remote(Some("\"def\""), 25));
+        assert_eq!(track.downloaded(), 0);
+
+        DownloadManifest::remove(&path).unwrap();
+        assert!(DownloadManifest::load(&path).unwrap().is_none());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,2 +1,9 @@
 pub mod aid_bvid;
+pub mod app_sign;
+pub mod cdn;
+pub mod download;
+pub mod naming;
+pub mod query;
+pub mod time;
 pub mod wbi;
+pub mod web_location;
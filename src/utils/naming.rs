@@ -0,0 +1,366 @@
+//! 下载文件命名模板
+//!
+//! 批量下载番剧或合集时，用模板统一生成文件名，例如 `{title} S{season:02}E{ep:02} [{quality}]`。
+//! 字段写作 `{name}`，数字字段可用 `{name:02}` 补零到指定宽度，`{{` 与 `}}` 表示字面的花括号。
+//! 渲染结果会经过 [`sanitize_filename`] 处理，字段中的 `/`、`:` 等字符不会产生意外的路径。
+//!
+//! ```
+//! use bpi_rs::utils::naming::{ NameTemplate, NameVars };
+//!
+//! let template = NameTemplate::parse("{title} S{season:02}E{ep:02} [{quality}]").unwrap();
+//! let vars = NameVars::new()
+//!     .set("title", "轻音少女")
+//!     .set("season", 1)
+//!     .set("ep", 3)
+//!     .set("quality", "1080P");
+//! assert_eq!(template.render(&vars), "轻音少女 S01E03 [1080P]");
+//! ```
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::BpiError;
+
+/// 文件名的最大字节数，留出余量给扩展名与临时文件后缀
+pub const MAX_FILENAME_BYTES: usize = 200;
+
+/// Windows 下的保留文件名
+const RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL",
+    "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9",
+    "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// 模板字段的值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameValue {
+    Text(String),
+    Number(i64),
+}
+
+impl From<&str> for NameValue {
+    fn from(value: &str) -> Self {
+        NameValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for NameValue {
+    fn from(value: String) -> Self {
+        NameValue::Text(value)
+    }
+}
+
+macro_rules! impl_number_value {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for NameValue {
+                fn from(value: $ty) -> Self {
+                    NameValue::Number(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_value!(i32, i64, u32, u64, usize);
+
+/// 模板字段的取值
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NameVars {
+    values: BTreeMap<String, NameValue>,
+}
+
+impl NameVars {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置字段，已有的同名字段会被覆盖
+    pub fn set(mut self, name: impl Into<String>, value: impl Into<NameValue>) -> Self {
+        self.values.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&NameValue> {
+        self.values.get(name)
+    }
+
+    /// 番剧分集的字段
+    ///
+    /// | 字段 | 说明 |
+    /// | ---- | ---- |
+    /// | `title` | 番剧标题 |
+    /// | `season_title` | 季度标题 |
+    /// | `season` | 第几季，按 `seasons` 中的位置，从 1 开始 |
+    /// | `ep` | 第几话，正片标题不是数字时按位置，从 1 开始 |
+    /// | `ep_title` | 分集标题 |
+    /// | `ep_id` / `bvid` / `cid` | 分集 id |
+    ///
+    /// `index` 超出分集列表时返回 `None`。
+    #[cfg(feature = "bangumi")]
+    pub fn bangumi_episode(
+        detail: &crate::bangumi::info::BangumiDetailResult,
+        index: usize
+    ) -> Option<Self> {
+        let episode = detail.episodes.get(index)?;
+        let season = detail.seasons
+            .iter()
+            .position(|s| s.season_id == detail.season_id)
+            .map_or(1, |i| i + 1);
+        let ep = episode.title.trim().parse::<i64>().unwrap_or(index as i64 + 1);
+
+        Some(
+            Self::new()
+                .set("title", detail.title.as_str())
+                .set("season_title", detail.season_title.as_str())
+                .set("season", season)
+                .set("ep", ep)
+                .set("ep_title", episode.long_title.as_str())
+                .set("ep_id", episode.ep_id)
+                .set("bvid", episode.bvid.as_str())
+                .set("cid", episode.cid)
+        )
+    }
+
+    /// 合集分集的字段
+    ///
+    /// | 字段 | 说明 |
+    /// | ---- | ---- |
+    /// | `title` | 合集标题 |
+    /// | `season_title` | 小节标题 |
+    /// | `season` | 第几个小节，从 1 开始 |
+    /// | `ep` | 小节内第几集，从 1 开始 |
+    /// | `ep_title` | 视频标题 |
+    /// | `author` | UP 主昵称 |
+    /// | `bvid` / `aid` / `cid` | 视频 id |
+    ///
+    /// `section` 或 `episode` 超出范围时返回 `None`。
+    #[cfg(feature = "video")]
+    pub fn ugc_season_episode(
+        season: &crate::video::info::view::UgcSeason,
+        section: usize,
+        episode: usize
+    ) -> Option<Self> {
+        let sec = season.sections.get(section)?;
+        let ep = sec.episodes.get(episode)?;
+
+        Some(
+            Self::new()
+                .set("title", season.title.as_str())
+                .set("season_title", sec.title.as_str())
+                .set("season", section + 1)
+                .set("ep", episode + 1)
+                .set("ep_title", ep.title.as_str())
+                .set("author", ep.arc.author.name.as_str())
+                .set("bvid", ep.bvid.as_str())
+                .set("aid", ep.aid)
+                .set("cid", ep.cid)
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Field {
+        name: String,
+        width: usize,
+        zero_pad: bool,
+    },
+}
+
+/// 文件命名模板
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameTemplate {
+    parts: Vec<Part>,
+}
+
+impl NameTemplate {
+    /// 解析模板
+    ///
+    /// 花括号不配对、字段名为空或宽度不是数字时返回 [`BpiError::InvalidParameter`]。
+    pub fn parse(template: &str) -> Result<Self, BpiError> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut field = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => {
+                                break;
+                            }
+                            Some('{') | None => {
+                                return Err(BpiError::invalid_parameter("template", "模板中的 { 未闭合"));
+                            }
+                            Some(c) => field.push(c),
+                        }
+                    }
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(std::mem::take(&mut literal)));
+                    }
+                    parts.push(Self::parse_field(&field)?);
+                }
+                '}' => {
+                    return Err(BpiError::invalid_parameter("template", "模板中的 } 未配对"));
+                }
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+
+        Ok(Self { parts })
+    }
+
+    fn parse_field(field: &str) -> Result<Part, BpiError> {
+        let (name, spec) = field.split_once(':').unwrap_or((field, ""));
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(BpiError::invalid_parameter("template", "模板字段名不能为空"));
+        }
+        let width = if spec.is_empty() {
+            0
+        } else {
+            spec
+                .parse::<usize>()
+                .map_err(|_| BpiError::invalid_parameter("template", "模板字段宽度必须是数字"))?
+        };
+
+        Ok(Part::Field {
+            name: name.to_string(),
+            width,
+            zero_pad: spec.starts_with('0'),
+        })
+    }
+
+    /// 模板中用到的字段名
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(|part| {
+            match part {
+                Part::Field { name, .. } => Some(name.as_str()),
+                Part::Literal(_) => None,
+            }
+        })
+    }
+
+    /// 渲染为文件名，缺少的字段渲染为空
+    pub fn render(&self, vars: &NameVars) -> String {
+        let mut name = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Literal(text) => name.push_str(text),
+                Part::Field { name: field, width, zero_pad } => {
+                    match vars.get(field) {
+                        Some(NameValue::Number(n)) if *zero_pad => {
+                            name.push_str(&format!("{:0width$}", n, width = *width));
+                        }
+                        Some(NameValue::Number(n)) => {
+                            name.push_str(&format!("{:width$}", n, width = *width));
+                        }
+                        Some(NameValue::Text(text)) => {
+                            name.push_str(&format!("{:width$}", text, width = *width));
+                        }
+                        None => {}
+                    }
+                }
+            }
+        }
+        sanitize_filename(&name)
+    }
+}
+
+impl FromStr for NameTemplate {
+    type Err = BpiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// 将字符串处理为各平台都可用的文件名
+///
+/// - `<>:"/\|?*` 与控制字符替换为 `_`
+/// - 连续空白合并为一个空格，去掉首尾空白与末尾的 `.`
+/// - Windows 保留名（如 `CON`、`NUL`）后追加 `_`
+/// - 超过 [`MAX_FILENAME_BYTES`] 字节时按字符截断
+/// - 结果为空时返回 `_`
+pub fn sanitize_filename(name: &str) -> String {
+    let replaced: String = name
+        .chars()
+        .map(|c| {
+            if c.is_control() || matches!(c, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*') {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    let mut name = replaced.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if name.len() > MAX_FILENAME_BYTES {
+        let mut end = MAX_FILENAME_BYTES;
+        while !name.is_char_boundary(end) {
+            end -= 1;
+        }
+        name.truncate(end);
+    }
+
+    let mut name = name.trim_end_matches(['.', ' ']).to_string();
+
+    let stem = name.split('.').next().unwrap_or_default();
+    if RESERVED_NAMES.iter().any(|r| r.eq_ignore_ascii_case(stem)) {
+        name.insert(stem.len(), '_');
+    }
+
+    if name.is_empty() {
+        name.push('_');
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let template: NameTemplate = "{title} - {{{ep:03}}} {ep_title:4}|{missing}".parse().unwrap();
+        assert_eq!(template.fields().collect::<Vec<_>>(), ["title", "ep", "ep_title", "missing"]);
+
+        let vars = NameVars::new().set("title", "A/B: C?").set("ep", 7).set("ep_title", "x");
+        assert_eq!(template.render(&vars), "A_B_ C_ - {007} x _");
+
+        assert!(NameTemplate::parse("{title").is_err());
+        assert!(NameTemplate::parse("title}").is_err());
+        assert!(NameTemplate::parse("{}").is_err());
+        assert!(NameTemplate::parse("{ep:x}").is_err());
+    }
+
+    #[test]
+    fn test_sanitize_filename() {
+        assert_eq!(sanitize_filename("  a   b\tc  "), "a b_c");
+        assert_eq!(sanitize_filename("title..."), "title");
+        assert_eq!(sanitize_filename("con.mp4"), "con_.mp4");
+        assert_eq!(sanitize_filename("???"), "___");
+        assert_eq!(sanitize_filename(""), "_");
+
+        let long = "番".repeat(100);
+        let name = sanitize_filename(&long);
+        assert!(name.len() <= MAX_FILENAME_BYTES);
+        assert!(name.chars().all(|c| c == '番'));
+    }
+}
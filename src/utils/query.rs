@@ -0,0 +1,98 @@
+//! 请求参数构建
+//!
+//! 可选参数用 `unwrap_or("")` 拼进参数列表时会发出 `offset=` 这样的空参数，
+//! 部分 WBI 接口会因此校验失败。[`Query`] 构建参数时直接跳过 `None` 与空字符串，
+//! 可传给 `RequestBuilder::query` 或 `RequestBuilder::form`。
+//!
+//! ```
+//! use bpi_rs::utils::query::Query;
+//!
+//! let offset: Option<&str> = None;
+//! let query = Query::new().with("host_mid", 2).with_opt("offset", offset).with_opt("page", Some(1));
+//! assert_eq!(query.pairs(), [("host_mid", "2".to_string()), ("page", "1".to_string())]);
+//! ```
+
+use serde::ser::{ Serialize, SerializeSeq, Serializer };
+
+/// 请求参数列表，忽略缺省值
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    pairs: Vec<(&'static str, String)>,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加参数，值为空字符串时跳过
+    pub fn with(mut self, key: &'static str, value: impl ToString) -> Self {
+        self.push(key, value);
+        self
+    }
+
+    /// 添加可选参数，`None` 或空字符串时跳过
+    pub fn with_opt<T: ToString>(mut self, key: &'static str, value: Option<T>) -> Self {
+        if let Some(value) = value {
+            self.push(key, value);
+        }
+        self
+    }
+
+    /// 添加参数，值为空字符串时跳过
+    pub fn push(&mut self, key: &'static str, value: impl ToString) {
+        let value = value.to_string();
+        if !value.is_empty() {
+            self.pairs.push((key, value));
+        }
+    }
+
+    /// 已添加的参数
+    pub fn pairs(&self) -> &[(&'static str, String)] {
+        &self.pairs
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+}
+
+impl Serialize for Query {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.pairs.len()))?;
+        for pair in &self.pairs {
+            seq.serialize_element(pair)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_skips_absent() {
+        let bvid: Option<String> = None;
+        let query = Query::new()
+            .with("aid", 10001)
+            .with_opt("bvid", bvid)
+            .with_opt("offset", Some(""))
+            .with("type", "all");
+        assert_eq!(query.pairs(), [("aid", "10001".to_string()), ("type", "all".to_string())]);
+
+        let url = reqwest::Url::parse_with_params("https://api.bilibili.com/x", query.pairs()).unwrap();
+        assert_eq!(url.query(), Some("aid=10001&type=all"));
+    }
+
+    #[test]
+    fn test_query_as_request_query() {
+        let client = reqwest::Client::new();
+        let request = client
+            .get("https://api.bilibili.com/x")
+            .query(&Query::new().with("keyword", "a b").with_opt("page", None::<u32>))
+            .build()
+            .unwrap();
+        assert_eq!(request.url().query(), Some("keyword=a+b"));
+    }
+}
@@ -0,0 +1,50 @@
+use chrono::{ DateTime, FixedOffset };
+
+/// 默认时区偏移，与 B站 web 端一致（UTC+8）
+pub const DEFAULT_TIMEZONE_OFFSET: i32 = -480;
+
+/// 将秒级时间戳转换为带时区的时间
+///
+/// `timezone_offset` 与接口参数含义一致，即 JS `Date.getTimezoneOffset()` 的返回值（分钟），
+/// 例如 UTC+8 为 `-480`。
+pub fn timestamp_to_datetime(ts: i64, timezone_offset: i32) -> Option<DateTime<FixedOffset>> {
+    let offset = FixedOffset::west_opt(timezone_offset.checked_mul(60)?)?;
+    DateTime::from_timestamp(ts, 0).map(|dt| dt.with_timezone(&offset))
+}
+
+/// 从动态/图文的 `modules` 中读取作者模块的发布时间戳
+///
+/// 动态接口的 `modules` 为对象，图文接口为 `[{ module_type, module_author, .. }]` 数组，两种形式均支持。
+pub(crate) fn modules_pub_ts(modules: &serde_json::Value) -> Option<i64> {
+    let author = match modules {
+        serde_json::Value::Array(list) => list.iter().find_map(|m| m.get("module_author"))?,
+        other => other.get("module_author")?,
+    };
+    author.get("pub_ts").and_then(|ts| ts.as_i64().or_else(|| ts.as_str()?.parse().ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_to_datetime() {
+        let dt = timestamp_to_datetime(1_700_000_000, DEFAULT_TIMEZONE_OFFSET).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-11-15T06:13:20+08:00");
+
+        let dt = timestamp_to_datetime(1_700_000_000, 0).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-11-14T22:13:20+00:00");
+    }
+
+    #[test]
+    fn test_modules_pub_ts() {
+        let dynamic = serde_json::json!({ "module_author": { "pub_ts": 1700000000 } });
+        assert_eq!(modules_pub_ts(&dynamic), Some(1_700_000_000));
+
+        let opus = serde_json::json!([
+            { "module_type": "MODULE_TYPE_TITLE" },
+            { "module_type": "MODULE_TYPE_AUTHOR", "module_author": { "pub_ts": "1700000000" } }
+        ]);
+        assert_eq!(modules_pub_ts(&opus), Some(1_700_000_000));
+    }
+}
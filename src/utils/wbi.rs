@@ -1,7 +1,7 @@
 use serde::{ Deserialize, Serialize };
-use std::collections::{ BTreeMap, HashMap };
-use std::sync::{ RwLock, LazyLock };
-use chrono::Local;
+use std::collections::BTreeMap;
+use std::sync::{ Arc, LazyLock, RwLock };
+use std::time::{ Duration, Instant };
 
 use crate::models::WbiData;
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
@@ -13,9 +13,129 @@ const MIXIN_KEY_TAB: [usize; 64] = [
     56, 59, 6, 63, 57, 62, 11, 36, 20, 34, 44, 52,
 ];
 
-pub static WBI_KEY_MAP: LazyLock<RwLock<HashMap<String, String>>> = LazyLock::new(||
-    RwLock::new(HashMap::new())
-);
+/// 缓存的 WBI 密钥有效期
+const WBI_KEYS_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// 当前缓存的 WBI 密钥，签名时只克隆其中的 `Arc`，刷新时才加写锁
+static WBI_KEYS: LazyLock<RwLock<Option<Arc<WbiKeys>>>> = LazyLock::new(|| RwLock::new(None));
+
+/// 刷新密钥时持有，避免并发请求同时访问 nav 接口
+static WBI_REFRESH: LazyLock<tokio::sync::Mutex<()>> = LazyLock::new(|| tokio::sync::Mutex::new(()));
+
+/// WBI 密钥
+#[derive(Debug, Clone)]
+pub struct WbiKeys {
+    pub img_key: String,
+    pub sub_key: String,
+    /// 由两个密钥预先算好的混合密钥
+    mixin_key: String,
+    fetched_at: Instant,
+}
+
+impl WbiKeys {
+    pub fn new(img_key: impl Into<String>, sub_key: impl Into<String>) -> Self {
+        let img_key = img_key.into();
+        let sub_key = sub_key.into();
+        let mixin_key = get_mixin_key(&format!("{}{}", img_key, sub_key));
+        Self {
+            img_key,
+            sub_key,
+            mixin_key,
+            fetched_at: Instant::now(),
+        }
+    }
+
+    /// 从 nav 接口返回的图片地址中取出密钥
+    fn from_nav(data: &NavData) -> Self {
+        Self::new(key_from_url(&data.wbi_img.img_url), key_from_url(&data.wbi_img.sub_url))
+    }
+
+    /// 是否已超过缓存有效期
+    pub fn is_expired(&self) -> bool {
+        self.fetched_at.elapsed() >= WBI_KEYS_TTL
+    }
+
+    /// 为参数添加 `wts` 与 `w_rid`
+    pub fn sign(&self, params: &mut BTreeMap<String, String>) {
+        enc_wbi(params, &self.mixin_key);
+    }
+}
+
+/// 当前缓存且未过期的 WBI 密钥
+pub fn cached_wbi_keys() -> Option<Arc<WbiKeys>> {
+    WBI_KEYS.read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .filter(|keys| !keys.is_expired())
+        .cloned()
+}
+
+/// 写入 WBI 密钥缓存，可用于预置从其他途径获取的密钥
+pub fn store_wbi_keys(keys: WbiKeys) -> Arc<WbiKeys> {
+    let keys = Arc::new(keys);
+    *WBI_KEYS.write().unwrap_or_else(|e| e.into_inner()) = Some(keys.clone());
+    keys
+}
+
+/// 提供 WBI 密钥的 nav 接口
+pub(crate) const NAV_URL: &str = "https://api.bilibili.com/x/web-interface/nav";
+
+/// 获取 WBI 密钥，缓存过期时用 `nav` 请求刷新
+///
+/// 未登录时 nav 接口返回 -101，但仍会下发密钥，因此不检查业务状态码。
+pub(crate) async fn wbi_keys_via(nav: reqwest::RequestBuilder) -> Result<Arc<WbiKeys>, BpiError> {
+    if let Some(keys) = cached_wbi_keys() {
+        return Ok(keys);
+    }
+
+    let _guard = WBI_REFRESH.lock().await;
+    // 等待期间可能已被其他任务刷新
+    if let Some(keys) = cached_wbi_keys() {
+        return Ok(keys);
+    }
+
+    let bytes = nav.send_request("获取 wbi 签名").await?;
+    let resp: BpiResponse<NavData> = serde_json::from_slice(&bytes)?;
+    let data = resp.data.ok_or_else(|| BpiError::parse("获取 wbi 签名失败"))?;
+
+    Ok(store_wbi_keys(WbiKeys::from_nav(&data)))
+}
+
+/// `dm_img_str`：WebGL 版本 `WebGL 1.0 (OpenGL ES 2.0 Chromium)` 的 Base64 去掉末尾两位
+const DM_IMG_STR: &str = "V2ViR0wgMS4wIChPcGVuR0wgRVMgMi4wIENocm9taXVtKQ";
+
+/// `dm_cover_img_str`：WebGL 渲染器信息的 Base64 去掉末尾两位
+const DM_COVER_IMG_STR: &str =
+    "QU5HTEUgKEludGVsLCBJbnRlbChSKSBVSEQgR3JhcGhpY3MgNjIwICgweDAwMDAzRUEwKSBEaXJlY3QzRDExIHZzXzVfMCBwc181XzAsIEQzRDExKUdvb2dsZSBJbmMuIChJbnRlbC";
+
+/// 网页端上报的浏览器指纹参数
+///
+/// 个人空间的 WBI 接口（`x/space/wbi/*`）缺少这些参数时返回 -352 风控校验失败，需与其他查询参数一起签名。
+pub(crate) fn dm_img_params() -> [(&'static str, String); 4] {
+    [
+        ("dm_img_list", "[]".to_string()),
+        ("dm_img_str", DM_IMG_STR.to_string()),
+        ("dm_cover_img_str", DM_COVER_IMG_STR.to_string()),
+        ("dm_img_inter", r#"{"ds":[],"wh":[0,0,0],"of":[0,0,0]}"#.to_string()),
+    ]
+}
+
+/// 为请求地址中的查询参数签名，已有的 `wts` 与 `w_rid` 会被替换
+pub(crate) fn sign_url(url: &mut reqwest::Url, keys: &WbiKeys) {
+    let mut params: BTreeMap<String, String> = url
+        .query_pairs()
+        .filter(|(k, _)| k != "wts" && k != "w_rid")
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+
+    keys.sign(&mut params);
+    url.query_pairs_mut().clear().extend_pairs(&params);
+}
+
+fn key_from_url(url: &str) -> &str {
+    let name = url.rsplit('/').next().unwrap_or(url);
+    name.split('.').next().unwrap_or(name)
+}
 
 fn get_mixin_key(orig: &str) -> String {
     let bytes = orig.as_bytes();
@@ -28,7 +148,7 @@ fn get_mixin_key(orig: &str) -> String {
     s.into_iter().take(32).collect()
 }
 
-fn url_encode(s: &str) -> String {
+pub(crate) fn url_encode(s: &str) -> String {
     let mut result = String::new();
     for byte in s.bytes() {
         match byte {
@@ -45,8 +165,7 @@ fn url_encode(s: &str) -> String {
     result
 }
 
-fn enc_wbi(params: &mut BTreeMap<String, String>, img_key: &str, sub_key: &str) {
-    let mixin_key = get_mixin_key(&(img_key.to_owned() + sub_key));
+fn enc_wbi(params: &mut BTreeMap<String, String>, mixin_key: &str) {
     let wts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
     params.insert("wts".to_string(), wts.to_string());
 
@@ -86,16 +205,13 @@ impl BpiClient {
         let mut params = BTreeMap::new();
 
         let resp: BpiResponse<NavData> = self
-            .get("https://api.bilibili.com/x/web-interface/nav")
+            .get(NAV_URL)
             .with_bilibili_headers()
             .send_bpi("获取 wbi 签名").await?;
 
         let data = resp.data.ok_or_else(|| BpiError::parse("获取 wbi 签名失败"))?;
 
-        let img_key = data.wbi_img.img_url.rsplit('/').next().unwrap().split('.').next().unwrap();
-        let sub_key = data.wbi_img.sub_url.rsplit('/').next().unwrap().split('.').next().unwrap();
-
-        enc_wbi(&mut params, img_key, sub_key);
+        store_wbi_keys(WbiKeys::from_nav(&data)).sign(&mut params);
 
         Ok(WbiData {
             wts: params
@@ -110,56 +226,15 @@ impl BpiClient {
         })
     }
 
+    /// 获取 WBI 密钥，缓存未过期时直接返回缓存
+    pub async fn wbi_keys(&self) -> Result<Arc<WbiKeys>, BpiError> {
+        wbi_keys_via(self.get(NAV_URL)).await
+    }
+
     pub async fn get_wbi_sign2<I, K, V>(&self, params: I) -> Result<Vec<(String, String)>, BpiError>
         where I: IntoIterator<Item = (K, V)>, K: ToString, V: ToString
     {
-        let now = Local::now();
-        let s = now.format("%Y-%m-%d %H").to_string();
-
-        let img_key_key = format!("{}img_key", s);
-        let sub_key_key = format!("{}sub_key", s);
-
-        // 先尝试从缓存读取
-        let (img_key, sub_key) = {
-            let map = WBI_KEY_MAP.read().unwrap();
-            if let (Some(img), Some(sub)) = (map.get(&img_key_key), map.get(&sub_key_key)) {
-                (img.clone(), sub.clone())
-            } else {
-                drop(map); // 释放读锁再去写
-
-                // 缓存没有 -> 请求 API
-                let resp: BpiResponse<NavData> = self
-                    .get("https://api.bilibili.com/x/web-interface/nav")
-                    .send_bpi("获取 wbi 签名").await?;
-
-                let data = resp.data.ok_or_else(|| BpiError::parse("获取 wbi 签名失败"))?;
-
-                let img = data.wbi_img.img_url
-                    .rsplit('/')
-                    .next()
-                    .unwrap()
-                    .split('.')
-                    .next()
-                    .unwrap()
-                    .to_string();
-
-                let sub = data.wbi_img.sub_url
-                    .rsplit('/')
-                    .next()
-                    .unwrap()
-                    .split('.')
-                    .next()
-                    .unwrap()
-                    .to_string();
-
-                // 插入缓存
-                let mut map = WBI_KEY_MAP.write().unwrap();
-                map.insert(img_key_key.clone(), img.clone());
-                map.insert(sub_key_key.clone(), sub.clone());
-
-                (img, sub)
-            }
-        };
+        let keys = self.wbi_keys().await?;
 
         // 构造参数
         let mut params: BTreeMap<String, String> = params
@@ -167,7 +242,7 @@ impl BpiClient {
             .map(|(k, v)| (k.to_string(), v.to_string()))
             .collect();
 
-        enc_wbi(&mut params, &img_key, &sub_key);
+        keys.sign(&mut params);
 
         Ok(params.into_iter().collect())
     }
@@ -177,9 +252,54 @@ impl BpiClient {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_wbi_keys_sign() {
+        // 文档示例密钥
+        let keys = WbiKeys::new("7cd084941338484aae1ad9425b84077c", "4932caff0ff746eab6f01bf08b70ac45");
+        assert_eq!(keys.mixin_key, "ea1db124af3c7062474693fa704f4ff8");
+        assert_eq!(key_from_url("https://i0.hdslb.com/bfs/wbi/7cd084941338484aae1ad9425b84077c.png"), keys.img_key);
+
+        let mut params = BTreeMap::from([("foo".to_string(), "one two".to_string())]);
+        keys.sign(&mut params);
+        assert!(params.contains_key("wts"));
+        assert_eq!(params["w_rid"].len(), 32);
+    }
+
+    #[test]
+    fn test_sign_url() {
+        let keys = WbiKeys::new("7cd084941338484aae1ad9425b84077c", "4932caff0ff746eab6f01bf08b70ac45");
+        let mut url = reqwest::Url
+            ::parse("https://api.bilibili.com/x/space/wbi/acc/info?mid=2&w_rid=old&wts=1")
+            .unwrap();
+        sign_url(&mut url, &keys);
+
+        let pairs: BTreeMap<String, String> = url.query_pairs().into_owned().collect();
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs["mid"], "2");
+        assert_ne!(pairs["w_rid"], "old");
+
+        let query = format!("mid=2&wts={}", pairs["wts"]);
+        let expected = format!("{:x}", md5::compute(format!("{}{}", query, keys.mixin_key)));
+        assert_eq!(pairs["w_rid"], expected);
+    }
+
+    #[test]
+    fn test_sign_url_dm_img_params() {
+        let keys = WbiKeys::new("7cd084941338484aae1ad9425b84077c", "4932caff0ff746eab6f01bf08b70ac45");
+        let mut url = reqwest::Url::parse("https://api.bilibili.com/x/space/wbi/acc/info").unwrap();
+        url.query_pairs_mut().append_pair("mid", "2").extend_pairs(dm_img_params());
+        sign_url(&mut url, &keys);
+
+        // 签名不改动参数值
+        let pairs: BTreeMap<String, String> = url.query_pairs().into_owned().collect();
+        assert_eq!(pairs["dm_img_inter"], r#"{"ds":[],"wh":[0,0,0],"of":[0,0,0]}"#);
+        assert_eq!(pairs["dm_img_str"], DM_IMG_STR);
+    }
+
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_wts_and_rid2() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let params = vec![
             ("bvid", "BV18x411c74j".to_string()),
@@ -190,7 +310,7 @@ mod tests {
 
         let wbi = bpi.get_wbi_sign2(params.clone()).await.unwrap();
         tracing::info!("{:?}", wbi);
-        tracing::info!("{:?}", WBI_KEY_MAP);
+        tracing::info!("{:?}", cached_wbi_keys());
 
         let wbi = bpi.get_wbi_sign2(params).await.unwrap();
         tracing::info!("{:?}", wbi);
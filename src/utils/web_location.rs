@@ -0,0 +1,60 @@
+//! 网页端埋点参数
+//!
+//! 部分接口（尤其是 WBI 接口）会检查 `web_location` 与 `spmid`/`from_spmid`，
+//! 缺失或与页面不符时可能返回降级数据。各页面的取值集中定义在 [`WebLocation`]，
+//! 页面改版后可通过 [`BpiClient::set_web_location`](crate::BpiClient::set_web_location) 覆盖，无需等待更新。
+
+/// 网页端页面的 `web_location`（spm 的前两段）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WebLocation(&'static str);
+
+impl WebLocation {
+    /// 首页，直播推荐等接口使用
+    pub const HOME: Self = Self("333.1007");
+    /// 视频播放页，发送弹幕等接口使用
+    pub const VIDEO: Self = Self("333.788");
+    /// 专栏，专栏卡片接口使用
+    pub const ARTICLE: Self = Self("333.1305");
+    /// 个人空间，收藏夹与空间图文接口使用
+    pub const SPACE: Self = Self("333.1387");
+    /// 动态首页，动态列表与公告栏接口使用
+    pub const DYNAMIC: Self = Self("333.1365");
+    /// 动态详情页，动态点赞的 `spmid`
+    pub const DYNAMIC_DETAIL: Self = Self("333.1369");
+    /// 动态点赞的 `from_spmid`
+    pub const DYNAMIC_SOURCE: Self = Self("333.999");
+    /// 账号中心，登录记录接口使用
+    pub const ACCOUNT: Self = Self("333.33");
+    /// 直播间，信息流认证接口使用
+    pub const LIVE_ROOM: Self = Self("444.8");
+
+    /// 默认取值
+    pub const fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+/// 由 `web_location` 补全为四段的 spm id，如 `333.788` → `333.788.0.0`
+pub(crate) fn spm_id(web_location: &str) -> String {
+    format!("{}.0.0", web_location)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BpiClient;
+
+    #[test]
+    fn test_web_location_override() {
+        let bpi = BpiClient::builder()
+            .web_location(WebLocation::SPACE, "333.999")
+            .build()
+            .unwrap();
+        assert_eq!(bpi.web_location(WebLocation::SPACE), "333.999");
+        assert_eq!(bpi.web_location(WebLocation::VIDEO), "333.788");
+        assert_eq!(bpi.spm_id(WebLocation::VIDEO), "333.788.0.0");
+
+        bpi.set_web_location(WebLocation::VIDEO, "333.1");
+        assert_eq!(bpi.spm_id(WebLocation::VIDEO), "333.1.0.0");
+    }
+}
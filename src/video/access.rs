@@ -0,0 +1,111 @@
+//! 充电专属视频的访问状态
+//!
+//! 充电专属视频未充电时只能试看或完全无法播放，取流接口不会直接报错，
+//! 下载工具容易拿到试看片段或空流。这里将视频详情、播放器信息与取流数据中的
+//! 充电相关标记统一为 [`UpowerState`]，便于在下载前跳过当前账号无法获取的内容。
+use super::info::view::VideoData;
+use super::player::PlayerInfoResponseData;
+use super::playurl::VideoPlayUrl;
+
+/// 充电专属状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpowerState {
+    /// 不是充电专属视频
+    Free,
+    /// 充电专属，当前账号已充电或为 UP 主本人，可完整观看
+    Unlocked,
+    /// 充电专属，当前账号只能试看
+    Preview,
+    /// 充电专属，当前账号无法观看
+    Locked,
+}
+
+impl UpowerState {
+    fn from_flags(exclusive: bool, play: bool, preview: bool) -> Self {
+        match (exclusive, play, preview) {
+            (false, _, _) => Self::Free,
+            (true, true, _) => Self::Unlocked,
+            (true, false, true) => Self::Preview,
+            (true, false, false) => Self::Locked,
+        }
+    }
+
+    /// 当前账号能否完整观看
+    pub fn is_accessible(self) -> bool {
+        matches!(self, Self::Free | Self::Unlocked)
+    }
+}
+
+impl VideoData {
+    /// 充电专属状态，结果取决于请求时登录的账号
+    pub fn upower_state(&self) -> UpowerState {
+        UpowerState::from_flags(
+            self.is_upower_exclusive,
+            self.is_upower_play || self.is_view_self,
+            self.is_upower_preview
+        )
+    }
+
+    /// 当前账号能否完整观看，充电专属未充电时为 `false`
+    pub fn is_accessible_with_current_account(&self) -> bool {
+        self.upower_state().is_accessible()
+    }
+}
+
+impl PlayerInfoResponseData {
+    /// 充电专属状态，结果取决于请求时登录的账号
+    ///
+    /// 播放器信息不区分试看，未充电时为 [`UpowerState::Locked`]。
+    pub fn upower_state(&self) -> UpowerState {
+        UpowerState::from_flags(self.is_upower_exclusive, self.is_upower_play, false)
+    }
+
+    /// 当前账号能否完整观看，充电专属未充电或付费视频仅可试看时为 `false`
+    pub fn is_accessible_with_current_account(&self) -> bool {
+        self.upower_state().is_accessible() && !self.is_ugc_pay_preview
+    }
+}
+
+impl VideoPlayUrl {
+    /// 返回的是否为试看片段而非完整视频
+    pub fn is_preview(&self) -> bool {
+        self.is_preview == Some(1)
+    }
+
+    /// 返回的流是否为完整视频
+    pub fn is_accessible_with_current_account(&self) -> bool {
+        !self.is_preview()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upower_state_from_flags() {
+        assert_eq!(UpowerState::from_flags(false, false, false), UpowerState::Free);
+        assert_eq!(UpowerState::from_flags(true, true, false), UpowerState::Unlocked);
+        assert_eq!(UpowerState::from_flags(true, false, true), UpowerState::Preview);
+        assert_eq!(UpowerState::from_flags(true, false, false), UpowerState::Locked);
+        assert!(UpowerState::Unlocked.is_accessible());
+        assert!(!UpowerState::Preview.is_accessible());
+    }
+
+    #[test]
+    fn test_playurl_preview() {
+        let mut data: VideoPlayUrl = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "quality": 32, "format": "flv480", "timelength": 30000, "accept_format": "flv480",
+                    "accept_description": ["清晰 480P"], "accept_quality": [32], "video_codecid": 7,
+                    "is_preview": 1
+                })
+            )
+            .unwrap();
+        assert!(!data.is_accessible_with_current_account());
+
+        data.is_preview = None;
+        assert!(data.is_accessible_with_current_account());
+    }
+}
@@ -5,6 +5,7 @@
 use std::collections::HashMap;
 
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+use crate::utils::query::Query;
 use serde::{ Deserialize, Serialize };
 
 /// 点赞视频 - 请求参数
@@ -53,6 +54,49 @@ pub struct FavoriteData {
 
 pub type FavoriteResponse = BpiResponse<FavoriteData>;
 
+/// 一键三连 - 响应结构体
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct TripleData {
+    /// 是否点赞成功
+    pub like: bool,
+    /// 是否投币成功
+    pub coin: bool,
+    /// 是否收藏成功
+    pub fav: bool,
+    /// 投币枚数
+    pub multiply: u8,
+    /// 是否为未关注用户操作
+    #[serde(default)]
+    pub prompt: bool,
+}
+
+/// 已投币数 - 响应结构体
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct CoinCountData {
+    /// 已投币枚数
+    pub multiply: u8,
+}
+
+/// 是否已收藏 - 响应结构体
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct FavouredData {
+    /// 作用不明确
+    pub count: u64,
+    /// 是否已收藏
+    pub favoured: bool,
+}
+
+/// 当前用户对视频的操作状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VideoActionState {
+    /// 是否已点赞
+    pub liked: bool,
+    /// 已投币枚数
+    pub coins: u8,
+    /// 是否已收藏
+    pub favoured: bool,
+}
+
 impl BpiClient {
     /// 点赞/取消点赞
     ///
@@ -77,12 +121,11 @@ impl BpiClient {
             .post("https://api.bilibili.com/x/web-interface/archive/like")
             .with_bilibili_headers()
             .form(
-                &[
-                    ("aid", aid.unwrap_or(0).to_string()),
-                    ("bvid", bvid.unwrap_or("".to_string())),
-                    ("like", like.to_string()),
-                    ("csrf", csrf),
-                ]
+                &Query::new()
+                    .with_opt("aid", aid)
+                    .with_opt("bvid", bvid)
+                    .with("like", like)
+                    .with("csrf", csrf)
             )
             .send_bpi("点赞").await?;
 
@@ -114,13 +157,12 @@ impl BpiClient {
             .post("https://api.bilibili.com/x/web-interface/coin/add")
             .with_bilibili_headers()
             .form(
-                &[
-                    ("aid", aid.unwrap_or(0).to_string()),
-                    ("bvid", bvid.unwrap_or("".to_string())),
-                    ("multiply", multiply.to_string()),
-                    ("select_like", select_like.unwrap_or(0).to_string()),
-                    ("csrf", csrf),
-                ]
+                &Query::new()
+                    .with_opt("aid", aid)
+                    .with_opt("bvid", bvid)
+                    .with("multiply", multiply)
+                    .with("select_like", select_like.unwrap_or(0))
+                    .with("csrf", csrf)
             )
             .send_bpi("投币").await
     }
@@ -182,6 +224,119 @@ impl BpiClient {
             .form(&params)
             .send_bpi("收藏视频").await
     }
+
+    /// 一键三连
+    ///
+    /// 点赞、投 2 枚硬币并收藏到默认收藏夹，已完成的操作会跳过。
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/video/action.md)
+    ///
+    /// # 参数
+    /// | 名称   | 类型             | 说明            |
+    /// | ------ | ---------------- | --------------- |
+    /// | `aid`  | `Option<u64>`    | 稿件 avid，可选 |
+    /// | `bvid` | `Option<String>` | 稿件 bvid，可选 |
+    pub async fn video_triple(
+        &self,
+        aid: Option<u64>,
+        bvid: Option<String>
+    ) -> Result<BpiResponse<TripleData>, BpiError> {
+        let csrf = self.csrf()?;
+
+        self
+            .post("https://api.bilibili.com/x/web-interface/archive/like/triple")
+            .with_bilibili_headers()
+            .form(&Query::new().with_opt("aid", aid).with_opt("bvid", bvid).with("csrf", csrf))
+            .send_bpi("一键三连").await
+    }
+
+    /// 查询是否已点赞，`data` 为 1 表示已点赞
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/video/action.md)
+    ///
+    /// # 参数
+    /// | 名称   | 类型           | 说明            |
+    /// | ------ | -------------- | --------------- |
+    /// | `aid`  | `Option<u64>`  | 稿件 avid，可选 |
+    /// | `bvid` | `Option<&str>` | 稿件 bvid，可选 |
+    pub async fn video_has_liked(
+        &self,
+        aid: Option<u64>,
+        bvid: Option<&str>
+    ) -> Result<BpiResponse<u8>, BpiError> {
+        self
+            .get("https://api.bilibili.com/x/web-interface/archive/has/like")
+            .query(&video_id_query(aid, bvid))
+            .send_bpi("查询是否已点赞").await
+    }
+
+    /// 查询已投币数
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/video/action.md)
+    ///
+    /// # 参数
+    /// | 名称   | 类型           | 说明            |
+    /// | ------ | -------------- | --------------- |
+    /// | `aid`  | `Option<u64>`  | 稿件 avid，可选 |
+    /// | `bvid` | `Option<&str>` | 稿件 bvid，可选 |
+    pub async fn video_coin_count(
+        &self,
+        aid: Option<u64>,
+        bvid: Option<&str>
+    ) -> Result<BpiResponse<CoinCountData>, BpiError> {
+        self
+            .get("https://api.bilibili.com/x/web-interface/archive/coins")
+            .query(&video_id_query(aid, bvid))
+            .send_bpi("查询已投币数").await
+    }
+
+    /// 查询是否已收藏
+    ///
+    /// # 文档
+    /// [查看API文档](https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/video/action.md)
+    ///
+    /// # 参数
+    /// | 名称  | 类型   | 说明              |
+    /// | ----- | ------ | ----------------- |
+    /// | `aid` | &str   | 稿件 avid 或 bvid |
+    pub async fn video_favoured(&self, aid: &str) -> Result<BpiResponse<FavouredData>, BpiError> {
+        self
+            .get("https://api.bilibili.com/x/v2/fav/video/favoured")
+            .query(&[("aid", aid)])
+            .send_bpi("查询是否已收藏").await
+    }
+
+    /// 并发查询当前用户对视频的点赞、投币、收藏状态
+    ///
+    /// # 参数
+    /// | 名称  | 类型 | 说明      |
+    /// | ----- | ---- | --------- |
+    /// | `aid` | u64  | 稿件 avid |
+    pub async fn video_action_state(&self, aid: u64) -> Result<VideoActionState, BpiError> {
+        let aid_str = aid.to_string();
+        let (liked, coins, favoured) = tokio::try_join!(
+            async { self.video_has_liked(Some(aid), None).await?.into_data() },
+            async { self.video_coin_count(Some(aid), None).await?.into_data() },
+            async { self.video_favoured(&aid_str).await?.into_data() }
+        )?;
+
+        Ok(VideoActionState {
+            liked: liked == 1,
+            coins: coins.multiply,
+            favoured: favoured.favoured,
+        })
+    }
+}
+
+/// `aid` 与 `bvid` 查询参数，两者都有时只传 `aid`
+fn video_id_query(aid: Option<u64>, bvid: Option<&str>) -> Query {
+    match aid {
+        Some(aid) => Query::new().with("aid", aid),
+        None => Query::new().with_opt("bvid", bvid),
+    }
 }
 
 #[cfg(test)]
@@ -189,8 +344,9 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_like_video() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         match bpi.video_like(Some(10001), None, 1).await {
             Ok(resp) => tracing::info!("点赞响应: {:?}", resp),
@@ -203,8 +359,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_coin_video() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         match bpi.video_coin(Some(10001), None, 1, Some(1)).await {
             Ok(resp) => tracing::info!("投币响应: {:?}", resp),
@@ -217,8 +374,28 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
+    async fn test_triple_video() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Write);
+        let data = bpi.video_triple(Some(10001), None).await?.into_data()?;
+        tracing::info!("三连结果: {:?}", data);
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "authed"]
+    async fn test_video_action_state() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Authed);
+        let state = bpi.video_action_state(10001).await?;
+        tracing::info!("操作状态: {:?}", state);
+        assert!(state.coins <= 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "write"]
     async fn test_favorite_video() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         match bpi.video_favorite(10001, Some(vec!["44717370"]), None).await {
             Ok(resp) => tracing::info!("收藏响应: {:?}", resp),
@@ -224,8 +224,9 @@ mod tests {
     const TEST_AID: &str = "772876546";
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_create_and_add_archives() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi.collection_create_and_add_archives(
             TEST_MID,
             "Rust Bilibili API Test",
@@ -242,8 +243,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_add_archives_to_series() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi.collection_add_archives_to_series(TEST_MID, TEST_SERIES_ID, TEST_AID).await?;
 
         info!("添加稿件至视频列表成功: {:?}", resp);
@@ -252,8 +254,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_update_series() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi.collection_update_series(
             TEST_MID,
             TEST_SERIES_ID,
@@ -270,8 +273,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_delete_archives_from_series() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi.collection_delete_archives_from_series(
             TEST_MID,
             TEST_SERIES_ID,
@@ -284,8 +288,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_delete_series() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         // 假设 TEST_SERIES_ID 是一个需要被删除的测试用列表
         let resp = bpi.collection_delete_series(TEST_MID, TEST_SERIES_ID).await?;
 
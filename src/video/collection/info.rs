@@ -228,14 +228,11 @@ impl BpiClient {
             params.push(("page_size", size.to_string()));
         }
 
-        // 签名
-        let params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/polymer/web-space/seasons_archives_list")
             .with_bilibili_headers()
             .query(&params)
-            .send_bpi("获取视频合集信息").await
+            .send_bpi_wbi("获取视频合集信息").await
     }
 
     /// 只获取系列视频列表
@@ -258,13 +255,10 @@ impl BpiClient {
             ("page_size", page_size.to_string())
         ];
 
-        // 签名
-        let params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/polymer/web-space/home/seasons_series")
             .query(&params)
-            .send_bpi("只获取系列视频列表").await
+            .send_bpi_wbi("只获取系列视频列表").await
     }
 
     /// 获取系列和合集视频列表
@@ -290,13 +284,10 @@ impl BpiClient {
             params.push(("page_size", size.to_string()));
         }
 
-        // 签名
-        let params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/polymer/web-space/seasons_series_list")
             .query(&params)
-            .send_bpi("获取系列和合集视频列表").await
+            .send_bpi_wbi("获取系列和合集视频列表").await
     }
 
     /// 查询指定系列信息
@@ -376,8 +367,9 @@ mod tests {
     const TEST_SERIES_ID: u64 = 250285;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_seasons_archives_list() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_seasons_list(TEST_MID, TEST_SEASON_ID, Some(false), None, None).await?;
         let data = resp.into_data()?;
 
@@ -388,8 +380,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_seasons_series_only() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_series_list(TEST_MID, 1, 10).await?;
         let data = resp.into_data()?;
 
@@ -399,8 +392,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_seasons_series_list() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_seasons_series_list(TEST_MID, Some(1), Some(5)).await?;
         let data = resp.into_data()?;
 
@@ -412,8 +406,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_series_info() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_series_info(TEST_SERIES_ID).await?;
         let data = resp.into_data()?;
 
@@ -424,8 +419,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_series_archives() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_series_archives(
             TEST_MID,
             TEST_SERIES_ID,
@@ -54,11 +54,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_desc() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         match bpi.video_desc(Some(10001), None).await {
             Ok(resp) => {
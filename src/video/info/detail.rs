@@ -454,11 +454,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_detail() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let aid = Some(10001);
         // let aid = Some(114993303389765);
@@ -1,9 +1,23 @@
 //! 查询视频分P列表 (avid/bvid 转 cid)
 //!
+//! 取流、弹幕、字幕等接口都需要 cid，可用 [`BpiClient::video_resolve_cid`] 按页码、标题或 cid 选出分P：
+//!
+//! ```no_run
+//! # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+//! use bpi_rs::utils::aid_bvid::VideoId;
+//! use bpi_rs::video::info::pagelist::PartSelector;
+//!
+//! let id: VideoId = "BV1bx411c7us".parse()?;
+//! let cid = bpi.video_resolve_cid(id, PartSelector::Title("第二集".into())).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/video)
 //!
 //! [文档](https://socialsisteryi.github.io/bilibili-API-collect/docs/video/video.html#查询视频分p列表)
 
+use crate::utils::aid_bvid::VideoId;
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 use serde::{ Deserialize, Serialize };
 
@@ -43,6 +57,28 @@ pub struct PageItem {
     pub ctime: u64,
 }
 
+/// 分P的选择方式
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartSelector {
+    /// 页码，从 1 开始
+    Page(u32),
+    /// 标题包含的文字，取第一个匹配的分P
+    Title(String),
+    /// cid，需属于该稿件
+    Cid(u64),
+}
+
+impl PartSelector {
+    /// 从分P列表中选出分P
+    pub fn select<'a>(&self, pages: &'a [PageItem]) -> Option<&'a PageItem> {
+        match self {
+            Self::Page(page) => pages.iter().find(|p| p.page == *page),
+            Self::Title(title) => pages.iter().find(|p| p.part.contains(title.as_str())),
+            Self::Cid(cid) => pages.iter().find(|p| p.cid == *cid),
+        }
+    }
+}
+
 /// 分P列表响应
 type PageListResponse = BpiResponse<Vec<PageItem>>;
 impl BpiClient {
@@ -75,15 +111,68 @@ impl BpiClient {
             )
             .send_bpi("查询视频分P列表").await
     }
+
+    /// 按页码、标题或 cid 选出分P并返回 cid
+    ///
+    /// 没有匹配的分P时返回 [`BpiError::InvalidParameter`]。
+    ///
+    /// # 参数
+    /// | 名称       | 类型                | 说明              |
+    /// | ---------- | ------------------- | ----------------- |
+    /// | `id`       | `impl Into<VideoId>` | 稿件 avid 或 bvid |
+    /// | `selector` | `PartSelector`      | 分P的选择方式     |
+    pub async fn video_resolve_cid(
+        &self,
+        id: impl Into<VideoId>,
+        selector: PartSelector
+    ) -> Result<u64, BpiError> {
+        let pages = match id.into() {
+            VideoId::Aid(aid) => self.video_pagelist(Some(aid), None).await?,
+            VideoId::Bvid(bvid) => self.video_pagelist(None, Some(&bvid)).await?,
+        }.into_data()?;
+
+        selector
+            .select(&pages)
+            .map(|p| p.cid)
+            .ok_or(BpiError::invalid_parameter("selector", "未找到匹配的分P"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_part_selector() {
+        let pages: Vec<PageItem> = serde_json
+            ::from_value(
+                serde_json::json!([
+                    { "cid": 101, "page": 1, "from": "vupload", "part": "第一集 开端", "duration": 60, "vid": "", "weblink": "", "ctime": 0 },
+                    { "cid": 102, "page": 2, "from": "vupload", "part": "第二集 发展", "duration": 60, "vid": "", "weblink": "", "ctime": 0 }
+                ])
+            )
+            .unwrap();
+
+        assert_eq!(PartSelector::Page(2).select(&pages).map(|p| p.cid), Some(102));
+        assert_eq!(PartSelector::Title("开端".into()).select(&pages).map(|p| p.cid), Some(101));
+        assert_eq!(PartSelector::Cid(102).select(&pages).map(|p| p.page), Some(2));
+        assert!(PartSelector::Page(3).select(&pages).is_none());
+        assert!(PartSelector::Cid(999).select(&pages).is_none());
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_video_resolve_cid() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let cid = bpi.video_resolve_cid(10001, PartSelector::Page(1)).await?;
+        assert!(cid > 0);
+        Ok(())
+    }
+
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_pagelist() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         match bpi.video_pagelist(Some(10001), None).await {
             Ok(resp) => {
@@ -3,6 +3,7 @@
 //! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/video)
 
 use crate::models::Account;
+use crate::utils::aid_bvid::VideoId;
 use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
 use serde::{ Deserialize, Serialize };
 
@@ -366,6 +367,22 @@ impl BpiClient {
             )
             .send_bpi("视频详细信息").await
     }
+
+    /// 获取视频详细信息，使用 WBI 签名的接口
+    ///
+    /// # 文档
+    /// [查看API文档](https://socialsisteryi.github.io/bilibili-API-collect/docs/video/video.html#获取视频详细信息)
+    ///
+    /// # 参数
+    /// | 名称 | 类型                   | 说明                                              |
+    /// | ---- | ---------------------- | ------------------------------------------------- |
+    /// | `id` | `impl Into<VideoId>`   | 稿件 avid 或 bvid，字符串可用 `"BV1..".parse()?` |
+    pub async fn video_view(&self, id: impl Into<VideoId>) -> Result<VideoInfoResponse, BpiError> {
+        self
+            .get("https://api.bilibili.com/x/web-interface/wbi/view")
+            .query(&[id.into().query_pair()])
+            .send_bpi_wbi("视频详细信息").await
+    }
 }
 
 #[cfg(test)]
@@ -373,8 +390,19 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_video_view() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let id: VideoId = "BV1bx411c7us".parse()?;
+        let data = bpi.video_view(id).await?.into_data()?;
+        assert_eq!(data.aid, 10001);
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_info() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
 
         let aid = Some(10001);
         let bvid = None;
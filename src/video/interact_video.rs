@@ -216,8 +216,9 @@ mod tests {
     const TEST_GRAPH_VERSION: u64 = 1273647;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_interactive_video_info_by_aid() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_interactive_video_info(
             Some(TEST_AID),
             None,
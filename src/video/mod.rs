@@ -1,6 +1,7 @@
 //! 视频主模块
 //!
 //! [查看 API 文档](https://github.com/SocialSisterYi/bilibili-API-collect/tree/master/docs/video)
+pub mod access;
 pub mod action;
 pub mod appeal;
 pub mod attribute_data;
@@ -9,6 +10,7 @@ pub mod info;
 pub mod interact_video;
 pub mod online;
 pub mod pbp;
+pub mod playurl;
 pub mod player;
 pub mod recommend;
 pub mod report;
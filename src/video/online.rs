@@ -78,8 +78,9 @@ mod tests {
     const TEST_BVID: &str = "BV1y64y1q757";
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_online_total_by_aid() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_online_total(Some(TEST_AID), None, TEST_CID).await?;
 
         let data = resp.into_data()?;
@@ -92,8 +93,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_online_total_by_bvid() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_online_total(None, Some(TEST_BVID), TEST_CID).await?;
 
         let data = resp.into_data()?;
@@ -235,12 +235,10 @@ impl BpiClient {
         if let Some(e) = ep_id {
             params.push(("ep_id", e.to_string()));
         }
-        let params = self.get_wbi_sign2(params).await?;
-
         self
             .get("https://api.bilibili.com/x/player/wbi/v2")
             .query(&params)
-            .send_bpi("获取 web 播放器信息").await
+            .send_bpi_wbi("获取 web 播放器信息").await
     }
 }
 
@@ -255,8 +253,9 @@ mod tests {
     const TEST_CID: u64 = 636329244;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_player_info_v2_by_aid() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_player_info_v2(Some(TEST_AID), None, TEST_CID, None, None).await?;
         let data = resp.into_data()?;
 
@@ -269,8 +268,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_player_info_v2_by_bvid() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_player_info_v2(Some(TEST_AID), None, TEST_CID, None, None).await?;
         let data = resp.into_data()?;
 
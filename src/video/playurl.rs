@@ -0,0 +1,441 @@
+//! 视频取流
+//!
+//! 使用 [`crate::models`] 中的通用流类型返回 DASH 音视频流、FLV/MP4 直链与可选清晰度，
+//! 可直接配合 [`CodecPreference`] 选流。
+//!
+//! [查看 API 文档](https://socialsisteryi.github.io/bilibili-API-collect/docs/video/videostream_url.html)
+use std::collections::BTreeMap;
+
+use serde::{ Deserialize, Serialize };
+
+use crate::models::{
+    CodecPreference,
+    DashStreams,
+    DashTrack,
+    Durl,
+    Fnval,
+    SupportFormat,
+    VideoCodec,
+    VideoQuality,
+};
+use crate::{ BilibiliRequest, BpiClient, BpiError, BpiResponse };
+
+/// 未指定 `fnval` 时使用的取流方式：DASH 及全部可选的画质、音质与编码
+pub const DEFAULT_FNVAL: Fnval = Fnval::DASH
+    .union(Fnval::HDR)
+    .union(Fnval::FOURK)
+    .union(Fnval::DOLBY_AUDIO)
+    .union(Fnval::DOLBY_VISION)
+    .union(Fnval::EIGHTK)
+    .union(Fnval::AV1);
+
+/// 视频取流数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoPlayUrl {
+    /// 当前清晰度代码
+    pub quality: u32,
+    /// 当前格式
+    pub format: String,
+    /// 时长，毫秒
+    pub timelength: u64,
+    /// 支持的格式，逗号分隔
+    pub accept_format: String,
+    /// 清晰度描述，与 `accept_quality` 一一对应
+    pub accept_description: Vec<String>,
+    /// 清晰度代码列表
+    pub accept_quality: Vec<u32>,
+    /// 视频编码ID
+    pub video_codecid: u32,
+    /// FLV/MP4 直链，未请求 DASH 或稿件不支持 DASH 时存在
+    #[serde(default)]
+    pub durl: Option<Vec<Durl>>,
+    /// DASH 流
+    #[serde(default)]
+    pub dash: Option<DashStreams>,
+    /// 支持的格式列表
+    #[serde(default)]
+    pub support_formats: Vec<SupportFormat>,
+    /// 上次播放进度，毫秒
+    #[serde(default)]
+    pub last_play_time: u64,
+    /// 上次播放的分P cid
+    #[serde(default)]
+    pub last_play_cid: u64,
+    /// 是否为试看片段，充电专属或付费视频无权观看时为 1
+    #[serde(default)]
+    pub is_preview: Option<u8>,
+}
+
+/// 视频流，DASH 优先，不可用时回退为直链
+#[derive(Debug, Clone, Copy)]
+pub enum PlayStream<'a> {
+    Dash(&'a DashStreams),
+    Durl(&'a [Durl]),
+}
+
+impl VideoPlayUrl {
+    /// 可用的视频流，DASH 优先
+    pub fn stream(&self) -> Option<PlayStream<'_>> {
+        if let Some(dash) = &self.dash {
+            return Some(PlayStream::Dash(dash));
+        }
+        self.durl
+            .as_deref()
+            .filter(|durl| !durl.is_empty())
+            .map(PlayStream::Durl)
+    }
+
+    /// 可选清晰度，`(清晰度代码, 描述)`，从高到低
+    pub fn qualities(&self) -> Vec<(u32, &str)> {
+        self.accept_quality
+            .iter()
+            .copied()
+            .zip(self.accept_description.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// 获取最高清晰度的视频流，同清晰度取码率最高者
+    pub fn best_video(&self) -> Option<&DashTrack> {
+        self.dash.as_ref().and_then(|dash| {
+            dash.video.iter().max_by(|a, b| a.id.cmp(&b.id).then_with(|| a.bandwidth.cmp(&b.bandwidth)))
+        })
+    }
+
+    /// 按编码协商规则选择视频流
+    pub fn select_video(&self, preference: &CodecPreference) -> Option<&DashTrack> {
+        self.dash.as_ref().and_then(|dash| preference.select(&dash.video))
+    }
+
+    /// 获取码率最高的普通音频流
+    pub fn best_audio(&self) -> Option<&DashTrack> {
+        self.dash.as_ref().and_then(|dash| dash.audio.iter().max_by_key(|a| a.bandwidth))
+    }
+
+    /// DASH 视频流中各清晰度可用的编码，按清晰度代码升序
+    pub fn dash_codecs(&self) -> BTreeMap<u32, Vec<VideoCodec>> {
+        let mut codecs: BTreeMap<u32, Vec<VideoCodec>> = BTreeMap::new();
+        for track in self.dash.iter().flat_map(|dash| &dash.video) {
+            let Some(codec) = VideoCodec::from_codecid(track.codecid).or_else(||
+                VideoCodec::from_codecs(&track.codecs)
+            ) else {
+                continue;
+            };
+            let entry = codecs.entry(track.id).or_default();
+            if !entry.contains(&codec) {
+                entry.push(codec);
+            }
+        }
+        codecs
+    }
+
+    /// 获取无损或杜比音频流，优先无损
+    pub fn hires_audio(&self) -> Option<&DashTrack> {
+        let dash = self.dash.as_ref()?;
+        dash.flac
+            .as_ref()
+            .map(|flac| &flac.audio)
+            .or_else(|| dash.dolby.as_ref().and_then(|dolby| dolby.audio.first()))
+    }
+}
+
+/// 某一清晰度下可用的编码
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QualityCodecs {
+    /// 清晰度代码
+    pub quality: u32,
+    /// 清晰度描述
+    pub description: String,
+    /// 可用编码，按 AVC、HEVC、AV1 排序
+    pub codecs: Vec<VideoCodec>,
+}
+
+/// 视频的清晰度与编码组合
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodecAvailability {
+    /// 可获取的清晰度，从高到低
+    pub qualities: Vec<QualityCodecs>,
+    /// 稿件支持但当前账号无法获取的清晰度代码，通常需要登录或大会员
+    pub restricted: Vec<u32>,
+}
+
+impl CodecAvailability {
+    /// 清晰度与编码组合是否可用
+    pub fn has(&self, quality: u32, codec: VideoCodec) -> bool {
+        self.codecs_for(quality).contains(&codec)
+    }
+
+    /// 清晰度下可用的编码
+    pub fn codecs_for(&self, quality: u32) -> &[VideoCodec] {
+        self.qualities
+            .iter()
+            .find(|q| q.quality == quality)
+            .map(|q| q.codecs.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 提供该编码的清晰度代码，从高到低
+    pub fn qualities_with(&self, codec: VideoCodec) -> Vec<u32> {
+        self.qualities
+            .iter()
+            .filter(|q| q.codecs.contains(&codec))
+            .map(|q| q.quality)
+            .collect()
+    }
+
+    /// 合并多次取流的结果，`accept` 为稿件支持的 `(清晰度代码, 描述)`
+    fn collect(accept: &[(u32, &str)], found: &BTreeMap<u32, Vec<VideoCodec>>) -> Self {
+        let mut availability = Self::default();
+        for &(quality, description) in accept {
+            match found.get(&quality) {
+                Some(codecs) => {
+                    let mut codecs = codecs.clone();
+                    codecs.sort_by_key(|codec| codec.as_u32());
+                    availability.qualities.push(QualityCodecs {
+                        quality,
+                        description: description.to_string(),
+                        codecs,
+                    });
+                }
+                None => availability.restricted.push(quality),
+            }
+        }
+        availability
+    }
+}
+
+impl BpiClient {
+    /// 获取视频流地址
+    ///
+    /// # 文档
+    /// [查看API文档](https://socialsisteryi.github.io/bilibili-API-collect/docs/video/videostream_url.html#获取视频流地址)
+    ///
+    /// # 参数
+    /// | 名称    | 类型                    | 说明                                  |
+    /// | ------- | ----------------------- | ------------------------------------- |
+    /// | `bvid`  | &str                    | 稿件 bvid                             |
+    /// | `cid`   | u64                     | 视频 cid                              |
+    /// | `qn`    | `Option<VideoQuality>`  | 清晰度，DASH 方式下返回全部清晰度     |
+    /// | `fnval` | `Option<Fnval>`         | 取流方式，默认 [`DEFAULT_FNVAL`]      |
+    pub async fn video_stream(
+        &self,
+        bvid: &str,
+        cid: u64,
+        qn: Option<VideoQuality>,
+        fnval: Option<Fnval>
+    ) -> Result<BpiResponse<VideoPlayUrl>, BpiError> {
+        let fnval = fnval.unwrap_or(DEFAULT_FNVAL);
+
+        let mut params = vec![
+            ("bvid", bvid.to_string()),
+            ("cid", cid.to_string()),
+            ("fnval", fnval.bits().to_string()),
+            ("fnver", "0".to_string())
+        ];
+        if fnval.is_fourk() {
+            params.push(("fourk", "1".to_string()));
+        }
+        if let Some(q) = qn {
+            params.push(("qn", q.as_u32().to_string()));
+        }
+
+        self
+            .get("https://api.bilibili.com/x/player/wbi/playurl")
+            .with_bilibili_headers()
+            .query(&params)
+            .send_bpi_wbi("获取视频流地址").await
+    }
+
+    /// 探测视频可用的清晰度与编码组合
+    ///
+    /// 先以默认参数取流，再对稿件支持但未返回视频流的清晰度逐个指定 `qn` 并发补充请求，
+    /// 仍无法获取的清晰度记入 [`CodecAvailability::restricted`]。补充请求失败时视为不可用。
+    ///
+    /// # 参数
+    /// | 名称   | 类型 | 说明      |
+    /// | ------ | ---- | --------- |
+    /// | `bvid` | &str | 稿件 bvid |
+    /// | `cid`  | u64  | 视频 cid  |
+    pub async fn video_codecs_available(&self, bvid: &str, cid: u64) -> Result<CodecAvailability, BpiError> {
+        let first = self.video_stream(bvid, cid, None, None).await?.into_data()?;
+        let mut found = first.dash_codecs();
+
+        let missing: Vec<VideoQuality> = first.accept_quality
+            .iter()
+            .filter(|qn| !found.contains_key(qn))
+            .filter_map(|&qn| VideoQuality::from_qn(qn))
+            .collect();
+        let probes = missing.into_iter().map(|qn| self.video_stream(bvid, cid, Some(qn), None));
+
+        for result in futures_util::future::join_all(probes).await {
+            match result.and_then(|resp| resp.into_data()) {
+                Ok(data) => {
+                    for (quality, codecs) in data.dash_codecs() {
+                        let entry = found.entry(quality).or_default();
+                        for codec in codecs {
+                            if !entry.contains(&codec) {
+                                entry.push(codec);
+                            }
+                        }
+                    }
+                }
+                Err(e) => tracing::warn!("{} 补充取流失败: {}", bvid, e),
+            }
+        }
+
+        Ok(CodecAvailability::collect(&first.qualities(), &found))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_BVID: &str = "BV1y7411Q7Eq";
+    const TEST_CID: u64 = 171776208;
+
+    fn track(id: u32, codecid: u32, codecs: &str, bandwidth: u32) -> serde_json::Value {
+        serde_json::json!({
+            "id": id, "base_url": format!("https://upos/{}-{}", id, codecid), "backup_url": [],
+            "bandwidth": bandwidth, "mime_type": "video/mp4", "codecs": codecs,
+            "width": 1920, "height": 1080, "frame_rate": "30", "sar": "1:1", "start_with_sap": 1,
+            "segment_base": { "initialization": "0-1000", "index_range": "1001-2000" },
+            "codecid": codecid, "size": 0
+        })
+    }
+
+    #[test]
+    fn test_parse_dash() {
+        let data: VideoPlayUrl = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "quality": 80, "format": "flv", "timelength": 60000,
+                    "accept_format": "flv,flv720,flv480,mp4",
+                    "accept_description": ["高清 1080P", "高清 720P"],
+                    "accept_quality": [80, 64],
+                    "video_codecid": 7,
+                    "dash": {
+                        "duration": 60, "min_buffer_time": 1.5,
+                        "video": [
+                            track(80, 7, "avc1.640032", 2000),
+                            track(80, 12, "hev1.1.6.L120.90", 1000),
+                            track(64, 7, "avc1.640028", 800)
+                        ],
+                        "audio": [track(30280, 0, "mp4a.40.2", 320), track(30216, 0, "mp4a.40.2", 64)],
+                        "dolby": null, "flac": null
+                    },
+                    "support_formats": [{
+                        "quality": 80, "format": "flv", "new_description": "1080P 高清",
+                        "display_desc": "1080P", "superscript": "", "codecs": ["avc1.640032"]
+                    }]
+                })
+            )
+            .unwrap();
+
+        assert!(matches!(data.stream(), Some(PlayStream::Dash(_))));
+        assert_eq!(data.qualities(), vec![(80, "高清 1080P"), (64, "高清 720P")]);
+        assert_eq!(data.best_video().map(|t| t.codecid), Some(7));
+        assert_eq!(data.best_audio().map(|t| t.id), Some(30280));
+        assert!(data.hires_audio().is_none());
+
+        let hevc = data.select_video(&CodecPreference::new([VideoCodec::Hevc])).unwrap();
+        assert_eq!(hevc.codecid, 12);
+    }
+
+    #[test]
+    fn test_codec_availability() {
+        let data: VideoPlayUrl = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "quality": 80, "format": "flv", "timelength": 60000,
+                    "accept_format": "hdflv2,flv,flv720",
+                    "accept_description": ["高清 1080P+", "高清 1080P", "高清 720P"],
+                    "accept_quality": [112, 80, 64],
+                    "video_codecid": 7,
+                    "dash": {
+                        "duration": 60, "min_buffer_time": 1.5,
+                        "video": [
+                            track(80, 13, "av01.0.08M.08.0.110.01.01.01.0", 900),
+                            track(80, 7, "avc1.640032", 2000),
+                            track(80, 12, "hev1.1.6.L120.90", 1000),
+                            track(64, 7, "avc1.640028", 800)
+                        ],
+                        "audio": [], "dolby": null, "flac": null
+                    }
+                })
+            )
+            .unwrap();
+
+        let found = data.dash_codecs();
+        assert_eq!(found[&80], vec![VideoCodec::Av1, VideoCodec::Avc, VideoCodec::Hevc]);
+
+        let availability = CodecAvailability::collect(&data.qualities(), &found);
+        assert_eq!(availability.restricted, vec![112]);
+        assert_eq!(availability.codecs_for(80), [VideoCodec::Avc, VideoCodec::Hevc, VideoCodec::Av1]);
+        assert!(availability.has(64, VideoCodec::Avc));
+        assert!(!availability.has(64, VideoCodec::Av1));
+        assert_eq!(availability.qualities_with(VideoCodec::Avc), vec![80, 64]);
+        assert_eq!(availability.qualities[0].description, "高清 1080P");
+    }
+
+    #[test]
+    fn test_parse_durl_fallback() {
+        let data: VideoPlayUrl = serde_json
+            ::from_value(
+                serde_json::json!({
+                    "quality": 64, "format": "mp4720", "timelength": 60000,
+                    "accept_format": "mp4720", "accept_description": ["高清 720P"],
+                    "accept_quality": [64], "video_codecid": 7,
+                    "durl": [{
+                        "order": 1, "length": 60000, "size": 1024, "ahead": "", "vhead": "",
+                        "url": "https://upos/1.mp4", "backup_url": []
+                    }]
+                })
+            )
+            .unwrap();
+
+        match data.stream() {
+            Some(PlayStream::Durl(durl)) => assert_eq!(durl[0].url, "https://upos/1.mp4"),
+            other => panic!("unexpected stream: {:?}", other),
+        }
+        assert!(data.best_video().is_none());
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_video_stream() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi.video_stream(TEST_BVID, TEST_CID, None, None).await?.into_data()?;
+
+        tracing::info!("清晰度: {:?}", data.qualities());
+        let video = data.best_video().ok_or_else(BpiError::missing_data)?;
+        tracing::info!("最佳视频流: {} {}", video.id, video.codecs);
+        assert!(data.best_audio().is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_video_codecs_available() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let availability = bpi.video_codecs_available(TEST_BVID, TEST_CID).await?;
+        for quality in &availability.qualities {
+            tracing::info!("{} {}: {:?}", quality.quality, quality.description, quality.codecs);
+        }
+        tracing::info!("无法获取: {:?}", availability.restricted);
+        assert!(!availability.qualities.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_video_stream_mp4() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let data = bpi
+            .video_stream(TEST_BVID, TEST_CID, Some(VideoQuality::P480), Some(Fnval::MP4)).await?
+            .into_data()?;
+
+        assert!(matches!(data.stream(), Some(PlayStream::Durl(_))));
+        Ok(())
+    }
+}
@@ -249,8 +249,9 @@ mod tests {
     const TEST_AID: u64 = 10001;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_related_videos_by_aid() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_related_videos(Some(TEST_AID), None).await?;
         let data = resp.into_data()?;
 
@@ -263,8 +264,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_homepage_recommendations() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_homepage_recommendations(Some(12), Some(1), Some(1)).await?;
         let data = resp.into_data()?;
 
@@ -53,8 +53,9 @@ mod tests {
     const TEST_CID: u64 = 16546;
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_report_watch_progress() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         // 上报观看进度为 1248 秒
         let resp = bpi.video_report_watch_progress(TEST_AID, TEST_CID, Some(120)).await?;
 
@@ -112,8 +112,9 @@ mod tests {
     const TEST_UP_MID: u64 = 34893;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_ai_summary_by_aid() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_ai_summary(Some(TEST_AID), None, TEST_CID, TEST_UP_MID).await?;
         let data = resp.into_data()?;
 
@@ -73,8 +73,9 @@ mod tests {
     const TEST_CID: u64 = 153322313;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_tags_by_aid() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_tags(Some(TEST_AID), None, Some(TEST_CID)).await?;
         let data = resp.into_data()?;
 
@@ -86,8 +87,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_tags_by_bvid() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_tags(None, Some(TEST_BVID), None).await?;
         let data = resp.into_data()?;
 
@@ -198,8 +198,9 @@ mod tests {
     const TEST_CID: u64 = 28104724389;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_playurl_mp4_by_aid() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 请求 MP4 格式，720P
         let resp = bpi.video_playurl(
             Some(TEST_AID),
@@ -223,8 +224,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_playurl_4k() -> Result<(), BpiError> {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 请求 4K
         let resp = bpi.video_playurl(
             Some(TEST_AID),
@@ -244,13 +244,13 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use chrono::{ Duration, Local };
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_region_dynamic() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let rid = 21; // 日常分区
         let ps = Some(2);
         let pn = Some(1);
@@ -268,8 +268,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_region_tag_dynamic() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let rid = 136; // 音游分区
         let tag_id = 10026108; // Phigros
         let ps = Some(2);
@@ -288,8 +289,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_region_newlist_rank() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let cate_id = 231; // 计算机技术
         let pagesize = 2;
         let today = Local::now().date_naive();
@@ -4,3 +4,4 @@ pub mod dynamic;
 pub mod popular;
 pub mod precious_videos;
 pub mod ranking;
+pub mod snapshot;
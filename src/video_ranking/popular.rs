@@ -146,12 +146,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_popular_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_popular_list(Some(1), Some(2)).await;
 
         info!("{:?}", resp);
@@ -166,8 +166,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_popular_series_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_popular_series_list().await;
 
         info!("{:?}", resp);
@@ -181,8 +182,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_popular_series_one() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.video_popular_series_one(1).await;
 
         info!("{:?}", resp);
@@ -61,12 +61,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_ranking_list() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 获取全站排行榜
         let resp = bpi.video_ranking_list(None, None).await;
 
@@ -86,8 +86,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_ranking_list_with_rid() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 获取日常分区排行榜 (rid=21)
         let resp = bpi.video_ranking_list(Some(21), None).await;
 
@@ -103,8 +104,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_video_ranking_list_with_type() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 获取新人排行榜
         let resp = bpi.video_ranking_list(None, Some("rookie")).await;
 
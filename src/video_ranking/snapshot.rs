@@ -0,0 +1,410 @@
+//! 排行榜快照
+//!
+//! 按天保存排行榜与热门列表，便于做趋势分析：[`SnapshotStore`] 负责存取快照，
+//! [`FsSnapshotStore`] 将每天的快照保存为一个 JSON 文件，[`RankingSnapshot::diff`]
+//! 比较两天的快照，得出新上榜、掉榜与名次变化的视频。
+//!
+//! 存储以 JSON 保存任意实现了 [`Snapshot`] 的快照，番剧评分走势等也复用同一存储。
+//!
+//! ```no_run
+//! # async fn run(bpi: &bpi_rs::BpiClient) -> Result<(), bpi_rs::BpiError> {
+//! use bpi_rs::video_ranking::snapshot::FsSnapshotStore;
+//!
+//! let mut store = FsSnapshotStore::new("snapshots");
+//! let snapshot = bpi.video_ranking_snapshot(None, None).await?;
+//! let diff = bpi.video_snapshot_save(&mut store, &snapshot)?;
+//! if let Some(diff) = diff {
+//!     println!("新上榜 {} 个，掉榜 {} 个", diff.added.len(), diff.dropped.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use serde::de::DeserializeOwned;
+use serde::{ Deserialize, Serialize };
+
+use crate::{ BpiClient, BpiError };
+
+/// 快照中的一个视频
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotEntry {
+    /// 名次，从 1 开始
+    pub rank: u32,
+    pub aid: u64,
+    pub bvid: String,
+    pub title: String,
+    /// UP 主名称
+    pub owner: String,
+    /// 播放数
+    pub view: u64,
+}
+
+impl SnapshotEntry {
+    /// 从排行榜或热门列表中的视频 JSON 提取
+    fn from_value(rank: u32, value: &serde_json::Value) -> Self {
+        let str_at = |pointer: &str| {
+            value
+                .pointer(pointer)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        Self {
+            rank,
+            aid: value["aid"].as_u64().unwrap_or_default(),
+            bvid: str_at("/bvid"),
+            title: str_at("/title"),
+            owner: str_at("/owner/name"),
+            view: value.pointer("/stat/view").and_then(|v| v.as_u64()).unwrap_or_default(),
+        }
+    }
+}
+
+/// 某天的榜单快照
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RankingSnapshot {
+    /// 榜单名，如 `ranking-all-0`、`popular`，同时作为存储目录名
+    pub kind: String,
+    /// 快照日期（本地时间）
+    pub date: NaiveDate,
+    /// 获取时的秒级时间戳
+    pub fetched_at: i64,
+    pub entries: Vec<SnapshotEntry>,
+}
+
+/// 名次变化
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankChange {
+    pub entry: SnapshotEntry,
+    /// 之前的名次
+    pub from: u32,
+}
+
+impl RankChange {
+    /// 名次上升的位数，下降为负
+    pub fn delta(&self) -> i64 {
+        i64::from(self.from) - i64::from(self.entry.rank)
+    }
+}
+
+/// 两次快照的差异
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SnapshotDiff {
+    /// 新上榜
+    pub added: Vec<SnapshotEntry>,
+    /// 掉出榜单，名次为之前的名次
+    pub dropped: Vec<SnapshotEntry>,
+    /// 名次变化，不含名次不变的视频
+    pub moved: Vec<RankChange>,
+}
+
+impl RankingSnapshot {
+    /// 由视频 JSON 列表创建当天的快照
+    pub fn new(kind: impl Into<String>, items: &[serde_json::Value]) -> Self {
+        let now = chrono::Local::now();
+        Self {
+            kind: kind.into(),
+            date: now.date_naive(),
+            fetched_at: now.timestamp(),
+            entries: items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| SnapshotEntry::from_value((i as u32) + 1, item))
+                .collect(),
+        }
+    }
+
+    /// 与之前的快照比较，按 bvid 匹配视频
+    pub fn diff(&self, previous: &RankingSnapshot) -> SnapshotDiff {
+        let before: HashMap<&str, &SnapshotEntry> = previous.entries
+            .iter()
+            .map(|e| (e.bvid.as_str(), e))
+            .collect();
+        let now: HashMap<&str, &SnapshotEntry> = self.entries
+            .iter()
+            .map(|e| (e.bvid.as_str(), e))
+            .collect();
+
+        let mut diff = SnapshotDiff::default();
+        for entry in &self.entries {
+            match before.get(entry.bvid.as_str()) {
+                None => diff.added.push(entry.clone()),
+                Some(old) if old.rank != entry.rank => {
+                    diff.moved.push(RankChange { entry: entry.clone(), from: old.rank });
+                }
+                Some(_) => {}
+            }
+        }
+        diff.dropped = previous.entries
+            .iter()
+            .filter(|e| !now.contains_key(e.bvid.as_str()))
+            .cloned()
+            .collect();
+        diff
+    }
+}
+
+/// 按天保存的快照
+pub trait Snapshot: Serialize + DeserializeOwned {
+    /// 快照种类，同一种类的快照存放在一起
+    fn kind(&self) -> &str;
+
+    /// 快照日期
+    fn date(&self) -> NaiveDate;
+}
+
+impl Snapshot for RankingSnapshot {
+    fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    fn date(&self) -> NaiveDate {
+        self.date
+    }
+}
+
+/// 快照存储
+///
+/// 实现方只需存取 JSON，按类型读写快照由默认方法完成。
+pub trait SnapshotStore {
+    /// 保存 JSON 形式的快照，同一种类同一天的快照会被覆盖
+    fn save_value(
+        &mut self,
+        kind: &str,
+        date: NaiveDate,
+        value: &serde_json::Value
+    ) -> Result<(), BpiError>;
+
+    /// 读取指定日期 JSON 形式的快照
+    fn load_value(&self, kind: &str, date: NaiveDate) -> Result<Option<serde_json::Value>, BpiError>;
+
+    /// 已保存的日期，从早到晚排列
+    fn dates(&self, kind: &str) -> Result<Vec<NaiveDate>, BpiError>;
+
+    /// 保存快照，同一种类同一天的快照会被覆盖
+    fn save<S: Snapshot>(&mut self, snapshot: &S) -> Result<(), BpiError> where Self: Sized {
+        self.save_value(snapshot.kind(), snapshot.date(), &serde_json::to_value(snapshot)?)
+    }
+
+    /// 读取指定日期的快照
+    fn load<S: Snapshot>(&self, kind: &str, date: NaiveDate) -> Result<Option<S>, BpiError>
+        where Self: Sized
+    {
+        match self.load_value(kind, date)? {
+            Some(value) => Ok(Some(serde_json::from_value(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// 指定日期之前最近的一次快照
+    fn previous<S: Snapshot>(&self, kind: &str, date: NaiveDate) -> Result<Option<S>, BpiError>
+        where Self: Sized
+    {
+        match self.dates(kind)?.into_iter().rfind(|d| *d < date) {
+            Some(d) => self.load(kind, d),
+            None => Ok(None),
+        }
+    }
+
+    /// 全部快照，从早到晚排列
+    fn history<S: Snapshot>(&self, kind: &str) -> Result<Vec<S>, BpiError> where Self: Sized {
+        let mut snapshots = Vec::new();
+        for date in self.dates(kind)? {
+            snapshots.extend(self.load(kind, date)?);
+        }
+        Ok(snapshots)
+    }
+}
+
+/// 文件系统快照存储，路径为 `{root}/{kind}/{YYYY-MM-DD}.json`
+#[derive(Debug, Clone)]
+pub struct FsSnapshotStore {
+    root: PathBuf,
+}
+
+impl FsSnapshotStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn dir(&self, kind: &str) -> PathBuf {
+        let name: String = kind
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        self.root.join(name)
+    }
+
+    fn path(&self, kind: &str, date: NaiveDate) -> PathBuf {
+        self.dir(kind).join(format!("{}.json", date.format("%Y-%m-%d")))
+    }
+}
+
+impl SnapshotStore for FsSnapshotStore {
+    fn save_value(
+        &mut self,
+        kind: &str,
+        date: NaiveDate,
+        value: &serde_json::Value
+    ) -> Result<(), BpiError> {
+        let dir = self.dir(kind);
+        fs::create_dir_all(&dir).map_err(|e| BpiError::io_at("创建目录", &dir, e))?;
+        let path = self.path(kind, date);
+        let json = serde_json::to_vec_pretty(value)?;
+        fs::write(&path, json).map_err(|e| BpiError::io_at("写入", &path, e))
+    }
+
+    fn load_value(&self, kind: &str, date: NaiveDate) -> Result<Option<serde_json::Value>, BpiError> {
+        let path = self.path(kind, date);
+        match fs::read(&path) {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(BpiError::io_at("读取", &path, e)),
+        }
+    }
+
+    fn dates(&self, kind: &str) -> Result<Vec<NaiveDate>, BpiError> {
+        let dir = self.dir(kind);
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Vec::new());
+            }
+            Err(e) => {
+                return Err(BpiError::io_at("读取目录", &dir, e));
+            }
+        };
+        let mut dates: Vec<NaiveDate> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                NaiveDate::parse_from_str(name.strip_suffix(".json")?, "%Y-%m-%d").ok()
+            })
+            .collect();
+        dates.sort();
+        Ok(dates)
+    }
+}
+
+impl BpiClient {
+    /// 获取分区排行榜快照
+    ///
+    /// # 参数
+    /// | 名称        | 类型           | 说明                 |
+    /// | ----------- | --------------| -------------------- |
+    /// | `rid`       | `Option<u32>`   | 目标分区 tid，默认0(全站) |
+    /// | `type_name` | `Option<&str>`  | 榜单类型 all/rookie/origin，默认 all |
+    pub async fn video_ranking_snapshot(
+        &self,
+        rid: Option<u32>,
+        type_name: Option<&str>
+    ) -> Result<RankingSnapshot, BpiError> {
+        let data = self.video_ranking_list(rid, type_name).await?.into_data()?;
+        let items: Vec<serde_json::Value> = data.list
+            .into_iter()
+            .map(|item| item.inner)
+            .collect();
+        let kind = format!("ranking-{}-{}", type_name.unwrap_or("all"), rid.unwrap_or(0));
+        Ok(RankingSnapshot::new(kind, &items))
+    }
+
+    /// 获取热门视频快照，逐页获取直到没有更多或达到 `max_pages`
+    ///
+    /// # 参数
+    /// | 名称        | 类型  | 说明         |
+    /// | ----------- | ----- | ------------ |
+    /// | `max_pages` | u32   | 最多获取页数 |
+    pub async fn video_popular_snapshot(&self, max_pages: u32) -> Result<RankingSnapshot, BpiError> {
+        let mut items = Vec::new();
+        for pn in 1..=max_pages {
+            let data = self.video_popular_list(Some(pn), Some(20)).await?.into_data()?;
+            items.extend(data.list);
+            if data.no_more {
+                break;
+            }
+        }
+        Ok(RankingSnapshot::new("popular", &items))
+    }
+
+    /// 保存快照，并与同一榜单之前最近的一次快照比较
+    ///
+    /// 没有更早的快照时返回 `None`。
+    pub fn video_snapshot_save(
+        &self,
+        store: &mut impl SnapshotStore,
+        snapshot: &RankingSnapshot
+    ) -> Result<Option<SnapshotDiff>, BpiError> {
+        let previous: Option<RankingSnapshot> = store.previous(&snapshot.kind, snapshot.date)?;
+        store.save(snapshot)?;
+        Ok(previous.map(|previous| snapshot.diff(&previous)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn video(bvid: &str, view: u64) -> serde_json::Value {
+        serde_json::json!({
+            "aid": 1, "bvid": bvid, "title": bvid, "owner": { "name": "碧诗" }, "stat": { "view": view }
+        })
+    }
+
+    fn snapshot(date: &str, bvids: &[&str]) -> RankingSnapshot {
+        let items: Vec<_> = bvids
+            .iter()
+            .map(|b| video(b, 100))
+            .collect();
+        let mut snapshot = RankingSnapshot::new("ranking-all-0", &items);
+        snapshot.date = date.parse().unwrap();
+        snapshot
+    }
+
+    #[test]
+    fn test_snapshot_diff() {
+        let before = snapshot("2025-01-01", &["BV1", "BV2", "BV3"]);
+        let after = snapshot("2025-01-02", &["BV3", "BV1", "BV4"]);
+        assert_eq!(after.entries[0].owner, "碧诗");
+        assert_eq!(after.entries[0].view, 100);
+
+        let diff = after.diff(&before);
+        assert_eq!(diff.added[0].bvid, "BV4");
+        assert_eq!(diff.dropped[0].bvid, "BV2");
+        assert_eq!(diff.moved.len(), 2);
+        assert_eq!((diff.moved[0].entry.bvid.as_str(), diff.moved[0].delta()), ("BV3", 2));
+        assert_eq!((diff.moved[1].entry.bvid.as_str(), diff.moved[1].delta()), ("BV1", -1));
+    }
+
+    #[test]
+    fn test_fs_snapshot_store() {
+        let root = std::env::temp_dir().join(format!("bpi-snapshot-{}", uuid::Uuid::new_v4()));
+        let mut store = FsSnapshotStore::new(&root);
+        let bpi = BpiClient::try_new_local().unwrap();
+
+        let first = snapshot("2025-01-01", &["BV1", "BV2"]);
+        assert!(bpi.video_snapshot_save(&mut store, &first).unwrap().is_none());
+        let second = snapshot("2025-01-03", &["BV2"]);
+        let diff = bpi.video_snapshot_save(&mut store, &second).unwrap().unwrap();
+        assert_eq!(diff.dropped[0].bvid, "BV1");
+
+        assert_eq!(store.dates("ranking-all-0").unwrap(), [first.date, second.date]);
+        assert_eq!(store.load("ranking-all-0", first.date).unwrap(), Some(first));
+        assert!(store.dates("popular").unwrap().is_empty());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "guest"]
+    async fn test_video_ranking_snapshot() -> Result<(), BpiError> {
+        let bpi = crate::test_client!(Guest);
+        let snapshot = bpi.video_ranking_snapshot(None, None).await?;
+        tracing::info!("{} {} 共 {} 个视频", snapshot.kind, snapshot.date, snapshot.entries.len());
+        Ok(())
+    }
+}
@@ -52,11 +52,10 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_receive_vip_privilege() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         // 1: B币券，2: 会员购优惠券等
         let resp = bpi.vip_receive_privilege(1).await;
         match resp {
@@ -68,8 +67,9 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_add_vip_experience() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
         let resp = bpi.vip_add_experience().await;
         match resp {
             Ok(resp) => { assert_eq!(resp.code, 0) }
@@ -89,10 +89,11 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_vip_center_info_comprehensive() {
         tracing::info!("开始测试大会员中心信息的综合功能");
 
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.vip_center_info().await;
 
         match resp {
@@ -193,10 +194,11 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_time_calculation() {
         tracing::info!("开始测试时间计算功能");
 
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.vip_center_info().await;
 
         match resp {
@@ -23,8 +23,9 @@ mod tests {
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "write"]
     async fn test_vip_sign_cookie() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Write);
 
         let resp = bpi.vip_sign().await;
         assert!(resp.is_ok());
@@ -57,12 +57,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "authed"]
     async fn test_get_user_wallet() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Authed);
         let resp = bpi.wallet_info().await;
         info!("响应: {:?}", resp);
         assert!(resp.is_ok());
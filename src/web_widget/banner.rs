@@ -46,14 +46,14 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use crate::video::video_zone_v2::{ Douga, VideoPartitionV2 };
 
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_region_banner() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         // 例如 region_id = 1 (动画)
         let resp = bpi.web_widget_region_banner(VideoPartitionV2::Douga(Douga::Douga)).await;
         info!("响应: {:?}", resp);
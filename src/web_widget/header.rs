@@ -121,12 +121,12 @@ impl BpiClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use tracing::info;
 
     #[tokio::test]
+    #[ignore = "guest"]
     async fn test_get_header_page() {
-        let bpi = BpiClient::new();
+        let bpi = crate::test_client!(Guest);
         let resp = bpi.web_widget_header_page().await;
         info!("响应: {:?}", resp);
         assert!(resp.is_ok());